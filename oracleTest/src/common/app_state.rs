@@ -1,10 +1,28 @@
+use crate::common::health::Health;
+use crate::repositories::stores::DbPool;
+use crate::services::attachment_service::AttachmentService;
+use crate::services::auth_service::AuthService;
 use crate::services::board_service::BoardService;
+use crate::services::category_service::CategoryService;
+use crate::services::comment_service::CommentService;
 use std::sync::Arc;
 
 /// 애플리케이션의 공유 상태를 나타내는 구조체.
-/// 모든 핸들러에서 접근할 수 있도록 `BoardService` 인스턴스를 포함합니다.
+/// 모든 핸들러에서 접근할 수 있도록 `BoardService`, `CategoryService`, `CommentService`, `AttachmentService`, `AuthService` 인스턴스를 포함합니다.
 #[derive(Clone)]
 pub struct AppState {
     /// `BoardService` 인스턴스를 `Arc`로 래핑하여 여러 스레드에서 안전하게 공유하고 접근할 수 있도록 합니다.
     pub service: Arc<BoardService>,
+    /// 게시글 카테고리 관리를 담당하는 `CategoryService` 인스턴스
+    pub categories: Arc<CategoryService>,
+    /// 게시글 댓글을 담당하는 `CommentService` 인스턴스
+    pub comments: Arc<CommentService>,
+    /// 게시글 첨부파일 업로드/다운로드를 담당하는 `AttachmentService` 인스턴스
+    pub attachments: Arc<AttachmentService>,
+    /// 회원가입/로그인 및 토큰 검증을 담당하는 `AuthService` 인스턴스
+    pub auth: Arc<AuthService>,
+    /// 저장소들이 공유하는 커넥션 풀 (`/metrics`, `/readyz`에서 풀 상태를 노출할 때 사용)
+    pub pool: DbPool,
+    /// `/readyz`의 DB ping 시간 제한과 종료 진행 여부
+    pub health: Arc<Health>,
 }
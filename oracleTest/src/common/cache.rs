@@ -0,0 +1,226 @@
+//! 읽기 전용 조회 결과를 메모리에 보관하는 LRU + TTL 캐시
+//!
+//! 캐시에 없는 키를 동시에 여러 요청이 찾으면 첫 요청만 값을 읽어 오고, 나머지는 그 결과를 기다렸다가 함께 씁니다.
+//! 적중/실패 수는 캐시마다 따로 세며 `cache_requests_total` 메트릭으로도 노출합니다.
+
+use crate::common::metrics::metrics;
+use lru::LruCache;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// 캐시 적중/실패 수와 현재 항목 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    /// 값을 읽어 온(DB를 조회한) 횟수
+    pub misses: u64,
+    pub entries: usize,
+    /// 지금 값을 읽어 오는 중인 키 수
+    pub loading: usize,
+}
+
+impl std::ops::Add for CacheStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            entries: self.entries + other.entries,
+            loading: self.loading + other.loading,
+        }
+    }
+}
+
+struct Entry<V> {
+    value: V,
+    expires_at: Instant,
+}
+
+/// 값을 읽어 오는 중인 키 하나의 상태
+#[derive(Default)]
+struct Loading {
+    /// 같은 키의 동시 실패를 한 번의 조회로 합치는 잠금
+    gate: Arc<tokio::sync::Mutex<()>>,
+    /// 이 키를 읽어 오거나 기다리는 요청 수 (0이 되면 항목을 치웁니다)
+    waiters: usize,
+    /// 이 키를 무효화할 때마다 1 증가
+    generation: u64,
+}
+
+struct State<K, V> {
+    entries: LruCache<K, Entry<V>>,
+    /// 값을 읽어 오는 중인 키별 상태
+    loading: HashMap<K, Loading>,
+    /// 모든 항목을 버릴 때마다 1 증가
+    generation: u64,
+}
+
+impl<K: Hash + Eq, V> State<K, V> {
+    /// 캐시 전체와 키의 무효화 세대. 읽어 오는 사이 둘 중 하나라도 바뀌었다면 읽은 값을 저장하지 않습니다.
+    /// 키 하나를 버려도 다른 키를 읽어 오던 요청은 영향을 받지 않습니다.
+    fn generation_of(&self, key: &K) -> (u64, u64) {
+        let key_generation = self
+            .loading
+            .get(key)
+            .map_or(0, |loading| loading.generation);
+        (self.generation, key_generation)
+    }
+}
+
+/// 키별로 값을 읽어 와 보관하는 캐시
+pub struct ReadThroughCache<K, V> {
+    name: &'static str,
+    ttl: Duration,
+    state: Mutex<State<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K, V> ReadThroughCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// 최대 `capacity`개의 항목을 `ttl` 동안 보관하는 캐시를 만듭니다. (`name`은 메트릭 라벨)
+    pub fn new(name: &'static str, capacity: usize, ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            name,
+            ttl,
+            state: Mutex::new(State {
+                entries: LruCache::new(capacity),
+                loading: HashMap::new(),
+                generation: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// 보관된 값을 반환하고, 없거나 만료되었으면 `load`로 읽어 와 보관합니다.
+    ///
+    /// 같은 키를 읽어 오는 중이면 끝날 때까지 기다렸다가 그 값을 씁니다.
+    /// 읽기에 실패하면 보관하지 않으므로, 기다리던 요청은 각자 다시 읽어 옵니다.
+    pub async fn get_or_load<E, F, Fut>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.lookup(&key) {
+            return Ok(value);
+        }
+        let gate = {
+            let mut state = self.state();
+            let loading = state.loading.entry(key.clone()).or_default();
+            loading.waiters += 1;
+            loading.gate.clone()
+        };
+        // 읽기가 끝나거나 요청이 취소되어 future가 버려지면 기다리는 요청 수를 줄이고, 마지막이면 항목을 치웁니다.
+        let _waiter = LoadingGuard {
+            cache: self,
+            key: &key,
+        };
+        let _loading = gate.lock().await;
+        // 먼저 읽어 온 요청이 채워 두었으면 다시 읽지 않습니다.
+        if let Some(value) = self.lookup(&key) {
+            return Ok(value);
+        }
+
+        self.record(false);
+        let generation = self.state().generation_of(&key);
+        let result = load().await;
+        let mut state = self.state();
+        if let Ok(value) = &result
+            && state.generation_of(&key) == generation
+        {
+            let entry = Entry {
+                value: value.clone(),
+                expires_at: Instant::now() + self.ttl,
+            };
+            state.entries.put(key.clone(), entry);
+        }
+        drop(state);
+        result
+    }
+
+    /// 키 하나를 버립니다. 그 키를 읽어 오는 중인 값도 저장하지 않습니다.
+    pub fn invalidate(&self, key: &K) {
+        let mut state = self.state();
+        state.entries.pop(key);
+        if let Some(loading) = state.loading.get_mut(key) {
+            loading.generation += 1;
+        }
+    }
+
+    /// 모든 항목을 버립니다.
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.generation += 1;
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: state.entries.len(),
+            loading: state.loading.len(),
+        }
+    }
+
+    /// 만료되지 않은 값을 찾아 적중으로 기록합니다. 만료된 항목은 이때 버립니다.
+    fn lookup(&self, key: &K) -> Option<V> {
+        let mut state = self.state();
+        let fresh = state
+            .entries
+            .get(key)
+            .map(|entry| (entry.expires_at > Instant::now()).then(|| entry.value.clone()));
+        match fresh {
+            Some(Some(value)) => {
+                drop(state);
+                self.record(true);
+                Some(value)
+            }
+            Some(None) => {
+                state.entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        metrics().observe_cache(self.name, hit);
+    }
+
+    fn state(&self) -> MutexGuard<'_, State<K, V>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 키를 읽어 오거나 기다리던 요청이 끝날 때 자기 몫을 치우는 가드
+struct LoadingGuard<'a, K: Hash + Eq, V> {
+    cache: &'a ReadThroughCache<K, V>,
+    key: &'a K,
+}
+
+impl<K: Hash + Eq, V> Drop for LoadingGuard<'_, K, V> {
+    fn drop(&mut self) {
+        let mut state = self.cache.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(loading) = state.loading.get_mut(self.key) {
+            loading.waiters -= 1;
+            if loading.waiters == 0 {
+                state.loading.remove(self.key);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! HTTP 조건부 요청 헤더(`If-Match`, `If-None-Match`)와 ETag 비교 유틸리티 (RFC 9110)
+
+/// `If-Match` 헤더가 `etag`와 일치하는지 강한 비교(strong comparison)로 확인합니다.
+/// `*`는 리소스가 존재하기만 하면 일치하며, 약한 ETag(`W/"..."`)는 일치하지 않습니다.
+pub fn if_match(header: &str, etag: &str) -> bool {
+    tags(header).any(|tag| tag == "*" || (!tag.starts_with("W/") && tag == etag))
+}
+
+/// `If-None-Match` 헤더가 `etag`와 일치하는지 약한 비교(weak comparison)로 확인합니다.
+pub fn if_none_match(header: &str, etag: &str) -> bool {
+    let etag = strip_weak(etag);
+    tags(header).any(|tag| tag == "*" || strip_weak(tag) == etag)
+}
+
+/// 쉼표로 구분된 엔티티 태그 목록
+fn tags(header: &str) -> impl Iterator<Item = &str> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+}
+
+fn strip_weak(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
@@ -0,0 +1,37 @@
+//! 헬스 체크 상태: 준비(readiness) 판단에 필요한 설정과 종료 진행 여부
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// `/readyz` 판단에 쓰이는 공유 상태.
+///
+/// 우아한 종료가 시작되면 `main`이 `mark_shutting_down`을 호출하고,
+/// 이후 준비 상태 확인은 DB 연결과 무관하게 실패하여 로드 밸런서가 새 요청을 보내지 않도록 합니다.
+#[derive(Debug)]
+pub struct Health {
+    /// DB ping 시간 제한 (커넥션 대기 포함)
+    ping_timeout: Duration,
+    shutting_down: AtomicBool,
+}
+
+impl Health {
+    pub fn new(ping_timeout: Duration) -> Self {
+        Self {
+            ping_timeout,
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    pub fn ping_timeout(&self) -> Duration {
+        self.ping_timeout
+    }
+
+    /// 종료가 시작되었음을 표시합니다. 이후 준비 상태 확인은 항상 실패합니다.
+    pub fn mark_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,105 @@
+//! 게시글 본문을 화면에 바로 넣을 수 있는 HTML로 변환하는 유틸리티
+//!
+//! Markdown은 pulldown-cmark로 렌더링한 뒤 ammonia로 정화합니다.
+//! 본문에 직접 쓴 HTML도 같은 허용 목록을 거치므로, 목록에 없는 태그·속성·URL 스킴은 모두 제거됩니다.
+
+use crate::models::board::ContentFormat;
+use ammonia::Builder;
+use pulldown_cmark::{Options, Parser, html};
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// 정화 후 남길 수 있는 태그
+const ALLOWED_TAGS: &[&str] = &[
+    "p",
+    "br",
+    "hr",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "strong",
+    "em",
+    "del",
+    "code",
+    "pre",
+    "blockquote",
+    "ul",
+    "ol",
+    "li",
+    "a",
+    "img",
+    "table",
+    "thead",
+    "tbody",
+    "tr",
+    "th",
+    "td",
+];
+
+/// 태그별로 남길 수 있는 속성 (그 외 속성과 모든 이벤트 핸들러는 제거)
+const ALLOWED_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href", "title"]),
+    ("img", &["src", "alt", "title"]),
+    ("ol", &["start"]),
+];
+
+/// 링크와 이미지에 허용하는 URL 스킴 (`javascript:`, `data:` 등은 속성째 제거)
+const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// 사용자 링크에 붙이는 `rel` (작성자가 지정한 값은 덮어씁니다)
+const LINK_REL: &str = "noopener noreferrer nofollow";
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+    builder
+        .tags(ALLOWED_TAGS.iter().copied().collect())
+        .tag_attributes(
+            ALLOWED_ATTRIBUTES
+                .iter()
+                .map(|(tag, attributes)| (*tag, attributes.iter().copied().collect()))
+                .collect::<HashMap<_, HashSet<_>>>(),
+        )
+        .generic_attributes(HashSet::new())
+        .url_schemes(ALLOWED_URL_SCHEMES.iter().copied().collect())
+        .link_rel(Some(LINK_REL));
+    builder
+});
+
+/// 본문을 형식에 맞게 HTML로 변환합니다. 반환값은 항상 허용 목록만 남은 안전한 HTML입니다.
+pub fn render_content(content: &str, format: ContentFormat) -> String {
+    match format {
+        ContentFormat::Plain => plain_to_html(content),
+        ContentFormat::Markdown => markdown_to_html(content),
+    }
+}
+
+/// Markdown(표, 취소선 포함)을 렌더링한 뒤 정화합니다.
+fn markdown_to_html(content: &str) -> String {
+    let parser = Parser::new_ext(
+        content,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    );
+    let mut rendered = String::with_capacity(content.len() * 3 / 2);
+    html::push_html(&mut rendered, parser);
+    SANITIZER.clean(&rendered).to_string()
+}
+
+/// 일반 텍스트는 HTML 특수 문자를 이스케이프하고 줄바꿈만 `<br>`로 바꿉니다.
+fn plain_to_html(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+    for ch in content.replace("\r\n", "\n").chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("<br>\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
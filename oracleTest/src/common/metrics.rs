@@ -0,0 +1,210 @@
+//! Prometheus 메트릭 레지스트리: HTTP 요청, 커넥션 풀, 블로킹 작업 대기열, 조회 캐시, 프로세스 메모리
+//!
+//! 메트릭은 프로세스 전역 레지스트리 하나에 등록되며 `GET /metrics`에서 텍스트 형식으로 노출됩니다.
+
+use crate::common::shutdown::BlockingGuard;
+use crate::common::utils::current_rss_kb;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use r2d2::event::{CheckoutEvent, HandleEvent, TimeoutEvent};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::{Span, error};
+
+/// 대기 시간 히스토그램 버킷 (초): 커넥션 풀 대기, `spawn_blocking` 대기열
+const WAIT_BUCKETS: &[f64] = &[
+    0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// 전역 메트릭 레지스트리를 반환합니다.
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// 애플리케이션 메트릭 모음
+pub struct Metrics {
+    registry: Registry,
+    /// 라우트/메서드/상태 코드별 요청 수
+    http_requests: IntCounterVec,
+    /// 라우트/메서드/상태 코드별 처리 시간 (초)
+    http_request_duration: HistogramVec,
+    /// 커넥션 풀의 유휴/사용 중 커넥션 수 (스크레이프 시점 기준)
+    db_pool_connections: IntGaugeVec,
+    /// 커넥션을 얻기까지 기다린 시간 (초)
+    db_pool_checkout_wait: Histogram,
+    /// 커넥션을 얻지 못하고 시간 초과된 횟수
+    db_pool_checkout_timeouts: IntCounter,
+    /// `spawn_blocking` 작업이 실행되기까지 대기열에서 기다린 시간 (초)
+    blocking_queue_wait: Histogram,
+    /// 캐시별 조회 결과 수 (`result=hit|miss`, miss는 DB를 조회한 횟수)
+    cache_requests: IntCounterVec,
+    /// 프로세스 RSS (바이트, 값을 읽을 수 없는 시스템에서는 0)
+    process_resident_memory: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "처리한 HTTP 요청 수"),
+            &["method", "route", "status"],
+        )
+        .expect("http_requests_total 메트릭 정의 오류");
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP 요청 처리 시간 (초)"),
+            &["method", "route", "status"],
+        )
+        .expect("http_request_duration_seconds 메트릭 정의 오류");
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new(
+                "db_pool_connections",
+                "커넥션 풀의 커넥션 수 (state=idle|active)",
+            ),
+            &["state"],
+        )
+        .expect("db_pool_connections 메트릭 정의 오류");
+        let db_pool_checkout_wait = Histogram::with_opts(
+            HistogramOpts::new(
+                "db_pool_checkout_wait_seconds",
+                "커넥션 풀에서 커넥션을 얻기까지 기다린 시간 (초)",
+            )
+            .buckets(WAIT_BUCKETS.to_vec()),
+        )
+        .expect("db_pool_checkout_wait_seconds 메트릭 정의 오류");
+        let db_pool_checkout_timeouts = IntCounter::new(
+            "db_pool_checkout_timeouts_total",
+            "커넥션 풀 대기 시간 초과 횟수",
+        )
+        .expect("db_pool_checkout_timeouts_total 메트릭 정의 오류");
+        let blocking_queue_wait = Histogram::with_opts(
+            HistogramOpts::new(
+                "blocking_task_queue_seconds",
+                "spawn_blocking 작업이 실행되기까지 기다린 시간 (초)",
+            )
+            .buckets(WAIT_BUCKETS.to_vec()),
+        )
+        .expect("blocking_task_queue_seconds 메트릭 정의 오류");
+        let cache_requests = IntCounterVec::new(
+            Opts::new(
+                "cache_requests_total",
+                "캐시 조회 수 (result=hit|miss, miss는 DB를 조회한 횟수)",
+            ),
+            &["cache", "result"],
+        )
+        .expect("cache_requests_total 메트릭 정의 오류");
+        let process_resident_memory = IntGauge::new(
+            "process_resident_memory_bytes",
+            "프로세스 RSS 메모리 사용량 (바이트)",
+        )
+        .expect("process_resident_memory_bytes 메트릭 정의 오류");
+
+        let collectors: [Box<dyn prometheus::core::Collector>; 8] = [
+            Box::new(http_requests.clone()),
+            Box::new(http_request_duration.clone()),
+            Box::new(db_pool_connections.clone()),
+            Box::new(db_pool_checkout_wait.clone()),
+            Box::new(db_pool_checkout_timeouts.clone()),
+            Box::new(blocking_queue_wait.clone()),
+            Box::new(cache_requests.clone()),
+            Box::new(process_resident_memory.clone()),
+        ];
+        for collector in collectors {
+            registry.register(collector).expect("메트릭 중복 등록 오류");
+        }
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_checkout_wait,
+            db_pool_checkout_timeouts,
+            blocking_queue_wait,
+            cache_requests,
+            process_resident_memory,
+        }
+    }
+
+    /// 처리한 HTTP 요청 하나를 기록합니다. `route`는 매칭된 라우트 패턴(예: `/boards/:id`)입니다.
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// 캐시 조회 결과 하나를 기록합니다. `cache`는 캐시 이름(예: `board_detail`)입니다.
+    pub fn observe_cache(&self, cache: &str, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        self.cache_requests
+            .with_label_values(&[cache, result])
+            .inc();
+    }
+
+    /// 스크레이프 시점의 풀 상태와 메모리 사용량을 반영한 뒤 Prometheus 텍스트 형식으로 출력합니다.
+    pub fn render(&self, pool: r2d2::State) -> String {
+        let idle = i64::from(pool.idle_connections);
+        let active = i64::from(pool.connections) - idle;
+        self.db_pool_connections
+            .with_label_values(&["idle"])
+            .set(idle);
+        self.db_pool_connections
+            .with_label_values(&["active"])
+            .set(active);
+        let rss_bytes = current_rss_kb().map_or(0, |kb| kb.saturating_mul(1024));
+        self.process_resident_memory
+            .set(i64::try_from(rss_bytes).unwrap_or(i64::MAX));
+
+        let mut buffer = Vec::new();
+        if let Err(err) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("[Metrics] 메트릭 인코딩 실패: {}", err);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// 커넥션 풀 이벤트를 메트릭으로 기록하는 r2d2 이벤트 핸들러.
+/// 풀을 만들 때 `Pool::builder().event_handler(Box::new(PoolMetrics))`로 등록합니다.
+#[derive(Debug)]
+pub struct PoolMetrics;
+
+impl HandleEvent for PoolMetrics {
+    fn handle_checkout(&self, event: CheckoutEvent) {
+        metrics()
+            .db_pool_checkout_wait
+            .observe(event.duration().as_secs_f64());
+    }
+
+    fn handle_timeout(&self, _event: TimeoutEvent) {
+        metrics().db_pool_checkout_timeouts.inc();
+    }
+}
+
+/// `tokio::task::spawn_blocking`과 같지만, 작업이 블로킹 스레드에서 실행되기까지
+/// 대기열에서 기다린 시간을 `blocking_task_queue_seconds`에 기록합니다.
+/// 우아한 종료 시 처리 중인 작업을 기다릴 수 있도록 작업 수도 추적합니다.
+/// 호출한 쪽의 tracing span을 블로킹 스레드로 넘기므로 SQL 로그가 요청 ID와 함께 남습니다.
+pub fn spawn_blocking<F, R>(f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let queued_at = Instant::now();
+    let guard = BlockingGuard::new();
+    let span = Span::current();
+    tokio::task::spawn_blocking(move || {
+        let _guard = guard;
+        let _entered = span.enter();
+        metrics()
+            .blocking_queue_wait
+            .observe(queued_at.elapsed().as_secs_f64());
+        f()
+    })
+}
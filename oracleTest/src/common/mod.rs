@@ -1,3 +1,9 @@
 pub mod app_state;
+pub mod cache;
+pub mod etag;
+pub mod health;
+pub mod markdown;
+pub mod metrics;
 pub mod queries;
+pub mod shutdown;
 pub mod utils;
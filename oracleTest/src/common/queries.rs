@@ -1,7 +1,192 @@
 pub const INSERT_BOARD: &str = include_str!("../sql/insert_board.sql");
 pub const UPDATE_BOARD: &str = include_str!("../sql/update_board.sql");
+pub const SOFT_DELETE_BOARD: &str = include_str!("../sql/soft_delete_board.sql");
+pub const RESTORE_BOARD: &str = include_str!("../sql/restore_board.sql");
 pub const DELETE_BOARD: &str = include_str!("../sql/delete_board.sql");
+// SELECT_BOARD_COUNT/PAGED/KEYSET의 `{where}`, `{order_by}`는 저장소에서 검색 조건으로 치환합니다.
 pub const SELECT_BOARD_COUNT: &str = include_str!("../sql/select_board_count.sql");
 pub const SELECT_BOARD_BY_ID: &str = include_str!("../sql/select_board_by_id.sql");
+pub const SELECT_BOARD_FOR_UPDATE: &str = include_str!("../sql/select_board_for_update.sql");
 pub const SELECT_BOARD_SEQ_CURRVAL: &str = include_str!("../sql/select_board_seq_currval.sql");
 pub const SELECT_BOARD_PAGED: &str = include_str!("../sql/select_board_paged.sql"); // 새로 추가
+pub const SELECT_BOARD_KEYSET: &str = include_str!("../sql/select_board_keyset.sql");
+pub const SELECT_TRASHED_BOARD_BY_ID: &str = include_str!("../sql/select_trashed_board_by_id.sql");
+pub const SELECT_TRASH_COUNT: &str = include_str!("../sql/select_trash_count.sql");
+pub const SELECT_TRASH_PAGED: &str = include_str!("../sql/select_trash_paged.sql");
+pub const SELECT_EXPIRED_TRASH_IDS: &str = include_str!("../sql/select_expired_trash_ids.sql");
+pub const INSERT_BOARD_REVISION: &str = include_str!("../sql/insert_board_revision.sql");
+pub const SELECT_BOARD_REVISIONS: &str = include_str!("../sql/select_board_revisions.sql");
+pub const SELECT_BOARD_REVISION: &str = include_str!("../sql/select_board_revision.sql");
+pub const DELETE_REVISIONS_BY_BOARD: &str = include_str!("../sql/delete_revisions_by_board.sql");
+pub const INSERT_USER: &str = include_str!("../sql/insert_user.sql");
+pub const SELECT_USER_BY_USERNAME: &str = include_str!("../sql/select_user_by_username.sql");
+pub const SELECT_USER_SEQ_CURRVAL: &str = include_str!("../sql/select_user_seq_currval.sql");
+pub const INSERT_COMMENT: &str = include_str!("../sql/insert_comment.sql");
+pub const UPDATE_COMMENT: &str = include_str!("../sql/update_comment.sql");
+pub const DELETE_COMMENT: &str = include_str!("../sql/delete_comment.sql");
+pub const DELETE_COMMENTS_BY_BOARD: &str = include_str!("../sql/delete_comments_by_board.sql");
+pub const SELECT_COMMENT_BY_ID: &str = include_str!("../sql/select_comment_by_id.sql");
+pub const SELECT_COMMENT_SEQ_CURRVAL: &str = include_str!("../sql/select_comment_seq_currval.sql");
+pub const SELECT_COMMENT_ROOT_COUNT: &str = include_str!("../sql/select_comment_root_count.sql");
+pub const SELECT_COMMENT_ROOTS_PAGED: &str = include_str!("../sql/select_comment_roots_paged.sql");
+pub const SELECT_COMMENT_REPLIES: &str = include_str!("../sql/select_comment_replies.sql");
+pub const INSERT_ATTACHMENT: &str = include_str!("../sql/insert_attachment.sql");
+pub const DELETE_ATTACHMENTS_BY_BOARD: &str =
+    include_str!("../sql/delete_attachments_by_board.sql");
+pub const SELECT_ATTACHMENT_BY_ID: &str = include_str!("../sql/select_attachment_by_id.sql");
+pub const SELECT_ATTACHMENTS_BY_BOARD: &str =
+    include_str!("../sql/select_attachments_by_board.sql");
+pub const SELECT_ATTACHMENT_SEQ_CURRVAL: &str =
+    include_str!("../sql/select_attachment_seq_currval.sql");
+pub const UPDATE_BOARD_CATEGORY: &str = include_str!("../sql/update_board_category.sql");
+pub const DELETE_BOARD_TAGS: &str = include_str!("../sql/delete_board_tags.sql");
+pub const UPSERT_TAG: &str = include_str!("../sql/upsert_tag.sql");
+pub const INSERT_BOARD_TAG: &str = include_str!("../sql/insert_board_tag.sql");
+// SELECT_BOARD_TAGS의 `{ids}`는 저장소에서 게시글 수만큼의 위치 바인드 변수로 치환합니다.
+pub const SELECT_BOARD_TAGS: &str = include_str!("../sql/select_board_tags.sql");
+pub const SELECT_TAG_COUNTS: &str = include_str!("../sql/select_tag_counts.sql");
+pub const SELECT_CATEGORIES: &str = include_str!("../sql/select_categories.sql");
+pub const SELECT_CATEGORY_BY_ID: &str = include_str!("../sql/select_category_by_id.sql");
+pub const SELECT_CATEGORY_BY_NAME: &str = include_str!("../sql/select_category_by_name.sql");
+pub const INSERT_CATEGORY: &str = include_str!("../sql/insert_category.sql");
+pub const UPDATE_CATEGORY: &str = include_str!("../sql/update_category.sql");
+pub const DELETE_CATEGORY: &str = include_str!("../sql/delete_category.sql");
+pub const CLEAR_BOARD_CATEGORY: &str = include_str!("../sql/clear_board_category.sql");
+pub const SELECT_CATEGORY_SEQ_CURRVAL: &str =
+    include_str!("../sql/select_category_seq_currval.sql");
+pub const UPDATE_BOARD_VIEW_COUNT: &str = include_str!("../sql/update_board_view_count.sql");
+pub const UPSERT_BOARD_VIEWS_DAILY: &str = include_str!("../sql/upsert_board_views_daily.sql");
+pub const INSERT_BOARD_LIKE: &str = include_str!("../sql/insert_board_like.sql");
+pub const DELETE_BOARD_LIKE: &str = include_str!("../sql/delete_board_like.sql");
+pub const SELECT_BOARD_LIKE_COUNT: &str = include_str!("../sql/select_board_like_count.sql");
+pub const DELETE_BOARD_LIKES: &str = include_str!("../sql/delete_board_likes.sql");
+pub const DELETE_BOARD_VIEWS: &str = include_str!("../sql/delete_board_views.sql");
+pub const SELECT_POPULAR_BOARDS: &str = include_str!("../sql/select_popular_boards.sql");
+pub const CREATE_SCHEMA_MIGRATIONS: &str = include_str!("../sql/create_schema_migrations.sql");
+pub const SELECT_SCHEMA_MIGRATIONS_EXISTS: &str =
+    include_str!("../sql/select_schema_migrations_exists.sql");
+pub const SELECT_SCHEMA_MIGRATIONS: &str = include_str!("../sql/select_schema_migrations.sql");
+pub const INSERT_SCHEMA_MIGRATION: &str = include_str!("../sql/insert_schema_migration.sql");
+pub const DELETE_SCHEMA_MIGRATION: &str = include_str!("../sql/delete_schema_migration.sql");
+
+/// 스키마 마이그레이션 스크립트 (`src/sql/migrations/`, 버전별 up/down)
+pub mod migrations {
+    pub const INITIAL_SCHEMA_UP: &str =
+        include_str!("../sql/migrations/0001_initial_schema.up.sql");
+    pub const INITIAL_SCHEMA_DOWN: &str =
+        include_str!("../sql/migrations/0001_initial_schema.down.sql");
+    pub const CATEGORIES_AND_TAGS_UP: &str =
+        include_str!("../sql/migrations/0002_categories_and_tags.up.sql");
+    pub const CATEGORIES_AND_TAGS_DOWN: &str =
+        include_str!("../sql/migrations/0002_categories_and_tags.down.sql");
+    pub const VIEWS_AND_LIKES_UP: &str =
+        include_str!("../sql/migrations/0003_views_and_likes.up.sql");
+    pub const VIEWS_AND_LIKES_DOWN: &str =
+        include_str!("../sql/migrations/0003_views_and_likes.down.sql");
+    pub const CONTENT_FORMAT_UP: &str =
+        include_str!("../sql/migrations/0004_content_format.up.sql");
+    pub const CONTENT_FORMAT_DOWN: &str =
+        include_str!("../sql/migrations/0004_content_format.down.sql");
+}
+
+/// SQLite 백엔드 전용 쿼리 (`src/sql/sqlite/`)
+pub mod sqlite {
+    pub const CREATE_SCHEMA_MIGRATIONS: &str =
+        include_str!("../sql/sqlite/create_schema_migrations.sql");
+    pub const SELECT_SCHEMA_MIGRATIONS: &str =
+        include_str!("../sql/sqlite/select_schema_migrations.sql");
+    pub const INSERT_SCHEMA_MIGRATION: &str =
+        include_str!("../sql/sqlite/insert_schema_migration.sql");
+    pub const DELETE_SCHEMA_MIGRATION: &str =
+        include_str!("../sql/sqlite/delete_schema_migration.sql");
+    pub const INSERT_BOARD: &str = include_str!("../sql/sqlite/insert_board.sql");
+    pub const UPDATE_BOARD: &str = include_str!("../sql/sqlite/update_board.sql");
+    pub const SOFT_DELETE_BOARD: &str = include_str!("../sql/sqlite/soft_delete_board.sql");
+    pub const RESTORE_BOARD: &str = include_str!("../sql/sqlite/restore_board.sql");
+    pub const DELETE_BOARD: &str = include_str!("../sql/sqlite/delete_board.sql");
+    pub const SELECT_BOARD_COUNT: &str = include_str!("../sql/sqlite/select_board_count.sql");
+    pub const SELECT_BOARD_BY_ID: &str = include_str!("../sql/sqlite/select_board_by_id.sql");
+    pub const SELECT_BOARD_FOR_UPDATE: &str =
+        include_str!("../sql/sqlite/select_board_for_update.sql");
+    pub const SELECT_BOARD_PAGED: &str = include_str!("../sql/sqlite/select_board_paged.sql");
+    pub const SELECT_BOARD_KEYSET: &str = include_str!("../sql/sqlite/select_board_keyset.sql");
+    pub const SELECT_TRASHED_BOARD_BY_ID: &str =
+        include_str!("../sql/sqlite/select_trashed_board_by_id.sql");
+    pub const SELECT_TRASH_COUNT: &str = include_str!("../sql/sqlite/select_trash_count.sql");
+    pub const SELECT_TRASH_PAGED: &str = include_str!("../sql/sqlite/select_trash_paged.sql");
+    pub const SELECT_EXPIRED_TRASH_IDS: &str =
+        include_str!("../sql/sqlite/select_expired_trash_ids.sql");
+    pub const INSERT_BOARD_REVISION: &str = include_str!("../sql/sqlite/insert_board_revision.sql");
+    pub const SELECT_BOARD_REVISIONS: &str =
+        include_str!("../sql/sqlite/select_board_revisions.sql");
+    pub const SELECT_BOARD_REVISION: &str = include_str!("../sql/sqlite/select_board_revision.sql");
+    pub const DELETE_REVISIONS_BY_BOARD: &str =
+        include_str!("../sql/sqlite/delete_revisions_by_board.sql");
+    pub const INSERT_USER: &str = include_str!("../sql/sqlite/insert_user.sql");
+    pub const SELECT_USER_BY_USERNAME: &str =
+        include_str!("../sql/sqlite/select_user_by_username.sql");
+    pub const INSERT_COMMENT: &str = include_str!("../sql/sqlite/insert_comment.sql");
+    pub const UPDATE_COMMENT: &str = include_str!("../sql/sqlite/update_comment.sql");
+    pub const DELETE_COMMENT: &str = include_str!("../sql/sqlite/delete_comment.sql");
+    pub const DELETE_COMMENTS_BY_BOARD: &str =
+        include_str!("../sql/sqlite/delete_comments_by_board.sql");
+    pub const SELECT_COMMENT_BY_ID: &str = include_str!("../sql/sqlite/select_comment_by_id.sql");
+    pub const SELECT_COMMENT_ROOT_COUNT: &str =
+        include_str!("../sql/sqlite/select_comment_root_count.sql");
+    pub const SELECT_COMMENT_ROOTS_PAGED: &str =
+        include_str!("../sql/sqlite/select_comment_roots_paged.sql");
+    pub const SELECT_COMMENT_REPLIES: &str =
+        include_str!("../sql/sqlite/select_comment_replies.sql");
+    pub const INSERT_ATTACHMENT: &str = include_str!("../sql/sqlite/insert_attachment.sql");
+    pub const DELETE_ATTACHMENTS_BY_BOARD: &str =
+        include_str!("../sql/sqlite/delete_attachments_by_board.sql");
+    pub const SELECT_ATTACHMENT_BY_ID: &str =
+        include_str!("../sql/sqlite/select_attachment_by_id.sql");
+    pub const SELECT_ATTACHMENTS_BY_BOARD: &str =
+        include_str!("../sql/sqlite/select_attachments_by_board.sql");
+    pub const UPDATE_BOARD_CATEGORY: &str = include_str!("../sql/sqlite/update_board_category.sql");
+    pub const DELETE_BOARD_TAGS: &str = include_str!("../sql/sqlite/delete_board_tags.sql");
+    pub const UPSERT_TAG: &str = include_str!("../sql/sqlite/upsert_tag.sql");
+    pub const INSERT_BOARD_TAG: &str = include_str!("../sql/sqlite/insert_board_tag.sql");
+    pub const SELECT_BOARD_TAGS: &str = include_str!("../sql/sqlite/select_board_tags.sql");
+    pub const SELECT_TAG_COUNTS: &str = include_str!("../sql/sqlite/select_tag_counts.sql");
+    pub const SELECT_CATEGORIES: &str = include_str!("../sql/sqlite/select_categories.sql");
+    pub const SELECT_CATEGORY_BY_ID: &str = include_str!("../sql/sqlite/select_category_by_id.sql");
+    pub const SELECT_CATEGORY_BY_NAME: &str =
+        include_str!("../sql/sqlite/select_category_by_name.sql");
+    pub const INSERT_CATEGORY: &str = include_str!("../sql/sqlite/insert_category.sql");
+    pub const UPDATE_CATEGORY: &str = include_str!("../sql/sqlite/update_category.sql");
+    pub const DELETE_CATEGORY: &str = include_str!("../sql/sqlite/delete_category.sql");
+    pub const CLEAR_BOARD_CATEGORY: &str = include_str!("../sql/sqlite/clear_board_category.sql");
+    pub const UPDATE_BOARD_VIEW_COUNT: &str =
+        include_str!("../sql/sqlite/update_board_view_count.sql");
+    pub const UPSERT_BOARD_VIEWS_DAILY: &str =
+        include_str!("../sql/sqlite/upsert_board_views_daily.sql");
+    pub const INSERT_BOARD_LIKE: &str = include_str!("../sql/sqlite/insert_board_like.sql");
+    pub const DELETE_BOARD_LIKE: &str = include_str!("../sql/sqlite/delete_board_like.sql");
+    pub const SELECT_BOARD_LIKE_COUNT: &str =
+        include_str!("../sql/sqlite/select_board_like_count.sql");
+    pub const DELETE_BOARD_LIKES: &str = include_str!("../sql/sqlite/delete_board_likes.sql");
+    pub const DELETE_BOARD_VIEWS: &str = include_str!("../sql/sqlite/delete_board_views.sql");
+    pub const SELECT_POPULAR_BOARDS: &str = include_str!("../sql/sqlite/select_popular_boards.sql");
+
+    /// SQLite 스키마 마이그레이션 스크립트 (`src/sql/sqlite/migrations/`)
+    pub mod migrations {
+        pub const INITIAL_SCHEMA_UP: &str =
+            include_str!("../sql/sqlite/migrations/0001_initial_schema.up.sql");
+        pub const INITIAL_SCHEMA_DOWN: &str =
+            include_str!("../sql/sqlite/migrations/0001_initial_schema.down.sql");
+        pub const CATEGORIES_AND_TAGS_UP: &str =
+            include_str!("../sql/sqlite/migrations/0002_categories_and_tags.up.sql");
+        pub const CATEGORIES_AND_TAGS_DOWN: &str =
+            include_str!("../sql/sqlite/migrations/0002_categories_and_tags.down.sql");
+        pub const VIEWS_AND_LIKES_UP: &str =
+            include_str!("../sql/sqlite/migrations/0003_views_and_likes.up.sql");
+        pub const VIEWS_AND_LIKES_DOWN: &str =
+            include_str!("../sql/sqlite/migrations/0003_views_and_likes.down.sql");
+        pub const CONTENT_FORMAT_UP: &str =
+            include_str!("../sql/sqlite/migrations/0004_content_format.up.sql");
+        pub const CONTENT_FORMAT_DOWN: &str =
+            include_str!("../sql/sqlite/migrations/0004_content_format.down.sql");
+    }
+}
@@ -0,0 +1,84 @@
+//! 우아한 종료 지원: 종료 신호 대기와 처리 중인 블로킹 DB 작업 추적
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// 실행 중이거나 대기열에 있는 블로킹 작업 수
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+/// 블로킹 작업이 끝날 때마다 깨우는 알림
+static IDLE: Notify = Notify::const_new();
+
+/// 블로킹 작업 하나가 끝날 때까지 살아 있는 추적 가드.
+///
+/// `common::metrics::spawn_blocking`이 작업마다 하나씩 만들며,
+/// 작업 클로저와 함께 드롭되므로 패닉으로 끝나도 집계에서 빠집니다.
+pub(crate) struct BlockingGuard(());
+
+impl BlockingGuard {
+    pub(crate) fn new() -> Self {
+        IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+        BlockingGuard(())
+    }
+}
+
+impl Drop for BlockingGuard {
+    fn drop(&mut self) {
+        if IN_FLIGHT.fetch_sub(1, Ordering::SeqCst) == 1 {
+            IDLE.notify_waiters();
+        }
+    }
+}
+
+/// 현재 처리 중인 블로킹 DB 작업 수
+pub fn in_flight_blocking() -> usize {
+    IN_FLIGHT.load(Ordering::SeqCst)
+}
+
+/// 처리 중인 블로킹 DB 작업이 모두 끝날 때까지 최대 `timeout` 동안 기다립니다.
+///
+/// 블로킹 작업은 중간에 취소할 수 없으므로, 트랜잭션은 커밋되거나 커넥션 반환 시 롤백됩니다.
+/// 시간 안에 모두 끝나면 `true`를 반환합니다.
+pub async fn wait_blocking_idle(timeout: Duration) -> bool {
+    tokio::time::timeout(timeout, async {
+        loop {
+            let idle = IDLE.notified();
+            if in_flight_blocking() == 0 {
+                return;
+            }
+            idle.await;
+        }
+    })
+    .await
+    .is_ok()
+}
+
+/// SIGINT(Ctrl+C) 또는 SIGTERM을 받을 때까지 기다립니다.
+pub async fn signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            tracing::error!("SIGINT 처리기 등록 실패: {}", err);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(err) => {
+                tracing::error!("SIGTERM 처리기 등록 실패: {}", err);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("SIGINT 수신"),
+        _ = terminate => tracing::info!("SIGTERM 수신"),
+    }
+}
@@ -0,0 +1,93 @@
+//! 명령줄 인자: 설정 파일 경로와 설정 덮어쓰기, 하위 명령(`migrate`)
+
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// 명령줄 인자.
+///
+/// 여기서 지정한 값은 설정 파일과 환경 변수보다 우선합니다.
+#[derive(Debug, Default, Parser)]
+#[command(name = "oracleTest", about = "Oracle/SQLite 게시판 API 서버")]
+pub struct CliArgs {
+    /// 설정 파일 경로 (.toml, .yaml, .yml)
+    #[arg(short, long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// 서버 호스트 주소 (`server_host`)
+    #[arg(long, value_name = "HOST")]
+    pub host: Option<String>,
+    /// 서버 포트 번호 (`server_port`)
+    #[arg(short, long, value_name = "PORT")]
+    pub port: Option<u16>,
+    /// 저장소 백엔드 (`db_backend`: oracle, sqlite)
+    #[arg(long, value_name = "BACKEND")]
+    pub db_backend: Option<String>,
+    /// 로그 출력 형식 (`log_format`: text, json)
+    #[arg(long, value_name = "FORMAT")]
+    pub log_format: Option<String>,
+    /// 임의의 설정 키 덮어쓰기 (여러 번 지정 가능, 예: `--set db_pool_max_size=20`)
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub overrides: Vec<String>,
+    /// 하위 명령 (없으면 서버를 실행합니다)
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// 서버 대신 실행할 하위 명령
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// 데이터베이스 스키마 마이그레이션을 실행하고 종료합니다.
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+}
+
+/// `migrate` 하위 명령
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum MigrateAction {
+    /// 마이그레이션별 적용 여부를 출력합니다.
+    Status,
+    /// 적용되지 않은 마이그레이션을 순서대로 적용합니다.
+    Up {
+        /// 이 버전까지만 적용합니다.
+        #[arg(long, value_name = "VERSION")]
+        to: Option<u32>,
+    },
+    /// 최근에 적용한 마이그레이션을 되돌립니다.
+    Down {
+        /// 되돌릴 마이그레이션 수
+        #[arg(long, default_value_t = 1, value_name = "N")]
+        steps: u32,
+    },
+    /// 이미 만들어 둔 스키마를 지정한 버전까지 적용된 것으로 기록합니다. (스크립트는 실행하지 않음)
+    Baseline {
+        #[arg(value_name = "VERSION")]
+        version: u32,
+    },
+}
+
+impl CliArgs {
+    /// `--set`과 개별 플래그를 `(키, 값)` 목록으로 펼칩니다. 개별 플래그가 `--set`보다 나중에 적용됩니다.
+    pub(super) fn assignments(&self) -> Result<Vec<(String, String)>, String> {
+        let mut pairs = Vec::new();
+        for pair in &self.overrides {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("--set {pair}: KEY=VALUE 형식이어야 합니다."))?;
+            pairs.push((key.trim().to_string(), value.to_string()));
+        }
+        if let Some(host) = &self.host {
+            pairs.push(("server_host".to_string(), host.clone()));
+        }
+        if let Some(port) = self.port {
+            pairs.push(("server_port".to_string(), port.to_string()));
+        }
+        if let Some(backend) = &self.db_backend {
+            pairs.push(("db_backend".to_string(), backend.clone()));
+        }
+        if let Some(format) = &self.log_format {
+            pairs.push(("log_format".to_string(), format.clone()));
+        }
+        Ok(pairs)
+    }
+}
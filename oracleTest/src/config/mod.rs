@@ -1,74 +1,576 @@
 //! 환경 설정 모듈: 애플리케이션 실행에 필요한 설정을 관리
+//!
+//! 설정은 아래 순서로 겹쳐 적용되며, 뒤에 오는 값이 앞의 값을 덮어씁니다.
+//!
+//! 1. 기본값 (`Config::default`)
+//! 2. 설정 파일 (`--config` 또는 `BOARD_CONFIG`, TOML/YAML)
+//! 3. 환경 변수 (`BOARD_` 접두사 + 대문자 키, 예: `BOARD_SERVER_PORT`. `.env` 파일도 읽습니다)
+//! 4. 명령줄 인자 (`--port`, `--set key=value` 등)
+//!
+//! 모든 값을 적용한 뒤 `validate`로 검사하므로, 잘못된 설정은 서버가 뜨기 전에 한꺼번에 보고됩니다.
 
+pub mod cli;
+pub mod secret;
+
+pub use cli::{CliArgs, Command, MigrateAction};
+pub use secret::Secret;
+
+use axum::http::{HeaderName, HeaderValue};
 use serde::Deserialize;
-use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tracing_subscriber::EnvFilter;
+
+use crate::middleware::security_headers::DEFAULT_CONTENT_SECURITY_POLICY;
+
+/// 환경 변수 접두사
+pub const ENV_PREFIX: &str = "BOARD_";
+/// 설정 파일 경로를 지정하는 환경 변수
+pub const ENV_CONFIG_FILE: &str = "BOARD_CONFIG";
+
+/// JWT 서명 키의 최소 길이 (바이트)
+const MIN_JWT_SECRET_BYTES: usize = 16;
+
+/// 게시글 저장소 백엔드 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DbBackend {
+    /// Oracle 데이터베이스 (`r2d2_oracle` 커넥션 풀)
+    Oracle,
+    /// 내장 SQLite 데이터베이스 (파일 또는 `:memory:`)
+    Sqlite,
+}
+
+impl FromStr for DbBackend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "oracle" => Ok(DbBackend::Oracle),
+            "sqlite" => Ok(DbBackend::Sqlite),
+            _ => Err("oracle 또는 sqlite여야 합니다.".to_string()),
+        }
+    }
+}
+
+/// 로그 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// 사람이 읽기 쉬운 텍스트
+    Text,
+    /// 한 줄에 하나의 JSON 객체 (로그 수집기용)
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err("text 또는 json이어야 합니다.".to_string()),
+        }
+    }
+}
 
 /// 애플리케이션 환경 설정 구조체
 ///
-/// `.env` 파일 또는 시스템 환경 변수로부터 설정을 로드합니다.
+/// 설정 파일의 키와 `--set`의 키는 필드 이름과 같고, 환경 변수는 `BOARD_` + 대문자 필드 이름입니다.
+/// 비밀 값(`db_password`, `jwt_secret`)은 `Debug` 출력에서 가려집니다.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
     /// 서버 호스트 주소 (예: 0.0.0.0)
-    #[serde(default = "default_host")]
     pub server_host: String,
     /// 서버 포트 번호 (예: 8080)
-    #[serde(default = "default_port")]
     pub server_port: u16,
-    /// 로깅 레벨 (예: info, debug)
-    #[serde(default = "default_log")]
+    /// 로그 필터 (예: info, oracle_test=debug). `RUST_LOG`도 읽습니다.
     pub rust_log: String,
-    /// 데이터베이스 사용자명
-    #[serde(default = "default_db_user")]
+    /// 로그 출력 형식 (text, json)
+    pub log_format: LogFormat,
+    /// 요청 헤더를 로그에 남길 때 값을 가릴 헤더 (`Authorization`, `Cookie` 등 기본 목록에 추가)
+    pub log_redact_headers: Vec<String>,
+    /// 저장소 백엔드 (oracle, sqlite)
+    pub db_backend: DbBackend,
+    /// 데이터베이스 사용자명 (Oracle 백엔드에서 필수)
     pub db_user: String,
-    /// 데이터베이스 비밀번호
-    #[serde(default = "default_db_password")]
-    pub db_password: String,
+    /// 데이터베이스 비밀번호 (Oracle 백엔드에서 필수)
+    pub db_password: Secret,
     /// 데이터베이스 접속 문자열 (예: localhost:1521/ORCL)
-    #[serde(default = "default_db_connect")]
     pub db_connect: String,
+    /// SQLite 데이터베이스 파일 경로 (`:memory:`이면 인메모리 DB)
+    pub sqlite_path: String,
+    /// 커넥션 풀 최대 크기
+    pub db_pool_max_size: u32,
+    /// 풀에서 커넥션을 얻기까지 기다리는 최대 시간 (초)
+    pub db_pool_connection_timeout_secs: u64,
+    /// 서버 시작 시 적용되지 않은 스키마 마이그레이션을 적용할지 여부 (인메모리 SQLite는 항상 적용)
+    pub migrate_on_startup: bool,
+    /// 요청 하나의 처리 시간 제한 (초). 초과하면 408을 반환합니다.
+    pub request_timeout_secs: u64,
+    /// 요청 본문 최대 크기 (바이트). 자체 제한이 있는 라우트(게시글/댓글/인증/업로드)에는 적용되지 않습니다.
+    pub max_body_bytes: usize,
+    /// 클라이언트(로그인 사용자 또는 IP)별 초당 허용 요청 수. 0이면 빈도 제한을 끕니다.
+    pub rate_limit_per_second: u32,
+    /// 한 번에 몰아서 보낼 수 있는 최대 요청 수 (토큰 버킷 크기)
+    pub rate_limit_burst: u32,
+    /// CORS를 허용할 출처 목록 (비어 있으면 CORS 헤더를 보내지 않음, `*`는 모든 출처)
+    pub cors_allowed_origins: Vec<String>,
+    /// 응답에 붙일 Content-Security-Policy (비어 있으면 보내지 않음)
+    pub content_security_policy: String,
+    /// 정적 파일(프런트엔드) 디렉터리
+    pub static_dir: String,
+    /// HTML이 아닌 정적 파일의 브라우저 캐시 시간 (초). 0이면 캐시하지 않습니다.
+    pub static_max_age_secs: u64,
+    /// 액세스 토큰(JWT) 서명 비밀 키 (필수)
+    pub jwt_secret: Secret,
+    /// 액세스 토큰 유효 시간 (초)
+    pub jwt_ttl_secs: u64,
+    /// 첨부파일 저장 디렉터리
+    pub attachment_dir: String,
+    /// 첨부파일 최대 크기 (바이트)
+    pub attachment_max_bytes: u64,
+    /// 업로드를 허용할 MIME 타입 목록 (환경 변수/`--set`에서는 쉼표로 구분)
+    pub attachment_allowed_types: Vec<String>,
+    /// 휴지통 보존 기간 (일). 이 기간이 지난 게시글은 영구 삭제됩니다.
+    pub trash_retention_days: u32,
+    /// 휴지통 영구 삭제 작업 실행 주기 (초)
+    pub trash_purge_interval_secs: u64,
+    /// 메모리에 모은 게시글 조회수를 DB에 반영하는 주기 (초)
+    pub view_flush_interval_secs: u64,
+    /// 인기 게시글 목록 캐시 유지 시간 (초, 0이면 캐시하지 않음)
+    pub popular_cache_ttl_secs: u64,
+    /// 게시글 상세/목록/개수 조회 결과를 메모리에 캐시할지 여부
+    pub board_cache_enabled: bool,
+    /// 게시글 캐시의 종류(상세, 목록, 개수)별 최대 항목 수. 넘치면 가장 오래 쓰지 않은 항목부터 버립니다.
+    pub board_cache_capacity: usize,
+    /// 게시글 캐시 항목의 유지 시간 (초). 다른 인스턴스에서 바꾼 내용은 이 시간 안에 반영됩니다.
+    pub board_cache_ttl_secs: u64,
+    /// 재연결한 구독자에게 다시 보낼 수 있도록 보관하는 최근 게시글 이벤트 수
+    pub events_buffer_size: usize,
+    /// 이벤트 구독 연결의 하트비트 주기 (초, SSE 주석과 WebSocket ping)
+    pub events_heartbeat_secs: u64,
+    /// `/readyz`의 DB ping 시간 제한 (밀리초, 커넥션 대기 포함)
+    pub readiness_timeout_ms: u64,
+    /// 종료 신호 후 처리 중인 요청과 DB 작업을 기다리는 최대 시간 (초)
+    pub shutdown_timeout_secs: u64,
 }
 
-fn default_host() -> String {
-    env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string())
-}
-
-fn default_port() -> u16 {
-    env::var("SERVER_PORT")
-        .ok()
-        .and_then(|p| p.parse().ok())
-        .unwrap_or(8080)
-}
-
-fn default_log() -> String {
-    env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string())
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            server_host: "0.0.0.0".to_string(),
+            server_port: 8080,
+            rust_log: "info".to_string(),
+            log_format: LogFormat::Text,
+            log_redact_headers: Vec::new(),
+            db_backend: DbBackend::Oracle,
+            db_user: String::new(),
+            db_password: Secret::default(),
+            db_connect: "127.0.0.1:1521/ORCL".to_string(),
+            sqlite_path: "board.db".to_string(),
+            db_pool_max_size: 10,
+            db_pool_connection_timeout_secs: 30,
+            migrate_on_startup: false,
+            request_timeout_secs: 30,
+            max_body_bytes: 1024 * 1024,
+            rate_limit_per_second: 10,
+            rate_limit_burst: 30,
+            cors_allowed_origins: Vec::new(),
+            content_security_policy: DEFAULT_CONTENT_SECURITY_POLICY.to_string(),
+            static_dir: "static".to_string(),
+            static_max_age_secs: 3600,
+            jwt_secret: Secret::default(),
+            jwt_ttl_secs: 3600,
+            attachment_dir: "uploads".to_string(),
+            attachment_max_bytes: 10 * 1024 * 1024,
+            attachment_allowed_types: [
+                "image/png",
+                "image/jpeg",
+                "image/gif",
+                "image/webp",
+                "application/pdf",
+                "text/plain",
+            ]
+            .map(String::from)
+            .to_vec(),
+            trash_retention_days: 30,
+            trash_purge_interval_secs: 3600,
+            view_flush_interval_secs: 5,
+            popular_cache_ttl_secs: 60,
+            board_cache_enabled: true,
+            board_cache_capacity: 1000,
+            board_cache_ttl_secs: 30,
+            events_buffer_size: 256,
+            events_heartbeat_secs: 15,
+            readiness_timeout_ms: 2000,
+            shutdown_timeout_secs: 30,
+        }
+    }
 }
 
-fn default_db_user() -> String {
-    env::var("DB_USER").unwrap_or_else(|_| "docker".to_string())
+/// 설정을 읽거나 검사하다 발생한 에러
+#[derive(Debug)]
+pub enum ConfigError {
+    /// 설정 파일을 읽거나 해석할 수 없음
+    File { path: PathBuf, message: String },
+    /// 환경 변수나 명령줄로 준 값이 잘못됨 (`source`: 값을 준 곳)
+    Override {
+        source: String,
+        key: String,
+        message: String,
+    },
+    /// 모든 값을 적용한 뒤 검사에서 발견된 문제들
+    Invalid(Vec<String>),
 }
 
-fn default_db_password() -> String {
-    env::var("DB_PASSWORD").unwrap_or_else(|_| "docker123".to_string())
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::File { path, message } => {
+                write!(f, "설정 파일 {}: {}", path.display(), message)
+            }
+            ConfigError::Override {
+                source,
+                key,
+                message,
+            } => write!(f, "{source} ({key}): {message}"),
+            ConfigError::Invalid(problems) => {
+                write!(f, "잘못된 설정 {}건", problems.len())?;
+                for problem in problems {
+                    write!(f, "\n  - {problem}")?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
-fn default_db_connect() -> String {
-    env::var("DB_CONNECT").unwrap_or_else(|_| "127.0.0.1:1521/ORCL".to_string())
-}
+impl std::error::Error for ConfigError {}
 
 impl Config {
-    /// 환경 변수에서 설정을 로드하여 Config 인스턴스를 생성합니다.
-    ///
-    /// 1. `.env` 파일이 있다면 로드합니다.
-    /// 2. 환경 변수가 없으면 기본값을 사용합니다.
-    pub fn from_env() -> Self {
+    /// 파싱한 명령줄 인자와 `.env` 파일, 환경 변수, 설정 파일을 읽어 검증된 설정을 만듭니다.
+    pub fn load(cli: &CliArgs) -> Result<Self, ConfigError> {
         dotenv::dotenv().ok();
-        Self {
-            server_host: default_host(),
-            server_port: default_port(),
-            rust_log: default_log(),
-            db_user: default_db_user(),
-            db_password: default_db_password(),
-            db_connect: default_db_connect(),
+        Self::load_from(cli, std::env::vars())
+    }
+
+    /// 주어진 명령줄 인자와 환경 변수 목록으로 설정을 만듭니다. (`load`의 테스트 가능한 본체)
+    pub fn load_from(
+        cli: &CliArgs,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ConfigError> {
+        let mut vars: Vec<(String, String)> = vars.into_iter().collect();
+        vars.sort();
+
+        // 1~2. 기본값 위에 설정 파일
+        let file = cli.config.clone().or_else(|| {
+            vars.iter()
+                .find(|(key, _)| key == ENV_CONFIG_FILE)
+                .map(|(_, path)| PathBuf::from(path))
+        });
+        let mut config = match &file {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+
+        // 3. 환경 변수 (관례상 `RUST_LOG`도 받되, `BOARD_RUST_LOG`가 우선합니다)
+        if let Some((_, filter)) = vars.iter().find(|(key, _)| key == "RUST_LOG") {
+            config.rust_log = filter.clone();
         }
+        for (name, value) in &vars {
+            let Some(key) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            if name == ENV_CONFIG_FILE {
+                continue;
+            }
+            let key = key.to_ascii_lowercase();
+            config
+                .set(&key, value)
+                .map_err(|message| ConfigError::Override {
+                    source: format!("환경 변수 {name}"),
+                    key,
+                    message,
+                })?;
+        }
+
+        // 4. 명령줄 인자
+        let assignments = cli.assignments().map_err(|message| ConfigError::Override {
+            source: "명령줄".to_string(),
+            key: "--set".to_string(),
+            message,
+        })?;
+        for (key, value) in assignments {
+            config
+                .set(&key, &value)
+                .map_err(|message| ConfigError::Override {
+                    source: "명령줄".to_string(),
+                    key,
+                    message,
+                })?;
+        }
+
+        config.normalize();
+        config.validate()?;
+        Ok(config)
     }
+
+    /// 확장자(.toml, .yaml, .yml)에 따라 설정 파일을 읽습니다. 빠진 키는 기본값을 씁니다.
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let file_error = |message: String| ConfigError::File {
+            path: path.to_path_buf(),
+            message,
+        };
+        let text = std::fs::read_to_string(path).map_err(|err| file_error(err.to_string()))?;
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => toml::from_str(&text).map_err(|err| file_error(err.to_string())),
+            Some("yaml" | "yml") => {
+                serde_yaml::from_str(&text).map_err(|err| file_error(err.to_string()))
+            }
+            _ => Err(file_error(
+                "확장자가 .toml, .yaml, .yml 중 하나여야 합니다.".to_string(),
+            )),
+        }
+    }
+
+    /// 키 하나를 문자열 값으로 덮어씁니다. 목록 값은 쉼표로 구분합니다.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "server_host" => self.server_host = value.to_string(),
+            "server_port" => self.server_port = parse(value)?,
+            "rust_log" => self.rust_log = value.to_string(),
+            "log_format" => self.log_format = value.parse()?,
+            "log_redact_headers" => self.log_redact_headers = parse_list(value),
+            "db_backend" => self.db_backend = value.parse()?,
+            "db_user" => self.db_user = value.to_string(),
+            "db_password" => self.db_password = Secret::new(value),
+            "db_connect" => self.db_connect = value.to_string(),
+            "sqlite_path" => self.sqlite_path = value.to_string(),
+            "db_pool_max_size" => self.db_pool_max_size = parse(value)?,
+            "db_pool_connection_timeout_secs" => {
+                self.db_pool_connection_timeout_secs = parse(value)?
+            }
+            "migrate_on_startup" => self.migrate_on_startup = parse(value)?,
+            "request_timeout_secs" => self.request_timeout_secs = parse(value)?,
+            "max_body_bytes" => self.max_body_bytes = parse(value)?,
+            "rate_limit_per_second" => self.rate_limit_per_second = parse(value)?,
+            "rate_limit_burst" => self.rate_limit_burst = parse(value)?,
+            "cors_allowed_origins" => self.cors_allowed_origins = parse_list(value),
+            "content_security_policy" => self.content_security_policy = value.to_string(),
+            "static_dir" => self.static_dir = value.to_string(),
+            "static_max_age_secs" => self.static_max_age_secs = parse(value)?,
+            "jwt_secret" => self.jwt_secret = Secret::new(value),
+            "jwt_ttl_secs" => self.jwt_ttl_secs = parse(value)?,
+            "attachment_dir" => self.attachment_dir = value.to_string(),
+            "attachment_max_bytes" => self.attachment_max_bytes = parse(value)?,
+            "attachment_allowed_types" => self.attachment_allowed_types = parse_list(value),
+            "trash_retention_days" => self.trash_retention_days = parse(value)?,
+            "trash_purge_interval_secs" => self.trash_purge_interval_secs = parse(value)?,
+            "view_flush_interval_secs" => self.view_flush_interval_secs = parse(value)?,
+            "popular_cache_ttl_secs" => self.popular_cache_ttl_secs = parse(value)?,
+            "board_cache_enabled" => self.board_cache_enabled = parse(value)?,
+            "board_cache_capacity" => self.board_cache_capacity = parse(value)?,
+            "board_cache_ttl_secs" => self.board_cache_ttl_secs = parse(value)?,
+            "events_buffer_size" => self.events_buffer_size = parse(value)?,
+            "events_heartbeat_secs" => self.events_heartbeat_secs = parse(value)?,
+            "readiness_timeout_ms" => self.readiness_timeout_ms = parse(value)?,
+            "shutdown_timeout_secs" => self.shutdown_timeout_secs = parse(value)?,
+            _ => return Err("알 수 없는 설정 키입니다.".to_string()),
+        }
+        Ok(())
+    }
+
+    /// 목록 값의 공백을 정리하고 MIME 타입과 헤더 이름을 소문자로 맞춥니다.
+    fn normalize(&mut self) {
+        self.attachment_allowed_types = self
+            .attachment_allowed_types
+            .iter()
+            .map(|mime| mime.trim().to_ascii_lowercase())
+            .filter(|mime| !mime.is_empty())
+            .collect();
+        self.log_redact_headers = self
+            .log_redact_headers
+            .iter()
+            .map(|name| name.trim().to_ascii_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+        self.cors_allowed_origins = self
+            .cors_allowed_origins
+            .iter()
+            .map(|origin| origin.trim().trim_end_matches('/').to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+    }
+
+    /// 설정 값을 검사하고, 문제가 있으면 모두 모아 `ConfigError::Invalid`로 반환합니다.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+        let mut check = |ok: bool, problem: &str| {
+            if !ok {
+                problems.push(problem.to_string());
+            }
+        };
+
+        check(
+            !self.server_host.trim().is_empty(),
+            "server_host는 비어 있을 수 없습니다.",
+        );
+        check(self.server_port != 0, "server_port는 1 이상이어야 합니다.");
+        check(
+            EnvFilter::try_new(&self.rust_log).is_ok(),
+            "rust_log는 올바른 로그 필터가 아닙니다.",
+        );
+        for name in &self.log_redact_headers {
+            check(
+                HeaderName::try_from(name.as_str()).is_ok(),
+                &format!("log_redact_headers의 {name}은 올바른 헤더 이름이 아닙니다."),
+            );
+        }
+        match self.db_backend {
+            DbBackend::Oracle => {
+                check(
+                    !self.db_user.is_empty(),
+                    "Oracle 백엔드에는 db_user가 필요합니다.",
+                );
+                check(
+                    !self.db_password.is_empty(),
+                    "Oracle 백엔드에는 db_password가 필요합니다.",
+                );
+                check(
+                    !self.db_connect.is_empty(),
+                    "Oracle 백엔드에는 db_connect가 필요합니다.",
+                );
+            }
+            DbBackend::Sqlite => check(
+                !self.sqlite_path.is_empty(),
+                "SQLite 백엔드에는 sqlite_path가 필요합니다.",
+            ),
+        }
+        check(
+            self.db_pool_max_size > 0,
+            "db_pool_max_size는 1 이상이어야 합니다.",
+        );
+        check(
+            self.db_pool_connection_timeout_secs > 0,
+            "db_pool_connection_timeout_secs는 1 이상이어야 합니다.",
+        );
+        check(
+            self.request_timeout_secs > 0,
+            "request_timeout_secs는 1 이상이어야 합니다.",
+        );
+        check(
+            self.max_body_bytes > 0,
+            "max_body_bytes는 1 이상이어야 합니다.",
+        );
+        check(
+            self.rate_limit_per_second == 0 || self.rate_limit_burst > 0,
+            "rate_limit_burst는 1 이상이어야 합니다. (빈도 제한을 끄려면 rate_limit_per_second=0)",
+        );
+        check(
+            self.jwt_secret.expose().len() >= MIN_JWT_SECRET_BYTES,
+            &format!("jwt_secret은 {MIN_JWT_SECRET_BYTES}바이트 이상이어야 합니다."),
+        );
+        check(self.jwt_ttl_secs > 0, "jwt_ttl_secs는 1 이상이어야 합니다.");
+        check(
+            !self.attachment_dir.is_empty(),
+            "attachment_dir은 비어 있을 수 없습니다.",
+        );
+        check(
+            self.attachment_max_bytes > 0,
+            "attachment_max_bytes는 1 이상이어야 합니다.",
+        );
+        check(
+            !self.attachment_allowed_types.is_empty(),
+            "attachment_allowed_types는 비어 있을 수 없습니다.",
+        );
+        for mime in &self.attachment_allowed_types {
+            check(
+                mime.contains('/'),
+                &format!("attachment_allowed_types의 {mime}은 MIME 타입이 아닙니다."),
+            );
+        }
+        check(
+            self.trash_purge_interval_secs > 0,
+            "trash_purge_interval_secs는 1 이상이어야 합니다.",
+        );
+        check(
+            self.view_flush_interval_secs > 0,
+            "view_flush_interval_secs는 1 이상이어야 합니다.",
+        );
+        check(
+            !self.board_cache_enabled || self.board_cache_capacity > 0,
+            "board_cache_capacity는 1 이상이어야 합니다. (캐시를 끄려면 board_cache_enabled=false)",
+        );
+        check(
+            !self.board_cache_enabled || self.board_cache_ttl_secs > 0,
+            "board_cache_ttl_secs는 1 이상이어야 합니다. (캐시를 끄려면 board_cache_enabled=false)",
+        );
+        check(
+            self.events_buffer_size > 0,
+            "events_buffer_size는 1 이상이어야 합니다.",
+        );
+        check(
+            self.events_heartbeat_secs > 0,
+            "events_heartbeat_secs는 1 이상이어야 합니다.",
+        );
+        check(
+            self.readiness_timeout_ms > 0,
+            "readiness_timeout_ms는 1 이상이어야 합니다.",
+        );
+        check(
+            HeaderValue::from_str(&self.content_security_policy).is_ok(),
+            "content_security_policy는 헤더 값으로 쓸 수 없는 문자를 포함합니다.",
+        );
+        check(
+            !self.static_dir.is_empty(),
+            "static_dir은 비어 있을 수 없습니다.",
+        );
+        for origin in &self.cors_allowed_origins {
+            let valid = if origin == "*" {
+                self.cors_allowed_origins.len() == 1
+            } else {
+                (origin.starts_with("http://") || origin.starts_with("https://"))
+                    && HeaderValue::from_str(origin).is_ok()
+            };
+            check(
+                valid,
+                &format!(
+                    "cors_allowed_origins의 {origin}은 올바른 출처가 아닙니다. (http(s)://호스트[:포트] 또는 단독 *)"
+                ),
+            );
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+fn parse<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|err| format!("{value:?}을(를) 해석할 수 없습니다: {err}"))
+}
+
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
 }
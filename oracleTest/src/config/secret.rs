@@ -0,0 +1,36 @@
+//! 비밀 설정 값: `Debug` 출력과 로그에 원문이 남지 않도록 감싸는 타입
+
+use serde::Deserialize;
+use std::fmt;
+
+/// 비밀번호, 서명 키처럼 로그에 남으면 안 되는 설정 값.
+///
+/// `Debug`는 항상 가려진 값을 출력하며, 원문은 `expose`로만 꺼낼 수 있습니다.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Secret(value.into())
+    }
+
+    /// 원문 값을 반환합니다. 로그나 응답에 그대로 쓰지 마세요.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("\"\"")
+        } else {
+            f.write_str("[REDACTED]")
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! `attachment` 리소스(게시글 첨부파일)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{
+    Json,
+    body::Body,
+    extract::{Multipart, Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use tokio::io::AsyncReadExt;
+use tokio_util::io::ReaderStream;
+use tracing::{info, warn};
+
+use crate::common::app_state::AppState;
+use crate::middleware::auth::AuthUser;
+use crate::models::attachment::ByteRange;
+use crate::services::board_service::ServiceError;
+
+use super::{
+    dto::{AttachmentResponse, AttachmentUploadForm},
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 업로드 파일을 담는 multipart 필드 이름
+const FILE_FIELD: &str = "file";
+
+/// 게시글에 파일을 업로드합니다. (게시글 작성자만 가능)
+///
+/// `multipart/form-data`의 `file` 필드 하나를 스트리밍으로 저장합니다.
+#[utoipa::path(
+    post,
+    path = "/boards/{id}/attachments",
+    tag = "attachments",
+    request_body(content = AttachmentUploadForm, content_type = "multipart/form-data"),
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 201, description = "업로드된 첨부파일", body = AttachmentResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+        (status = 413, description = "파일이 너무 큽니다", body = ErrorResponse),
+        (status = 415, description = "허용되지 않은 파일 형식입니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn upload_attachment(
+    user: AuthUser,
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponse>), ControllerError> {
+    info!(
+        "[Controller] upload_attachment 호출됨, user={}, board_id={}",
+        user.username, board_id
+    );
+    while let Some(field) = multipart.next_field().await.map_err(|err| {
+        warn!("[Controller] multipart 해석 실패: {}", err);
+        ServiceError::InvalidInput("multipart 본문을 해석할 수 없습니다.".to_string())
+    })? {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+        let filename = field.file_name().unwrap_or_default().to_string();
+        let content_type = field
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let attachment = state
+            .attachments
+            .upload(user.id, board_id, &filename, &content_type, field)
+            .await?;
+        return Ok((
+            StatusCode::CREATED,
+            Json(AttachmentResponse::from(attachment)),
+        ));
+    }
+
+    Err(ServiceError::InvalidInput(format!("`{}` 필드가 필요합니다.", FILE_FIELD)).into())
+}
+
+/// 게시글의 첨부파일 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/attachments",
+    tag = "attachments",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "첨부파일 목록", body = Vec<AttachmentResponse>),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn list_attachments(
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<AttachmentResponse>>, ControllerError> {
+    info!(
+        "[Controller] list_attachments 호출됨, board_id={}",
+        board_id
+    );
+    let attachments = state.attachments.list_attachments(board_id).await?;
+    Ok(Json(
+        attachments
+            .into_iter()
+            .map(AttachmentResponse::from)
+            .collect(),
+    ))
+}
+
+/// 첨부파일을 스트리밍으로 내려받습니다.
+///
+/// `Range: bytes=...` 헤더(단일 범위)를 지원하며, 부분 응답은 206으로 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/attachments/{id}",
+    tag = "attachments",
+    params(
+        ("id" = i64, Path, description = "첨부파일 ID"),
+        ("Range" = Option<String>, Header, description = "단일 바이트 범위 (예: bytes=0-1023)"),
+    ),
+    responses(
+        (status = 200, description = "파일 전체", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 206, description = "요청한 바이트 범위", content_type = "application/octet-stream", body = Vec<u8>, headers(("Content-Range" = String, description = "응답 범위"))),
+        (status = 404, description = "첨부파일을 찾을 수 없습니다", body = ErrorResponse),
+        (status = 416, description = "요청한 범위를 만족할 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn download_attachment(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, ControllerError> {
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(ByteRange::parse);
+    info!(
+        "[Controller] download_attachment 호출됨, id={}, range={:?}",
+        id, range
+    );
+
+    let download = state.attachments.open_download(id, range).await?;
+    let attachment = download.attachment;
+    let size = u64::try_from(attachment.size_bytes).unwrap_or(0);
+    let (status, length) = match download.range {
+        Some((start, end)) => (StatusCode::PARTIAL_CONTENT, end - start + 1),
+        None => (StatusCode::OK, size),
+    };
+    let body = Body::from_stream(ReaderStream::new(download.file.take(length)));
+
+    let mut response = (status, body).into_response();
+    let response_headers = response.headers_mut();
+    response_headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&attachment.content_type)
+            .unwrap_or(HeaderValue::from_static("application/octet-stream")),
+    );
+    response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(length));
+    response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    if let Ok(etag) = HeaderValue::from_str(&format!("\"{}\"", attachment.sha256)) {
+        response_headers.insert(header::ETAG, etag);
+    }
+    if let Ok(disposition) = HeaderValue::from_str(&content_disposition(&attachment.filename)) {
+        response_headers.insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    if let Some((start, end)) = download.range
+        && let Ok(content_range) = HeaderValue::from_str(&format!("bytes {start}-{end}/{size}"))
+    {
+        response_headers.insert(header::CONTENT_RANGE, content_range);
+    }
+    Ok(response)
+}
+
+/// RFC 6266/5987 형식의 `Content-Disposition` 값을 만듭니다.
+/// 비 ASCII 파일명은 `filename*`에 퍼센트 인코딩하여 전달합니다.
+fn content_disposition(filename: &str) -> String {
+    let fallback: String = filename
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = filename
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    format!("attachment; filename=\"{fallback}\"; filename*=UTF-8''{encoded}")
+}
@@ -0,0 +1,63 @@
+//! `auth` 리소스(회원가입/로그인)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{Json, extract::State, http::StatusCode};
+use tracing::info;
+
+use crate::common::app_state::AppState;
+
+use super::{
+    dto::{LoginRequest, RegisterRequest, TokenResponse, UserResponse},
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 새 사용자를 등록합니다.
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    tag = "auth",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "등록된 사용자", body = UserResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 409, description = "이미 사용 중인 사용자명입니다", body = ErrorResponse),
+    ),
+)]
+pub async fn register(
+    State(state): State<AppState>,
+    Json(req): Json<RegisterRequest>,
+) -> Result<(StatusCode, Json<UserResponse>), ControllerError> {
+    info!("[Controller] register 호출됨, username={}", req.username);
+    let user = state.auth.register(&req.username, &req.password).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(UserResponse {
+            id: user.id,
+            username: user.username,
+        }),
+    ))
+}
+
+/// 로그인하여 액세스 토큰을 발급받습니다.
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "액세스 토큰", body = TokenResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "사용자명 또는 비밀번호가 올바르지 않습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn login(
+    State(state): State<AppState>,
+    Json(req): Json<LoginRequest>,
+) -> Result<Json<TokenResponse>, ControllerError> {
+    info!("[Controller] login 호출됨, username={}", req.username);
+    let token = state.auth.login(&req.username, &req.password).await?;
+    Ok(Json(TokenResponse {
+        access_token: token.access_token,
+        token_type: "Bearer".to_string(),
+        expires_in: token.expires_in,
+    }))
+}
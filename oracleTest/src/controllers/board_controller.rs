@@ -2,96 +2,536 @@
 
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::Html,
+    body::{Body, Bytes},
+    extract::{ConnectInfo, Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
 };
-use tracing::info;
+use futures_util::TryStreamExt;
+use std::net::SocketAddr;
+use tracing::{error, info};
 
 use crate::common::app_state::AppState;
+use crate::common::etag;
+use crate::middleware::auth::AuthUser;
+use crate::models::board::BoardSearchQuery;
 
 use super::{
     dto::{
-        BoardResponse, CreateBoardRequest, PaginationMeta, PaginationRequest, PaginationResponse,
-        UpdateBoardRequest,
+        BoardListResponse, BoardResponse, BoardTransferRequest, CreateBoardRequest, CursorMeta,
+        CursorPaginationResponse, EngagementResponse, ImportReportResponse, LikeResponse,
+        PaginationMeta, PaginationRequest, PaginationResponse, PopularBoardResponse,
+        PopularBoardsResponse, PopularRequest, TrashPaginationRequest, TrashPaginationResponse,
+        TrashedBoardResponse, UpdateBoardRequest,
     },
-    error::ControllerError,
+    error::{ControllerError, ErrorResponse},
 };
 
-/// 게시글 목록을 페이지네이션으로 조회합니다.
+/// 게시글 목록을 검색 조건과 페이지네이션으로 조회합니다.
+///
+/// `cursor` 파라미터가 있으면 키셋(커서) 방식, 없으면 기존 page/size 방식으로 동작합니다.
+#[utoipa::path(
+    get,
+    path = "/boards",
+    tag = "boards",
+    params(
+        PaginationRequest,
+    ),
+    responses(
+        (status = 200, description = "게시글 목록 (cursor가 있으면 커서 방식)", body = BoardListResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+    ),
+)]
 pub async fn list_boards(
     State(state): State<AppState>,
     Query(pagination_req): Query<PaginationRequest>,
-) -> Result<Json<PaginationResponse>, ControllerError> {
+) -> Result<Json<BoardListResponse>, ControllerError> {
     info!(
         "[Controller] list_boards 호출됨, pagination_req={:?}",
         pagination_req
     );
     let page = pagination_req.page.unwrap_or(1); // 기본 1페이지
     let size = pagination_req.size.unwrap_or(10); // 기본 10개
+    let search = BoardSearchQuery {
+        q: pagination_req.q,
+        title: pagination_req.title,
+        from: pagination_req.from,
+        to: pagination_req.to,
+        sort: pagination_req.sort,
+        category: pagination_req.category,
+        tag: pagination_req.tag,
+    };
+
+    if pagination_req.cursor.is_some() {
+        let with_total = pagination_req.with_total.unwrap_or(false);
+        let result = state
+            .service
+            .get_boards_by_cursor(pagination_req.cursor, size, with_total, search)
+            .await?;
+
+        let data = result.items.into_iter().map(BoardResponse::from).collect();
+
+        return Ok(Json(BoardListResponse::Cursor(CursorPaginationResponse {
+            data,
+            pagination: CursorMeta {
+                size,
+                next_cursor: result.next_cursor,
+                total_count: result.total_count,
+            },
+        })));
+    }
 
     // 서비스 계층을 호출하여 데이터를 가져옵니다.
-    let (boards, total_pages) = state.service.get_boards_paged(page, size).await?;
+    let (boards, total_pages) = state.service.get_boards_paged(page, size, search).await?;
 
     let data = boards.into_iter().map(BoardResponse::from).collect();
 
-    Ok(Json(PaginationResponse {
+    Ok(Json(BoardListResponse::Paged(PaginationResponse {
         data,
         pagination: PaginationMeta {
             current_page: page,
             total_pages,
             size,
         },
-    }))
+    })))
 }
 
 /// 특정 ID의 게시글을 조회합니다.
+///
+/// 응답에 현재 버전을 `ETag`로 담으며, `If-None-Match`가 일치하면 본문 없이 304를 반환합니다.
+/// 304 응답도 조회로 세어 조회수를 올립니다.
+/// 조회수와 좋아요 수는 버전을 바꾸지 않으므로 본문에 싣지 않고 `/boards/{id}/engagement`로 제공합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}",
+    tag = "boards",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        ("If-None-Match" = Option<String>, Header, description = "이전에 받은 ETag"),
+    ),
+    responses(
+        (status = 200, description = "게시글", body = BoardResponse, headers(("ETag" = String, description = "게시글의 현재 버전"))),
+        (status = 304, description = "변경 없음"),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
 pub async fn get_board(
     Path(id): Path<i64>,
     State(state): State<AppState>,
-) -> Result<Json<BoardResponse>, ControllerError> {
+    headers: HeaderMap,
+) -> Result<Response, ControllerError> {
     info!("[Controller] get_board 호출됨, id={}", id);
-    let board = state.service.get_board(id).await?;
-    Ok(Json(BoardResponse::from(board)))
+    let board = state.service.view_board(id).await?;
+    let etag = board.etag();
+
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| etag::if_none_match(value, &etag));
+    if not_modified {
+        info!("[Controller] get_board 304 Not Modified, id={}", id);
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response());
+    }
+
+    Ok(([(header::ETAG, etag)], Json(BoardResponse::from(board))).into_response())
 }
 
-/// 새로운 게시글을 생성합니다.
+/// 새로운 게시글을 생성합니다. (로그인 필요, 응답에 첫 버전의 `ETag` 포함)
+#[utoipa::path(
+    post,
+    path = "/boards",
+    tag = "boards",
+    request_body = CreateBoardRequest,
+    responses(
+        (status = 201, description = "생성된 게시글", body = BoardResponse, headers(("ETag" = String, description = "게시글의 현재 버전"))),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 pub async fn create_board(
+    user: AuthUser,
     State(state): State<AppState>,
     Json(req): Json<CreateBoardRequest>,
-) -> Result<(StatusCode, Json<BoardResponse>), ControllerError> {
-    info!("[Controller] create_board 호출됨, title={}", req.title);
-    let board = state.service.create_board(&req.title, &req.content).await?;
-    Ok((StatusCode::CREATED, Json(BoardResponse::from(board))))
+) -> Result<Response, ControllerError> {
+    info!(
+        "[Controller] create_board 호출됨, user={}, title={}",
+        user.username, req.title
+    );
+    let board = state.service.create_board(user.id, req.into()).await?;
+    Ok((
+        StatusCode::CREATED,
+        [(header::ETAG, board.etag())],
+        Json(BoardResponse::from(board)),
+    )
+        .into_response())
 }
 
-/// 기존 게시글을 수정합니다.
+/// 기존 게시글을 수정합니다. (작성자 본인만 가능, `If-Match` 필수)
+///
+/// 성공하면 새 버전을 `ETag` 헤더로 반환합니다.
+#[utoipa::path(
+    put,
+    path = "/boards/{id}",
+    tag = "boards",
+    request_body = UpdateBoardRequest,
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        ("If-Match" = String, Header, description = "현재 게시글의 ETag (예: \"3\")"),
+    ),
+    responses(
+        (status = 200, description = "수정 완료", headers(("ETag" = String, description = "게시글의 현재 버전"))),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+        (status = 412, description = "ETag가 현재 버전과 다릅니다", body = ErrorResponse),
+        (status = 428, description = "If-Match 헤더가 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 pub async fn update_board(
+    user: AuthUser,
     Path(id): Path<i64>,
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<UpdateBoardRequest>,
-) -> Result<StatusCode, ControllerError> {
-    info!("[Controller] update_board 호출됨, id={}", id);
-    state
+) -> Result<Response, ControllerError> {
+    info!(
+        "[Controller] update_board 호출됨, user={}, id={}",
+        user.username, id
+    );
+    let board = state
         .service
-        .update_board(id, &req.title, &req.content)
+        .update_board(user.id, id, if_match(&headers), req.into())
         .await?;
-    Ok(StatusCode::OK)
+    Ok((StatusCode::OK, [(header::ETAG, board.etag())]).into_response())
 }
 
-/// 특정 ID의 게시글을 삭제합니다.
+/// 특정 ID의 게시글을 휴지통으로 옮깁니다. (작성자 본인만 가능, `If-Match` 필수)
+#[utoipa::path(
+    delete,
+    path = "/boards/{id}",
+    tag = "boards",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        ("If-Match" = String, Header, description = "현재 게시글의 ETag (예: \"3\")"),
+    ),
+    responses(
+        (status = 204, description = "휴지통으로 이동"),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+        (status = 412, description = "ETag가 현재 버전과 다릅니다", body = ErrorResponse),
+        (status = 428, description = "If-Match 헤더가 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 pub async fn delete_board(
+    user: AuthUser,
     Path(id): Path<i64>,
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Result<StatusCode, ControllerError> {
-    info!("[Controller] delete_board 호출됨, id={}", id);
-    state.service.delete_board(id).await?;
+    info!(
+        "[Controller] delete_board 호출됨, user={}, id={}",
+        user.username, id
+    );
+    state
+        .service
+        .delete_board(user.id, id, if_match(&headers))
+        .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
-/// 정적 파일을 서빙합니다 (예: index.html).
-pub async fn serve_index() -> Result<Html<String>, ControllerError> {
-    info!("[Controller] static/index.html 호출됨");
-    let content = tokio::fs::read_to_string("static/index.html").await?;
-    Ok(Html(content))
+/// 로그인한 사용자의 휴지통 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/trash",
+    tag = "trash",
+    params(
+        TrashPaginationRequest,
+    ),
+    responses(
+        (status = 200, description = "휴지통 목록", body = TrashPaginationResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn list_trash(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(pagination_req): Query<TrashPaginationRequest>,
+) -> Result<Json<TrashPaginationResponse>, ControllerError> {
+    info!(
+        "[Controller] list_trash 호출됨, user={}, pagination_req={:?}",
+        user.username, pagination_req
+    );
+    let page = pagination_req.page.unwrap_or(1); // 기본 1페이지
+    let size = pagination_req.size.unwrap_or(10); // 기본 10개
+    let (boards, total_pages) = state.service.get_trash(user.id, page, size).await?;
+
+    Ok(Json(TrashPaginationResponse {
+        data: boards.into_iter().map(TrashedBoardResponse::from).collect(),
+        pagination: PaginationMeta {
+            current_page: page,
+            total_pages,
+            size,
+        },
+    }))
+}
+
+/// 휴지통의 게시글을 복원합니다. (작성자 본인만 가능, 응답에 새 `ETag` 포함)
+#[utoipa::path(
+    post,
+    path = "/boards/{id}/restore",
+    tag = "trash",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "복원된 게시글", body = BoardResponse, headers(("ETag" = String, description = "게시글의 현재 버전"))),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "휴지통에서 게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn restore_board(
+    user: AuthUser,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Response, ControllerError> {
+    info!(
+        "[Controller] restore_board 호출됨, user={}, id={}",
+        user.username, id
+    );
+    let board = state.service.restore_board(user.id, id).await?;
+    Ok((
+        [(header::ETAG, board.etag())],
+        Json(BoardResponse::from(board)),
+    )
+        .into_response())
+}
+
+/// 게시글에 좋아요를 누릅니다. 같은 사용자(비로그인이면 같은 IP)가 다시 눌러도 한 번만 셉니다.
+#[utoipa::path(
+    post,
+    path = "/boards/{id}/like",
+    tag = "boards",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "좋아요 상태와 좋아요 수", body = LikeResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn like_board(
+    user: Option<AuthUser>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<LikeResponse>, ControllerError> {
+    let liker = liker_key(user.as_ref(), connect_info.as_ref());
+    info!("[Controller] like_board 호출됨, id={}, liker={}", id, liker);
+    let like_count = state.service.like_board(id, &liker).await?;
+    Ok(Json(LikeResponse {
+        board_id: id,
+        liked: true,
+        like_count,
+    }))
+}
+
+/// 게시글의 좋아요를 취소합니다. 누르지 않은 상태에서 다시 취소해도 성공합니다.
+#[utoipa::path(
+    delete,
+    path = "/boards/{id}/like",
+    tag = "boards",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "좋아요 상태와 좋아요 수", body = LikeResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn unlike_board(
+    user: Option<AuthUser>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<LikeResponse>, ControllerError> {
+    let liker = liker_key(user.as_ref(), connect_info.as_ref());
+    info!(
+        "[Controller] unlike_board 호출됨, id={}, liker={}",
+        id, liker
+    );
+    let like_count = state.service.unlike_board(id, &liker).await?;
+    Ok(Json(LikeResponse {
+        board_id: id,
+        liked: false,
+        like_count,
+    }))
+}
+
+/// 게시글의 조회수와 좋아요 수를 조회합니다. 조회수는 올리지 않습니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/engagement",
+    tag = "boards",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "조회수와 좋아요 수", body = EngagementResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn get_engagement(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<EngagementResponse>, ControllerError> {
+    info!("[Controller] get_engagement 호출됨, id={}", id);
+    let board = state.service.get_engagement(id).await?;
+    Ok(Json(EngagementResponse {
+        board_id: board.id,
+        view_count: board.view_count,
+        like_count: board.like_count,
+    }))
+}
+
+/// 최근 기간의 조회수와 좋아요로 매긴 점수 순으로 인기 게시글을 조회합니다.
+///
+/// 결과는 잠시 캐시되므로 방금 올라간 조회수/좋아요는 늦게 반영될 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/boards/popular",
+    tag = "boards",
+    params(
+        PopularRequest,
+    ),
+    responses(
+        (status = 200, description = "인기 게시글 목록", body = PopularBoardsResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+    ),
+)]
+pub async fn popular_boards(
+    State(state): State<AppState>,
+    Query(req): Query<PopularRequest>,
+) -> Result<Json<PopularBoardsResponse>, ControllerError> {
+    info!("[Controller] popular_boards 호출됨, req={:?}", req);
+    let (days, boards) = state
+        .service
+        .popular_boards(req.window.as_deref(), req.limit)
+        .await?;
+    Ok(Json(PopularBoardsResponse {
+        window: format!("{days}d"),
+        data: boards.into_iter().map(PopularBoardResponse::from).collect(),
+    }))
+}
+
+/// 좋아요를 구분하는 키: 로그인했으면 `user:<id>`, 아니면 `ip:<주소>` (빈도 제한의 클라이언트 키와 같은 형식)
+fn liker_key(user: Option<&AuthUser>, connect_info: Option<&ConnectInfo<SocketAddr>>) -> String {
+    match (user, connect_info) {
+        (Some(user), _) => format!("user:{}", user.id),
+        (None, Some(ConnectInfo(addr))) => format!("ip:{}", addr.ip()),
+        (None, None) => "ip:unknown".to_string(),
+    }
+}
+
+/// 요청의 `If-Match` 헤더 값 (없거나 문자열이 아니면 `None`)
+pub(super) fn if_match(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::IF_MATCH)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// 휴지통에 없는 모든 게시글을 작성 순서대로 내보냅니다. (로그인 필요)
+///
+/// 게시글을 묶음 단위로 조회하면서 바로 응답으로 흘려보내므로, 게시글 수와 관계없이 메모리 사용량이 일정합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/export",
+    tag = "boards",
+    params(
+        BoardTransferRequest,
+    ),
+    responses(
+        (status = 200, description = "게시글 파일 (JSON Lines 또는 CSV)", content(
+            (String = "application/x-ndjson"),
+            (String = "text/csv"),
+        )),
+        (status = 400, description = "지원하지 않는 형식입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn export_boards(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(req): Query<BoardTransferRequest>,
+) -> Result<Response, ControllerError> {
+    info!(
+        "[Controller] export_boards 호출됨, user={}, format={:?}",
+        user.username, req.format
+    );
+    let (format, chunks) = state.service.export_boards(req.format).await?;
+
+    // 응답을 보내기 시작한 뒤의 오류는 상태 코드로 알릴 수 없으므로 로그를 남기고 연결을 끊습니다.
+    let body = Body::from_stream(chunks.map_err(|err| {
+        error!("[Controller] 내보내기 스트림 중단: {:?}", err);
+        std::io::Error::other("게시글 내보내기 중 오류가 발생했습니다.")
+    }));
+    let disposition = format!("attachment; filename=\"boards.{}\"", format.extension());
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// 내보내기와 같은 형식의 파일로 게시글을 일괄 추가합니다. (로그인 필요, 작성자는 요청한 사용자)
+///
+/// 올바른 행만 추가하고, 추가하지 못한 행은 줄 번호와 사유를 결과에 담아 돌려줍니다.
+#[utoipa::path(
+    post,
+    path = "/boards/import",
+    tag = "boards",
+    params(
+        BoardTransferRequest,
+    ),
+    request_body(description = "내보내기와 같은 형식의 파일 (`title`, `content`, `format`, `category_id`, `tags` 외 필드는 무시)", content(
+        (String = "application/x-ndjson"),
+        (String = "text/csv"),
+    )),
+    responses(
+        (status = 200, description = "행별 가져오기 결과", body = ImportReportResponse),
+        (status = 400, description = "지원하지 않는 형식입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 413, description = "파일이 너무 큽니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn import_boards(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(req): Query<BoardTransferRequest>,
+    body: Bytes,
+) -> Result<Json<ImportReportResponse>, ControllerError> {
+    info!(
+        "[Controller] import_boards 호출됨, user={}, format={:?}, bytes={}",
+        user.username,
+        req.format,
+        body.len()
+    );
+    let report = state
+        .service
+        .import_boards(user.id, req.format, &body)
+        .await?;
+    Ok(Json(ImportReportResponse::from(report)))
 }
@@ -0,0 +1,150 @@
+//! `category` 리소스(게시글 카테고리)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::info;
+
+use crate::common::app_state::AppState;
+use crate::middleware::auth::AuthUser;
+
+use super::{
+    dto::{CategoryRequest, CategoryResponse},
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 전체 카테고리를 이름 순으로 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/categories",
+    tag = "categories",
+    responses(
+        (status = 200, description = "카테고리 목록", body = [CategoryResponse]),
+    ),
+)]
+pub async fn list_categories(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<CategoryResponse>>, ControllerError> {
+    info!("[Controller] list_categories 호출됨");
+    let categories = state.categories.list_categories().await?;
+    Ok(Json(
+        categories.into_iter().map(CategoryResponse::from).collect(),
+    ))
+}
+
+/// 특정 ID의 카테고리를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/categories/{id}",
+    tag = "categories",
+    params(
+        ("id" = i64, Path, description = "카테고리 ID"),
+    ),
+    responses(
+        (status = 200, description = "카테고리", body = CategoryResponse),
+        (status = 404, description = "카테고리를 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn get_category(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<CategoryResponse>, ControllerError> {
+    info!("[Controller] get_category 호출됨, id={}", id);
+    let category = state.categories.get_category(id).await?;
+    Ok(Json(CategoryResponse::from(category)))
+}
+
+/// 새 카테고리를 만듭니다. (로그인 필요)
+#[utoipa::path(
+    post,
+    path = "/categories",
+    tag = "categories",
+    request_body = CategoryRequest,
+    responses(
+        (status = 201, description = "생성된 카테고리", body = CategoryResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 409, description = "이미 있는 카테고리 이름입니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn create_category(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Json(req): Json<CategoryRequest>,
+) -> Result<(StatusCode, Json<CategoryResponse>), ControllerError> {
+    info!(
+        "[Controller] create_category 호출됨, user={}, name={}",
+        user.username, req.name
+    );
+    let category = state
+        .categories
+        .create_category(&req.name, req.description.as_deref())
+        .await?;
+    Ok((StatusCode::CREATED, Json(CategoryResponse::from(category))))
+}
+
+/// 카테고리 이름과 설명을 수정합니다. (로그인 필요)
+#[utoipa::path(
+    put,
+    path = "/categories/{id}",
+    tag = "categories",
+    request_body = CategoryRequest,
+    params(
+        ("id" = i64, Path, description = "카테고리 ID"),
+    ),
+    responses(
+        (status = 200, description = "수정된 카테고리", body = CategoryResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 404, description = "카테고리를 찾을 수 없습니다", body = ErrorResponse),
+        (status = 409, description = "이미 있는 카테고리 이름입니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn update_category(
+    user: AuthUser,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+    Json(req): Json<CategoryRequest>,
+) -> Result<Json<CategoryResponse>, ControllerError> {
+    info!(
+        "[Controller] update_category 호출됨, user={}, id={}",
+        user.username, id
+    );
+    let category = state
+        .categories
+        .update_category(id, &req.name, req.description.as_deref())
+        .await?;
+    Ok(Json(CategoryResponse::from(category)))
+}
+
+/// 카테고리를 삭제합니다. 이 카테고리의 게시글은 미분류가 됩니다. (로그인 필요)
+#[utoipa::path(
+    delete,
+    path = "/categories/{id}",
+    tag = "categories",
+    params(
+        ("id" = i64, Path, description = "카테고리 ID"),
+    ),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 404, description = "카테고리를 찾을 수 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn delete_category(
+    user: AuthUser,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<StatusCode, ControllerError> {
+    info!(
+        "[Controller] delete_category 호출됨, user={}, id={}",
+        user.username, id
+    );
+    state.categories.delete_category(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,161 @@
+//! `comment` 리소스(게시글 하위 댓글)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use tracing::info;
+
+use crate::common::app_state::AppState;
+use crate::middleware::auth::AuthUser;
+
+use super::{
+    dto::{
+        CommentPaginationRequest, CommentPaginationResponse, CommentResponse, CreateCommentRequest,
+        PaginationMeta, UpdateCommentRequest,
+    },
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 게시글의 댓글 목록을 루트 댓글 기준으로 페이지네이션하여 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/comments",
+    tag = "comments",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        CommentPaginationRequest,
+    ),
+    responses(
+        (status = 200, description = "루트 댓글과 답글 목록", body = CommentPaginationResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn list_comments(
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+    Query(pagination_req): Query<CommentPaginationRequest>,
+) -> Result<Json<CommentPaginationResponse>, ControllerError> {
+    info!(
+        "[Controller] list_comments 호출됨, board_id={}, pagination_req={:?}",
+        board_id, pagination_req
+    );
+    let page = pagination_req.page.unwrap_or(1); // 기본 1페이지
+    let size = pagination_req.size.unwrap_or(20); // 기본 20개
+
+    let (threads, total_pages) = state
+        .comments
+        .get_comments_paged(board_id, page, size)
+        .await?;
+
+    let data = threads.into_iter().map(CommentResponse::from).collect();
+
+    Ok(Json(CommentPaginationResponse {
+        data,
+        pagination: PaginationMeta {
+            current_page: page,
+            total_pages,
+            size,
+        },
+    }))
+}
+
+/// 게시글에 댓글 또는 답글을 작성합니다. (로그인 필요)
+#[utoipa::path(
+    post,
+    path = "/boards/{id}/comments",
+    tag = "comments",
+    request_body = CreateCommentRequest,
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 201, description = "작성된 댓글", body = CommentResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn create_comment(
+    user: AuthUser,
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+    Json(req): Json<CreateCommentRequest>,
+) -> Result<(StatusCode, Json<CommentResponse>), ControllerError> {
+    info!(
+        "[Controller] create_comment 호출됨, user={}, board_id={}, parent_id={:?}",
+        user.username, board_id, req.parent_id
+    );
+    let comment = state
+        .comments
+        .create_comment(user.id, board_id, req.parent_id, &req.content)
+        .await?;
+    Ok((StatusCode::CREATED, Json(CommentResponse::from(comment))))
+}
+
+/// 댓글을 수정합니다. (작성자 본인만 가능)
+#[utoipa::path(
+    put,
+    path = "/comments/{id}",
+    tag = "comments",
+    request_body = UpdateCommentRequest,
+    params(
+        ("id" = i64, Path, description = "댓글 ID"),
+    ),
+    responses(
+        (status = 200, description = "수정된 댓글", body = CommentResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "댓글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn update_comment(
+    user: AuthUser,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+    Json(req): Json<UpdateCommentRequest>,
+) -> Result<Json<CommentResponse>, ControllerError> {
+    info!(
+        "[Controller] update_comment 호출됨, user={}, id={}",
+        user.username, id
+    );
+    let comment = state
+        .comments
+        .update_comment(user.id, id, &req.content)
+        .await?;
+    Ok(Json(CommentResponse::from(comment)))
+}
+
+/// 댓글을 삭제합니다. 루트 댓글이면 답글도 함께 삭제됩니다. (작성자 본인만 가능)
+#[utoipa::path(
+    delete,
+    path = "/comments/{id}",
+    tag = "comments",
+    params(
+        ("id" = i64, Path, description = "댓글 ID"),
+    ),
+    responses(
+        (status = 204, description = "삭제 완료"),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "댓글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn delete_comment(
+    user: AuthUser,
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<StatusCode, ControllerError> {
+    info!(
+        "[Controller] delete_comment 호출됨, user={}, id={}",
+        user.username, id
+    );
+    state.comments.delete_comment(user.id, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,14 +1,38 @@
 //! Controller 계층에서 사용하는 데이터 전송 객체 (DTO) 모음
 
-use crate::models::board::{Board, BoardListItem};
-use serde::{Deserialize, Serialize};
+use crate::common::markdown::render_content;
+use crate::models::attachment::Attachment;
+use crate::models::board::{
+    Board, BoardListItem, BoardUpdate, ContentFormat, NewBoard, PopularBoard, TrashedBoard,
+};
+use crate::models::board_event::BoardEvent;
+use crate::models::board_transfer::{ImportReport, ImportRowError};
+use crate::models::category::{Category, TagCount};
+use crate::models::comment::{Comment, CommentThread};
+use crate::models::revision::{BoardRevision, DiffLine, DiffOp, RevisionDiff};
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 /// 게시글 응답을 위한 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct BoardResponse {
     pub id: i64,
     pub title: String,
+    /// 작성자가 입력한 본문 원문
     pub content: String,
+    /// 본문 형식 (`plain` 또는 `markdown`)
+    #[schema(example = "markdown")]
+    pub format: String,
+    /// 본문을 형식에 맞게 렌더링하고 허용 목록으로 정화한 HTML (그대로 화면에 넣어도 안전)
+    pub content_html: String,
+    pub author_id: Option<i64>,
+    /// 카테고리 ID (미분류이면 null)
+    pub category_id: Option<i64>,
+    /// 태그 이름 (가나다순)
+    pub tags: Vec<String>,
+    /// 단건 조회 시에만 포함되는 버전 (`ETag` 헤더와 같은 값)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
     pub created_at: Option<String>,
 }
 
@@ -18,7 +42,13 @@ impl From<Board> for BoardResponse {
         Self {
             id: board.id,
             title: board.title,
+            content_html: render_content(&board.content, board.content_format),
             content: board.content,
+            format: board.content_format.as_str().to_string(),
+            author_id: board.author_id,
+            category_id: board.category_id,
+            tags: board.tags,
+            version: Some(board.version),
             created_at: board.created_at.map(|ts| ts.to_string()),
         }
     }
@@ -29,44 +59,681 @@ impl From<BoardListItem> for BoardResponse {
         Self {
             id: board.id,
             title: board.title,
+            content_html: render_content(&board.content, board.content_format),
             content: board.content,
+            format: board.content_format.as_str().to_string(),
+            author_id: board.author_id,
+            category_id: board.category_id,
+            tags: board.tags,
+            version: None,
             created_at: board.created_at,
         }
     }
 }
 
-/// 페이지네이션 요청 DTO
-#[derive(Debug, Deserialize)]
+/// 휴지통 게시글 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedBoardResponse {
+    pub id: i64,
+    pub title: String,
+    pub author_id: Option<i64>,
+    pub created_at: Option<String>,
+    /// 휴지통으로 옮긴 시각
+    pub deleted_at: Option<String>,
+}
+
+/// TrashedBoard 모델을 TrashedBoardResponse DTO로 변환
+impl From<TrashedBoard> for TrashedBoardResponse {
+    fn from(board: TrashedBoard) -> Self {
+        Self {
+            id: board.id,
+            title: board.title,
+            author_id: board.author_id,
+            created_at: board.created_at,
+            deleted_at: board.deleted_at,
+        }
+    }
+}
+
+/// 휴지통 목록 페이지네이션 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrashPaginationRequest {
+    pub page: Option<u32>,
+    pub size: Option<u32>,
+}
+
+/// 휴지통 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashPaginationResponse {
+    pub data: Vec<TrashedBoardResponse>,
+    pub pagination: PaginationMeta,
+}
+
+/// 게시글 수정 이력 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionResponse {
+    pub board_id: i64,
+    pub revision: i64,
+    pub title: String,
+    /// 단건 조회 시에만 포함되는 내용
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// 보관 당시 게시글 버전
+    pub version: i64,
+    pub created_at: Option<String>,
+}
+
+impl RevisionResponse {
+    /// 목록용 응답 (내용 제외)
+    pub fn summary(revision: BoardRevision) -> Self {
+        Self {
+            content: None,
+            ..Self::from(revision)
+        }
+    }
+}
+
+/// BoardRevision 모델을 RevisionResponse DTO로 변환
+impl From<BoardRevision> for RevisionResponse {
+    fn from(revision: BoardRevision) -> Self {
+        Self {
+            board_id: revision.board_id,
+            revision: revision.revision,
+            title: revision.title,
+            content: Some(revision.content),
+            version: revision.version,
+            created_at: revision.created_at.map(|ts| ts.to_string()),
+        }
+    }
+}
+
+/// 수정 이력 비교 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RevisionDiffRequest {
+    /// 비교 기준 이력 번호
+    pub from: i64,
+    /// 비교 대상 이력 번호 (없으면 현재 게시글)
+    pub to: Option<i64>,
+}
+
+/// 제목 비교 결과 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TitleDiffResponse {
+    pub old: String,
+    pub new: String,
+    pub changed: bool,
+}
+
+/// 줄 단위 비교 결과의 한 줄 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiffLineResponse {
+    /// `equal`, `insert`, `delete` 중 하나
+    #[schema(value_type = String, example = "insert")]
+    pub op: &'static str,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+impl From<DiffLine> for DiffLineResponse {
+    fn from(line: DiffLine) -> Self {
+        Self {
+            op: match line.op {
+                DiffOp::Equal => "equal",
+                DiffOp::Insert => "insert",
+                DiffOp::Delete => "delete",
+            },
+            old_line: line.old_line,
+            new_line: line.new_line,
+            text: line.text,
+        }
+    }
+}
+
+/// 수정 이력 비교 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionDiffResponse {
+    pub from: i64,
+    /// 비교 대상 이력 번호 (현재 게시글과 비교했으면 null)
+    pub to: Option<i64>,
+    pub title: TitleDiffResponse,
+    pub lines: Vec<DiffLineResponse>,
+}
+
+/// RevisionDiff 모델을 RevisionDiffResponse DTO로 변환
+impl From<RevisionDiff> for RevisionDiffResponse {
+    fn from(diff: RevisionDiff) -> Self {
+        Self {
+            from: diff.from,
+            to: diff.to,
+            title: TitleDiffResponse {
+                changed: diff.old_title != diff.new_title,
+                old: diff.old_title,
+                new: diff.new_title,
+            },
+            lines: diff.lines.into_iter().map(DiffLineResponse::from).collect(),
+        }
+    }
+}
+
+/// 페이지네이션 및 검색 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationRequest {
     pub page: Option<u32>,
+    /// 한 페이지의 게시글 수 (기본 10, 최대 100)
     pub size: Option<u32>,
+    /// 제목/내용 검색어
+    pub q: Option<String>,
+    /// 제목 검색어
+    pub title: Option<String>,
+    /// 작성일 시작 (YYYY-MM-DD)
+    pub from: Option<String>,
+    /// 작성일 끝 (YYYY-MM-DD)
+    pub to: Option<String>,
+    /// 정렬 기준 (예: created_at,desc)
+    pub sort: Option<String>,
+    /// 카테고리 ID
+    pub category: Option<String>,
+    /// 태그 이름 (대소문자 무시)
+    pub tag: Option<String>,
+    /// 키셋 페이지네이션 커서. 파라미터가 있으면 커서 모드로 동작합니다 (빈 값 = 첫 페이지).
+    pub cursor: Option<String>,
+    /// 커서 모드에서 전체 게시글 수를 함께 조회할지 여부 (기본 false)
+    pub with_total: Option<bool>,
 }
 
 /// 페이지네이션 응답 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginationResponse {
     pub data: Vec<BoardResponse>,
     pub pagination: PaginationMeta,
 }
 
+/// 게시글 목록 응답 DTO: `cursor` 파라미터 유무에 따라 페이지 방식 또는 커서 방식
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum BoardListResponse {
+    Paged(PaginationResponse),
+    Cursor(CursorPaginationResponse),
+}
+
 /// 페이지네이션 메타데이터 DTO
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginationMeta {
     pub current_page: u32,
     pub total_pages: u32,
     pub size: u32,
 }
 
+/// 커서 페이지네이션 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CursorPaginationResponse {
+    pub data: Vec<BoardResponse>,
+    pub pagination: CursorMeta,
+}
+
+/// 커서 페이지네이션 메타데이터 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CursorMeta {
+    pub size: u32,
+    /// 다음 페이지 커서 (마지막 페이지면 null)
+    pub next_cursor: Option<String>,
+    /// `with_total=true`로 요청한 경우에만 포함되는 전체 게시글 수
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u32>,
+}
+
 /// 게시글 생성을 위한 요청 DTO
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateBoardRequest {
     pub title: String,
     pub content: String,
+    /// 본문 형식: `plain`(기본값) 또는 `markdown`
+    #[serde(default, deserialize_with = "content_format")]
+    #[schema(value_type = Option<String>, example = "markdown")]
+    pub format: Option<ContentFormat>,
+    /// 카테고리 ID (없으면 미분류)
+    pub category_id: Option<i64>,
+    /// 태그 이름 (앞뒤 공백 제거 후 소문자로 저장, 최대 10개)
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl From<CreateBoardRequest> for NewBoard {
+    fn from(req: CreateBoardRequest) -> Self {
+        Self {
+            title: req.title,
+            content: req.content,
+            format: req.format.unwrap_or_default(),
+            category_id: req.category_id,
+            tags: req.tags,
+        }
+    }
 }
 
 /// 게시글 수정을 위한 요청 DTO
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateBoardRequest {
     pub title: String,
     pub content: String,
+    /// 본문 형식: `plain` 또는 `markdown` (없으면 그대로 둡니다)
+    #[serde(default, deserialize_with = "content_format")]
+    #[schema(value_type = Option<String>, example = "markdown")]
+    pub format: Option<ContentFormat>,
+    /// 필드가 없으면 카테고리를 그대로 두고, null이면 미분류로 바꿉니다.
+    #[serde(default, deserialize_with = "present")]
+    #[schema(value_type = Option<i64>)]
+    pub category_id: Option<Option<i64>>,
+    /// 필드가 없으면 태그를 그대로 두고, 있으면 이 목록으로 통째로 바꿉니다.
+    pub tags: Option<Vec<String>>,
+}
+
+impl From<UpdateBoardRequest> for BoardUpdate {
+    fn from(req: UpdateBoardRequest) -> Self {
+        Self {
+            title: req.title,
+            content: req.content,
+            format: req.format,
+            category_id: req.category_id,
+            tags: req.tags,
+        }
+    }
+}
+
+/// 필드가 있으면(null 포함) `Some`으로 감쌉니다. `#[serde(default)]`와 함께 써서 없는 필드와 null을 구분합니다.
+fn present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// 본문 형식 이름(`plain`/`markdown`)을 해석합니다. null이면 `None`, 그 외의 값은 역직렬화 오류입니다.
+fn content_format<'de, D>(deserializer: D) -> Result<Option<ContentFormat>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|format| {
+            ContentFormat::parse(&format).ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "지원하지 않는 본문 형식입니다: {format} (plain 또는 markdown)"
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// 카테고리 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryResponse {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// Category 모델을 CategoryResponse DTO로 변환
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+            description: category.description,
+            created_at: category.created_at.map(|ts| ts.to_string()),
+        }
+    }
+}
+
+/// 카테고리 생성/수정 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CategoryRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// 태그별 게시글 수 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagCountResponse {
+    pub name: String,
+    /// 이 태그가 붙은 게시글 수 (휴지통 제외)
+    pub board_count: u32,
+}
+
+/// TagCount 모델을 TagCountResponse DTO로 변환
+impl From<TagCount> for TagCountResponse {
+    fn from(tag: TagCount) -> Self {
+        Self {
+            name: tag.name,
+            board_count: tag.board_count,
+        }
+    }
+}
+
+/// 좋아요/좋아요 취소 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LikeResponse {
+    pub board_id: i64,
+    /// 요청한 사용자(또는 IP)가 좋아요를 누른 상태인지
+    pub liked: bool,
+    /// 게시글의 전체 좋아요 수
+    pub like_count: i64,
+}
+
+/// 조회수/좋아요 수 응답 DTO
+///
+/// 조회수와 좋아요 수는 게시글 버전을 바꾸지 않으므로, `ETag`가 붙는 게시글 응답과 분리해 제공합니다.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EngagementResponse {
+    pub board_id: i64,
+    /// 누적 조회수 (아직 DB에 반영하지 않은 조회수 포함)
+    pub view_count: i64,
+    /// 게시글의 전체 좋아요 수
+    pub like_count: i64,
+}
+
+/// 인기 게시글 조회 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PopularRequest {
+    /// 집계 기간: `<일수>d` 형식, 1d~30d (기본값 7d)
+    pub window: Option<String>,
+    /// 가져올 게시글 수 (기본값 10, 최대 50)
+    pub limit: Option<u32>,
+}
+
+/// 인기 게시글 항목 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PopularBoardResponse {
+    pub id: i64,
+    pub title: String,
+    pub author_id: Option<i64>,
+    pub category_id: Option<i64>,
+    /// 누적 조회수
+    pub view_count: i64,
+    /// 집계 기간 안의 조회수
+    pub window_views: i64,
+    /// 집계 기간 안에 받은 좋아요 수
+    pub window_likes: i64,
+    /// 기간 안의 조회수와 좋아요에 가중치를 곱해 더한 점수
+    pub score: i64,
+    pub created_at: Option<String>,
+}
+
+/// PopularBoard 모델을 PopularBoardResponse DTO로 변환
+impl From<PopularBoard> for PopularBoardResponse {
+    fn from(board: PopularBoard) -> Self {
+        Self {
+            id: board.id,
+            title: board.title,
+            author_id: board.author_id,
+            category_id: board.category_id,
+            view_count: board.view_count,
+            window_views: board.window_views,
+            window_likes: board.window_likes,
+            score: board.score,
+            created_at: board.created_at,
+        }
+    }
+}
+
+/// 인기 게시글 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PopularBoardsResponse {
+    /// 적용된 집계 기간 (예: `7d`)
+    pub window: String,
+    /// 점수가 높은 순서의 게시글
+    pub data: Vec<PopularBoardResponse>,
+}
+
+/// 게시글 변경 이벤트 구독 요청 DTO (SSE/WebSocket 공용)
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventStreamRequest {
+    /// 쉼표로 구분한 구독할 게시글 ID (없으면 모든 게시글)
+    pub boards: Option<String>,
+    /// 이 ID 뒤의 이벤트부터 다시 받습니다. (SSE는 `Last-Event-ID` 헤더가 우선)
+    pub last_event_id: Option<String>,
+}
+
+/// 게시글 변경 이벤트 DTO (SSE `data:`, WebSocket 텍스트 메시지)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardEventResponse {
+    /// 이벤트 ID (`<epoch>-<순번>`, SSE `id:`와 같은 값)
+    pub id: String,
+    /// `created`, `updated`, `deleted` 중 하나
+    pub kind: String,
+    pub board_id: i64,
+    pub author_id: Option<i64>,
+    pub title: String,
+    /// 변경 후 버전 (삭제 이벤트에는 없음)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+    /// 이벤트 발생 시각 (RFC 3339, UTC)
+    pub occurred_at: String,
+}
+
+/// BoardEvent 모델을 BoardEventResponse DTO로 변환
+impl From<&BoardEvent> for BoardEventResponse {
+    fn from(event: &BoardEvent) -> Self {
+        Self {
+            id: event.id.to_string(),
+            kind: event.kind.as_str().to_string(),
+            board_id: event.board_id,
+            author_id: event.author_id,
+            title: event.title.clone(),
+            version: event.version,
+            occurred_at: event.occurred_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 이벤트가 아닌 알림 메시지 DTO (`reset`: 놓친 이벤트가 있어 목록을 다시 불러와야 함, `error`: 잘못된 요청)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventNoticeResponse {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// WebSocket 클라이언트가 보내는 구독 변경 메시지
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EventSubscribeMessage {
+    /// 구독할 게시글 ID 목록으로 바꿉니다. (빈 목록이면 모든 게시글)
+    pub subscribe: Vec<i64>,
+}
+
+/// 게시글 내보내기/가져오기 형식 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BoardTransferRequest {
+    /// 파일 형식: `jsonl`(기본값) 또는 `csv`
+    pub format: Option<String>,
+}
+
+/// 가져오지 못한 행 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportRowErrorResponse {
+    /// 파일의 줄 번호 (1부터 시작, CSV는 머리글이 1번 줄)
+    pub line: u64,
+    pub error: String,
+}
+
+/// 게시글 가져오기 결과 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportReportResponse {
+    /// 해석한 행 수 (빈 줄 제외)
+    pub total: usize,
+    pub imported: usize,
+    pub failed: usize,
+    /// 추가된 게시글 ID (파일 순서)
+    pub ids: Vec<i64>,
+    pub errors: Vec<ImportRowErrorResponse>,
+}
+
+/// ImportReport 모델을 ImportReportResponse DTO로 변환
+impl From<ImportReport> for ImportReportResponse {
+    fn from(report: ImportReport) -> Self {
+        Self {
+            total: report.total,
+            imported: report.imported,
+            failed: report.failed,
+            ids: report.ids,
+            errors: report
+                .errors
+                .into_iter()
+                .map(|ImportRowError { line, error }| ImportRowErrorResponse { line, error })
+                .collect(),
+        }
+    }
+}
+
+/// 회원가입 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegisterRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// 로그인 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// 사용자 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserResponse {
+    pub id: i64,
+    pub username: String,
+}
+
+/// 액세스 토큰 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    /// 항상 `Bearer`
+    pub token_type: String,
+    /// 토큰 유효 시간 (초)
+    pub expires_in: u64,
+}
+
+/// 댓글 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: i64,
+    pub board_id: i64,
+    /// 답글이면 루트 댓글 ID
+    pub parent_id: Option<i64>,
+    pub author_id: Option<i64>,
+    pub content: String,
+    pub created_at: Option<String>,
+    /// 목록 조회 시 루트 댓글에만 포함되는 답글 목록
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(no_recursion)]
+    pub replies: Option<Vec<CommentResponse>>,
+}
+
+/// Comment 모델을 CommentResponse DTO로 변환
+impl From<Comment> for CommentResponse {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: comment.id,
+            board_id: comment.board_id,
+            parent_id: comment.parent_id,
+            author_id: comment.author_id,
+            content: comment.content,
+            created_at: comment.created_at.map(|ts| ts.to_string()),
+            replies: None,
+        }
+    }
+}
+
+impl From<CommentThread> for CommentResponse {
+    fn from(thread: CommentThread) -> Self {
+        Self {
+            replies: Some(
+                thread
+                    .replies
+                    .into_iter()
+                    .map(CommentResponse::from)
+                    .collect(),
+            ),
+            ..CommentResponse::from(thread.comment)
+        }
+    }
+}
+
+/// 댓글 목록 페이지네이션 요청 DTO
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CommentPaginationRequest {
+    pub page: Option<u32>,
+    pub size: Option<u32>,
+}
+
+/// 댓글 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentPaginationResponse {
+    pub data: Vec<CommentResponse>,
+    pub pagination: PaginationMeta,
+}
+
+/// 댓글 작성 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCommentRequest {
+    pub content: String,
+    /// 답글을 달 루트 댓글 ID (없으면 루트 댓글)
+    pub parent_id: Option<i64>,
+}
+
+/// 댓글 수정 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCommentRequest {
+    pub content: String,
+}
+
+/// 첨부파일 메타데이터 응답 DTO (저장 경로는 노출하지 않습니다)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: i64,
+    pub board_id: i64,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub uploader_id: Option<i64>,
+    pub created_at: Option<String>,
+    /// 다운로드 URL
+    pub url: String,
+}
+
+/// Attachment 모델을 AttachmentResponse DTO로 변환
+impl From<Attachment> for AttachmentResponse {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            url: format!("/attachments/{}", attachment.id),
+            id: attachment.id,
+            board_id: attachment.board_id,
+            filename: attachment.filename,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            sha256: attachment.sha256,
+            uploader_id: attachment.uploader_id,
+            created_at: attachment.created_at.map(|ts| ts.to_string()),
+        }
+    }
+}
+
+/// 첨부파일 업로드 요청 본문 (`multipart/form-data`, API 문서용)
+#[derive(Debug, ToSchema)]
+pub struct AttachmentUploadForm {
+    /// 업로드할 파일
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
 }
@@ -2,14 +2,22 @@
 
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
-use serde_json::json;
+use serde::Serialize;
 use tracing::error;
+use utoipa::ToSchema;
 
 use crate::services::board_service::ServiceError;
 
+/// 모든 에러 응답의 JSON 본문
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    /// 사용자에게 보여줄 에러 메시지
+    pub error: String,
+}
+
 /// 컨트롤러에서 발생하는 에러를 통합적으로 다루는 열거형
 pub enum ControllerError {
     ServiceError(ServiceError),
@@ -43,6 +51,56 @@ impl IntoResponse for ControllerError {
                         "요청한 리소스를 찾을 수 없습니다.".to_string(),
                     ),
                     ServiceError::InvalidInput(msg) => (StatusCode::BAD_REQUEST, msg),
+                    ServiceError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+                    ServiceError::Forbidden => (
+                        StatusCode::FORBIDDEN,
+                        "해당 리소스에 대한 권한이 없습니다.".to_string(),
+                    ),
+                    ServiceError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+                    ServiceError::PreconditionFailed => (
+                        StatusCode::PRECONDITION_FAILED,
+                        "다른 사용자가 먼저 수정했습니다. 최신 내용을 다시 불러와 주세요."
+                            .to_string(),
+                    ),
+                    ServiceError::PreconditionRequired => (
+                        StatusCode::PRECONDITION_REQUIRED,
+                        "If-Match 헤더가 필요합니다.".to_string(),
+                    ),
+                    ServiceError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
+                    ServiceError::UnsupportedMediaType(msg) => {
+                        (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg)
+                    }
+                    ServiceError::RangeNotSatisfiable(size) => {
+                        // 416 응답에는 전체 크기를 알려주는 Content-Range 헤더가 필요합니다.
+                        let body = Json(ErrorResponse {
+                            error: "요청한 범위를 만족할 수 없습니다.".to_string(),
+                        });
+                        return (
+                            StatusCode::RANGE_NOT_SATISFIABLE,
+                            [(header::CONTENT_RANGE, format!("bytes */{}", size))],
+                            body,
+                        )
+                            .into_response();
+                    }
+                    ServiceError::TooManyRequests(retry_after) => {
+                        // 429 응답에는 다시 시도할 수 있는 시점을 알려주는 Retry-After 헤더를 붙입니다.
+                        let body = Json(ErrorResponse {
+                            error: "요청이 너무 많습니다. 잠시 후 다시 시도해 주세요.".to_string(),
+                        });
+                        return (
+                            StatusCode::TOO_MANY_REQUESTS,
+                            [(header::RETRY_AFTER, retry_after.to_string())],
+                            body,
+                        )
+                            .into_response();
+                    }
+                    ServiceError::StorageError(io_err) => {
+                        error!("첨부파일 저장소 오류 발생: {:?}", io_err);
+                        (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            "서버 내부 오류가 발생했습니다.".to_string(),
+                        )
+                    }
                     ServiceError::DatabaseError(db_err) => {
                         error!("데이터베이스 오류 발생: {:?}", db_err);
                         (
@@ -62,9 +120,9 @@ impl IntoResponse for ControllerError {
         };
 
         // 에러 응답을 JSON 형식으로 생성
-        let body = Json(json!({
-            "error": error_message,
-        }));
+        let body = Json(ErrorResponse {
+            error: error_message,
+        });
 
         (status, body).into_response()
     }
@@ -0,0 +1,241 @@
+//! 게시글 변경 이벤트를 Server-Sent Events와 WebSocket으로 보내는 핸들러 함수들
+//!
+//! 두 방식 모두 `boards`로 구독할 게시글을 고르고, 마지막으로 받은 이벤트 ID로 끊긴 뒤의 이벤트를 이어 받습니다.
+//! 이어 받을 수 없으면 `reset` 알림을 먼저 보내므로, 클라이언트는 이때 `/boards`를 다시 불러오면 됩니다.
+
+use axum::{
+    extract::{
+        Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::HeaderMap,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use futures_util::stream;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::common::app_state::AppState;
+use crate::models::board_event::EventId;
+use crate::services::board_events::{
+    Delivery, MAX_SUBSCRIBED_BOARDS, Subscription, parse_board_filter,
+};
+use crate::services::board_service::ServiceError;
+
+use super::{
+    dto::{BoardEventResponse, EventNoticeResponse, EventStreamRequest, EventSubscribeMessage},
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 재연결 시 브라우저가 보내는 마지막 이벤트 ID 헤더
+const LAST_EVENT_ID: &str = "last-event-id";
+
+/// 게시글 생성/수정/삭제 이벤트를 Server-Sent Events로 구독합니다.
+///
+/// 이벤트 이름은 `created`/`updated`/`deleted`이며, 놓친 이벤트가 있으면 `reset`을 보냅니다.
+/// 연결을 유지하도록 하트비트 주기마다 주석 줄을 보냅니다.
+#[utoipa::path(
+    get,
+    path = "/boards/events",
+    tag = "events",
+    params(
+        EventStreamRequest,
+        ("Last-Event-ID" = Option<String>, Header, description = "마지막으로 받은 이벤트 ID (재연결 시)"),
+    ),
+    responses(
+        (status = 200, description = "게시글 변경 이벤트 스트림", content_type = "text/event-stream", body = BoardEventResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+    ),
+)]
+pub async fn board_events_sse(
+    State(state): State<AppState>,
+    Query(req): Query<EventStreamRequest>,
+    headers: HeaderMap,
+) -> Result<Response, ControllerError> {
+    let filter = parse_board_filter(req.boards.as_deref())?;
+    let last_event_id = match headers.get(LAST_EVENT_ID) {
+        Some(value) => Some(parse_last_event_id(value.to_str().unwrap_or_default())?),
+        None => req
+            .last_event_id
+            .as_deref()
+            .map(parse_last_event_id)
+            .transpose()?,
+    };
+    info!(
+        "[Controller] board_events_sse 호출됨, boards={:?}, last_event_id={:?}",
+        filter, last_event_id
+    );
+
+    let events = state.service.events();
+    let subscription = events.subscribe(last_event_id);
+    let stream = stream::unfold(
+        (subscription, filter),
+        |(mut subscription, filter)| async move {
+            loop {
+                let delivery = subscription.next().await?;
+                if wanted(filter.as_ref(), &delivery) {
+                    return Some((
+                        Ok::<_, Infallible>(sse_event(&delivery)),
+                        (subscription, filter),
+                    ));
+                }
+            }
+        },
+    );
+    Ok(Sse::new(stream)
+        .keep_alive(KeepAlive::new().interval(events.heartbeat()))
+        .into_response())
+}
+
+/// 게시글 생성/수정/삭제 이벤트를 WebSocket으로 구독합니다.
+///
+/// 서버는 이벤트마다 JSON 텍스트 메시지를 보내고 하트비트 주기마다 ping을 보냅니다.
+/// 클라이언트는 `{"subscribe": [1, 2]}`를 보내 구독할 게시글을 바꿀 수 있습니다. (빈 목록이면 모든 게시글)
+/// 브라우저 WebSocket은 헤더를 정할 수 없으므로 이어 받기는 `last_event_id` 파라미터로 요청합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/events/ws",
+    tag = "events",
+    params(
+        EventStreamRequest,
+    ),
+    responses(
+        (status = 101, description = "WebSocket으로 전환 (메시지 형식은 BoardEventResponse, EventNoticeResponse)"),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+    ),
+)]
+pub async fn board_events_ws(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(req): Query<EventStreamRequest>,
+) -> Result<Response, ControllerError> {
+    let filter = parse_board_filter(req.boards.as_deref())?;
+    let last_event_id = req
+        .last_event_id
+        .as_deref()
+        .map(parse_last_event_id)
+        .transpose()?;
+    info!(
+        "[Controller] board_events_ws 호출됨, boards={:?}, last_event_id={:?}",
+        filter, last_event_id
+    );
+    // 연결 전환 전에 구독해 두어야 요청 시점 이후의 이벤트를 놓치지 않습니다.
+    let events = state.service.events();
+    let subscription = events.subscribe(last_event_id);
+    let heartbeat = events.heartbeat();
+    Ok(ws.on_upgrade(move |socket| serve_socket(socket, subscription, filter, heartbeat)))
+}
+
+/// WebSocket 연결 하나를 처리합니다. 클라이언트가 닫거나 서버가 종료되면 끝납니다.
+async fn serve_socket(
+    mut socket: WebSocket,
+    mut subscription: Subscription,
+    mut filter: Option<HashSet<i64>>,
+    heartbeat: Duration,
+) {
+    let mut ticker = interval(heartbeat);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // 첫 tick은 바로 끝나므로 연결 직후 ping을 보내지 않도록 넘깁니다.
+    ticker.tick().await;
+
+    loop {
+        let outgoing = tokio::select! {
+            delivery = subscription.next() => match delivery {
+                Some(delivery) if wanted(filter.as_ref(), &delivery) => {
+                    Message::Text(socket_text(&delivery))
+                }
+                Some(_) => continue,
+                None => Message::Close(None),
+            },
+            _ = ticker.tick() => Message::Ping(Vec::new()),
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => match resubscribe(&text) {
+                    Ok(ids) => {
+                        info!("[Controller] WebSocket 구독 변경: {:?}", ids);
+                        filter = ids;
+                        continue;
+                    }
+                    Err(error) => {
+                        warn!("[Controller] 잘못된 WebSocket 메시지: {}", error);
+                        Message::Text(notice("error", Some(error)))
+                    }
+                },
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+        };
+        let closing = matches!(outgoing, Message::Close(_));
+        if socket.send(outgoing).await.is_err() || closing {
+            break;
+        }
+    }
+}
+
+/// `Last-Event-ID` 헤더나 `last_event_id` 파라미터 값을 해석합니다.
+fn parse_last_event_id(value: &str) -> Result<EventId, ServiceError> {
+    EventId::parse(value)
+        .ok_or_else(|| ServiceError::InvalidInput("Last-Event-ID가 올바르지 않습니다.".to_string()))
+}
+
+/// 구독 대상이 아닌 게시글의 이벤트는 건너뜁니다. (`reset` 알림은 항상 보냄)
+fn wanted(filter: Option<&HashSet<i64>>, delivery: &Delivery) -> bool {
+    match (filter, delivery) {
+        (Some(ids), Delivery::Event(event)) => ids.contains(&event.board_id),
+        _ => true,
+    }
+}
+
+/// SSE 이벤트: 게시글 변경은 `id:`/`event:`/`data:`를, 놓친 이벤트 알림은 `event: reset`을 씁니다.
+fn sse_event(delivery: &Delivery) -> Event {
+    match delivery {
+        Delivery::Event(event) => Event::default()
+            .id(event.id.to_string())
+            .event(event.kind.as_str())
+            .data(
+                serde_json::to_string(&BoardEventResponse::from(event.as_ref()))
+                    .unwrap_or_default(),
+            ),
+        Delivery::Missed => Event::default().event("reset").data(notice("reset", None)),
+    }
+}
+
+/// WebSocket 텍스트 메시지 (이벤트 또는 `reset` 알림)
+fn socket_text(delivery: &Delivery) -> String {
+    match delivery {
+        Delivery::Event(event) => {
+            serde_json::to_string(&BoardEventResponse::from(event.as_ref())).unwrap_or_default()
+        }
+        Delivery::Missed => notice("reset", None),
+    }
+}
+
+/// 알림 메시지 JSON
+fn notice(kind: &str, error: Option<String>) -> String {
+    serde_json::to_string(&EventNoticeResponse {
+        kind: kind.to_string(),
+        error,
+    })
+    .unwrap_or_default()
+}
+
+/// 클라이언트의 구독 변경 메시지를 새 구독 대상으로 변환합니다.
+fn resubscribe(text: &str) -> Result<Option<HashSet<i64>>, String> {
+    let message: EventSubscribeMessage =
+        serde_json::from_str(text).map_err(|err| format!("구독 메시지 형식 오류: {err}"))?;
+    if let Some(id) = message.subscribe.iter().find(|id| **id <= 0) {
+        return Err(format!("잘못된 게시글 ID입니다: {id}"));
+    }
+    let ids: HashSet<i64> = message.subscribe.into_iter().collect();
+    if ids.len() > MAX_SUBSCRIBED_BOARDS {
+        return Err(format!(
+            "한 번에 구독할 수 있는 게시글은 최대 {MAX_SUBSCRIBED_BOARDS}개입니다."
+        ));
+    }
+    Ok((!ids.is_empty()).then_some(ids))
+}
@@ -0,0 +1,118 @@
+//! 로드 밸런서/오케스트레이터용 헬스 체크 핸들러 함수들
+
+use axum::{Json, extract::State, http::StatusCode};
+use serde::Serialize;
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::common::app_state::AppState;
+
+/// 생존 여부 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LivenessResponse {
+    /// 항상 `ok`
+    #[schema(value_type = String, example = "ok")]
+    pub status: &'static str,
+}
+
+/// 준비 상태 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `ready` 또는 `not_ready`
+    #[schema(value_type = String, example = "ready")]
+    pub status: &'static str,
+    /// 우아한 종료가 진행 중인지 여부
+    pub shutting_down: bool,
+    pub database: DatabaseCheck,
+    pub pool: PoolStatus,
+}
+
+/// DB ping 결과
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatabaseCheck {
+    /// 저장소 백엔드 (`oracle`, `sqlite`)
+    #[schema(value_type = String, example = "oracle")]
+    pub backend: &'static str,
+    pub ok: bool,
+    /// ping 왕복 시간 (밀리초, 실패 시 null)
+    #[schema(value_type = Option<u64>)]
+    pub latency_ms: Option<u128>,
+    /// 실패 원인 (성공 시 null)
+    pub error: Option<String>,
+}
+
+/// 커넥션 풀 상태
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolStatus {
+    pub max_size: u32,
+    pub connections: u32,
+    pub idle_connections: u32,
+    pub active_connections: u32,
+}
+
+/// 프로세스가 살아 있는지 확인합니다 (liveness). 외부 의존성은 확인하지 않습니다.
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "ops",
+    responses(
+        (status = 200, description = "프로세스 동작 중", body = LivenessResponse),
+    ),
+)]
+pub async fn healthz() -> Json<LivenessResponse> {
+    Json(LivenessResponse { status: "ok" })
+}
+
+/// 요청을 받을 준비가 되었는지 확인합니다 (readiness).
+///
+/// 커넥션 풀에서 커넥션을 꺼내 시간 제한 안에 ping이 성공해야 200이며,
+/// DB에 닿지 못하거나 종료가 진행 중이면 503을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    tag = "ops",
+    responses(
+        (status = 200, description = "요청을 받을 준비가 됨", body = ReadinessResponse),
+        (status = 503, description = "DB에 닿지 못하거나 종료 중", body = ReadinessResponse),
+    ),
+)]
+pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<ReadinessResponse>) {
+    let shutting_down = state.health.is_shutting_down();
+    let ping = state.pool.ping(state.health.ping_timeout()).await;
+    let pool_state = state.pool.state();
+
+    let ready = ping.is_ok() && !shutting_down;
+    if !ready {
+        warn!(
+            "[Controller] readyz 실패: shutting_down={}, ping={:?}",
+            shutting_down, ping
+        );
+    }
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let (latency_ms, error) = match ping {
+        Ok(latency) => (Some(latency.as_millis()), None),
+        Err(err) => (None, Some(err)),
+    };
+    let body = ReadinessResponse {
+        status: if ready { "ready" } else { "not_ready" },
+        shutting_down,
+        database: DatabaseCheck {
+            backend: state.pool.backend(),
+            ok: error.is_none(),
+            latency_ms,
+            error,
+        },
+        pool: PoolStatus {
+            max_size: state.pool.max_size(),
+            connections: pool_state.connections,
+            idle_connections: pool_state.idle_connections,
+            active_connections: pool_state.connections - pool_state.idle_connections,
+        },
+    };
+    (status, Json(body))
+}
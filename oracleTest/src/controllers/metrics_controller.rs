@@ -0,0 +1,23 @@
+//! 운영 메트릭을 노출하는 핸들러 함수
+
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::common::app_state::AppState;
+use crate::common::metrics::metrics;
+
+/// Prometheus 텍스트 노출 형식의 Content-Type
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// 전역 메트릭 레지스트리를 Prometheus 텍스트 형식으로 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "ops",
+    responses(
+        (status = 200, description = "Prometheus 텍스트 노출 형식", content_type = "text/plain", body = String),
+    ),
+)]
+pub async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    let body = metrics().render(state.pool.state());
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], body)
+}
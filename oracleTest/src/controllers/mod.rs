@@ -2,6 +2,15 @@
 //!
 //! Spring MVC의 @RestController와 동일한 역할을 합니다.
 
+pub mod attachment_controller; // 게시글 첨부파일 업로드/다운로드 핸들러 함수들
+pub mod auth_controller; // 회원가입/로그인 HTTP 요청을 처리하는 핸들러 함수들
 pub mod board_controller; // 게시판 관련 HTTP 요청을 처리하는 핸들러 함수들
+pub mod category_controller; // 게시글 카테고리 관리 HTTP 요청을 처리하는 핸들러 함수들
+pub mod comment_controller; // 게시글 댓글 관련 HTTP 요청을 처리하는 핸들러 함수들
 pub mod dto; // 데이터 전송 객체 (Request/Response 모델)
 pub mod error; // 컨트롤러 계층의 에러 처리
+pub mod event_controller; // 게시글 변경 이벤트 SSE/WebSocket 구독 핸들러 함수들
+pub mod health_controller; // 헬스 체크(liveness/readiness) 핸들러 함수들
+pub mod metrics_controller; // Prometheus 메트릭 노출 핸들러 함수
+pub mod revision_controller; // 게시글 수정 이력 조회/비교/되돌리기 핸들러 함수들
+pub mod tag_controller; // 태그별 게시글 수 조회 핸들러 함수
@@ -0,0 +1,145 @@
+//! `revision` 리소스(게시글 수정 이력)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
+};
+use tracing::info;
+
+use crate::common::app_state::AppState;
+use crate::middleware::auth::AuthUser;
+
+use super::{
+    board_controller::if_match,
+    dto::{BoardResponse, RevisionDiffRequest, RevisionDiffResponse, RevisionResponse},
+    error::{ControllerError, ErrorResponse},
+};
+
+/// 게시글의 수정 이력 목록을 최신 이력부터 조회합니다. (내용 제외)
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/revisions",
+    tag = "revisions",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+    ),
+    responses(
+        (status = 200, description = "수정 이력 목록 (내용 제외)", body = Vec<RevisionResponse>),
+        (status = 404, description = "게시글을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn list_revisions(
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<RevisionResponse>>, ControllerError> {
+    info!("[Controller] list_revisions 호출됨, board_id={}", board_id);
+    let revisions = state.service.list_revisions(board_id).await?;
+    Ok(Json(
+        revisions
+            .into_iter()
+            .map(RevisionResponse::summary)
+            .collect(),
+    ))
+}
+
+/// 게시글의 특정 수정 이력을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/revisions/{rev}",
+    tag = "revisions",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        ("rev" = i64, Path, description = "수정 이력 번호"),
+    ),
+    responses(
+        (status = 200, description = "수정 이력", body = RevisionResponse),
+        (status = 404, description = "게시글 또는 이력을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn get_revision(
+    Path((board_id, revision)): Path<(i64, i64)>,
+    State(state): State<AppState>,
+) -> Result<Json<RevisionResponse>, ControllerError> {
+    info!(
+        "[Controller] get_revision 호출됨, board_id={}, revision={}",
+        board_id, revision
+    );
+    let revision = state.service.get_revision(board_id, revision).await?;
+    Ok(Json(RevisionResponse::from(revision)))
+}
+
+/// 두 수정 이력(또는 이력과 현재 게시글)의 내용을 줄 단위로 비교합니다.
+#[utoipa::path(
+    get,
+    path = "/boards/{id}/revisions/diff",
+    tag = "revisions",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        RevisionDiffRequest,
+    ),
+    responses(
+        (status = 200, description = "줄 단위 비교 결과", body = RevisionDiffResponse),
+        (status = 400, description = "잘못된 입력입니다", body = ErrorResponse),
+        (status = 404, description = "게시글 또는 이력을 찾을 수 없습니다", body = ErrorResponse),
+    ),
+)]
+pub async fn diff_revisions(
+    Path(board_id): Path<i64>,
+    State(state): State<AppState>,
+    Query(req): Query<RevisionDiffRequest>,
+) -> Result<Json<RevisionDiffResponse>, ControllerError> {
+    info!(
+        "[Controller] diff_revisions 호출됨, board_id={}, req={:?}",
+        board_id, req
+    );
+    let diff = state
+        .service
+        .diff_revisions(board_id, req.from, req.to)
+        .await?;
+    Ok(Json(RevisionDiffResponse::from(diff)))
+}
+
+/// 게시글을 특정 수정 이력으로 되돌립니다. (작성자 본인만 가능, `If-Match` 필수)
+///
+/// 성공하면 되돌린 게시글과 새 버전의 `ETag` 헤더를 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/boards/{id}/revisions/{rev}/revert",
+    tag = "revisions",
+    params(
+        ("id" = i64, Path, description = "게시글 ID"),
+        ("rev" = i64, Path, description = "수정 이력 번호"),
+        ("If-Match" = String, Header, description = "현재 게시글의 ETag (예: \"3\")"),
+    ),
+    responses(
+        (status = 200, description = "되돌린 게시글", body = BoardResponse, headers(("ETag" = String, description = "게시글의 현재 버전"))),
+        (status = 401, description = "로그인이 필요합니다", body = ErrorResponse),
+        (status = 403, description = "작성자가 아닙니다", body = ErrorResponse),
+        (status = 404, description = "게시글 또는 이력을 찾을 수 없습니다", body = ErrorResponse),
+        (status = 412, description = "ETag가 현재 버전과 다릅니다", body = ErrorResponse),
+        (status = 428, description = "If-Match 헤더가 없습니다", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn revert_revision(
+    user: AuthUser,
+    Path((board_id, revision)): Path<(i64, i64)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, ControllerError> {
+    info!(
+        "[Controller] revert_revision 호출됨, user={}, board_id={}, revision={}",
+        user.username, board_id, revision
+    );
+    let board = state
+        .service
+        .revert_revision(user.id, board_id, revision, if_match(&headers))
+        .await?;
+    Ok((
+        [(header::ETAG, board.etag())],
+        Json(BoardResponse::from(board)),
+    )
+        .into_response())
+}
@@ -0,0 +1,25 @@
+//! `tag` 리소스(게시글 태그)에 대한 HTTP 요청을 처리하는 핸들러 함수들
+
+use axum::{Json, extract::State};
+use tracing::info;
+
+use crate::common::app_state::AppState;
+
+use super::{dto::TagCountResponse, error::ControllerError};
+
+/// 게시글에 붙은 태그와 태그별 게시글 수를 많이 쓰인 순으로 조회합니다. (휴지통의 게시글 제외)
+#[utoipa::path(
+    get,
+    path = "/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "태그별 게시글 수", body = [TagCountResponse]),
+    ),
+)]
+pub async fn list_tags(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TagCountResponse>>, ControllerError> {
+    info!("[Controller] list_tags 호출됨");
+    let tags = state.service.list_tags().await?;
+    Ok(Json(tags.into_iter().map(TagCountResponse::from).collect()))
+}
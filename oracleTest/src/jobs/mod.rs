@@ -0,0 +1,4 @@
+//! 백그라운드 작업 모음: 요청과 무관하게 주기적으로 실행되는 작업들
+
+pub mod trash_purge;
+pub mod view_flush;
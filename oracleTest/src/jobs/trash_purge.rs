@@ -0,0 +1,31 @@
+//! 휴지통 영구 삭제 작업: 보존 기간이 지난 게시글을 주기적으로 정리합니다.
+
+use crate::services::board_service::BoardService;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+/// 휴지통 영구 삭제 작업을 백그라운드 태스크로 시작합니다.
+///
+/// 시작 직후 한 번 실행한 뒤 `every`마다 반복하며, 실패해도 다음 주기에 다시 시도합니다.
+pub fn spawn(service: Arc<BoardService>, retention_days: u32, every: Duration) -> JoinHandle<()> {
+    info!(
+        "[Job] 휴지통 정리 작업 시작: retention_days={}, interval={:?}",
+        retention_days, every
+    );
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        // 실행이 밀려도 놓친 주기를 몰아서 실행하지 않습니다.
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match service.purge_expired(retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => info!("[Job] 휴지통 정리: {}개 영구 삭제", purged),
+                Err(err) => error!("[Job] 휴지통 정리 실패: {:?}", err),
+            }
+        }
+    })
+}
@@ -0,0 +1,29 @@
+//! 조회수 반영 작업: 메모리에 모아 둔 게시글 조회수를 주기적으로 DB에 씁니다.
+
+use crate::services::board_service::BoardService;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{debug, error, info};
+
+/// 조회수 반영 작업을 백그라운드 태스크로 시작합니다.
+///
+/// `every`마다 반복하며, 실패한 조회수는 서비스에 남아 다음 주기에 다시 반영됩니다.
+/// 종료 시 남은 조회수는 작업을 멈춘 뒤 `BoardService::flush_views`로 한 번 더 반영합니다.
+pub fn spawn(service: Arc<BoardService>, every: Duration) -> JoinHandle<()> {
+    info!("[Job] 조회수 반영 작업 시작: interval={:?}", every);
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        // 실행이 밀려도 놓친 주기를 몰아서 실행하지 않습니다.
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match service.flush_views().await {
+                Ok(0) => {}
+                Ok(boards) => debug!("[Job] 조회수 반영: {}개 게시글", boards),
+                Err(err) => error!("[Job] 조회수 반영 실패: {:?}", err),
+            }
+        }
+    })
+}
@@ -0,0 +1,13 @@
+//! 라이브러리 루트: 애플리케이션 모듈 트리를 공개합니다.
+//!
+//! 바이너리(`main.rs`)와 통합 테스트(`tests/`)가 같은 라우터, 서비스, 저장소 구현을 공유합니다.
+
+pub mod common;
+pub mod config;
+pub mod controllers;
+pub mod jobs;
+pub mod middleware;
+pub mod models;
+pub mod repositories;
+pub mod routes;
+pub mod services;
@@ -1,72 +1,214 @@
 //! 메인 엔트리 포인트: 애플리케이션 초기화 및 서버 실행
 
-mod common;
-mod config;
-mod controllers;
-mod middleware;
-mod models;
-mod repositories;
-mod routes;
-mod services;
-
-use crate::common::app_state::AppState;
-use crate::common::utils::current_rss_kb;
-use crate::middleware::logging::log_middleware;
-use crate::routes::api_routes;
-use axum::middleware as axum_middleware;
-use config::Config;
+use clap::Parser;
+use oracle_test::common::app_state::AppState;
+use oracle_test::common::health::Health;
+use oracle_test::common::metrics::PoolMetrics;
+use oracle_test::common::shutdown;
+use oracle_test::common::utils::current_rss_kb;
+use oracle_test::config::{CliArgs, Command, Config, DbBackend, LogFormat, MigrateAction};
+use oracle_test::jobs::{trash_purge, view_flush};
+use oracle_test::repositories::file_storage::LocalFileStorage;
+use oracle_test::repositories::migrations::{MigrationError, Migrator};
+use oracle_test::repositories::sqlite;
+use oracle_test::repositories::stores::Stores;
+use oracle_test::routes::build_app;
+use oracle_test::services::attachment_service::{AttachmentLimits, AttachmentService};
+use oracle_test::services::auth_service::AuthService;
+use oracle_test::services::board_events::BoardEvents;
+use oracle_test::services::board_service::BoardService;
+use oracle_test::services::category_service::CategoryService;
+use oracle_test::services::comment_service::CommentService;
 use r2d2::Pool;
 use r2d2_oracle::OracleConnectionManager;
-use repositories::board_repository::BoardRepository;
-use services::board_service::BoardService;
+use std::future::IntoFuture;
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::info;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
 use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
 /// 애플리케이션의 진입점
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. 환경 설정 로드
-    // .env 파일에서 환경 변수를 로드하여 애플리케이션 설정을 초기화합니다.
-    let config = Config::from_env();
+    // 기본값 < 설정 파일 < 환경 변수(`BOARD_*`) < 명령줄 인자 순으로 겹쳐 적용하고 검증합니다.
+    // 잘못된 설정은 서버를 띄우기 전에 모두 보고하고 종료합니다.
+    let cli = CliArgs::parse();
+    let config = match Config::load(&cli) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("설정 오류: {err}");
+            std::process::exit(2);
+        }
+    };
 
     // 2. 로깅 초기화 (tracing-subscriber 사용)
-    // `tracing-subscriber`를 사용하여 애플리케이션의 로깅 시스템을 설정합니다.
-    // `RUST_LOG` 환경 변수를 기반으로 로그 필터를 적용합니다.
-    tracing_subscriber::registry()
-        .with(EnvFilter::new(&config.rust_log))
-        .with(fmt::layer())
-        .init();
+    // `rust_log` 필터를 적용하고, `log_format`에 따라 텍스트 또는 JSON 한 줄 형식으로 출력합니다.
+    let registry = tracing_subscriber::registry().with(EnvFilter::new(&config.rust_log));
+    match config.log_format {
+        LogFormat::Text => registry.with(fmt::layer()).init(),
+        // 요청 span의 필드(request_id, method, route)를 각 로그 줄에 함께 기록합니다.
+        LogFormat::Json => registry
+            .with(
+                fmt::layer()
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(false),
+            )
+            .init(),
+    }
 
     info!("Oracle MVC Board Application 시작");
     info!("서버 설정: {}:{}", config.server_host, config.server_port);
+    info!("설정: {:?}", config); // 비밀 값은 가려져서 출력됩니다.
 
     // 3. 데이터베이스 연결 및 풀 생성
-    // Oracle 데이터베이스에 연결하고 `r2d2` 풀을 사용하여 효율적인 연결 관리를 설정합니다.
-    // `max_size`는 최대 동시 연결 수를 정의합니다.
-    let manager =
-        OracleConnectionManager::new(&config.db_user, &config.db_password, &config.db_connect);
-    let pool = Pool::builder()
-        .max_size(10) // 최대 연결 수 설정
-        .build(manager)?;
+    // `DB_BACKEND` 설정에 따라 Oracle 또는 내장 SQLite 저장소를 선택합니다.
+    // Oracle은 `r2d2` 풀을 사용하여 효율적인 연결 관리를 설정하며,
+    // `db_pool_max_size`는 최대 동시 연결 수를, `db_pool_connection_timeout_secs`는 커넥션 대기 한도를 정의합니다.
+    let pool_timeout = Duration::from_secs(config.db_pool_connection_timeout_secs);
+    let stores = match config.db_backend {
+        DbBackend::Oracle => {
+            info!("저장소 백엔드: Oracle ({})", config.db_connect);
+            let manager = OracleConnectionManager::new(
+                &config.db_user,
+                config.db_password.expose(),
+                &config.db_connect,
+            );
+            let pool = Pool::builder()
+                .event_handler(Box::new(PoolMetrics)) // 커넥션 대기 시간/시간 초과를 메트릭으로 기록
+                .max_size(config.db_pool_max_size) // 최대 연결 수 설정
+                .connection_timeout(pool_timeout)
+                .build(manager)?;
+            Stores::oracle(pool)
+        }
+        DbBackend::Sqlite => {
+            info!("저장소 백엔드: SQLite ({})", config.sqlite_path);
+            let pool =
+                sqlite::build_pool(&config.sqlite_path, config.db_pool_max_size, pool_timeout)?;
+            Stores::sqlite(pool)
+        }
+    };
+
+    // 스키마 마이그레이션: `migrate` 하위 명령이면 실행하고 종료합니다.
+    // 서버 실행 시에는 `migrate_on_startup`이 켜져 있거나 인메모리 SQLite일 때만 자동으로 적용합니다.
+    let migrator = Migrator::new(stores.pool.clone());
+    let migrate_on_startup = config.migrate_on_startup;
+    if let Some(Command::Migrate { action }) = cli.command {
+        let result = tokio::task::spawn_blocking(move || run_migrate(&migrator, action)).await?;
+        if let Err(err) = result {
+            eprintln!("마이그레이션 실패: {err}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    let in_memory =
+        config.db_backend == DbBackend::Sqlite && config.sqlite_path == sqlite::MEMORY_PATH;
+    let startup_migration = tokio::task::spawn_blocking(move || {
+        if migrate_on_startup || in_memory {
+            migrator.up(None).map(|_| ())
+        } else {
+            let pending = migrator
+                .status()?
+                .into_iter()
+                .filter(|status| status.applied_at.is_none())
+                .count();
+            if pending > 0 {
+                warn!(
+                    "적용되지 않은 스키마 마이그레이션 {}개가 있습니다. `migrate up`으로 적용하거나 migrate_on_startup을 켜세요. (기존 스키마라면 `migrate baseline <버전>`)",
+                    pending
+                );
+            }
+            Ok(())
+        }
+    });
+    startup_migration.await??;
+
+    // 게시글 상세/목록/개수 조회 결과를 메모리에 캐시합니다. 쓰기는 캐시를 거치며 바뀐 항목을 버립니다.
+    let stores = if config.board_cache_enabled {
+        info!(
+            "게시글 조회 캐시 사용: capacity={}, ttl={}s",
+            config.board_cache_capacity, config.board_cache_ttl_secs
+        );
+        stores.with_board_cache(
+            config.board_cache_capacity,
+            Duration::from_secs(config.board_cache_ttl_secs),
+        )
+    } else {
+        info!("게시글 조회 캐시 사용 안 함");
+        stores
+    };
 
     // 4. 의존성 주입 (Repository -> Service)
-    // `BoardRepository`와 `BoardService` 인스턴스를 생성하고, `Arc`를 사용하여
-    // 여러 스레드에서 공유될 수 있도록 합니다. 서비스 계층은 리포지토리 계층에 의존합니다.
-    let repository = Arc::new(BoardRepository::new(pool));
-    let service = Arc::new(BoardService::new(repository));
+    // 선택된 저장소 구현체로 `BoardService`, `CategoryService`, `CommentService`, `AttachmentService`, `AuthService`를 생성하고, `Arc`를 사용하여
+    // 여러 스레드에서 공유될 수 있도록 합니다. 서비스 계층은 저장소 트레이트에만 의존합니다.
+    let files = Arc::new(LocalFileStorage::new(&config.attachment_dir));
+    let service = Arc::new(
+        BoardService::new(
+            stores.boards.clone(),
+            stores.categories.clone(),
+            files.clone(),
+        )
+        .with_popular_cache_ttl(Duration::from_secs(config.popular_cache_ttl_secs))
+        .with_events(Arc::new(BoardEvents::new(
+            config.events_buffer_size,
+            Duration::from_secs(config.events_heartbeat_secs),
+        ))),
+    );
+    let events = service.events().clone();
+    let categories = Arc::new(CategoryService::new(stores.categories));
+    let comments = Arc::new(CommentService::new(stores.comments, stores.boards.clone()));
+    let attachments = Arc::new(AttachmentService::new(
+        stores.attachments,
+        stores.boards,
+        files,
+        AttachmentLimits {
+            max_bytes: config.attachment_max_bytes,
+            allowed_types: config.attachment_allowed_types.clone(),
+        },
+    ));
+    let auth = Arc::new(AuthService::new(
+        stores.users,
+        config.jwt_secret.expose(),
+        config.jwt_ttl_secs,
+    ));
+
+    // 휴지통 보존 기간이 지난 게시글을 주기적으로 영구 삭제하는 백그라운드 작업을 시작합니다.
+    let purge_job = trash_purge::spawn(
+        service.clone(),
+        config.trash_retention_days,
+        Duration::from_secs(config.trash_purge_interval_secs),
+    );
+    // 게시글 조회수는 요청마다 쓰지 않고 모아 두었다가 주기적으로 반영합니다.
+    let views = service.clone();
+    let view_job = view_flush::spawn(
+        service.clone(),
+        Duration::from_secs(config.view_flush_interval_secs),
+    );
+
+    // `/readyz`가 참조하는 상태. 종료가 시작되면 준비 상태를 실패로 바꿉니다.
+    let health = Arc::new(Health::new(Duration::from_millis(
+        config.readiness_timeout_ms,
+    )));
 
     // 5. 애플리케이션 상태 생성 (Service 공유)
-    // Axum `State`를 통해 애플리케이션 전반에 걸쳐 `BoardService`를 공유할 수 있도록 `AppState`를 생성합니다.
-    let state = AppState { service };
+    // Axum `State`를 통해 애플리케이션 전반에 걸쳐 서비스들을 공유할 수 있도록 `AppState`를 생성합니다.
+    let state = AppState {
+        service,
+        categories,
+        comments,
+        attachments,
+        auth,
+        pool: stores.pool.clone(),
+        health: health.clone(),
+    };
 
     // 6. 라우터 설정 (미들웨어 및 상태 주입)
-    // `api_routes` 함수를 호출하여 모든 API 라우트를 정의하고, `log_middleware`를 적용하여
-    // 모든 요청에 대한 로깅을 처리합니다. `AppState`를 라우터에 주입하여 핸들러 함수에서
-    // 서비스에 접근할 수 있도록 합니다.
-    let app = api_routes()
-        .layer(axum_middleware::from_fn(log_middleware))
-        .with_state(state); // ✅ State는 여기 단 한 번
+    // `build_app`이 모든 API 라우트와 정적 파일 fallback에 미들웨어 스택(시간 제한, 본문 크기 제한,
+    // 빈도 제한, 로깅, 메트릭, 요청 ID, 보안 헤더, 압축, CORS)을 얹고 `AppState`를 주입합니다.
+    let app = build_app(&config, state);
 
     // 7. 서버 바인딩 및 실행
     // 설정된 호스트와 포트로 Axum 서버를 바인딩하고 비동기적으로 실행합니다.
@@ -75,16 +217,130 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("서버 시작: http://{}", addr);
 
     // 8. 우아한 종료 (Graceful Shutdown) 처리
-    // Ctrl+C 신호(SIGINT)를 감지하여 서버를 안전하게 종료하고, 종료 시점의 메모리 사용량을 기록합니다.
-    tokio::select! {
-        _ = async {
-            axum::serve(listener, app).await.ok();
-        } => {}
-        _ = tokio::signal::ctrl_c() => {
-            info!("서버 종료 중...");
-            info!("종료 시 메모리 사용량: {} KB", current_rss_kb());
+    // SIGINT/SIGTERM을 받으면 준비 상태를 내리고 새 연결 수락을 멈춘 뒤,
+    // 처리 중인 요청과 블로킹 DB 작업이 끝나기를 최대 `SHUTDOWN_TIMEOUT_SECS` 동안 기다립니다.
+    let drain_timeout = Duration::from_secs(config.shutdown_timeout_secs);
+    let stop = CancellationToken::new();
+    let mut server = tokio::spawn(
+        // 빈도 제한이 클라이언트 IP를 알 수 있도록 접속 정보를 요청 확장에 넣습니다.
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(stop.clone().cancelled_owned())
+        .into_future(),
+    );
+
+    // `drained`: 모든 연결이 스스로 끝났는지 여부. 강제로 끊은 연결의 태스크는 라우터(풀 참조)를 잠시 더 들고 있을 수 있습니다.
+    let (deadline, drained) = tokio::select! {
+        result = &mut server => {
+            // 종료 신호 없이 서버가 멈춘 경우 (바인딩된 리스너 오류 등)
+            health.mark_shutting_down();
+            result??;
+            (Instant::now(), true)
+        }
+        _ = shutdown::signal() => {
+            // 로드 밸런서가 더 이상 요청을 보내지 않도록 준비 상태부터 내립니다.
+            health.mark_shutting_down();
+            info!("서버 종료 중... 처리 중인 요청 마무리 (최대 {:?})", drain_timeout);
+            // 끝나지 않는 이벤트 구독(SSE/WebSocket) 연결이 종료를 막지 않도록 먼저 닫습니다.
+            events.close();
+            stop.cancel();
+            let deadline = Instant::now() + drain_timeout;
+            let drained = match tokio::time::timeout(drain_timeout, &mut server).await {
+                Ok(result) => {
+                    result??;
+                    true
+                }
+                Err(_) => {
+                    warn!("종료 대기 시간 초과: 남은 연결을 끊습니다.");
+                    server.abort();
+                    // 서버 태스크가 끝나야 라우터와 `AppState`(서비스, 풀 참조)가 드롭됩니다.
+                    let _ = server.await;
+                    false
+                }
+            };
+            (deadline, drained)
         }
+    };
+
+    // 새 DB 작업이 생기지 않도록 백그라운드 작업을 멈추고, 이미 시작된 블로킹 DB 작업을 기다립니다.
+    // 블로킹 작업은 취소할 수 없으므로 각 트랜잭션은 커밋되거나 커넥션 반환 시 롤백됩니다.
+    // 중단된 작업이 끝나기를 기다려야 작업이 들고 있던 서비스(풀 참조)가 드롭됩니다.
+    purge_job.abort();
+    view_job.abort();
+    let _ = purge_job.await;
+    let _ = view_job.await;
+    // 아직 반영하지 않은 조회수를 마지막으로 한 번 씁니다.
+    if let Err(err) = views.flush_views().await {
+        error!("남은 조회수 반영 실패: {:?}", err);
+    }
+    drop(views);
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if !shutdown::wait_blocking_idle(remaining).await {
+        warn!(
+            "종료 대기 시간 초과: 블로킹 DB 작업 {}개가 아직 실행 중입니다.",
+            shutdown::in_flight_blocking()
+        );
+    }
+
+    if let Some(cache) = &stores.board_cache {
+        let (detail, list) = (cache.detail_stats(), cache.list_stats());
+        info!(
+            "게시글 조회 캐시: 상세 hit={} miss={}, 목록 hit={} miss={}",
+            detail.hits, detail.misses, list.hits, list.misses
+        );
+    }
+
+    // 커넥션 풀 종료: 서버, 백그라운드 작업, 서비스가 모두 드롭되어 남은 풀 참조는 이것뿐입니다.
+    // 반환되지 않은 커넥션을 남은 대기 시간 동안 기다린 뒤 마지막 참조를 드롭해 유휴 커넥션을 닫습니다.
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let pool_state = stores.pool.close(remaining).await;
+    let in_use = pool_state.connections - pool_state.idle_connections;
+    if in_use == 0 && drained {
+        info!(
+            "커넥션 풀 종료: 유휴 커넥션 {}개를 닫았습니다.",
+            pool_state.idle_connections
+        );
+    } else if in_use == 0 {
+        warn!(
+            "커넥션 풀 종료: 강제로 끊은 연결이 남아 있어 유휴 커넥션 {}개는 프로세스 종료 시 닫힙니다.",
+            pool_state.idle_connections
+        );
+    } else {
+        warn!(
+            "커넥션 풀 종료: 유휴 커넥션 {}개를 닫았고, 반환되지 않은 커넥션 {}개는 프로세스 종료 시 끊깁니다.",
+            pool_state.idle_connections, in_use
+        );
+    }
+
+    match current_rss_kb() {
+        Some(kb) => info!("종료 시 메모리 사용량: {} KB", kb),
+        None => info!("종료 시 메모리 사용량: 확인할 수 없음"),
     }
 
     Ok(())
 }
+
+/// `migrate` 하위 명령을 실행하고 결과를 표준 출력에 씁니다.
+fn run_migrate(migrator: &Migrator, action: MigrateAction) -> Result<(), MigrationError> {
+    let (label, versions) = match action {
+        MigrateAction::Status => {
+            for status in migrator.status()? {
+                let state = status.applied_at.as_deref().unwrap_or("미적용");
+                println!("{:04}_{:<30} {}", status.version, status.name, state);
+            }
+            return Ok(());
+        }
+        MigrateAction::Up { to } => ("적용", migrator.up(to)?),
+        MigrateAction::Down { steps } => ("되돌림", migrator.down(steps)?),
+        MigrateAction::Baseline { version } => ("기준선 기록", migrator.baseline(version)?),
+    };
+    if versions.is_empty() {
+        println!("변경 없음");
+    }
+    for version in versions {
+        println!("{label}: {version:04}");
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! 인증 추출기: `Authorization: Bearer <token>` 헤더에서 로그인 사용자를 꺼냅니다.
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts},
+};
+
+use crate::{
+    common::app_state::AppState, controllers::error::ControllerError,
+    services::board_service::ServiceError,
+};
+
+/// 인증된 사용자. 핸들러 인자로 선언하면 유효한 토큰이 있는 요청만 통과합니다.
+#[derive(Debug, Clone)]
+pub struct AuthUser {
+    pub id: i64,
+    pub username: String,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = ControllerError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| ServiceError::Unauthorized("로그인이 필요합니다.".to_string()))?;
+
+        let user = state.auth.verify_token(token)?;
+        Ok(Self {
+            id: user.id,
+            username: user.username,
+        })
+    }
+}
@@ -0,0 +1,58 @@
+//! CORS 미들웨어: 설정된 출처에서 브라우저가 API를 호출할 수 있도록 허용
+
+use axum::http::{HeaderName, HeaderValue, Method, header};
+use std::time::Duration;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use super::request_id::REQUEST_ID_HEADER;
+
+/// 브라우저가 보낼 수 있는 요청 헤더
+const ALLOWED_HEADERS: [HeaderName; 6] = [
+    header::AUTHORIZATION,
+    header::CONTENT_TYPE,
+    header::IF_MATCH,
+    header::IF_NONE_MATCH,
+    header::RANGE,
+    REQUEST_ID_HEADER,
+];
+
+/// 스크립트에서 읽을 수 있도록 노출할 응답 헤더
+const EXPOSED_HEADERS: [HeaderName; 5] = [
+    header::ETAG,
+    header::CONTENT_RANGE,
+    header::CONTENT_DISPOSITION,
+    header::RETRY_AFTER,
+    REQUEST_ID_HEADER,
+];
+
+/// 허용 출처 목록으로 CORS 레이어를 만듭니다. 목록이 비어 있으면 `None`(CORS 비활성)입니다.
+///
+/// 출처 형식은 설정 검증(`Config::validate`)에서 확인하므로, 여기서 해석되지 않는 값은 건너뜁니다.
+pub fn cors_layer(origins: &[String]) -> Option<CorsLayer> {
+    if origins.is_empty() {
+        return None;
+    }
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        )
+    };
+    Some(
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::DELETE,
+                Method::OPTIONS,
+            ])
+            .allow_headers(ALLOWED_HEADERS)
+            .expose_headers(EXPOSED_HEADERS)
+            .max_age(Duration::from_secs(600)),
+    )
+}
@@ -1,76 +1,128 @@
-use crate::common::utils::current_rss_kb;
+//! 요청 로깅 미들웨어: 요청마다 span을 열고 요청/응답을 구조화된 필드로 기록합니다.
+//!
+//! span에는 요청 ID, 메서드, 라우트가 담기며, 핸들러와 서비스 계층은 같은 future 안에서 실행되므로
+//! 그 안의 로그가 모두 이 span에 묶입니다. 블로킹 스레드에서 실행되는 저장소 작업은
+//! `common::metrics::spawn_blocking`이 span을 넘겨주므로 SQL 디버그 로그도 같은 요청 ID로 이어집니다.
+
 use axum::{
     body::Body,
-    http::{Request, StatusCode},
+    extract::{MatchedPath, State},
+    http::{HeaderMap, HeaderName, Request},
     middleware::Next,
-    response::IntoResponse,
+    response::Response,
 };
-use tracing::{error, info, warn}; // current_rss_kb 함수 import
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{Instrument, debug, error, field, info, info_span, warn};
+
+use super::request_id::RequestId;
+
+/// 값을 가린 헤더에 대신 기록하는 문자열
+const REDACTED: &str = "[REDACTED]";
+
+/// 항상 값을 가리는 헤더 (자격 증명이 담기는 헤더)
+pub const DEFAULT_REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// 요청 헤더를 로그에 남길 때 값을 가릴 헤더 목록
+#[derive(Debug, Clone)]
+pub struct HeaderRedaction {
+    names: HashSet<HeaderName>,
+}
+
+impl Default for HeaderRedaction {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl HeaderRedaction {
+    /// 기본 목록(`DEFAULT_REDACTED_HEADERS`)에 `extra` 헤더를 더합니다. 헤더 이름이 아닌 값은 무시합니다.
+    pub fn new(extra: &[String]) -> Self {
+        let names = DEFAULT_REDACTED_HEADERS
+            .iter()
+            .copied()
+            .chain(extra.iter().map(String::as_str))
+            .filter_map(|name| HeaderName::try_from(name.trim()).ok())
+            .collect();
+        Self { names }
+    }
+
+    /// 헤더를 `(이름, 값)` 목록으로 바꾸면서 가릴 헤더의 값은 `[REDACTED]`로 바꿉니다.
+    pub fn redact(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.names.contains(name) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+}
 
-pub async fn log_middleware(req: Request<Body>, next: Next) -> impl IntoResponse {
+pub async fn log_middleware(
+    State(redaction): State<Arc<HeaderRedaction>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let started_at = Instant::now();
     let method = req.method().clone();
     let uri = req.uri().clone();
-    let headers = req.headers().clone();
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map_or("-", RequestId::as_str)
+        .to_string();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", MatchedPath::as_str)
+        .to_string();
 
-    info!(
-        target: "api_requests",
-        "Request: {} {}",
-        method,
-        uri
-    );
-    info!(
-        target: "api_requests",
-        "Headers: {:?}",
-        headers
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        method = %method,
+        route = %route,
+        status = field::Empty,
+        latency_ms = field::Empty,
     );
-    info!(
-        target: "api_requests",
-        "Memory before request: {} KB",
-        current_rss_kb()
-    );
-
-    let res = next.run(req).await;
 
-    let status = res.status();
-    if status.is_client_error() {
-        warn!(
-            target: "api_responses",
-            "Client error response: {} {} -> {}",
-            method,
-            uri,
-            status
+    async move {
+        info!(target: "api_requests", uri = %uri, "요청 수신");
+        debug!(
+            target: "api_requests",
+            headers = ?redaction.redact(req.headers()),
+            "요청 헤더"
         );
-        // Here you could add more detailed logging for specific client errors
-        if status == StatusCode::NOT_FOUND {
-            error!(
-                target: "api_responses",
-                "404 Not Found: No route for {} {}",
-                method,
-                uri
-            );
+
+        let res = next.run(req).await;
+
+        let status = res.status();
+        let latency_ms = started_at.elapsed().as_millis() as u64;
+        let span = tracing::Span::current();
+        span.record("status", status.as_u16());
+        span.record("latency_ms", latency_ms);
+
+        if status.is_server_error() {
+            error!(target: "api_responses", uri = %uri, %status, latency_ms, "서버 오류 응답");
+        } else if status.is_client_error() {
+            warn!(target: "api_responses", uri = %uri, %status, latency_ms, "클라이언트 오류 응답");
+        } else {
+            info!(target: "api_responses", uri = %uri, %status, latency_ms, "응답 완료");
         }
-    } else if status.is_server_error() {
-        error!(
-            target: "api_responses",
-            "Server error response: {} {} -> {}",
-            method,
-            uri,
-            status
-        );
-    } else {
-        info!(
-            target: "api_responses",
-            "Successful response: {} {} -> {}",
-            method,
-            uri,
-            status
-        );
+        res
     }
-    info!(
-        target: "api_responses",
-        "Memory after request: {} KB",
-        current_rss_kb()
-    );
-
-    res
+    .instrument(span)
+    .await
 }
@@ -0,0 +1,23 @@
+//! 요청 메트릭 미들웨어: 라우트/메서드/상태 코드별 요청 수와 처리 시간을 기록합니다.
+
+use crate::common::metrics::metrics;
+use axum::{body::Body, extract::MatchedPath, http::Request, middleware::Next, response::Response};
+use std::time::Instant;
+
+/// 매칭되는 라우트가 없는 요청의 `route` 레이블.
+/// 임의의 URI가 레이블 값으로 늘어나지 않도록 하나로 묶습니다.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+pub async fn track_metrics(req: Request<Body>, next: Next) -> Response {
+    let started_at = Instant::now();
+    let method = req.method().to_string();
+    let route = req.extensions().get::<MatchedPath>().map_or_else(
+        || UNMATCHED_ROUTE.to_string(),
+        |path| path.as_str().to_string(),
+    );
+
+    let res = next.run(req).await;
+
+    metrics().observe_request(&method, &route, res.status().as_u16(), started_at.elapsed());
+    res
+}
@@ -1 +1,7 @@
+pub mod auth;
+pub mod cors;
 pub mod logging;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
+pub mod security_headers;
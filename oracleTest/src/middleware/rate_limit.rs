@@ -0,0 +1,142 @@
+//! 요청 빈도 제한 미들웨어: 클라이언트마다 토큰 버킷을 두고 넘치는 요청은 429로 거절합니다.
+//!
+//! 유효한 액세스 토큰이 있으면 사용자 ID로, 없으면 접속한 클라이언트 IP로 버킷을 나눕니다.
+//! IP는 `ConnectInfo<SocketAddr>`에서 읽으므로 서버는 `into_make_service_with_connect_info`로 띄워야 합니다.
+//! 오케스트레이터와 모니터링이 호출하는 운영용 엔드포인트는 제한하지 않습니다.
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{Request, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use crate::controllers::error::ControllerError;
+use crate::services::auth_service::AuthService;
+use crate::services::board_service::ServiceError;
+
+/// 빈도 제한을 적용하지 않는 경로 (헬스 체크, 메트릭 수집)
+const EXEMPT_PATHS: &[&str] = &["/healthz", "/readyz", "/metrics"];
+
+/// 이 개수를 넘으면 새 버킷을 만들기 전에 가득 찬(오래 쉬던) 버킷을 정리합니다.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// 클라이언트 하나의 토큰 버킷
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// 키별 토큰 버킷 제한기
+///
+/// 버킷은 최대 `burst`개의 토큰을 담고 초당 `per_second`개씩 다시 채워지며, 요청 하나가 토큰 하나를 씁니다.
+#[derive(Debug)]
+pub struct RateLimiter {
+    per_second: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// `per_second`와 `burst`는 1 이상이어야 합니다. (설정 검증에서 보장)
+    pub fn new(per_second: u32, burst: u32) -> Self {
+        Self {
+            per_second: f64::from(per_second.max(1)),
+            burst: f64::from(burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// `key`의 토큰을 하나 씁니다. 토큰이 없으면 다음 토큰이 생길 때까지의 시간을 반환합니다.
+    pub fn check(&self, key: &str) -> Result<(), Duration> {
+        self.check_at(key, Instant::now())
+    }
+
+    /// `now` 시점 기준으로 `check`를 수행합니다.
+    pub fn check_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if !buckets.contains_key(key) && buckets.len() >= MAX_TRACKED_CLIENTS {
+            buckets.retain(|_, bucket| self.refill(*bucket, now) < self.burst);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: self.burst,
+            updated_at: now,
+        });
+        bucket.tokens = self.refill(*bucket, now);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.per_second,
+            ))
+        }
+    }
+
+    /// 마지막 갱신 이후 채워진 토큰을 더한 값 (최대 `burst`)
+    fn refill(&self, bucket: Bucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        (bucket.tokens + elapsed.as_secs_f64() * self.per_second).min(self.burst)
+    }
+}
+
+/// 빈도 제한 미들웨어의 상태 (토큰 검증에 `AuthService`가 필요합니다)
+pub struct RateLimitState {
+    pub limiter: RateLimiter,
+    pub auth: Arc<AuthService>,
+}
+
+impl RateLimitState {
+    pub fn new(limiter: RateLimiter, auth: Arc<AuthService>) -> Self {
+        Self { limiter, auth }
+    }
+
+    /// 버킷 키: 유효한 토큰이면 `user:<id>`, 아니면 `ip:<주소>`
+    fn client_key(&self, req: &Request<Body>) -> String {
+        let user = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| self.auth.verify_token(token.trim()).ok());
+        if let Some(user) = user {
+            return format!("user:{}", user.id);
+        }
+        match req.extensions().get::<ConnectInfo<SocketAddr>>() {
+            Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+            None => "ip:unknown".to_string(),
+        }
+    }
+}
+
+pub async fn rate_limit(
+    State(state): State<Arc<RateLimitState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if EXEMPT_PATHS.contains(&req.uri().path()) {
+        return next.run(req).await;
+    }
+
+    let key = state.client_key(&req);
+    if let Err(wait) = state.limiter.check(&key) {
+        // Retry-After는 초 단위 정수이므로 올림하고, 최소 1초로 안내합니다.
+        let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+        warn!(
+            "요청 빈도 제한 초과: {} (Retry-After {}초)",
+            key, retry_after
+        );
+        return ControllerError::from(ServiceError::TooManyRequests(retry_after)).into_response();
+    }
+    next.run(req).await
+}
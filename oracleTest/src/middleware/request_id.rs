@@ -0,0 +1,54 @@
+//! 요청 ID 미들웨어: 요청마다 `X-Request-Id`를 정하고 응답에도 돌려줍니다.
+//!
+//! 클라이언트(또는 앞단 프록시)가 보낸 값이 안전한 형식이면 그대로 이어받고,
+//! 없거나 형식이 맞지 않으면 UUID v4를 새로 만듭니다.
+
+use axum::{
+    body::Body,
+    http::{HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+/// 요청 ID 헤더
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// 이어받을 요청 ID의 최대 길이
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// 현재 요청의 ID (요청 확장에 저장됩니다)
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// 로그에 그대로 남겨도 안전한 ID인지 확인합니다. (영숫자와 `-`, `_`, `.`만 허용)
+fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+pub async fn request_id(mut req: Request<Body>, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid(id))
+        .map_or_else(|| Uuid::new_v4().to_string(), str::to_string);
+    let header = HeaderValue::from_str(&id).expect("검증된 요청 ID는 헤더 값으로 쓸 수 있습니다");
+
+    req.headers_mut().insert(REQUEST_ID_HEADER, header.clone());
+    req.extensions_mut().insert(RequestId(id));
+
+    let mut res = next.run(req).await;
+    res.headers_mut().insert(REQUEST_ID_HEADER, header);
+    res
+}
@@ -0,0 +1,67 @@
+//! 보안 헤더 미들웨어: 모든 응답에 브라우저 보안 관련 헤더를 붙입니다.
+//!
+//! 핸들러가 이미 같은 헤더를 정했다면 덮어쓰지 않습니다.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderName, HeaderValue, Request, header},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+
+/// 기본 Content-Security-Policy
+///
+/// 내장 프런트엔드(`static/index.html`)와 Swagger UI가 인라인 스크립트/스타일과 `data:` 아이콘을 쓰므로 이를 허용하고,
+/// 외부 출처의 스크립트와 다른 사이트의 프레임 삽입은 막습니다.
+pub const DEFAULT_CONTENT_SECURITY_POLICY: &str = "default-src 'self'; script-src 'self' 'unsafe-inline'; style-src 'self' 'unsafe-inline'; img-src 'self' data:; connect-src 'self'; object-src 'none'; base-uri 'self'; frame-ancestors 'none'";
+
+/// 응답에 붙일 보안 헤더 목록
+#[derive(Debug, Clone)]
+pub struct SecurityHeaders {
+    headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl SecurityHeaders {
+    /// `content_security_policy`가 비어 있으면 CSP 헤더는 보내지 않습니다. 헤더 값으로 쓸 수 없는 값도 건너뜁니다.
+    pub fn new(content_security_policy: &str) -> Self {
+        let mut headers = vec![
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                HeaderValue::from_static("nosniff"),
+            ),
+            (header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY")),
+            (
+                header::REFERRER_POLICY,
+                HeaderValue::from_static("strict-origin-when-cross-origin"),
+            ),
+        ];
+        if let Ok(csp) = HeaderValue::from_str(content_security_policy.trim())
+            && !csp.is_empty()
+        {
+            headers.push((header::CONTENT_SECURITY_POLICY, csp));
+        }
+        Self { headers }
+    }
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONTENT_SECURITY_POLICY)
+    }
+}
+
+pub async fn security_headers(
+    State(security): State<Arc<SecurityHeaders>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let mut res = next.run(req).await;
+    for (name, value) in &security.headers {
+        if !res.headers().contains_key(name) {
+            res.headers_mut().insert(name.clone(), value.clone());
+        }
+    }
+    res
+}
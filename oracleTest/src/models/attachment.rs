@@ -0,0 +1,77 @@
+//! Model 계층: 첨부파일 메타데이터 구조체
+
+/// 첨부파일 메타데이터 모델 (파일 본문은 로컬 디렉터리에 저장)
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub id: i64,
+    pub board_id: i64,
+    /// 업로드 시 클라이언트가 보낸 원본 파일명
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// 파일 본문의 SHA-256 (소문자 16진수)
+    pub sha256: String,
+    /// 저장 디렉터리 기준 상대 경로 (`{board_id}/{uuid}`)
+    pub storage_key: String,
+    pub uploader_id: Option<i64>,
+    pub created_at: Option<oracle::sql_type::Timestamp>,
+}
+
+/// 저장할 첨부파일 메타데이터 (ID/작성 시각은 DB가 채웁니다)
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub board_id: i64,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub storage_key: String,
+    pub uploader_id: i64,
+}
+
+/// `Range: bytes=...` 요청 헤더의 단일 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `bytes=start-end` (양 끝 포함)
+    Bounded { start: u64, end: u64 },
+    /// `bytes=start-`
+    From(u64),
+    /// `bytes=-len` (마지막 len 바이트)
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// `Range` 헤더 값을 해석합니다.
+    /// 형식이 잘못되었거나 여러 범위를 요청하면 `None`을 반환하며, 이 경우 전체 파일을 응답합니다.
+    pub fn parse(header: &str) -> Option<Self> {
+        let spec = header.trim().strip_prefix("bytes=")?;
+        if spec.contains(',') {
+            return None;
+        }
+        let (start, end) = spec.split_once('-')?;
+        match (start.trim(), end.trim()) {
+            ("", "") => None,
+            ("", len) => len.parse().ok().map(ByteRange::Suffix),
+            (start, "") => start.parse().ok().map(ByteRange::From),
+            (start, end) => {
+                let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+                (start <= end).then_some(ByteRange::Bounded { start, end })
+            }
+        }
+    }
+
+    /// 파일 크기에 맞춰 실제 바이트 구간 `(start, end)`(양 끝 포함)을 계산합니다.
+    /// 만족할 수 없는 범위면 `None`입니다.
+    pub fn resolve(self, size: u64) -> Option<(u64, u64)> {
+        if size == 0 {
+            return None;
+        }
+        let last = size - 1;
+        match self {
+            ByteRange::Bounded { start, end } if start <= last => Some((start, end.min(last))),
+            ByteRange::From(start) if start <= last => Some((start, last)),
+            ByteRange::Suffix(len) if len > 0 => Some((size.saturating_sub(len), last)),
+            _ => None,
+        }
+    }
+}
@@ -1,19 +1,225 @@
 //! Model 계층: 데이터 구조체
 
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
 /// 게시판 데이터 모델
 #[derive(Debug, Clone)]
 pub struct Board {
     pub id: i64,
     pub title: String,
     pub content: String,
+    /// 본문 형식 (HTML은 응답할 때 이 형식으로 렌더링합니다)
+    pub content_format: ContentFormat,
+    /// 작성자 ID (인증 도입 이전에 작성된 글은 `None`)
+    pub author_id: Option<i64>,
+    /// 카테고리 ID (미분류이면 `None`)
+    pub category_id: Option<i64>,
+    /// 태그 이름 (가나다순)
+    pub tags: Vec<String>,
+    /// 낙관적 동시성 제어용 버전 (수정할 때마다 1 증가)
+    pub version: i64,
+    /// 누적 조회수 (아직 DB에 반영되지 않은 조회 포함 여부는 조회한 쪽에서 정합니다)
+    pub view_count: i64,
+    /// 좋아요 수
+    pub like_count: i64,
     pub created_at: Option<oracle::sql_type::Timestamp>,
 }
 
+impl Board {
+    /// 현재 버전을 나타내는 강한(strong) ETag 값 (따옴표 포함)
+    pub fn etag(&self) -> String {
+        format!("\"{}\"", self.version)
+    }
+}
+
+/// 게시글 본문 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentFormat {
+    /// 일반 텍스트 (HTML로 보낼 때 이스케이프하고 줄바꿈만 살립니다)
+    #[default]
+    Plain,
+    /// Markdown (허용한 태그와 속성만 남기도록 정화합니다)
+    Markdown,
+}
+
+impl ContentFormat {
+    /// DB와 API에서 쓰는 이름
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentFormat::Plain => "plain",
+            ContentFormat::Markdown => "markdown",
+        }
+    }
+
+    /// `plain`/`markdown`을 해석합니다. (대소문자 무시, 그 외에는 `None`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "plain" => Some(ContentFormat::Plain),
+            "markdown" => Some(ContentFormat::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// 새로 추가할 게시글 (검증을 마친 값)
+#[derive(Debug, Clone, Default)]
+pub struct NewBoard {
+    pub title: String,
+    pub content: String,
+    pub format: ContentFormat,
+    pub category_id: Option<i64>,
+    /// 정규화된 태그 이름 (중복 없음)
+    pub tags: Vec<String>,
+}
+
+/// 게시글 수정 내용 (검증을 마친 값)
+#[derive(Debug, Clone, Default)]
+pub struct BoardUpdate {
+    pub title: String,
+    pub content: String,
+    /// `None`이면 본문 형식을 그대로 둡니다.
+    pub format: Option<ContentFormat>,
+    /// `None`이면 카테고리를 그대로 두고, `Some(None)`이면 미분류로 바꿉니다.
+    pub category_id: Option<Option<i64>>,
+    /// `None`이면 태그를 그대로 두고, `Some`이면 이 목록으로 통째로 바꿉니다.
+    pub tags: Option<Vec<String>>,
+}
+
 /// 게시글 목록 조회 전용 데이터 모델
 #[derive(Debug, Clone)]
 pub struct BoardListItem {
     pub id: i64,
     pub title: String,
     pub content: String,
+    pub content_format: ContentFormat,
+    pub author_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub tags: Vec<String>,
+    pub created_at: Option<String>,
+}
+
+/// 휴지통 목록 조회 전용 데이터 모델
+#[derive(Debug, Clone)]
+pub struct TrashedBoard {
+    pub id: i64,
+    pub title: String,
+    pub author_id: Option<i64>,
+    pub created_at: Option<String>,
+    /// 휴지통으로 옮긴 시각 (`YYYY-MM-DD HH:MM:SS`)
+    pub deleted_at: Option<String>,
+}
+
+/// 게시글 목록 검색 요청 (검증 전 원본 입력)
+#[derive(Debug, Clone, Default)]
+pub struct BoardSearchQuery {
+    /// 제목과 내용 전체에서 찾을 검색어
+    pub q: Option<String>,
+    /// 제목에서 찾을 검색어
+    pub title: Option<String>,
+    /// 작성일 시작 (YYYY-MM-DD, 포함)
+    pub from: Option<String>,
+    /// 작성일 끝 (YYYY-MM-DD, 포함)
+    pub to: Option<String>,
+    /// 정렬 기준 (예: `created_at,desc`)
+    pub sort: Option<String>,
+    /// 카테고리 ID
+    pub category: Option<String>,
+    /// 태그 이름
+    pub tag: Option<String>,
+}
+
+/// 게시글 목록 정렬 컬럼
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortField {
+    #[default]
+    Id,
+    CreatedAt,
+    Title,
+}
+
+/// 게시글 목록 정렬 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// 게시글 목록 정렬 기준 (기본값: ID 내림차순)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BoardSort {
+    pub field: SortField,
+    pub direction: SortDirection,
+}
+
+/// 서비스 계층에서 검증을 마친 게시글 검색 조건.
+/// 저장소 계층은 이 값을 바인드 변수를 사용하는 SQL로 변환합니다. (조회 캐시의 키로도 씁니다)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BoardFilter {
+    /// 제목 또는 내용에 포함되어야 하는 검색어
+    pub keyword: Option<String>,
+    /// 제목에 포함되어야 하는 검색어
+    pub title: Option<String>,
+    /// 작성일 하한 (포함)
+    pub from: Option<chrono::NaiveDate>,
+    /// 작성일 상한 (포함)
+    pub to: Option<chrono::NaiveDate>,
+    /// 이 카테고리에 속한 게시글만
+    pub category_id: Option<i64>,
+    /// 이 태그(정규화된 이름)가 붙은 게시글만
+    pub tag: Option<String>,
+    pub sort: BoardSort,
+}
+
+/// 키셋 페이지네이션 커서: 마지막으로 받은 게시글의 `(CREATED_AT, ID)`
+#[derive(Debug, Clone)]
+pub struct BoardCursor {
+    pub created_at: oracle::sql_type::Timestamp,
+    pub id: i64,
+}
+
+impl BoardCursor {
+    /// 클라이언트에 전달할 불투명(opaque) 문자열로 인코딩합니다.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}|{}", self.created_at, self.id))
+    }
+
+    /// `encode`로 만든 문자열을 해석합니다. 형식이 맞지 않으면 `None`을 반환합니다.
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        let raw = String::from_utf8(bytes).ok()?;
+        let (created_at, id) = raw.rsplit_once('|')?;
+        Some(Self {
+            created_at: created_at.parse().ok()?,
+            id: id.parse().ok()?,
+        })
+    }
+}
+
+/// 인기 게시글: 기간 안의 조회수와 좋아요에 가중치를 곱해 더한 점수 순
+#[derive(Debug, Clone)]
+pub struct PopularBoard {
+    pub id: i64,
+    pub title: String,
+    pub author_id: Option<i64>,
+    pub category_id: Option<i64>,
+    /// 누적 조회수
+    pub view_count: i64,
+    /// 기간 안의 조회수
+    pub window_views: i64,
+    /// 기간 안에 받은 좋아요 수
+    pub window_likes: i64,
+    pub score: i64,
     pub created_at: Option<String>,
 }
+
+/// 키셋 페이지네이션 조회 결과
+#[derive(Debug, Clone)]
+pub struct BoardCursorPage {
+    pub items: Vec<BoardListItem>,
+    /// 다음 페이지가 있으면 그 시작 커서
+    pub next_cursor: Option<String>,
+    /// 요청한 경우에만 계산되는 검색 조건 전체 게시글 수
+    pub total_count: Option<u32>,
+}
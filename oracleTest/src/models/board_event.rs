@@ -0,0 +1,72 @@
+//! 게시글 변경 이벤트 모델
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// 게시글에 일어난 변경의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardEventKind {
+    Created,
+    Updated,
+    /// 휴지통으로 이동
+    Deleted,
+}
+
+impl BoardEventKind {
+    /// SSE `event:` 필드와 JSON `kind`에 쓰는 이름
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BoardEventKind::Created => "created",
+            BoardEventKind::Updated => "updated",
+            BoardEventKind::Deleted => "deleted",
+        }
+    }
+}
+
+/// 구독자에게 보내는 게시글 변경 이벤트
+#[derive(Debug, Clone)]
+pub struct BoardEvent {
+    /// 이벤트 ID (`Last-Event-ID`로 재전송 위치를 정함)
+    pub id: EventId,
+    pub kind: BoardEventKind,
+    pub board_id: i64,
+    pub author_id: Option<i64>,
+    /// 변경 후 제목 (삭제 이벤트는 삭제 전 제목)
+    pub title: String,
+    /// 변경 후 버전 (삭제 이벤트는 `None`)
+    pub version: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// 이벤트 ID: 프로세스마다 새로 정하는 시작 표식(`epoch`)과 그 안에서 1부터 증가하는 순번
+///
+/// 문자열로는 `<epoch(16진수 8자리)>-<순번>`입니다. 재시작 전에 받은 ID는 `epoch`가 달라 이어 받을 수 없음을 알 수 있습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventId {
+    pub epoch: u32,
+    pub seq: u64,
+}
+
+impl EventId {
+    /// `<epoch>-<순번>`을 해석합니다. (앞뒤 공백 무시, 형식이 틀리면 `None`)
+    pub fn parse(value: &str) -> Option<Self> {
+        let (epoch, seq) = value.trim().split_once('-')?;
+        if epoch.len() != 8
+            || !epoch.bytes().all(|b| b.is_ascii_hexdigit())
+            || seq.is_empty()
+            || !seq.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        Some(Self {
+            epoch: u32::from_str_radix(epoch, 16).ok()?,
+            seq: seq.parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08x}-{}", self.epoch, self.seq)
+    }
+}
@@ -0,0 +1,264 @@
+//! 게시글 일괄 내보내기/가져오기 형식 (JSON Lines, CSV)
+//!
+//! 내보낸 행에는 `id`, `author_id`, `created_at`도 들어 있지만, 가져올 때는 `title`, `content`, `format`,
+//! `category_id`, `tags`만 읽고 나머지 필드는 무시합니다. 그래서 내보낸 파일을 다른 환경에 그대로 가져올 수 있습니다.
+//! (`category_id`는 가져오는 환경에도 같은 ID의 카테고리가 있어야 합니다)
+//!
+//! CSV의 `tags` 칸에는 태그 목록을 JSON 배열 문자열로 씁니다. 가져올 때는 쉼표로 구분한 목록도 받습니다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::board::BoardListItem;
+
+/// CSV 머리글 (내보내기 행의 필드 순서)
+const CSV_HEADER: [&str; 8] = [
+    "id",
+    "title",
+    "content",
+    "format",
+    "category_id",
+    "tags",
+    "author_id",
+    "created_at",
+];
+
+/// 내보내기/가져오기 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransferFormat {
+    /// 한 줄에 JSON 객체 하나 (`application/x-ndjson`)
+    #[default]
+    Jsonl,
+    /// 첫 줄이 머리글인 CSV (`text/csv`)
+    Csv,
+}
+
+impl TransferFormat {
+    /// `jsonl`, `csv`(대소문자 무시)를 해석합니다. 알 수 없는 값이면 `None`입니다.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "jsonl" | "ndjson" => Some(Self::Jsonl),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    /// 응답 `Content-Type`
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Jsonl => "application/x-ndjson",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    /// 내려받을 파일 확장자
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Csv => "csv",
+        }
+    }
+
+    /// 첫 행보다 먼저 보낼 머리글 (CSV만 해당)
+    pub fn header(self) -> Option<Vec<u8>> {
+        match self {
+            Self::Jsonl => None,
+            Self::Csv => {
+                let mut writer = csv::WriterBuilder::new().from_writer(Vec::new());
+                writer.write_record(CSV_HEADER).ok()?;
+                writer.into_inner().ok()
+            }
+        }
+    }
+
+    /// 게시글 묶음을 이 형식의 바이트로 직렬화합니다. (CSV는 머리글 없이 행만)
+    pub fn encode<'a>(
+        self,
+        items: impl IntoIterator<Item = &'a BoardListItem>,
+    ) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Jsonl => {
+                let mut buf = Vec::new();
+                for item in items {
+                    serde_json::to_writer(&mut buf, &ExportRecord::new(item, &item.tags))?;
+                    buf.push(b'\n');
+                }
+                Ok(buf)
+            }
+            Self::Csv => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(false)
+                    .from_writer(Vec::new());
+                for item in items {
+                    let tags = serde_json::to_string(&item.tags)?;
+                    writer.serialize(ExportRecord::new(item, tags))?;
+                }
+                writer.into_inner().map_err(|err| err.into_error())
+            }
+        }
+    }
+
+    /// 가져올 파일을 행 단위로 해석합니다. 형식이 잘못된 행도 줄 번호와 함께 결과에 남깁니다.
+    pub fn parse_rows(self, body: &[u8]) -> Vec<ImportRow> {
+        match self {
+            Self::Jsonl => parse_jsonl(body),
+            Self::Csv => parse_csv(body),
+        }
+    }
+}
+
+/// 내보내기 파일의 한 행 (`tags`는 JSON Lines에서 배열, CSV에서 JSON 배열 문자열)
+#[derive(Debug, Serialize)]
+struct ExportRecord<'a, T> {
+    id: i64,
+    title: &'a str,
+    content: &'a str,
+    format: &'static str,
+    category_id: Option<i64>,
+    tags: T,
+    author_id: Option<i64>,
+    created_at: Option<&'a str>,
+}
+
+impl<'a, T> ExportRecord<'a, T> {
+    fn new(item: &'a BoardListItem, tags: T) -> Self {
+        Self {
+            id: item.id,
+            title: &item.title,
+            content: &item.content,
+            format: item.content_format.as_str(),
+            category_id: item.category_id,
+            tags,
+            author_id: item.author_id,
+            created_at: item.created_at.as_deref(),
+        }
+    }
+}
+
+/// 가져올 파일의 한 행에서 읽는 필드 (그 밖의 필드는 무시)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportRecord {
+    pub title: String,
+    pub content: String,
+    /// 본문 형식 이름 (없거나 비어 있으면 `plain`)
+    #[serde(default)]
+    pub format: Option<String>,
+    #[serde(default)]
+    pub category_id: Option<i64>,
+    /// 정규화하기 전의 태그 이름
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// CSV 한 행에서 읽는 필드 (`tags`는 한 칸에 담긴 목록)
+#[derive(Debug, Deserialize)]
+struct CsvImportRecord {
+    title: String,
+    content: String,
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    category_id: Option<i64>,
+    #[serde(default)]
+    tags: Option<String>,
+}
+
+impl TryFrom<CsvImportRecord> for ImportRecord {
+    type Error = String;
+
+    fn try_from(record: CsvImportRecord) -> Result<Self, String> {
+        let tags = match record.tags.as_deref().map(str::trim) {
+            None | Some("") => Vec::new(),
+            Some(tags) if tags.starts_with('[') => serde_json::from_str(tags)
+                .map_err(|err| format!("tags 형식 오류 (JSON 배열): {}", err))?,
+            Some(tags) => tags.split(',').map(str::to_string).collect(),
+        };
+        Ok(Self {
+            title: record.title,
+            content: record.content,
+            format: record.format,
+            category_id: record.category_id,
+            tags,
+        })
+    }
+}
+
+/// 해석한 행 하나와 그 줄 번호 (1부터 시작, CSV는 머리글이 1번 줄)
+#[derive(Debug, Clone)]
+pub struct ImportRow {
+    pub line: u64,
+    pub record: Result<ImportRecord, String>,
+}
+
+/// 가져오기 결과 보고서
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    /// 해석한 행 수 (빈 줄 제외)
+    pub total: usize,
+    /// 추가된 게시글 수
+    pub imported: usize,
+    /// 추가하지 못한 행 수
+    pub failed: usize,
+    /// 추가된 게시글 ID (파일 순서)
+    pub ids: Vec<i64>,
+    /// 실패한 행별 사유
+    pub errors: Vec<ImportRowError>,
+}
+
+/// 추가하지 못한 행과 그 사유
+#[derive(Debug, Clone)]
+pub struct ImportRowError {
+    pub line: u64,
+    pub error: String,
+}
+
+/// JSON Lines: 빈 줄은 건너뛰고, 줄마다 JSON 객체 하나를 읽습니다.
+fn parse_jsonl(body: &[u8]) -> Vec<ImportRow> {
+    body.split(|byte| *byte == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim_ascii().is_empty())
+        .map(|(index, line)| ImportRow {
+            line: index as u64 + 1,
+            record: serde_json::from_slice(line).map_err(|err| format!("JSON 형식 오류: {}", err)),
+        })
+        .collect()
+}
+
+/// CSV: 첫 줄의 머리글로 가져올 열을 찾습니다. 따옴표 안의 줄바꿈도 허용합니다.
+fn parse_csv(body: &[u8]) -> Vec<ImportRow> {
+    let mut reader = csv::ReaderBuilder::new().from_reader(body);
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(err) => {
+            return vec![ImportRow {
+                line: 1,
+                record: Err(format!("CSV 머리글 오류: {}", err)),
+            }];
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => rows.push(ImportRow {
+                line: record.position().map_or(0, csv::Position::line),
+                record: record
+                    .deserialize::<CsvImportRecord>(Some(&headers))
+                    .map_err(|err| format!("CSV 형식 오류: {}", err))
+                    .and_then(ImportRecord::try_from),
+            }),
+            Err(err) => {
+                let fatal = matches!(err.kind(), csv::ErrorKind::Io(_));
+                rows.push(ImportRow {
+                    line: err.position().map_or(0, csv::Position::line),
+                    record: Err(format!("CSV 형식 오류: {}", err)),
+                });
+                if fatal {
+                    break;
+                }
+            }
+        }
+    }
+    rows
+}
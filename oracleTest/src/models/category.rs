@@ -0,0 +1,17 @@
+//! Model 계층: 게시글 카테고리와 태그
+
+/// 게시글 카테고리 데이터 모델 (게시글마다 하나까지 지정)
+#[derive(Debug, Clone)]
+pub struct Category {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<oracle::sql_type::Timestamp>,
+}
+
+/// 태그와 그 태그가 붙은 게시글 수 (휴지통의 게시글 제외)
+#[derive(Debug, Clone)]
+pub struct TagCount {
+    pub name: String,
+    pub board_count: u32,
+}
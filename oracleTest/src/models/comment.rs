@@ -0,0 +1,21 @@
+//! Model 계층: 댓글 데이터 구조체
+
+/// 댓글 데이터 모델
+#[derive(Debug, Clone)]
+pub struct Comment {
+    pub id: i64,
+    pub board_id: i64,
+    /// 답글이 가리키는 루트 댓글 ID (루트 댓글이면 `None`)
+    pub parent_id: Option<i64>,
+    pub author_id: Option<i64>,
+    pub content: String,
+    pub created_at: Option<oracle::sql_type::Timestamp>,
+}
+
+/// 루트 댓글과 그에 달린 답글 묶음
+#[derive(Debug, Clone)]
+pub struct CommentThread {
+    pub comment: Comment,
+    /// 작성 순으로 정렬된 답글 목록
+    pub replies: Vec<Comment>,
+}
@@ -1 +1,8 @@
+pub mod attachment;
 pub mod board;
+pub mod board_event;
+pub mod board_transfer;
+pub mod category;
+pub mod comment;
+pub mod revision;
+pub mod user;
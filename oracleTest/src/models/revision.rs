@@ -0,0 +1,74 @@
+//! Model 계층: 게시글 수정 이력과 줄 단위 비교 결과
+
+use similar::{ChangeTag, TextDiff};
+
+/// 게시글 수정 이력 데이터 모델.
+///
+/// 게시글을 수정할 때 덮어쓰기 직전의 제목/내용을 보관하며,
+/// `revision`은 게시글별로 1부터 증가하는 이력 번호입니다.
+#[derive(Debug, Clone)]
+pub struct BoardRevision {
+    pub board_id: i64,
+    pub revision: i64,
+    pub title: String,
+    pub content: String,
+    /// 보관 당시 게시글 버전
+    pub version: i64,
+    /// 이력으로 보관된 시각 (= 다음 수정 시각)
+    pub created_at: Option<oracle::sql_type::Timestamp>,
+}
+
+/// 줄 단위 비교에서 한 줄의 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// 줄 단위 비교 결과의 한 줄
+#[derive(Debug, Clone)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    /// 이전 본문에서의 줄 번호 (1부터, 추가된 줄이면 `None`)
+    pub old_line: Option<usize>,
+    /// 이후 본문에서의 줄 번호 (1부터, 삭제된 줄이면 `None`)
+    pub new_line: Option<usize>,
+    /// 줄 내용 (줄바꿈 문자 제외)
+    pub text: String,
+}
+
+/// 두 시점의 게시글을 비교한 결과
+#[derive(Debug, Clone)]
+pub struct RevisionDiff {
+    /// 비교 기준 이력 번호
+    pub from: i64,
+    /// 비교 대상 이력 번호 (`None`이면 현재 게시글)
+    pub to: Option<i64>,
+    pub old_title: String,
+    pub new_title: String,
+    /// 내용의 줄 단위 비교 결과
+    pub lines: Vec<DiffLine>,
+}
+
+impl RevisionDiff {
+    /// 두 본문을 줄 단위로 비교합니다.
+    /// 줄 끝 문자(`\n`, `\r\n`)의 종류와 마지막 줄바꿈 유무는 차이로 보지 않습니다.
+    pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+        let old_lines: Vec<&str> = old.lines().collect();
+        let new_lines: Vec<&str> = new.lines().collect();
+        TextDiff::from_slices(&old_lines, &new_lines)
+            .iter_all_changes()
+            .map(|change| DiffLine {
+                op: match change.tag() {
+                    ChangeTag::Equal => DiffOp::Equal,
+                    ChangeTag::Insert => DiffOp::Insert,
+                    ChangeTag::Delete => DiffOp::Delete,
+                },
+                old_line: change.old_index().map(|index| index + 1),
+                new_line: change.new_index().map(|index| index + 1),
+                text: change.value().to_string(),
+            })
+            .collect()
+    }
+}
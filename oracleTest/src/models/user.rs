@@ -0,0 +1,11 @@
+//! Model 계층: 사용자 데이터 구조체
+
+/// 사용자 데이터 모델
+#[derive(Debug, Clone)]
+pub struct User {
+    pub id: i64,
+    pub username: String,
+    /// Argon2 PHC 문자열 형식의 비밀번호 해시
+    pub password_hash: String,
+    pub created_at: Option<oracle::sql_type::Timestamp>,
+}
@@ -0,0 +1,131 @@
+//! Repository 계층: 첨부파일 메타데이터 테이블 작업 (Oracle)
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::{
+    INSERT_ATTACHMENT, SELECT_ATTACHMENT_BY_ID, SELECT_ATTACHMENT_SEQ_CURRVAL,
+    SELECT_ATTACHMENTS_BY_BOARD,
+};
+use crate::models::attachment::{Attachment, NewAttachment};
+use crate::repositories::attachment_store::AttachmentStore;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use oracle::Row;
+use oracle::sql_type::ToSql;
+use r2d2::Pool;
+use r2d2_oracle::OracleConnectionManager;
+use tracing::{debug, info};
+
+/// 첨부파일 메타데이터 접근 객체 (DAO).
+pub struct AttachmentRepository {
+    pool: Pool<OracleConnectionManager>,
+}
+
+impl AttachmentRepository {
+    /// `r2d2::Error`를 `oracle::Error`로 매핑하는 헬퍼 함수
+    fn map_pool_err(err: r2d2::Error) -> oracle::Error {
+        oracle::Error::InternalError(err.to_string())
+    }
+
+    /// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+    fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+        StoreError::from(err)
+    }
+
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<OracleConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Attachment 구조체로 변환하는 헬퍼 함수.
+    fn row_to_attachment(row: Row) -> Result<Attachment, oracle::Error> {
+        Ok(Attachment {
+            id: row.get("ID")?,
+            board_id: row.get("BOARD_ID")?,
+            filename: row.get("FILENAME")?,
+            content_type: row.get("CONTENT_TYPE")?,
+            size_bytes: row.get("SIZE_BYTES")?,
+            sha256: row.get("SHA256")?,
+            storage_key: row.get("STORAGE_KEY")?,
+            uploader_id: row.get("UPLOADER_ID")?,
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl AttachmentStore for AttachmentRepository {
+    /// 새 첨부파일 메타데이터 추가 후 생성된 ID 반환
+    async fn insert(&self, attachment: NewAttachment) -> Result<i64, StoreError> {
+        info!(
+            "[Repo] attachment insert 호출: board_id={}, filename={}",
+            attachment.board_id, attachment.filename
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 7] = [
+                ("board_id", &attachment.board_id),
+                ("filename", &attachment.filename),
+                ("content_type", &attachment.content_type),
+                ("size_bytes", &attachment.size_bytes),
+                ("sha256", &attachment.sha256),
+                ("storage_key", &attachment.storage_key),
+                ("uploader_id", &attachment.uploader_id),
+            ];
+            debug!("[Repo][SQL] {}", INSERT_ATTACHMENT.trim());
+            debug!("[Repo][BIND] {:?}", attachment);
+            conn.execute_named(INSERT_ATTACHMENT, &params)?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            debug!("[Repo][SQL] {}", SELECT_ATTACHMENT_SEQ_CURRVAL.trim());
+            conn.query_row_as::<i64>(SELECT_ATTACHMENT_SEQ_CURRVAL, &[])
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// ID로 단일 첨부파일 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Attachment>, StoreError> {
+        info!("[Repo] attachment find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
+            debug!("[Repo][SQL] {}", SELECT_ATTACHMENT_BY_ID.trim());
+            debug!("[Repo][BIND] id={}", id);
+            let mut rows = conn.query_named(SELECT_ATTACHMENT_BY_ID, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_attachment(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글의 첨부파일 목록 조회
+    async fn find_by_board(&self, board_id: i64) -> Result<Vec<Attachment>, StoreError> {
+        info!(
+            "[Repo] attachment find_by_board 호출: board_id={}",
+            board_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("board_id", &board_id)];
+            debug!("[Repo][SQL] {}", SELECT_ATTACHMENTS_BY_BOARD.trim());
+            debug!("[Repo][BIND] board_id={}", board_id);
+            let rows = conn.query_named(SELECT_ATTACHMENTS_BY_BOARD, &params)?;
+            rows.map(|row_result| Self::row_to_attachment(row_result?))
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+}
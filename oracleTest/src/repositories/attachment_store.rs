@@ -0,0 +1,19 @@
+//! 첨부파일 메타데이터 저장소 추상화: 저장소 백엔드(Oracle, SQLite)에 독립적인 계약
+
+use crate::models::attachment::{Attachment, NewAttachment};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+
+/// 첨부파일 메타데이터 저장소가 제공해야 하는 연산 집합.
+/// 게시글 삭제 시 메타데이터는 게시글 저장소의 삭제 트랜잭션에서 함께 지워집니다.
+#[async_trait]
+pub trait AttachmentStore: Send + Sync {
+    /// 새 첨부파일 메타데이터 추가 후 생성된 ID 반환
+    async fn insert(&self, attachment: NewAttachment) -> Result<i64, StoreError>;
+
+    /// ID로 단일 첨부파일 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Attachment>, StoreError>;
+
+    /// 게시글의 첨부파일 목록을 업로드 순으로 조회
+    async fn find_by_board(&self, board_id: i64) -> Result<Vec<Attachment>, StoreError>;
+}
@@ -1,15 +1,31 @@
 //! Repository 계층: 데이터베이스 CRUD 작업
 
+use crate::common::metrics::spawn_blocking;
 use crate::common::queries::{
-    DELETE_BOARD, INSERT_BOARD, SELECT_BOARD_BY_ID, SELECT_BOARD_COUNT, SELECT_BOARD_PAGED,
-    SELECT_BOARD_SEQ_CURRVAL, UPDATE_BOARD,
+    DELETE_ATTACHMENTS_BY_BOARD, DELETE_BOARD, DELETE_BOARD_LIKE, DELETE_BOARD_LIKES,
+    DELETE_BOARD_TAGS, DELETE_BOARD_VIEWS, DELETE_COMMENTS_BY_BOARD, DELETE_REVISIONS_BY_BOARD,
+    INSERT_BOARD, INSERT_BOARD_LIKE, INSERT_BOARD_REVISION, INSERT_BOARD_TAG, RESTORE_BOARD,
+    SELECT_BOARD_BY_ID, SELECT_BOARD_COUNT, SELECT_BOARD_FOR_UPDATE, SELECT_BOARD_KEYSET,
+    SELECT_BOARD_LIKE_COUNT, SELECT_BOARD_PAGED, SELECT_BOARD_REVISION, SELECT_BOARD_REVISIONS,
+    SELECT_BOARD_SEQ_CURRVAL, SELECT_BOARD_TAGS, SELECT_EXPIRED_TRASH_IDS, SELECT_POPULAR_BOARDS,
+    SELECT_TAG_COUNTS, SELECT_TRASH_COUNT, SELECT_TRASH_PAGED, SELECT_TRASHED_BOARD_BY_ID,
+    SOFT_DELETE_BOARD, UPDATE_BOARD, UPDATE_BOARD_CATEGORY, UPDATE_BOARD_VIEW_COUNT,
+    UPSERT_BOARD_VIEWS_DAILY, UPSERT_TAG,
 };
-use crate::models::board::{Board, BoardListItem};
-use oracle::Row;
+use crate::models::board::{
+    Board, BoardCursor, BoardFilter, BoardListItem, BoardUpdate, ContentFormat, NewBoard,
+    PopularBoard, TrashedBoard,
+};
+use crate::models::category::TagCount;
+use crate::models::revision::BoardRevision;
+use crate::repositories::board_store::{BoardStore, SearchSql};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
 use oracle::sql_type::ToSql;
+use oracle::{Connection, Row, Statement};
 use r2d2::Pool;
 use r2d2_oracle::OracleConnectionManager;
-use tokio::task::spawn_blocking;
+use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
 /// 게시판 데이터베이스 접근 객체 (DAO).
@@ -24,9 +40,14 @@ impl BoardRepository {
         oracle::Error::InternalError(err.to_string())
     }
 
-    /// `tokio::task::JoinError`를 `oracle::Error`로 매핑하는 헬퍼 함수
-    fn map_join_err(err: tokio::task::JoinError) -> oracle::Error {
-        oracle::Error::InternalError(err.to_string())
+    /// 고유 제약 위반(ORA-00001)인지 확인하는 헬퍼 함수
+    fn is_unique_violation(err: &oracle::Error) -> bool {
+        matches!(err, oracle::Error::OciError(db) if db.code() == 1)
+    }
+
+    /// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+    fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+        StoreError::from(err)
     }
 
     /// 새로운 Repository 인스턴스 생성
@@ -34,189 +55,932 @@ impl BoardRepository {
         Self { pool }
     }
 
-    /// 전체 게시글 수 조회
-    pub async fn count_all(&self) -> Result<u32, oracle::Error> {
-        info!("[Repo] count_all 호출");
+    /// 검색 조건의 바인드 값을 Statement에 바인딩하는 헬퍼 함수.
+    /// 조건이 없는 바인드 변수는 SQL에 존재하지 않으므로 건너뜁니다.
+    fn bind_search(stmt: &mut Statement, search: &SearchSql) -> Result<(), oracle::Error> {
+        if let Some(keyword) = &search.keyword {
+            debug!("[Repo][BIND] keyword={}", keyword);
+            stmt.bind("keyword", keyword)?;
+        }
+        if let Some(title) = &search.title {
+            debug!("[Repo][BIND] title={}", title);
+            stmt.bind("title", title)?;
+        }
+        if let Some(from_date) = &search.from_date {
+            debug!("[Repo][BIND] from_date={}", from_date);
+            stmt.bind("from_date", from_date)?;
+        }
+        if let Some(to_date) = &search.to_date {
+            debug!("[Repo][BIND] to_date={}", to_date);
+            stmt.bind("to_date", to_date)?;
+        }
+        if let Some(category_id) = &search.category_id {
+            debug!("[Repo][BIND] category_id={}", category_id);
+            stmt.bind("category_id", category_id)?;
+        }
+        if let Some(tag) = &search.tag {
+            debug!("[Repo][BIND] tag={}", tag);
+            stmt.bind("tag", tag)?;
+        }
+        if let Some(cursor) = &search.cursor {
+            debug!(
+                "[Repo][BIND] cursor_created_at={}, cursor_id={}",
+                cursor.created_at, cursor.id
+            );
+            stmt.bind("cursor_created_at", &cursor.created_at)?;
+            stmt.bind("cursor_id", &cursor.id)?;
+        }
+        Ok(())
+    }
+
+    /// `CONTENT_FORMAT` 컬럼을 읽습니다. 알 수 없는 값은 일반 텍스트로 취급합니다.
+    fn content_format(row: &Row) -> Result<ContentFormat, oracle::Error> {
+        Ok(row
+            .get::<&str, Option<String>>("CONTENT_FORMAT")?
+            .and_then(|format| ContentFormat::parse(&format))
+            .unwrap_or_default())
+    }
+
+    /// DB Row를 Board 구조체로 변환하는 헬퍼 함수.
+    /// `spawn_blocking` 내부에서 사용하기 위해 `&self` 의존성을 제거했습니다.
+    fn row_to_board(row: Row) -> Result<Board, oracle::Error> {
+        Ok(Board {
+            id: row.get("ID")?,
+            title: row
+                .get::<&str, Option<String>>("TITLE")?
+                .unwrap_or_default(),
+            content: row
+                .get::<&str, Option<String>>("CONTENT")?
+                .unwrap_or_default(),
+            content_format: Self::content_format(&row)?,
+            author_id: row.get("AUTHOR_ID")?,
+            category_id: row.get("CATEGORY_ID")?,
+            tags: Vec::new(),
+            version: row.get("VERSION")?,
+            view_count: row.get("VIEW_COUNT")?,
+            like_count: row.get("LIKE_COUNT")?,
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+
+    /// DB Row를 `BoardListItem` 구조체로 변환하는 헬퍼 함수.
+    /// `spawn_blocking` 내부에서 사용하기 위해 `&self` 의존성을 제거했습니다.
+    fn row_to_board_list_item(row: Row) -> Result<BoardListItem, oracle::Error> {
+        Ok(BoardListItem {
+            id: row.get("ID")?,
+            title: row
+                .get::<&str, Option<String>>("TITLE")?
+                .unwrap_or_default(),
+            content: row
+                .get::<&str, Option<String>>("CONTENT")?
+                .unwrap_or_default(),
+            content_format: Self::content_format(&row)?,
+            author_id: row.get("AUTHOR_ID")?,
+            category_id: row.get("CATEGORY_ID")?,
+            tags: Vec::new(),
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+
+    /// DB Row를 `TrashedBoard` 구조체로 변환하는 헬퍼 함수.
+    fn row_to_trashed_board(row: Row) -> Result<TrashedBoard, oracle::Error> {
+        Ok(TrashedBoard {
+            id: row.get("ID")?,
+            title: row
+                .get::<&str, Option<String>>("TITLE")?
+                .unwrap_or_default(),
+            author_id: row.get("AUTHOR_ID")?,
+            created_at: row.get("CREATED_AT")?,
+            deleted_at: row.get("DELETED_AT")?,
+        })
+    }
+
+    /// DB Row를 `BoardRevision` 구조체로 변환하는 헬퍼 함수.
+    fn row_to_revision(row: Row) -> Result<BoardRevision, oracle::Error> {
+        Ok(BoardRevision {
+            board_id: row.get("BOARD_ID")?,
+            revision: row.get("REVISION_NO")?,
+            title: row
+                .get::<&str, Option<String>>("TITLE")?
+                .unwrap_or_default(),
+            content: row
+                .get::<&str, Option<String>>("CONTENT")?
+                .unwrap_or_default(),
+            version: row.get("VERSION")?,
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+
+    /// 여러 게시글의 태그를 한 번에 조회하여 게시글 ID별 태그 이름 목록으로 반환합니다.
+    fn load_tags(
+        conn: &Connection,
+        ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<String>>, oracle::Error> {
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        if ids.is_empty() {
+            return Ok(tags);
+        }
+        let sql = SearchSql::render_ids(SELECT_BOARD_TAGS, ids.len(), |index| format!(":{index}"));
+        debug!("[Repo][SQL] {}", sql.trim());
+        debug!("[Repo][BIND] ids={:?}", ids);
+        let params: Vec<&dyn ToSql> = ids.iter().map(|id| id as &dyn ToSql).collect();
+        for row_result in conn.query(&sql, &params)? {
+            let row = row_result?;
+            tags.entry(row.get("BOARD_ID")?)
+                .or_default()
+                .push(row.get("NAME")?);
+        }
+        Ok(tags)
+    }
+
+    /// 목록 항목마다 태그를 채웁니다.
+    fn attach_tags<'a>(
+        conn: &Connection,
+        items: impl IntoIterator<Item = &'a mut BoardListItem>,
+    ) -> Result<(), oracle::Error> {
+        let items: Vec<&mut BoardListItem> = items.into_iter().collect();
+        let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+        let mut tags = Self::load_tags(conn, &ids)?;
+        for item in items {
+            item.tags = tags.remove(&item.id).unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// 단건 조회한 게시글에 태그를 채웁니다.
+    fn with_tags(conn: &Connection, board: Option<Board>) -> Result<Option<Board>, oracle::Error> {
+        board
+            .map(|mut board| {
+                board.tags = Self::load_tags(conn, &[board.id])?
+                    .remove(&board.id)
+                    .unwrap_or_default();
+                Ok(board)
+            })
+            .transpose()
+    }
+
+    /// 게시글의 태그를 `tags`로 통째로 바꿉니다. 없는 태그는 새로 만듭니다. (커밋은 호출한 쪽에서 합니다)
+    fn replace_tags(
+        conn: &Connection,
+        board_id: i64,
+        tags: &[String],
+    ) -> Result<(), oracle::Error> {
+        let board_params: [(&str, &dyn ToSql); 1] = [("board_id", &board_id)];
+        debug!("[Repo][SQL] {}", DELETE_BOARD_TAGS.trim());
+        debug!("[Repo][BIND] board_id={}, tags={:?}", board_id, tags);
+        conn.execute_named(DELETE_BOARD_TAGS, &board_params)?;
+        for name in tags {
+            let tag_params: [(&str, &dyn ToSql); 1] = [("name", name)];
+            conn.execute_named(UPSERT_TAG, &tag_params)?;
+            let link_params: [(&str, &dyn ToSql); 2] = [("board_id", &board_id), ("name", name)];
+            conn.execute_named(INSERT_BOARD_TAG, &link_params)?;
+        }
+        Ok(())
+    }
+
+    /// 게시글 한 건과 태그를 추가하고 생성된 ID를 반환합니다. (커밋은 호출한 쪽에서 합니다)
+    fn insert_one(
+        conn: &Connection,
+        board: &NewBoard,
+        author_id: i64,
+    ) -> Result<i64, oracle::Error> {
+        let format = board.format.as_str();
+        let params: [(&str, &dyn ToSql); 5] = [
+            ("title", &board.title),
+            ("content", &board.content),
+            ("format", &format),
+            ("author_id", &author_id),
+            ("category_id", &board.category_id),
+        ];
+        debug!("[Repo][SQL] {}", INSERT_BOARD.trim());
+        debug!(
+            "[Repo][BIND] title={}, content_len={}, format={}, author_id={}, category_id={:?}",
+            board.title,
+            board.content.chars().count(),
+            format,
+            author_id,
+            board.category_id
+        );
+        conn.execute_named(INSERT_BOARD, &params)?;
+        // CURRVAL은 세션 단위이므로 커밋 전에도 방금 추가한 행의 ID를 돌려줍니다.
+        debug!("[Repo][SQL] {}", SELECT_BOARD_SEQ_CURRVAL.trim());
+        let id = conn.query_row_as::<i64>(SELECT_BOARD_SEQ_CURRVAL, &[])?;
+        if !board.tags.is_empty() {
+            Self::replace_tags(conn, id, &board.tags)?;
+        }
+        Ok(id)
+    }
+}
+
+#[async_trait]
+impl BoardStore for BoardRepository {
+    /// 검색 조건에 맞는 게시글 수 조회
+    async fn count_all(&self, filter: &BoardFilter) -> Result<u32, StoreError> {
+        info!("[Repo] count_all 호출: filter={:?}", filter);
         let pool = self.pool.clone();
+        let search = SearchSql::new(filter);
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
-            debug!("[Repo][SQL] {}", SELECT_BOARD_COUNT.trim());
+            let sql = search.render(SELECT_BOARD_COUNT);
+            debug!("[Repo][SQL] {}", sql.trim());
             // 쿼리 실행 후 첫 번째 행의 첫 번째 컬럼 값을 가져옴
-            conn.query_row_as::<u32>(SELECT_BOARD_COUNT, &[])
+            let mut stmt = conn.statement(&sql).build()?;
+            Self::bind_search(&mut stmt, &search)?;
+            stmt.query_row_as::<u32>(&[])
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
-    /// 페이지네이션을 사용하여 게시글 목록 조회
-    pub async fn find_paged(
+    /// 검색 조건과 페이지네이션을 사용하여 게시글 목록 조회
+    async fn find_paged(
         &self,
+        filter: &BoardFilter,
         offset: u32,
         limit: u32,
-    ) -> Result<Vec<BoardListItem>, oracle::Error> {
-        info!("[Repo] find_paged 호출: offset={}, limit={}", offset, limit);
+    ) -> Result<Vec<BoardListItem>, StoreError> {
+        info!(
+            "[Repo] find_paged 호출: offset={}, limit={}, filter={:?}",
+            offset, limit, filter
+        );
         let pool = self.pool.clone();
+        let search = SearchSql::new(filter);
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
             let start_row = i64::from(offset);
             let end_row = i64::from(offset.saturating_add(limit));
-            let params: [(&str, &dyn ToSql); 2] =
-                [("start_row", &start_row), ("end_row", &end_row)];
-            debug!("[Repo][SQL] {}", SELECT_BOARD_PAGED.trim());
+            let sql = search.render(SELECT_BOARD_PAGED);
+            debug!("[Repo][SQL] {}", sql.trim());
             debug!("[Repo][BIND] start_row={}, end_row={}", start_row, end_row);
-            let rows = conn.query_named(SELECT_BOARD_PAGED, &params)?;
+            let mut stmt = conn.statement(&sql).build()?;
+            Self::bind_search(&mut stmt, &search)?;
+            stmt.bind("start_row", &start_row)?;
+            stmt.bind("end_row", &end_row)?;
+            let rows = stmt.query(&[])?;
 
-            rows.map(|row_result| Self::row_to_board_list_item(row_result?))
-                .collect()
+            let mut items = rows
+                .map(|row_result| Self::row_to_board_list_item(row_result?))
+                .collect::<Result<Vec<_>, oracle::Error>>()?;
+            Self::attach_tags(&conn, &mut items)?;
+            Ok(items)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 키셋(커서) 방식으로 게시글 목록 조회
+    async fn find_after(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError> {
+        info!(
+            "[Repo] find_after 호출: after={:?}, limit={}, filter={:?}",
+            after, limit, filter
+        );
+        let pool = self.pool.clone();
+        let search = match after {
+            Some(cursor) => SearchSql::new(filter).after(cursor, filter.sort.direction),
+            None => SearchSql::new(filter),
+        };
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let limit = i64::from(limit);
+            let sql = search.render(SELECT_BOARD_KEYSET);
+            debug!("[Repo][SQL] {}", sql.trim());
+            debug!("[Repo][BIND] limit={}", limit);
+            let mut stmt = conn.statement(&sql).build()?;
+            Self::bind_search(&mut stmt, &search)?;
+            stmt.bind("limit", &limit)?;
+            let rows = stmt.query(&[])?;
+
+            let mut items = rows
+                .map(|row_result| {
+                    let row = row_result?;
+                    let cursor = BoardCursor {
+                        created_at: row.get("CREATED_AT_KEY")?,
+                        id: row.get("ID")?,
+                    };
+                    Ok((Self::row_to_board_list_item(row)?, cursor))
+                })
+                .collect::<Result<Vec<_>, oracle::Error>>()?;
+            Self::attach_tags(&conn, items.iter_mut().map(|(item, _)| item))?;
+            Ok(items)
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
     /// ID로 단일 게시글 조회
-    pub async fn find_by_id(&self, id: i64) -> Result<Option<Board>, oracle::Error> {
+    async fn find_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
         info!("[Repo] find_by_id 호출: id={}", id);
         let pool = self.pool.clone();
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
             let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
             debug!("[Repo][SQL] {}", SELECT_BOARD_BY_ID.trim());
             debug!("[Repo][BIND] id={}", id);
             let mut rows = conn.query_named(SELECT_BOARD_BY_ID, &params)?;
-            rows.next()
+            let board = rows
+                .next()
                 .map(|row_result| Self::row_to_board(row_result?))
-                .transpose()
+                .transpose()?;
+            Self::with_tags(&conn, board)
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
-    /// 새 게시글 추가 후 생성된 ID 반환
-    pub async fn insert(&self, title: String, content: String) -> Result<i64, oracle::Error> {
-        info!("[Repo] insert 호출: title={}", title); // title은 여기서 사용 후 이동됨
+    /// 새 게시글과 태그를 한 트랜잭션으로 추가한 후 생성된 ID 반환
+    async fn insert(&self, board: NewBoard, author_id: i64) -> Result<i64, StoreError> {
+        info!(
+            "[Repo] insert 호출: title={}, tags={:?}",
+            board.title, board.tags
+        );
         let pool = self.pool.clone();
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
-
-            let params: [(&str, &dyn ToSql); 2] = [("title", &title), ("content", &content)];
-            debug!("[Repo][SQL] {}", INSERT_BOARD.trim());
-            debug!(
-                "[Repo][BIND] title={}, content_len={}",
-                title,
-                content.chars().count()
-            );
-            let stmt = conn.execute_named(INSERT_BOARD, &params)?;
-            debug!("[Repo] INSERT 실행, 영향 받은 행: {}", stmt.row_count()?);
+            let id = match Self::insert_one(&conn, &board, author_id) {
+                Ok(id) => id,
+                Err(err) => {
+                    conn.rollback()?;
+                    return Err(err);
+                }
+            };
             // 트랜잭션 커밋
             conn.commit()?;
+            debug!("[Repo] INSERT 완료: id={}", id);
+            Ok(id)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글 일괄 추가 (한 트랜잭션)
+    async fn insert_many(
+        &self,
+        boards: Vec<NewBoard>,
+        author_id: i64,
+    ) -> Result<Vec<i64>, StoreError> {
+        info!(
+            "[Repo] insert_many 호출: count={}, author_id={}",
+            boards.len(),
+            author_id
+        );
+        let pool = self.pool.clone();
 
-            debug!("[Repo][SQL] {}", SELECT_BOARD_SEQ_CURRVAL.trim());
-            let mut rows = conn.query(SELECT_BOARD_SEQ_CURRVAL, &[])?;
-            rows.next().transpose()?.map_or_else(
-                || {
-                    Err(oracle::Error::InternalError(
-                        "Failed to get CURRVAL after insert".to_string(),
-                    ))
-                },
-                |row| row.get::<usize, i64>(0),
-            )
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+
+            // 모든 행을 추가한 뒤 한 번에 커밋하고, 실패하면 모두 되돌립니다.
+            let insert_all = || -> Result<Vec<i64>, oracle::Error> {
+                boards
+                    .iter()
+                    .map(|board| Self::insert_one(&conn, board, author_id))
+                    .collect()
+            };
+            let ids = match insert_all() {
+                Ok(ids) => ids,
+                Err(err) => {
+                    conn.rollback()?;
+                    return Err(err);
+                }
+            };
+            // 트랜잭션 커밋
+            conn.commit()?;
+            debug!("[Repo] 일괄 추가된 게시글 수: {}", ids.len());
+            Ok(ids)
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
     /// 게시글 수정
-    pub async fn update(
+    async fn update(
         &self,
         id: i64,
-        title: String,
-        content: String,
-    ) -> Result<bool, oracle::Error> {
-        info!("[Repo] update 호출: id={}, title={}", id, title);
+        changes: BoardUpdate,
+        expected_version: i64,
+    ) -> Result<bool, StoreError> {
+        info!(
+            "[Repo] update 호출: id={}, title={}, expected_version={}",
+            id, changes.title, expected_version
+        );
         let pool = self.pool.clone();
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
 
-            let params: [(&str, &dyn ToSql); 3] =
-                [("title", &title), ("content", &content), ("id", &id)];
-            debug!("[Repo][SQL] {}", UPDATE_BOARD.trim());
-            debug!(
-                "[Repo][BIND] id={}, title={}, content_len={}",
-                id,
-                title,
-                content.chars().count()
-            );
-            let rows_affected = conn.execute_named(UPDATE_BOARD, &params)?.row_count()?;
+            // 게시글 행을 잠가 수정 전 내용을 읽고, 게시글과 카테고리/태그를 수정한 뒤 읽은 내용을 이력으로 보관합니다.
+            // 같은 버전으로 동시에 수정하면 뒤의 요청은 잠금을 기다렸다가 바뀐 버전을 보고 실패하므로, 이력 번호가 겹치지 않습니다.
+            let apply = || -> Result<bool, oracle::Error> {
+                let lock_params: [(&str, &dyn ToSql); 2] =
+                    [("id", &id), ("version", &expected_version)];
+                debug!("[Repo][SQL] {}", SELECT_BOARD_FOR_UPDATE.trim());
+                debug!("[Repo][BIND] id={}, version={}", id, expected_version);
+                let previous = conn
+                    .query_as_named::<(String, Option<String>)>(
+                        SELECT_BOARD_FOR_UPDATE,
+                        &lock_params,
+                    )?
+                    .next()
+                    .transpose()?;
+                let Some((previous_title, previous_content)) = previous else {
+                    return Ok(false);
+                };
+
+                let format = changes.format.map(ContentFormat::as_str);
+                let params: [(&str, &dyn ToSql); 5] = [
+                    ("title", &changes.title),
+                    ("content", &changes.content),
+                    ("format", &format),
+                    ("id", &id),
+                    ("version", &expected_version),
+                ];
+                debug!("[Repo][SQL] {}", UPDATE_BOARD.trim());
+                debug!(
+                    "[Repo][BIND] id={}, title={}, content_len={}, format={:?}, version={}",
+                    id,
+                    changes.title,
+                    changes.content.chars().count(),
+                    format,
+                    expected_version
+                );
+                if conn.execute_named(UPDATE_BOARD, &params)?.row_count()? == 0 {
+                    return Ok(false);
+                }
+
+                let revision_params: [(&str, &dyn ToSql); 4] = [
+                    ("id", &id),
+                    ("title", &previous_title),
+                    ("content", &previous_content),
+                    ("version", &expected_version),
+                ];
+                debug!("[Repo][SQL] {}", INSERT_BOARD_REVISION.trim());
+                debug!("[Repo][BIND] id={}, version={}", id, expected_version);
+                conn.execute_named(INSERT_BOARD_REVISION, &revision_params)?;
+
+                if let Some(category_id) = &changes.category_id {
+                    let category_params: [(&str, &dyn ToSql); 2] =
+                        [("category_id", category_id), ("id", &id)];
+                    debug!("[Repo][SQL] {}", UPDATE_BOARD_CATEGORY.trim());
+                    debug!("[Repo][BIND] id={}, category_id={:?}", id, category_id);
+                    conn.execute_named(UPDATE_BOARD_CATEGORY, &category_params)?;
+                }
+                if let Some(tags) = &changes.tags {
+                    Self::replace_tags(&conn, id, tags)?;
+                }
+                Ok(true)
+            };
+
+            match apply() {
+                Ok(true) => {
+                    // 트랜잭션 커밋
+                    conn.commit()?;
+                    Ok(true)
+                }
+                Ok(false) => {
+                    // 게시글이 없거나 버전이 바뀌었으면 잡은 잠금을 풉니다.
+                    conn.rollback()?;
+                    warn!(
+                        "[Repo] 수정할 게시글 없음 또는 버전 불일치: id={}, version={}",
+                        id, expected_version
+                    );
+                    Ok(false)
+                }
+                Err(err) => {
+                    conn.rollback()?;
+                    Err(err)
+                }
+            }
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글을 휴지통으로 이동
+    async fn delete(&self, id: i64, expected_version: i64) -> Result<bool, StoreError> {
+        info!(
+            "[Repo] delete 호출: id={}, expected_version={}",
+            id, expected_version
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+
+            let params: [(&str, &dyn ToSql); 2] = [("id", &id), ("version", &expected_version)];
+            debug!("[Repo][SQL] {}", SOFT_DELETE_BOARD.trim());
+            debug!("[Repo][BIND] id={}, version={}", id, expected_version);
+            let rows_affected = conn
+                .execute_named(SOFT_DELETE_BOARD, &params)?
+                .row_count()?;
             // 트랜잭션 커밋
             conn.commit()?;
 
             if rows_affected == 0 {
-                warn!("[Repo] 수정할 게시글 없음: id={}", id);
+                warn!(
+                    "[Repo] 삭제할 게시글 없음 또는 버전 불일치: id={}, version={}",
+                    id, expected_version
+                );
             }
 
             Ok(rows_affected > 0)
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 작성자의 휴지통에 있는 게시글 수 조회
+    async fn count_trash(&self, author_id: i64) -> Result<u32, StoreError> {
+        info!("[Repo] count_trash 호출: author_id={}", author_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("author_id", &author_id)];
+            debug!("[Repo][SQL] {}", SELECT_TRASH_COUNT.trim());
+            debug!("[Repo][BIND] author_id={}", author_id);
+            conn.query_row_as_named::<u32>(SELECT_TRASH_COUNT, &params)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 작성자의 휴지통 목록 조회
+    async fn find_trash(
+        &self,
+        author_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TrashedBoard>, StoreError> {
+        info!(
+            "[Repo] find_trash 호출: author_id={}, offset={}, limit={}",
+            author_id, offset, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let start_row = i64::from(offset);
+            let end_row = i64::from(offset.saturating_add(limit));
+            let params: [(&str, &dyn ToSql); 3] = [
+                ("author_id", &author_id),
+                ("start_row", &start_row),
+                ("end_row", &end_row),
+            ];
+            debug!("[Repo][SQL] {}", SELECT_TRASH_PAGED.trim());
+            debug!(
+                "[Repo][BIND] author_id={}, start_row={}, end_row={}",
+                author_id, start_row, end_row
+            );
+            let rows = conn.query_named(SELECT_TRASH_PAGED, &params)?;
+
+            rows.map(|row_result| Self::row_to_trashed_board(row_result?))
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
-    /// 게시글 삭제
-    pub async fn delete(&self, id: i64) -> Result<bool, oracle::Error> {
-        info!("[Repo] delete 호출: id={}", id);
+    /// ID로 휴지통에 있는 게시글 조회
+    async fn find_trashed_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
+        info!("[Repo] find_trashed_by_id 호출: id={}", id);
         let pool = self.pool.clone();
 
-        spawn_blocking(move || {
+        spawn_blocking(move || -> Result<_, oracle::Error> {
             let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
+            debug!("[Repo][SQL] {}", SELECT_TRASHED_BOARD_BY_ID.trim());
+            debug!("[Repo][BIND] id={}", id);
+            let mut rows = conn.query_named(SELECT_TRASHED_BOARD_BY_ID, &params)?;
+            let board = rows
+                .next()
+                .map(|row_result| Self::row_to_board(row_result?))
+                .transpose()?;
+            Self::with_tags(&conn, board)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
 
+    /// 휴지통의 게시글 복원
+    async fn restore(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo] restore 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
             let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
-            debug!("[Repo][SQL] {}", DELETE_BOARD.trim());
+            debug!("[Repo][SQL] {}", RESTORE_BOARD.trim());
             debug!("[Repo][BIND] id={}", id);
-            let rows_affected = conn.execute_named(DELETE_BOARD, &params)?.row_count()?;
+            let rows_affected = conn.execute_named(RESTORE_BOARD, &params)?.row_count()?;
             // 트랜잭션 커밋
             conn.commit()?;
 
             if rows_affected == 0 {
-                warn!("[Repo] 삭제할 게시글 없음: id={}", id);
+                warn!("[Repo] 복원할 게시글이 휴지통에 없음: id={}", id);
             }
 
             Ok(rows_affected > 0)
         })
         .await
         .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
-    /// DB Row를 Board 구조체로 변환하는 헬퍼 함수.
-    /// `spawn_blocking` 내부에서 사용하기 위해 `&self` 의존성을 제거했습니다.
-    fn row_to_board(row: Row) -> Result<Board, oracle::Error> {
-        Ok(Board {
-            id: row.get("ID")?,
-            title: row
-                .get::<&str, Option<String>>("TITLE")?
-                .unwrap_or_default(),
-            content: row
-                .get::<&str, Option<String>>("CONTENT")?
-                .unwrap_or_default(),
-            created_at: row.get("CREATED_AT")?,
+    /// 게시글의 수정 이력 목록 조회
+    async fn find_revisions(&self, board_id: i64) -> Result<Vec<BoardRevision>, StoreError> {
+        info!("[Repo] find_revisions 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("board_id", &board_id)];
+            debug!("[Repo][SQL] {}", SELECT_BOARD_REVISIONS.trim());
+            debug!("[Repo][BIND] board_id={}", board_id);
+            let rows = conn.query_named(SELECT_BOARD_REVISIONS, &params)?;
+
+            rows.map(|row_result| Self::row_to_revision(row_result?))
+                .collect()
         })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 
-    /// DB Row를 `BoardListItem` 구조체로 변환하는 헬퍼 함수.
-    /// `spawn_blocking` 내부에서 사용하기 위해 `&self` 의존성을 제거했습니다.
-    fn row_to_board_list_item(row: Row) -> Result<BoardListItem, oracle::Error> {
-        Ok(BoardListItem {
-            id: row.get("ID")?,
-            title: row
-                .get::<&str, Option<String>>("TITLE")?
-                .unwrap_or_default(),
-            content: row
-                .get::<&str, Option<String>>("CONTENT")?
-                .unwrap_or_default(),
-            created_at: row.get("CREATED_AT")?,
+    /// 게시글의 특정 이력 번호 조회
+    async fn find_revision(
+        &self,
+        board_id: i64,
+        revision: i64,
+    ) -> Result<Option<BoardRevision>, StoreError> {
+        info!(
+            "[Repo] find_revision 호출: board_id={}, revision={}",
+            board_id, revision
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 2] =
+                [("board_id", &board_id), ("revision", &revision)];
+            debug!("[Repo][SQL] {}", SELECT_BOARD_REVISION.trim());
+            debug!("[Repo][BIND] board_id={}, revision={}", board_id, revision);
+            let mut rows = conn.query_named(SELECT_BOARD_REVISION, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_revision(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 보존 기간이 지난 휴지통 게시글 영구 삭제
+    async fn purge_expired(&self, retention_days: u32) -> Result<Vec<i64>, StoreError> {
+        info!(
+            "[Repo] purge_expired 호출: retention_days={}",
+            retention_days
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let retention_days = i64::from(retention_days);
+            let params: [(&str, &dyn ToSql); 1] = [("retention_days", &retention_days)];
+            debug!("[Repo][SQL] {}", SELECT_EXPIRED_TRASH_IDS.trim());
+            debug!("[Repo][BIND] retention_days={}", retention_days);
+            let ids = conn
+                .query_named(SELECT_EXPIRED_TRASH_IDS, &params)?
+                .map(|row_result| row_result?.get::<usize, i64>(0))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // 댓글/첨부파일/이력/태그/좋아요/조회 기록과 게시글 삭제를 한 트랜잭션으로 커밋하고, 실패하면 모두 되돌립니다.
+            let purge = || -> Result<(), oracle::Error> {
+                for id in &ids {
+                    let child_params: [(&str, &dyn ToSql); 1] = [("board_id", id)];
+                    let board_params: [(&str, &dyn ToSql); 1] = [("id", id)];
+                    debug!("[Repo][BIND] board_id={}", id);
+                    conn.execute_named(DELETE_COMMENTS_BY_BOARD, &child_params)?;
+                    conn.execute_named(DELETE_ATTACHMENTS_BY_BOARD, &child_params)?;
+                    conn.execute_named(DELETE_REVISIONS_BY_BOARD, &child_params)?;
+                    conn.execute_named(DELETE_BOARD_TAGS, &child_params)?;
+                    conn.execute_named(DELETE_BOARD_LIKES, &child_params)?;
+                    conn.execute_named(DELETE_BOARD_VIEWS, &child_params)?;
+                    conn.execute_named(DELETE_BOARD, &board_params)?;
+                }
+                Ok(())
+            };
+            if let Err(err) = purge() {
+                conn.rollback()?;
+                return Err(err);
+            }
+            // 트랜잭션 커밋
+            conn.commit()?;
+            debug!("[Repo] 영구 삭제된 게시글: {:?}", ids);
+            Ok(ids)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 태그별 게시글 수 조회
+    async fn find_tag_counts(&self) -> Result<Vec<TagCount>, StoreError> {
+        info!("[Repo] find_tag_counts 호출");
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            debug!("[Repo][SQL] {}", SELECT_TAG_COUNTS.trim());
+            conn.query(SELECT_TAG_COUNTS, &[])?
+                .map(|row_result| {
+                    let row = row_result?;
+                    Ok(TagCount {
+                        name: row.get("NAME")?,
+                        board_count: row.get("BOARD_COUNT")?,
+                    })
+                })
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 모아 둔 조회수를 한 트랜잭션으로 반영
+    async fn add_views(&self, views: Vec<(i64, u64)>) -> Result<(), StoreError> {
+        info!("[Repo] add_views 호출: boards={}", views.len());
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let apply = || -> Result<(), oracle::Error> {
+                debug!("[Repo][SQL] {}", UPDATE_BOARD_VIEW_COUNT.trim());
+                debug!("[Repo][SQL] {}", UPSERT_BOARD_VIEWS_DAILY.trim());
+                for (id, count) in &views {
+                    let count = i64::try_from(*count).unwrap_or(i64::MAX);
+                    debug!("[Repo][BIND] id={}, views={}", id, count);
+                    conn.execute_named(UPDATE_BOARD_VIEW_COUNT, &[("views", &count), ("id", id)])?;
+                    conn.execute_named(
+                        UPSERT_BOARD_VIEWS_DAILY,
+                        &[("board_id", id), ("views", &count)],
+                    )?;
+                }
+                Ok(())
+            };
+            if let Err(err) = apply() {
+                conn.rollback()?;
+                return Err(err);
+            }
+            // 트랜잭션 커밋
+            conn.commit()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 좋아요 기록 (이미 있으면 무시)
+    async fn add_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        info!(
+            "[Repo] add_like 호출: board_id={}, liker={}",
+            board_id, liker
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 2] = [("board_id", &board_id), ("liker", &liker)];
+            debug!("[Repo][SQL] {}", INSERT_BOARD_LIKE.trim());
+            debug!("[Repo][BIND] board_id={}, liker={}", board_id, liker);
+            match conn.execute_named(INSERT_BOARD_LIKE, &params) {
+                Ok(_) => {}
+                // MERGE는 동시에 들어온 같은 좋아요를 막지 못하므로, 먼저 들어간 쪽이 있으면 그대로 둡니다.
+                Err(err) if Self::is_unique_violation(&err) => {
+                    debug!(
+                        "[Repo] 이미 누른 좋아요: board_id={}, liker={}",
+                        board_id, liker
+                    );
+                    return conn.rollback();
+                }
+                Err(err) => return Err(err),
+            }
+            // 트랜잭션 커밋
+            conn.commit()
         })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 좋아요 취소 (없으면 무시)
+    async fn remove_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        info!(
+            "[Repo] remove_like 호출: board_id={}, liker={}",
+            board_id, liker
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 2] = [("board_id", &board_id), ("liker", &liker)];
+            debug!("[Repo][SQL] {}", DELETE_BOARD_LIKE.trim());
+            debug!("[Repo][BIND] board_id={}, liker={}", board_id, liker);
+            conn.execute_named(DELETE_BOARD_LIKE, &params)?;
+            // 트랜잭션 커밋
+            conn.commit()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글의 좋아요 수 조회
+    async fn count_likes(&self, board_id: i64) -> Result<i64, StoreError> {
+        info!("[Repo] count_likes 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("board_id", &board_id)];
+            debug!("[Repo][SQL] {}", SELECT_BOARD_LIKE_COUNT.trim());
+            debug!("[Repo][BIND] board_id={}", board_id);
+            conn.query_row_as_named::<i64>(SELECT_BOARD_LIKE_COUNT, &params)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 기간 안의 조회수/좋아요 점수 순으로 인기 게시글 조회
+    async fn find_popular(
+        &self,
+        days: u32,
+        view_weight: i64,
+        like_weight: i64,
+        limit: u32,
+    ) -> Result<Vec<PopularBoard>, StoreError> {
+        info!(
+            "[Repo] find_popular 호출: days={}, view_weight={}, like_weight={}, limit={}",
+            days, view_weight, like_weight, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let days = i64::from(days);
+            let limit = i64::from(limit);
+            let params: [(&str, &dyn ToSql); 4] = [
+                ("days", &days),
+                ("view_weight", &view_weight),
+                ("like_weight", &like_weight),
+                ("limit", &limit),
+            ];
+            debug!("[Repo][SQL] {}", SELECT_POPULAR_BOARDS.trim());
+            debug!(
+                "[Repo][BIND] days={}, view_weight={}, like_weight={}, limit={}",
+                days, view_weight, like_weight, limit
+            );
+            conn.query_named(SELECT_POPULAR_BOARDS, &params)?
+                .map(|row_result| {
+                    let row = row_result?;
+                    Ok(PopularBoard {
+                        id: row.get("ID")?,
+                        title: row
+                            .get::<&str, Option<String>>("TITLE")?
+                            .unwrap_or_default(),
+                        author_id: row.get("AUTHOR_ID")?,
+                        category_id: row.get("CATEGORY_ID")?,
+                        view_count: row.get("VIEW_COUNT")?,
+                        window_views: row.get("WINDOW_VIEWS")?,
+                        window_likes: row.get("WINDOW_LIKES")?,
+                        score: row.get("SCORE")?,
+                        created_at: row.get("CREATED_AT")?,
+                    })
+                })
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
     }
 }
@@ -0,0 +1,265 @@
+//! 게시판 저장소 추상화: 저장소 백엔드(Oracle, SQLite)에 독립적인 CRUD 계약
+
+use crate::models::board::{
+    Board, BoardCursor, BoardFilter, BoardListItem, BoardSort, BoardUpdate, NewBoard, PopularBoard,
+    SortDirection, SortField, TrashedBoard,
+};
+use crate::models::category::TagCount;
+use crate::models::revision::BoardRevision;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+/// 게시글 저장소가 제공해야 하는 CRUD 연산 집합.
+///
+/// `BoardService`는 이 트레이트에만 의존하므로, 설정(`Config::db_backend`)에 따라
+/// Oracle 구현(`BoardRepository`)과 SQLite 구현(`SqliteBoardRepository`)을 바꿔 끼울 수 있습니다.
+/// 모든 구현체는 에러를 `StoreError`로 매핑하여 서비스 계층의 에러 처리를 단일하게 유지합니다.
+#[async_trait]
+pub trait BoardStore: Send + Sync {
+    /// 검색 조건에 맞는 게시글 수 조회
+    async fn count_all(&self, filter: &BoardFilter) -> Result<u32, StoreError>;
+
+    /// 검색 조건과 페이지네이션을 사용하여 게시글 목록 조회
+    async fn find_paged(
+        &self,
+        filter: &BoardFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BoardListItem>, StoreError>;
+
+    /// 키셋(커서) 방식으로 게시글 목록 조회.
+    /// `(CREATED_AT, ID)` 순서로 `after` 뒤에 오는 행을 최대 `limit`개 반환하며,
+    /// 각 행의 다음 커서 키를 함께 돌려줍니다. 정렬 방향은 `filter.sort.direction`을 따릅니다.
+    async fn find_after(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError>;
+
+    /// 캐시를 거치지 않는 `find_after` (전체 내보내기처럼 한 번 읽고 버리는 대량 조회용)
+    async fn find_after_uncached(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError> {
+        self.find_after(filter, after, limit).await
+    }
+
+    /// ID로 단일 게시글 조회 (휴지통에 있는 게시글은 제외)
+    async fn find_by_id(&self, id: i64) -> Result<Option<Board>, StoreError>;
+
+    /// 새 게시글과 태그를 한 트랜잭션으로 추가한 후 생성된 ID 반환
+    async fn insert(&self, board: NewBoard, author_id: i64) -> Result<i64, StoreError>;
+
+    /// 여러 게시글을 한 트랜잭션으로 추가하고, 생성된 ID를 입력 순서대로 반환합니다.
+    /// 한 건이라도 실패하면 트랜잭션 전체를 롤백합니다.
+    async fn insert_many(
+        &self,
+        boards: Vec<NewBoard>,
+        author_id: i64,
+    ) -> Result<Vec<i64>, StoreError>;
+
+    /// 버전이 `expected_version`일 때만 게시글을 수정하고 버전을 1 올립니다.
+    /// 수정 전 제목/내용은 같은 트랜잭션에서 수정 이력(`BOARD_REVISIONS`)으로 보관합니다.
+    /// 카테고리와 태그를 바꾸는 경우 같은 트랜잭션에서 함께 기록합니다.
+    /// 게시글이 없거나 그 사이 다른 수정으로 버전이 바뀌었으면 `false`이며 트랜잭션은 롤백됩니다.
+    async fn update(
+        &self,
+        id: i64,
+        changes: BoardUpdate,
+        expected_version: i64,
+    ) -> Result<bool, StoreError>;
+
+    /// 버전이 `expected_version`일 때만 게시글을 휴지통으로 옮깁니다 (`DELETED_AT` 기록, 버전 1 증가).
+    /// 댓글/첨부파일은 복원할 수 있도록 그대로 둡니다. 옮긴 게시글이 없으면 `false`입니다.
+    async fn delete(&self, id: i64, expected_version: i64) -> Result<bool, StoreError>;
+
+    /// 작성자의 휴지통에 있는 게시글 수 조회
+    async fn count_trash(&self, author_id: i64) -> Result<u32, StoreError>;
+
+    /// 작성자의 휴지통 목록을 삭제 시각 내림차순으로 조회
+    async fn find_trash(
+        &self,
+        author_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TrashedBoard>, StoreError>;
+
+    /// ID로 휴지통에 있는 게시글 조회
+    async fn find_trashed_by_id(&self, id: i64) -> Result<Option<Board>, StoreError>;
+
+    /// 휴지통의 게시글을 복원합니다 (`DELETED_AT` 해제, 버전 1 증가).
+    /// 휴지통에 없는 게시글이면 `false`입니다.
+    async fn restore(&self, id: i64) -> Result<bool, StoreError>;
+
+    /// 게시글의 수정 이력 목록을 최신 이력부터 조회
+    async fn find_revisions(&self, board_id: i64) -> Result<Vec<BoardRevision>, StoreError>;
+
+    /// 게시글의 특정 이력 번호 조회
+    async fn find_revision(
+        &self,
+        board_id: i64,
+        revision: i64,
+    ) -> Result<Option<BoardRevision>, StoreError>;
+
+    /// 휴지통에 들어간 지 `retention_days`일이 지난 게시글을 댓글/첨부 메타데이터/수정 이력/태그/좋아요/조회 기록과 함께
+    /// 한 트랜잭션으로 영구 삭제하고, 삭제한 게시글 ID 목록을 반환합니다.
+    async fn purge_expired(&self, retention_days: u32) -> Result<Vec<i64>, StoreError>;
+
+    /// 휴지통에 없는 게시글에 붙은 태그와 게시글 수를 많이 쓰인 순서로 조회
+    async fn find_tag_counts(&self) -> Result<Vec<TagCount>, StoreError>;
+
+    /// 모아 둔 `(게시글 ID, 조회수)`를 한 트랜잭션으로 누적 조회수와 오늘 날짜의 일별 조회수에 더합니다.
+    /// 게시글 버전은 바꾸지 않으며, 그 사이 영구 삭제된 게시글은 건너뜁니다.
+    async fn add_views(&self, views: Vec<(i64, u64)>) -> Result<(), StoreError>;
+
+    /// 좋아요를 기록합니다. 같은 `liker`가 이미 눌렀다면 아무것도 바꾸지 않습니다.
+    async fn add_like(&self, board_id: i64, liker: String) -> Result<(), StoreError>;
+
+    /// 좋아요를 취소합니다. 누른 적이 없으면 아무것도 바꾸지 않습니다.
+    async fn remove_like(&self, board_id: i64, liker: String) -> Result<(), StoreError>;
+
+    /// 게시글의 좋아요 수 조회
+    async fn count_likes(&self, board_id: i64) -> Result<i64, StoreError>;
+
+    /// 최근 `days`일 동안의 조회수와 좋아요에 가중치를 곱한 점수 순으로
+    /// 휴지통에 없는 게시글을 `limit`개까지 조회
+    async fn find_popular(
+        &self,
+        days: u32,
+        view_weight: i64,
+        like_weight: i64,
+        limit: u32,
+    ) -> Result<Vec<PopularBoard>, StoreError>;
+}
+
+/// 검색 조건을 SQL 조각과 바인드 값으로 변환한 결과 (Oracle/SQLite 공용).
+///
+/// 조건절에는 고정된 SQL 조각만 들어가고, 사용자 입력은 모두 바인드 변수
+/// (`:keyword`, `:title`, `:from_date`, `:to_date`, `:category_id`, `:tag`,
+/// `:cursor_created_at`, `:cursor_id`)로
+/// 전달됩니다.
+#[derive(Debug, Clone)]
+pub struct SearchSql {
+    conditions: Vec<&'static str>,
+    order_by: String,
+    /// `:keyword` 바인드 값 (`LIKE` 패턴)
+    pub keyword: Option<String>,
+    /// `:title` 바인드 값 (`LIKE` 패턴)
+    pub title: Option<String>,
+    /// `:from_date` 바인드 값 (포함)
+    pub from_date: Option<NaiveDate>,
+    /// `:to_date` 바인드 값 (상한 다음 날, 미포함)
+    pub to_date: Option<NaiveDate>,
+    /// `:category_id` 바인드 값
+    pub category_id: Option<i64>,
+    /// `:tag` 바인드 값 (정규화된 태그 이름)
+    pub tag: Option<String>,
+    /// `:cursor_created_at`, `:cursor_id` 바인드 값 (키셋 페이지네이션)
+    pub cursor: Option<BoardCursor>,
+}
+
+impl SearchSql {
+    /// 검증된 검색 조건으로부터 WHERE/ORDER BY 절과 바인드 값을 만듭니다.
+    pub fn new(filter: &BoardFilter) -> Self {
+        // 휴지통에 있는 게시글은 목록/개수에서 항상 제외합니다.
+        let mut conditions = vec!["b.DELETED_AT IS NULL"];
+        if filter.keyword.is_some() {
+            conditions.push(
+                "(UPPER(b.TITLE) LIKE UPPER(:keyword) ESCAPE '\\' \
+                 OR UPPER(b.CONTENT) LIKE UPPER(:keyword) ESCAPE '\\')",
+            );
+        }
+        if filter.title.is_some() {
+            conditions.push("UPPER(b.TITLE) LIKE UPPER(:title) ESCAPE '\\'");
+        }
+        if filter.from.is_some() {
+            conditions.push("b.CREATED_AT >= :from_date");
+        }
+        if filter.to.is_some() {
+            conditions.push("b.CREATED_AT < :to_date");
+        }
+        if filter.category_id.is_some() {
+            conditions.push("b.CATEGORY_ID = :category_id");
+        }
+        if filter.tag.is_some() {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM BOARD_TAGS bt JOIN TAGS t ON t.ID = bt.TAG_ID \
+                 WHERE bt.BOARD_ID = b.ID AND t.NAME = :tag)",
+            );
+        }
+
+        Self {
+            conditions,
+            order_by: Self::order_by_clause(&filter.sort),
+            keyword: filter.keyword.as_deref().map(Self::like_pattern),
+            title: filter.title.as_deref().map(Self::like_pattern),
+            from_date: filter.from,
+            to_date: filter.to.map(|to| to.succ_opt().unwrap_or(to)),
+            category_id: filter.category_id,
+            tag: filter.tag.clone(),
+            cursor: None,
+        }
+    }
+
+    /// 키셋 조건을 추가합니다: 정렬 방향 기준으로 `(CREATED_AT, ID)`가 커서 뒤에 오는 행만 조회합니다.
+    pub fn after(mut self, cursor: BoardCursor, direction: SortDirection) -> Self {
+        self.conditions.push(match direction {
+            SortDirection::Desc => {
+                "(b.CREATED_AT < :cursor_created_at \
+                 OR (b.CREATED_AT = :cursor_created_at AND b.ID < :cursor_id))"
+            }
+            SortDirection::Asc => {
+                "(b.CREATED_AT > :cursor_created_at \
+                 OR (b.CREATED_AT = :cursor_created_at AND b.ID > :cursor_id))"
+            }
+        });
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// SQL 템플릿의 `{where}`, `{order_by}` 자리표시자를 치환합니다.
+    pub fn render(&self, template: &str) -> String {
+        let where_clause = if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", self.conditions.join(" AND "))
+        };
+        template
+            .replace("{where}", &where_clause)
+            .replace("{order_by}", &self.order_by)
+    }
+
+    /// 태그 조회 SQL의 `{ids}` 자리표시자를 게시글 수만큼의 위치 바인드 변수로 치환합니다.
+    /// `placeholder`는 1부터 시작하는 위치를 받아 바인드 변수 표기를 만듭니다 (Oracle `:1`, SQLite `?1`).
+    pub fn render_ids(template: &str, count: usize, placeholder: fn(usize) -> String) -> String {
+        let ids = (1..=count).map(placeholder).collect::<Vec<_>>().join(", ");
+        template.replace("{ids}", &ids)
+    }
+
+    /// 정렬 기준을 `ORDER BY` 절의 본문으로 변환합니다.
+    /// ID 이외의 컬럼으로 정렬할 때는 페이지 경계가 흔들리지 않도록 ID를 보조 키로 덧붙입니다.
+    fn order_by_clause(sort: &BoardSort) -> String {
+        let direction = match sort.direction {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        };
+        match sort.field {
+            SortField::Id => format!("b.ID {}", direction),
+            SortField::CreatedAt => format!("b.CREATED_AT {0}, b.ID {0}", direction),
+            SortField::Title => format!("b.TITLE {0}, b.ID {0}", direction),
+        }
+    }
+
+    /// 검색어를 `LIKE` 부분 일치 패턴으로 변환합니다 (`%`, `_`, `\` 이스케이프).
+    fn like_pattern(term: &str) -> String {
+        let escaped = term
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("%{}%", escaped)
+    }
+}
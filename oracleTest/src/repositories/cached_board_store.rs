@@ -0,0 +1,320 @@
+//! 게시글 조회 캐시: `BoardStore`를 감싸 상세/목록/개수 조회 결과를 메모리에 보관하는 저장소
+//!
+//! 서비스 계층은 그대로 `BoardStore`만 보므로, 설정(`Config::board_cache_enabled`)에 따라 끼우거나 뺄 수 있습니다.
+//! 이 저장소를 거치는 쓰기는 바뀐 게시글의 상세 항목과 (목록이 달라지는 경우) 목록/개수 항목을 바로 버립니다.
+//! 다른 인스턴스나 외부에서 바꾼 내용은 TTL이 지나야 반영됩니다.
+
+use crate::common::cache::{CacheStats, ReadThroughCache};
+use crate::models::board::{
+    Board, BoardCursor, BoardFilter, BoardListItem, BoardUpdate, NewBoard, PopularBoard,
+    TrashedBoard,
+};
+use crate::models::category::{Category, TagCount};
+use crate::models::revision::BoardRevision;
+use crate::repositories::board_store::BoardStore;
+use crate::repositories::category_store::CategoryStore;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+/// 키셋 목록 캐시 키: 검색 조건, 시작 커서(인코딩한 값), 개수
+type KeysetKey = (BoardFilter, Option<String>, u32);
+
+/// 게시글 조회 캐시 묶음 (상세, 개수, 페이지 목록, 키셋 목록)
+pub struct BoardCache {
+    boards: ReadThroughCache<i64, Option<Board>>,
+    counts: ReadThroughCache<BoardFilter, u32>,
+    pages: ReadThroughCache<(BoardFilter, u32, u32), Vec<BoardListItem>>,
+    keyset: ReadThroughCache<KeysetKey, Vec<(BoardListItem, BoardCursor)>>,
+}
+
+impl BoardCache {
+    /// 종류별로 최대 `capacity`개의 항목을 `ttl` 동안 보관합니다.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            boards: ReadThroughCache::new("board_detail", capacity, ttl),
+            counts: ReadThroughCache::new("board_count", capacity, ttl),
+            pages: ReadThroughCache::new("board_page", capacity, ttl),
+            keyset: ReadThroughCache::new("board_keyset", capacity, ttl),
+        }
+    }
+
+    /// 게시글 하나의 상세 항목을 버립니다.
+    pub fn invalidate_board(&self, id: i64) {
+        debug!("[Cache] 게시글 상세 무효화: id={}", id);
+        self.boards.invalidate(&id);
+    }
+
+    /// 목록과 개수 항목을 모두 버립니다. (어느 페이지가 달라졌는지 알 수 없으므로 통째로)
+    pub fn invalidate_lists(&self) {
+        debug!("[Cache] 게시글 목록/개수 무효화");
+        self.counts.clear();
+        self.pages.clear();
+        self.keyset.clear();
+    }
+
+    /// 모든 항목을 버립니다.
+    pub fn clear(&self) {
+        debug!("[Cache] 게시글 캐시 전체 무효화");
+        self.boards.clear();
+        self.invalidate_lists();
+    }
+
+    /// 상세 캐시의 적중/실패 수
+    pub fn detail_stats(&self) -> CacheStats {
+        self.boards.stats()
+    }
+
+    /// 목록/개수 캐시의 적중/실패 수 합계
+    pub fn list_stats(&self) -> CacheStats {
+        self.counts.stats() + self.pages.stats() + self.keyset.stats()
+    }
+
+    /// 게시글 하나가 바뀌어 상세와 목록이 모두 달라질 때
+    fn invalidate_listed(&self, id: i64) {
+        self.invalidate_board(id);
+        self.invalidate_lists();
+    }
+}
+
+/// 조회 결과를 캐시하는 `BoardStore` 래퍼
+pub struct CachedBoardStore {
+    inner: Arc<dyn BoardStore>,
+    cache: Arc<BoardCache>,
+}
+
+impl CachedBoardStore {
+    pub fn new(inner: Arc<dyn BoardStore>, cache: Arc<BoardCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl BoardStore for CachedBoardStore {
+    async fn count_all(&self, filter: &BoardFilter) -> Result<u32, StoreError> {
+        self.cache
+            .counts
+            .get_or_load(filter.clone(), || self.inner.count_all(filter))
+            .await
+    }
+
+    async fn find_paged(
+        &self,
+        filter: &BoardFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BoardListItem>, StoreError> {
+        self.cache
+            .pages
+            .get_or_load((filter.clone(), offset, limit), || {
+                self.inner.find_paged(filter, offset, limit)
+            })
+            .await
+    }
+
+    async fn find_after(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError> {
+        let key = (
+            filter.clone(),
+            after.as_ref().map(BoardCursor::encode),
+            limit,
+        );
+        self.cache
+            .keyset
+            .get_or_load(key, || self.inner.find_after(filter, after, limit))
+            .await
+    }
+
+    async fn find_after_uncached(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError> {
+        self.inner.find_after(filter, after, limit).await
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
+        self.cache
+            .boards
+            .get_or_load(id, || self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn insert(&self, board: NewBoard, author_id: i64) -> Result<i64, StoreError> {
+        let id = self.inner.insert(board, author_id).await?;
+        self.cache.invalidate_listed(id);
+        Ok(id)
+    }
+
+    async fn insert_many(
+        &self,
+        boards: Vec<NewBoard>,
+        author_id: i64,
+    ) -> Result<Vec<i64>, StoreError> {
+        let ids = self.inner.insert_many(boards, author_id).await?;
+        for id in &ids {
+            self.cache.invalidate_board(*id);
+        }
+        self.cache.invalidate_lists();
+        Ok(ids)
+    }
+
+    async fn update(
+        &self,
+        id: i64,
+        changes: BoardUpdate,
+        expected_version: i64,
+    ) -> Result<bool, StoreError> {
+        let result = self.inner.update(id, changes, expected_version).await;
+        // 버전이 맞지 않아 실패했다면 캐시가 다른 인스턴스의 수정보다 오래된 것이므로 함께 버립니다.
+        self.cache.invalidate_listed(id);
+        result
+    }
+
+    async fn delete(&self, id: i64, expected_version: i64) -> Result<bool, StoreError> {
+        let result = self.inner.delete(id, expected_version).await;
+        self.cache.invalidate_listed(id);
+        result
+    }
+
+    async fn count_trash(&self, author_id: i64) -> Result<u32, StoreError> {
+        self.inner.count_trash(author_id).await
+    }
+
+    async fn find_trash(
+        &self,
+        author_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TrashedBoard>, StoreError> {
+        self.inner.find_trash(author_id, offset, limit).await
+    }
+
+    async fn find_trashed_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
+        self.inner.find_trashed_by_id(id).await
+    }
+
+    async fn restore(&self, id: i64) -> Result<bool, StoreError> {
+        let result = self.inner.restore(id).await;
+        self.cache.invalidate_listed(id);
+        result
+    }
+
+    async fn find_revisions(&self, board_id: i64) -> Result<Vec<BoardRevision>, StoreError> {
+        self.inner.find_revisions(board_id).await
+    }
+
+    async fn find_revision(
+        &self,
+        board_id: i64,
+        revision: i64,
+    ) -> Result<Option<BoardRevision>, StoreError> {
+        self.inner.find_revision(board_id, revision).await
+    }
+
+    async fn purge_expired(&self, retention_days: u32) -> Result<Vec<i64>, StoreError> {
+        // 휴지통의 게시글은 상세/목록에 나오지 않으므로 목록은 그대로 둡니다.
+        let ids = self.inner.purge_expired(retention_days).await?;
+        for id in &ids {
+            self.cache.invalidate_board(*id);
+        }
+        Ok(ids)
+    }
+
+    async fn find_tag_counts(&self) -> Result<Vec<TagCount>, StoreError> {
+        self.inner.find_tag_counts().await
+    }
+
+    async fn add_views(&self, views: Vec<(i64, u64)>) -> Result<(), StoreError> {
+        // 목록에는 조회수가 없으므로 상세 항목만 버립니다.
+        let ids: Vec<i64> = views.iter().map(|(id, _)| *id).collect();
+        let result = self.inner.add_views(views).await;
+        for id in ids {
+            self.cache.invalidate_board(id);
+        }
+        result
+    }
+
+    async fn add_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        let result = self.inner.add_like(board_id, liker).await;
+        self.cache.invalidate_board(board_id);
+        result
+    }
+
+    async fn remove_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        let result = self.inner.remove_like(board_id, liker).await;
+        self.cache.invalidate_board(board_id);
+        result
+    }
+
+    async fn count_likes(&self, board_id: i64) -> Result<i64, StoreError> {
+        self.inner.count_likes(board_id).await
+    }
+
+    async fn find_popular(
+        &self,
+        days: u32,
+        view_weight: i64,
+        like_weight: i64,
+        limit: u32,
+    ) -> Result<Vec<PopularBoard>, StoreError> {
+        self.inner
+            .find_popular(days, view_weight, like_weight, limit)
+            .await
+    }
+}
+
+/// 카테고리 삭제가 게시글의 카테고리를 비우므로, 삭제할 때 게시글 캐시를 함께 버리는 `CategoryStore` 래퍼
+pub struct CacheInvalidatingCategoryStore {
+    inner: Arc<dyn CategoryStore>,
+    cache: Arc<BoardCache>,
+}
+
+impl CacheInvalidatingCategoryStore {
+    pub fn new(inner: Arc<dyn CategoryStore>, cache: Arc<BoardCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl CategoryStore for CacheInvalidatingCategoryStore {
+    async fn find_all(&self) -> Result<Vec<Category>, StoreError> {
+        self.inner.find_all().await
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Category>, StoreError> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_name(&self, name: String) -> Result<Option<Category>, StoreError> {
+        self.inner.find_by_name(name).await
+    }
+
+    async fn insert(&self, name: String, description: Option<String>) -> Result<i64, StoreError> {
+        self.inner.insert(name, description).await
+    }
+
+    async fn update(
+        &self,
+        id: i64,
+        name: String,
+        description: Option<String>,
+    ) -> Result<bool, StoreError> {
+        self.inner.update(id, name, description).await
+    }
+
+    async fn delete(&self, id: i64) -> Result<bool, StoreError> {
+        let deleted = self.inner.delete(id).await?;
+        if deleted {
+            self.cache.clear();
+        }
+        Ok(deleted)
+    }
+}
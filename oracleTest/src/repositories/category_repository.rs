@@ -0,0 +1,202 @@
+//! Repository 계층: 카테고리 테이블 작업 (Oracle)
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::{
+    CLEAR_BOARD_CATEGORY, DELETE_CATEGORY, INSERT_CATEGORY, SELECT_CATEGORIES,
+    SELECT_CATEGORY_BY_ID, SELECT_CATEGORY_BY_NAME, SELECT_CATEGORY_SEQ_CURRVAL, UPDATE_CATEGORY,
+};
+use crate::models::category::Category;
+use crate::repositories::category_store::CategoryStore;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use oracle::Row;
+use oracle::sql_type::ToSql;
+use r2d2::Pool;
+use r2d2_oracle::OracleConnectionManager;
+use tracing::{debug, info, warn};
+
+/// 카테고리 데이터베이스 접근 객체 (DAO).
+pub struct CategoryRepository {
+    pool: Pool<OracleConnectionManager>,
+}
+
+impl CategoryRepository {
+    /// `r2d2::Error`를 `oracle::Error`로 매핑하는 헬퍼 함수
+    fn map_pool_err(err: r2d2::Error) -> oracle::Error {
+        oracle::Error::InternalError(err.to_string())
+    }
+
+    /// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+    fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+        StoreError::from(err)
+    }
+
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<OracleConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Category 구조체로 변환하는 헬퍼 함수.
+    fn row_to_category(row: Row) -> Result<Category, oracle::Error> {
+        Ok(Category {
+            id: row.get("ID")?,
+            name: row.get("NAME")?,
+            description: row.get("DESCRIPTION")?,
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl CategoryStore for CategoryRepository {
+    /// 전체 카테고리 조회
+    async fn find_all(&self) -> Result<Vec<Category>, StoreError> {
+        info!("[Repo] category find_all 호출");
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            debug!("[Repo][SQL] {}", SELECT_CATEGORIES.trim());
+            let rows = conn.query(SELECT_CATEGORIES, &[])?;
+            rows.map(|row_result| Self::row_to_category(row_result?))
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// ID로 단일 카테고리 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Category>, StoreError> {
+        info!("[Repo] category find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
+            debug!("[Repo][SQL] {}", SELECT_CATEGORY_BY_ID.trim());
+            debug!("[Repo][BIND] id={}", id);
+            let mut rows = conn.query_named(SELECT_CATEGORY_BY_ID, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_category(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 이름으로 단일 카테고리 조회
+    async fn find_by_name(&self, name: String) -> Result<Option<Category>, StoreError> {
+        info!("[Repo] category find_by_name 호출: name={}", name);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("name", &name)];
+            debug!("[Repo][SQL] {}", SELECT_CATEGORY_BY_NAME.trim());
+            debug!("[Repo][BIND] name={}", name);
+            let mut rows = conn.query_named(SELECT_CATEGORY_BY_NAME, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_category(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 새 카테고리 추가 후 생성된 ID 반환
+    async fn insert(&self, name: String, description: Option<String>) -> Result<i64, StoreError> {
+        info!("[Repo] category insert 호출: name={}", name);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 2] = [("name", &name), ("description", &description)];
+            debug!("[Repo][SQL] {}", INSERT_CATEGORY.trim());
+            debug!("[Repo][BIND] name={}, description={:?}", name, description);
+            conn.execute_named(INSERT_CATEGORY, &params)?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            debug!("[Repo][SQL] {}", SELECT_CATEGORY_SEQ_CURRVAL.trim());
+            conn.query_row_as::<i64>(SELECT_CATEGORY_SEQ_CURRVAL, &[])
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 카테고리 이름/설명 수정
+    async fn update(
+        &self,
+        id: i64,
+        name: String,
+        description: Option<String>,
+    ) -> Result<bool, StoreError> {
+        info!("[Repo] category update 호출: id={}, name={}", id, name);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 3] =
+                [("name", &name), ("description", &description), ("id", &id)];
+            debug!("[Repo][SQL] {}", UPDATE_CATEGORY.trim());
+            debug!(
+                "[Repo][BIND] id={}, name={}, description={:?}",
+                id, name, description
+            );
+            let rows_affected = conn.execute_named(UPDATE_CATEGORY, &params)?.row_count()?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            if rows_affected == 0 {
+                warn!("[Repo] 수정할 카테고리 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글의 카테고리 지정을 해제한 뒤 카테고리 삭제 (한 트랜잭션)
+    async fn delete(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo] category delete 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let delete = || -> Result<u64, oracle::Error> {
+                debug!("[Repo][SQL] {}", CLEAR_BOARD_CATEGORY.trim());
+                debug!("[Repo][BIND] category_id={}", id);
+                conn.execute_named(CLEAR_BOARD_CATEGORY, &[("category_id", &id)])?;
+                debug!("[Repo][SQL] {}", DELETE_CATEGORY.trim());
+                debug!("[Repo][BIND] id={}", id);
+                conn.execute_named(DELETE_CATEGORY, &[("id", &id)])?
+                    .row_count()
+            };
+
+            let rows_affected = match delete() {
+                Ok(rows_affected) => rows_affected,
+                Err(err) => {
+                    conn.rollback()?;
+                    return Err(err);
+                }
+            };
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            if rows_affected == 0 {
+                warn!("[Repo] 삭제할 카테고리 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+}
@@ -0,0 +1,33 @@
+//! 카테고리 저장소 추상화: 저장소 백엔드(Oracle, SQLite)에 독립적인 계약
+
+use crate::models::category::Category;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+
+/// 카테고리 저장소가 제공해야 하는 연산 집합.
+#[async_trait]
+pub trait CategoryStore: Send + Sync {
+    /// 전체 카테고리를 이름 순으로 조회
+    async fn find_all(&self) -> Result<Vec<Category>, StoreError>;
+
+    /// ID로 단일 카테고리 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Category>, StoreError>;
+
+    /// 이름으로 단일 카테고리 조회 (중복 확인용)
+    async fn find_by_name(&self, name: String) -> Result<Option<Category>, StoreError>;
+
+    /// 새 카테고리 추가 후 생성된 ID 반환
+    async fn insert(&self, name: String, description: Option<String>) -> Result<i64, StoreError>;
+
+    /// 카테고리 이름/설명 수정, 대상이 없으면 `false`
+    async fn update(
+        &self,
+        id: i64,
+        name: String,
+        description: Option<String>,
+    ) -> Result<bool, StoreError>;
+
+    /// 카테고리 삭제, 대상이 없으면 `false`.
+    /// 이 카테고리가 지정된 게시글은 한 트랜잭션 안에서 카테고리 없음으로 바꿉니다.
+    async fn delete(&self, id: i64) -> Result<bool, StoreError>;
+}
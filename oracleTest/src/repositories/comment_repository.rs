@@ -0,0 +1,259 @@
+//! Repository 계층: 댓글 테이블 작업 (Oracle)
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::{
+    DELETE_COMMENT, INSERT_COMMENT, SELECT_COMMENT_BY_ID, SELECT_COMMENT_REPLIES,
+    SELECT_COMMENT_ROOT_COUNT, SELECT_COMMENT_ROOTS_PAGED, SELECT_COMMENT_SEQ_CURRVAL,
+    UPDATE_COMMENT,
+};
+use crate::models::comment::Comment;
+use crate::repositories::comment_store::CommentStore;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use oracle::Row;
+use oracle::sql_type::ToSql;
+use r2d2::Pool;
+use r2d2_oracle::OracleConnectionManager;
+use tracing::{debug, info, warn};
+
+/// 댓글 데이터베이스 접근 객체 (DAO).
+pub struct CommentRepository {
+    pool: Pool<OracleConnectionManager>,
+}
+
+impl CommentRepository {
+    /// `r2d2::Error`를 `oracle::Error`로 매핑하는 헬퍼 함수
+    fn map_pool_err(err: r2d2::Error) -> oracle::Error {
+        oracle::Error::InternalError(err.to_string())
+    }
+
+    /// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+    fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+        StoreError::from(err)
+    }
+
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<OracleConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Comment 구조체로 변환하는 헬퍼 함수.
+    fn row_to_comment(row: Row) -> Result<Comment, oracle::Error> {
+        Ok(Comment {
+            id: row.get("ID")?,
+            board_id: row.get("BOARD_ID")?,
+            parent_id: row.get("PARENT_ID")?,
+            author_id: row.get("AUTHOR_ID")?,
+            content: row
+                .get::<&str, Option<String>>("CONTENT")?
+                .unwrap_or_default(),
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl CommentStore for CommentRepository {
+    /// 게시글의 루트 댓글 수 조회
+    async fn count_roots(&self, board_id: i64) -> Result<u32, StoreError> {
+        info!("[Repo] count_roots 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("board_id", &board_id)];
+            debug!("[Repo][SQL] {}", SELECT_COMMENT_ROOT_COUNT.trim());
+            debug!("[Repo][BIND] board_id={}", board_id);
+            conn.query_row_as_named::<u32>(SELECT_COMMENT_ROOT_COUNT, &params)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 게시글의 루트 댓글을 작성 순으로 페이지네이션 조회
+    async fn find_roots_paged(
+        &self,
+        board_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Comment>, StoreError> {
+        info!(
+            "[Repo] find_roots_paged 호출: board_id={}, offset={}, limit={}",
+            board_id, offset, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let start_row = i64::from(offset);
+            let end_row = i64::from(offset.saturating_add(limit));
+            let params: [(&str, &dyn ToSql); 3] = [
+                ("board_id", &board_id),
+                ("start_row", &start_row),
+                ("end_row", &end_row),
+            ];
+            debug!("[Repo][SQL] {}", SELECT_COMMENT_ROOTS_PAGED.trim());
+            debug!(
+                "[Repo][BIND] board_id={}, start_row={}, end_row={}",
+                board_id, start_row, end_row
+            );
+            let rows = conn.query_named(SELECT_COMMENT_ROOTS_PAGED, &params)?;
+            rows.map(|row_result| Self::row_to_comment(row_result?))
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 루트 댓글 ID 범위에 달린 답글 조회
+    async fn find_replies(
+        &self,
+        board_id: i64,
+        first_parent_id: i64,
+        last_parent_id: i64,
+    ) -> Result<Vec<Comment>, StoreError> {
+        info!(
+            "[Repo] find_replies 호출: board_id={}, parent_id={}..={}",
+            board_id, first_parent_id, last_parent_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 3] = [
+                ("board_id", &board_id),
+                ("first_parent_id", &first_parent_id),
+                ("last_parent_id", &last_parent_id),
+            ];
+            debug!("[Repo][SQL] {}", SELECT_COMMENT_REPLIES.trim());
+            debug!(
+                "[Repo][BIND] board_id={}, first_parent_id={}, last_parent_id={}",
+                board_id, first_parent_id, last_parent_id
+            );
+            let rows = conn.query_named(SELECT_COMMENT_REPLIES, &params)?;
+            rows.map(|row_result| Self::row_to_comment(row_result?))
+                .collect()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// ID로 단일 댓글 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Comment>, StoreError> {
+        info!("[Repo] comment find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
+            debug!("[Repo][SQL] {}", SELECT_COMMENT_BY_ID.trim());
+            debug!("[Repo][BIND] id={}", id);
+            let mut rows = conn.query_named(SELECT_COMMENT_BY_ID, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_comment(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 새 댓글 추가 후 생성된 ID 반환
+    async fn insert(
+        &self,
+        board_id: i64,
+        parent_id: Option<i64>,
+        author_id: i64,
+        content: String,
+    ) -> Result<i64, StoreError> {
+        info!(
+            "[Repo] comment insert 호출: board_id={}, parent_id={:?}",
+            board_id, parent_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 4] = [
+                ("board_id", &board_id),
+                ("parent_id", &parent_id),
+                ("author_id", &author_id),
+                ("content", &content),
+            ];
+            debug!("[Repo][SQL] {}", INSERT_COMMENT.trim());
+            debug!(
+                "[Repo][BIND] board_id={}, parent_id={:?}, author_id={}, content_len={}",
+                board_id,
+                parent_id,
+                author_id,
+                content.chars().count()
+            );
+            conn.execute_named(INSERT_COMMENT, &params)?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            debug!("[Repo][SQL] {}", SELECT_COMMENT_SEQ_CURRVAL.trim());
+            conn.query_row_as::<i64>(SELECT_COMMENT_SEQ_CURRVAL, &[])
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 댓글 내용 수정
+    async fn update(&self, id: i64, content: String) -> Result<bool, StoreError> {
+        info!("[Repo] comment update 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 2] = [("content", &content), ("id", &id)];
+            debug!("[Repo][SQL] {}", UPDATE_COMMENT.trim());
+            debug!(
+                "[Repo][BIND] id={}, content_len={}",
+                id,
+                content.chars().count()
+            );
+            let rows_affected = conn.execute_named(UPDATE_COMMENT, &params)?.row_count()?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            if rows_affected == 0 {
+                warn!("[Repo] 수정할 댓글 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 댓글과 그 답글 삭제
+    async fn delete(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo] comment delete 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("id", &id)];
+            debug!("[Repo][SQL] {}", DELETE_COMMENT.trim());
+            debug!("[Repo][BIND] id={}", id);
+            let rows_affected = conn.execute_named(DELETE_COMMENT, &params)?.row_count()?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            if rows_affected == 0 {
+                warn!("[Repo] 삭제할 댓글 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+}
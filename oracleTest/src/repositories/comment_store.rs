@@ -0,0 +1,47 @@
+//! 댓글 저장소 추상화: 저장소 백엔드(Oracle, SQLite)에 독립적인 계약
+
+use crate::models::comment::Comment;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+
+/// 댓글 저장소가 제공해야 하는 연산 집합.
+#[async_trait]
+pub trait CommentStore: Send + Sync {
+    /// 게시글의 루트 댓글 수 조회
+    async fn count_roots(&self, board_id: i64) -> Result<u32, StoreError>;
+
+    /// 게시글의 루트 댓글을 작성 순으로 페이지네이션 조회
+    async fn find_roots_paged(
+        &self,
+        board_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Comment>, StoreError>;
+
+    /// `first_parent_id..=last_parent_id` 범위의 루트 댓글에 달린 답글 조회.
+    /// 루트 댓글은 ID 순으로 페이지가 나뉘므로 한 페이지의 답글을 한 번에 가져올 수 있습니다.
+    async fn find_replies(
+        &self,
+        board_id: i64,
+        first_parent_id: i64,
+        last_parent_id: i64,
+    ) -> Result<Vec<Comment>, StoreError>;
+
+    /// ID로 단일 댓글 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Comment>, StoreError>;
+
+    /// 새 댓글 추가 후 생성된 ID 반환
+    async fn insert(
+        &self,
+        board_id: i64,
+        parent_id: Option<i64>,
+        author_id: i64,
+        content: String,
+    ) -> Result<i64, StoreError>;
+
+    /// 댓글 내용 수정, 대상이 없으면 `false`
+    async fn update(&self, id: i64, content: String) -> Result<bool, StoreError>;
+
+    /// 댓글과 그 답글 삭제, 대상이 없으면 `false`
+    async fn delete(&self, id: i64) -> Result<bool, StoreError>;
+}
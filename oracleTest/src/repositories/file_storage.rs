@@ -0,0 +1,72 @@
+//! 첨부파일 본문을 로컬 디렉터리에 저장하는 파일 저장소
+//!
+//! 파일은 `{root}/{board_id}/{uuid}` 경로에 저장되므로 게시글 단위로 한 번에 삭제할 수 있습니다.
+
+use std::io;
+use std::path::PathBuf;
+use tokio::fs::{self, File, OpenOptions};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// 로컬 디렉터리 기반 파일 저장소
+#[derive(Debug, Clone)]
+pub struct LocalFileStorage {
+    root: PathBuf,
+}
+
+impl LocalFileStorage {
+    /// 저장 디렉터리를 지정하여 생성합니다. 디렉터리는 첫 업로드 시 만들어집니다.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// 저장 키에 해당하는 실제 파일 경로
+    fn path(&self, storage_key: &str) -> PathBuf {
+        self.root.join(storage_key)
+    }
+
+    /// 게시글 디렉터리 경로
+    fn board_dir(&self, board_id: i64) -> PathBuf {
+        self.root.join(board_id.to_string())
+    }
+
+    /// 게시글 디렉터리에 새 파일을 만들고 `(저장 키, 쓰기용 파일)`을 반환합니다.
+    pub async fn create(&self, board_id: i64) -> io::Result<(String, File)> {
+        fs::create_dir_all(self.board_dir(board_id)).await?;
+        let storage_key = format!("{}/{}", board_id, Uuid::new_v4());
+        debug!("[Storage] 파일 생성: {}", storage_key);
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(self.path(&storage_key))
+            .await?;
+        Ok((storage_key, file))
+    }
+
+    /// 저장된 파일을 읽기용으로 엽니다.
+    pub async fn open(&self, storage_key: &str) -> io::Result<File> {
+        File::open(self.path(storage_key)).await
+    }
+
+    /// 파일 하나를 삭제합니다. 이미 없으면 성공으로 간주합니다.
+    pub async fn remove(&self, storage_key: &str) -> io::Result<()> {
+        debug!("[Storage] 파일 삭제: {}", storage_key);
+        ignore_not_found(fs::remove_file(self.path(storage_key)).await)
+    }
+
+    /// 게시글의 모든 첨부파일을 삭제합니다. 디렉터리가 없으면 성공으로 간주합니다.
+    pub async fn remove_board(&self, board_id: i64) -> io::Result<()> {
+        info!(
+            "[Storage] 게시글 첨부파일 디렉터리 삭제: board_id={}",
+            board_id
+        );
+        ignore_not_found(fs::remove_dir_all(self.board_dir(board_id)).await)
+    }
+}
+
+fn ignore_not_found(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
@@ -0,0 +1,434 @@
+//! 스키마 마이그레이션: 버전별 up/down 스크립트를 순서대로 적용하거나 되돌립니다.
+//!
+//! 적용 기록은 `SCHEMA_MIGRATIONS` 테이블(버전, 이름, up 스크립트의 SHA-256, 적용 시각)에 남깁니다.
+//! 이미 적용된 스크립트가 나중에 바뀌면 체크섬이 달라지므로 실행을 거부합니다.
+//!
+//! - SQLite는 스크립트와 적용 기록을 한 트랜잭션으로 실행하므로 실패하면 통째로 롤백됩니다.
+//! - Oracle의 DDL은 문장마다 자동 커밋되므로, 실패하면 그 앞의 문장은 남고 적용 기록만 빠집니다.
+//!   이때는 남은 객체를 정리한 뒤 다시 실행해야 합니다.
+//!
+//! 여러 인스턴스가 동시에 실행하지 않도록, 배포 시 한 곳에서만 `migrate up`(또는 `migrate_on_startup`)을 사용합니다.
+
+use crate::common::queries::{
+    self, CREATE_SCHEMA_MIGRATIONS, DELETE_SCHEMA_MIGRATION, INSERT_SCHEMA_MIGRATION,
+    SELECT_SCHEMA_MIGRATIONS, SELECT_SCHEMA_MIGRATIONS_EXISTS,
+};
+use crate::repositories::sqlite::{map_pool_err, map_sqlite_err};
+use crate::repositories::store_error::StoreError;
+use crate::repositories::stores::DbPool;
+use oracle::sql_type::ToSql;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use tracing::{debug, info, warn};
+
+/// 버전 하나의 마이그레이션 스크립트
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// 1부터 증가하는 버전 번호
+    pub version: u32,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+impl Migration {
+    /// up 스크립트의 SHA-256 (16진수). 적용 후 스크립트가 바뀌었는지 확인하는 데 씁니다.
+    pub fn checksum(&self) -> String {
+        hex::encode(Sha256::digest(self.up.as_bytes()))
+    }
+}
+
+/// Oracle 마이그레이션 (버전 순)
+pub const ORACLE_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        up: queries::migrations::INITIAL_SCHEMA_UP,
+        down: queries::migrations::INITIAL_SCHEMA_DOWN,
+    },
+    Migration {
+        version: 2,
+        name: "categories_and_tags",
+        up: queries::migrations::CATEGORIES_AND_TAGS_UP,
+        down: queries::migrations::CATEGORIES_AND_TAGS_DOWN,
+    },
+    Migration {
+        version: 3,
+        name: "views_and_likes",
+        up: queries::migrations::VIEWS_AND_LIKES_UP,
+        down: queries::migrations::VIEWS_AND_LIKES_DOWN,
+    },
+    Migration {
+        version: 4,
+        name: "content_format",
+        up: queries::migrations::CONTENT_FORMAT_UP,
+        down: queries::migrations::CONTENT_FORMAT_DOWN,
+    },
+];
+
+/// SQLite 마이그레이션 (버전 순, Oracle과 같은 번호를 씁니다)
+pub const SQLITE_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        up: queries::sqlite::migrations::INITIAL_SCHEMA_UP,
+        down: queries::sqlite::migrations::INITIAL_SCHEMA_DOWN,
+    },
+    Migration {
+        version: 2,
+        name: "categories_and_tags",
+        up: queries::sqlite::migrations::CATEGORIES_AND_TAGS_UP,
+        down: queries::sqlite::migrations::CATEGORIES_AND_TAGS_DOWN,
+    },
+    Migration {
+        version: 3,
+        name: "views_and_likes",
+        up: queries::sqlite::migrations::VIEWS_AND_LIKES_UP,
+        down: queries::sqlite::migrations::VIEWS_AND_LIKES_DOWN,
+    },
+    Migration {
+        version: 4,
+        name: "content_format",
+        up: queries::sqlite::migrations::CONTENT_FORMAT_UP,
+        down: queries::sqlite::migrations::CONTENT_FORMAT_DOWN,
+    },
+];
+
+/// `SCHEMA_MIGRATIONS`에 기록된 적용 내역
+#[derive(Debug, Clone)]
+struct AppliedMigration {
+    version: u32,
+    name: String,
+    checksum: String,
+    applied_at: String,
+}
+
+/// 마이그레이션 하나의 적용 상태 (`migrate status` 출력용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: u32,
+    pub name: String,
+    /// 적용 시각 (적용되지 않았으면 `None`)
+    pub applied_at: Option<String>,
+}
+
+/// 마이그레이션 실행 중 발생한 에러
+#[derive(Debug)]
+pub enum MigrationError {
+    Database(StoreError),
+    /// 적용된 뒤 스크립트 내용이 바뀜
+    ChecksumMismatch {
+        version: u32,
+        name: String,
+    },
+    /// DB에는 적용 기록이 있지만 이 애플리케이션이 모르는 버전 (더 새 버전으로 마이그레이션된 DB)
+    UnknownVersion(u32),
+    /// 존재하지 않는 목표 버전
+    InvalidTarget(u32),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::Database(err) => write!(f, "마이그레이션 DB 오류: {err}"),
+            MigrationError::ChecksumMismatch { version, name } => write!(
+                f,
+                "이미 적용된 마이그레이션 {version:04}_{name}의 스크립트가 변경되었습니다. 적용된 스크립트는 수정하지 말고 새 버전을 추가하세요."
+            ),
+            MigrationError::UnknownVersion(version) => write!(
+                f,
+                "DB에 이 애플리케이션이 모르는 마이그레이션 버전 {version}이 적용되어 있습니다."
+            ),
+            MigrationError::InvalidTarget(version) => {
+                write!(f, "존재하지 않는 마이그레이션 버전입니다: {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+impl From<StoreError> for MigrationError {
+    fn from(err: StoreError) -> Self {
+        MigrationError::Database(err)
+    }
+}
+
+impl From<oracle::Error> for MigrationError {
+    fn from(err: oracle::Error) -> Self {
+        MigrationError::Database(err.into())
+    }
+}
+
+/// 스크립트를 `;`로 끝나는 줄 단위로 나눕니다. 주석(`--`)만 있는 줄은 건너뜁니다.
+///
+/// Oracle 드라이버는 한 번에 한 문장만 실행하므로 필요합니다. (PL/SQL 블록은 지원하지 않습니다)
+pub fn split_statements(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    for line in script.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("--") {
+            continue;
+        }
+        match trimmed.strip_suffix(';') {
+            Some(end) => {
+                current.push_str(end);
+                statements.push(std::mem::take(&mut current).trim().to_string());
+            }
+            None => {
+                current.push_str(line);
+                current.push('\n');
+            }
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+    statements
+}
+
+/// 커넥션 풀에 마이그레이션을 적용하는 실행기
+///
+/// DB 작업은 블로킹이므로 비동기 코드에서는 `spawn_blocking` 안에서 호출합니다.
+pub struct Migrator {
+    pool: DbPool,
+}
+
+impl Migrator {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// 현재 백엔드의 마이그레이션 목록
+    pub fn migrations(&self) -> &'static [Migration] {
+        match self.pool {
+            DbPool::Oracle(_) => ORACLE_MIGRATIONS,
+            DbPool::Sqlite(_) => SQLITE_MIGRATIONS,
+        }
+    }
+
+    /// 모든 마이그레이션의 적용 상태를 버전 순으로 반환합니다.
+    pub fn status(&self) -> Result<Vec<MigrationStatus>, MigrationError> {
+        let applied = self.verified_applied()?;
+        Ok(self
+            .migrations()
+            .iter()
+            .map(|migration| MigrationStatus {
+                version: migration.version,
+                name: migration.name.to_string(),
+                applied_at: applied
+                    .iter()
+                    .find(|a| a.version == migration.version)
+                    .map(|a| a.applied_at.clone()),
+            })
+            .collect())
+    }
+
+    /// 적용되지 않은 마이그레이션을 `target` 버전까지(없으면 끝까지) 순서대로 적용하고, 적용한 버전을 반환합니다.
+    pub fn up(&self, target: Option<u32>) -> Result<Vec<u32>, MigrationError> {
+        let target = self.check_target(target)?;
+        let applied = self.verified_applied()?;
+        let mut done = Vec::new();
+        for migration in self.migrations() {
+            if migration.version > target || applied.iter().any(|a| a.version == migration.version)
+            {
+                continue;
+            }
+            info!(
+                "[Migration] 적용: {:04}_{} ({})",
+                migration.version,
+                migration.name,
+                self.pool.backend()
+            );
+            self.run(migration, migration.up, Direction::Up)?;
+            done.push(migration.version);
+        }
+        if done.is_empty() {
+            info!("[Migration] 적용할 마이그레이션이 없습니다.");
+        }
+        Ok(done)
+    }
+
+    /// 가장 최근에 적용한 마이그레이션부터 `steps`개를 되돌리고, 되돌린 버전을 반환합니다.
+    pub fn down(&self, steps: u32) -> Result<Vec<u32>, MigrationError> {
+        let applied = self.verified_applied()?;
+        let mut done = Vec::new();
+        for record in applied.iter().rev().take(steps as usize) {
+            let migration = self.find(record.version)?;
+            warn!(
+                "[Migration] 되돌리기: {:04}_{} ({})",
+                migration.version,
+                migration.name,
+                self.pool.backend()
+            );
+            self.run(migration, migration.down, Direction::Down)?;
+            done.push(migration.version);
+        }
+        Ok(done)
+    }
+
+    /// 스크립트를 실행하지 않고 `version`까지의 마이그레이션을 적용된 것으로 기록합니다.
+    ///
+    /// 마이그레이션 도입 전에 수동으로 만든 기존 스키마를 관리 대상으로 편입할 때 사용합니다.
+    pub fn baseline(&self, version: u32) -> Result<Vec<u32>, MigrationError> {
+        let target = self.check_target(Some(version))?;
+        let applied = self.verified_applied()?;
+        let mut done = Vec::new();
+        for migration in self.migrations() {
+            if migration.version > target || applied.iter().any(|a| a.version == migration.version)
+            {
+                continue;
+            }
+            info!(
+                "[Migration] 기준선 기록(실행 안 함): {:04}_{}",
+                migration.version, migration.name
+            );
+            self.run(migration, "", Direction::Up)?;
+            done.push(migration.version);
+        }
+        Ok(done)
+    }
+
+    fn find(&self, version: u32) -> Result<&'static Migration, MigrationError> {
+        self.migrations()
+            .iter()
+            .find(|migration| migration.version == version)
+            .ok_or(MigrationError::UnknownVersion(version))
+    }
+
+    fn check_target(&self, target: Option<u32>) -> Result<u32, MigrationError> {
+        match target {
+            None => Ok(self.migrations().last().map_or(0, |m| m.version)),
+            Some(version) => self
+                .find(version)
+                .map(|m| m.version)
+                .map_err(|_| MigrationError::InvalidTarget(version)),
+        }
+    }
+
+    /// 적용 기록을 읽고, 모르는 버전이나 바뀐 스크립트가 없는지 확인합니다.
+    fn verified_applied(&self) -> Result<Vec<AppliedMigration>, MigrationError> {
+        let applied = self.applied()?;
+        for record in &applied {
+            let migration = self.find(record.version)?;
+            if migration.checksum() != record.checksum.trim() {
+                return Err(MigrationError::ChecksumMismatch {
+                    version: record.version,
+                    name: record.name.clone(),
+                });
+            }
+        }
+        Ok(applied)
+    }
+
+    /// 추적 테이블을 (없으면 만들고) 읽습니다.
+    fn applied(&self) -> Result<Vec<AppliedMigration>, StoreError> {
+        match &self.pool {
+            DbPool::Oracle(pool) => {
+                let conn = pool.get().map_err(map_pool_err)?;
+                let exists = conn.query_row_as::<i64>(SELECT_SCHEMA_MIGRATIONS_EXISTS, &[])?;
+                if exists == 0 {
+                    info!("[Migration] SCHEMA_MIGRATIONS 테이블 생성");
+                    debug!("[Migration][SQL] {}", CREATE_SCHEMA_MIGRATIONS.trim());
+                    conn.execute(CREATE_SCHEMA_MIGRATIONS, &[])?;
+                }
+                conn.query(SELECT_SCHEMA_MIGRATIONS, &[])?
+                    .map(|row| {
+                        let row = row?;
+                        Ok(AppliedMigration {
+                            version: row.get("VERSION")?,
+                            name: row.get("NAME")?,
+                            checksum: row.get("CHECKSUM")?,
+                            applied_at: row.get("APPLIED_AT")?,
+                        })
+                    })
+                    .collect()
+            }
+            DbPool::Sqlite(pool) => {
+                use queries::sqlite::{CREATE_SCHEMA_MIGRATIONS, SELECT_SCHEMA_MIGRATIONS};
+                let conn = pool.get().map_err(map_pool_err)?;
+                conn.execute_batch(CREATE_SCHEMA_MIGRATIONS)
+                    .map_err(map_sqlite_err)?;
+                let mut stmt = conn
+                    .prepare(SELECT_SCHEMA_MIGRATIONS)
+                    .map_err(map_sqlite_err)?;
+                stmt.query_map([], |row| {
+                    Ok(AppliedMigration {
+                        version: row.get("VERSION")?,
+                        name: row.get("NAME")?,
+                        checksum: row.get("CHECKSUM")?,
+                        applied_at: row.get("APPLIED_AT")?,
+                    })
+                })
+                .map_err(map_sqlite_err)?
+                .collect::<Result<_, _>>()
+                .map_err(map_sqlite_err)
+            }
+        }
+    }
+
+    /// 스크립트를 실행하고 적용 기록을 추가(up)하거나 지웁니다(down).
+    fn run(
+        &self,
+        migration: &Migration,
+        script: &str,
+        direction: Direction,
+    ) -> Result<(), StoreError> {
+        let version = i64::from(migration.version);
+        let checksum = migration.checksum();
+        match &self.pool {
+            DbPool::Oracle(pool) => {
+                let conn = pool.get().map_err(map_pool_err)?;
+                for statement in split_statements(script) {
+                    debug!("[Migration][SQL] {}", statement);
+                    conn.execute(&statement, &[])?;
+                }
+                match direction {
+                    Direction::Up => {
+                        let params: [(&str, &dyn ToSql); 3] = [
+                            ("version", &version),
+                            ("name", &migration.name),
+                            ("checksum", &checksum),
+                        ];
+                        conn.execute_named(INSERT_SCHEMA_MIGRATION, &params)?;
+                    }
+                    Direction::Down => {
+                        conn.execute_named(DELETE_SCHEMA_MIGRATION, &[("version", &version)])?;
+                    }
+                }
+                Ok(conn.commit()?)
+            }
+            DbPool::Sqlite(pool) => {
+                use queries::sqlite::{DELETE_SCHEMA_MIGRATION, INSERT_SCHEMA_MIGRATION};
+                let mut conn = pool.get().map_err(map_pool_err)?;
+                let tx = conn.transaction().map_err(map_sqlite_err)?;
+                debug!("[Migration][SQL] {}", script.trim());
+                tx.execute_batch(script).map_err(map_sqlite_err)?;
+                match direction {
+                    Direction::Up => tx.execute(
+                        INSERT_SCHEMA_MIGRATION,
+                        rusqlite::named_params! {
+                            ":version": version,
+                            ":name": migration.name,
+                            ":checksum": checksum,
+                        },
+                    ),
+                    Direction::Down => tx.execute(
+                        DELETE_SCHEMA_MIGRATION,
+                        rusqlite::named_params! { ":version": version },
+                    ),
+                }
+                .map_err(map_sqlite_err)?;
+                tx.commit().map_err(map_sqlite_err)
+            }
+        }
+    }
+}
+
+/// 스크립트 실행 방향
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+}
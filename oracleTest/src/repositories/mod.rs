@@ -1 +1,21 @@
+pub mod attachment_repository;
+pub mod attachment_store;
 pub mod board_repository;
+pub mod board_store;
+pub mod cached_board_store;
+pub mod category_repository;
+pub mod category_store;
+pub mod comment_repository;
+pub mod comment_store;
+pub mod file_storage;
+pub mod migrations;
+pub mod sqlite;
+pub mod sqlite_attachment_repository;
+pub mod sqlite_board_repository;
+pub mod sqlite_category_repository;
+pub mod sqlite_comment_repository;
+pub mod sqlite_user_repository;
+pub mod store_error;
+pub mod stores;
+pub mod user_repository;
+pub mod user_store;
@@ -0,0 +1,84 @@
+//! SQLite 백엔드 공통 헬퍼: 커넥션 풀 생성, 스키마 초기화, 에러 매핑
+//!
+//! Oracle 컨테이너 없이 애플리케이션을 실행하기 위한 내장 저장소입니다.
+//! `sqlite_path`가 `:memory:`이면 프로세스 수명 동안만 유지되는 인메모리 DB를 사용합니다.
+
+use crate::common::metrics::PoolMetrics;
+use crate::repositories::migrations::{MigrationError, Migrator};
+use crate::repositories::store_error::StoreError;
+use crate::repositories::stores::DbPool;
+use oracle::sql_type::Timestamp;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::Row;
+use rusqlite::types::Type;
+use std::time::Duration;
+use tracing::info;
+
+/// 인메모리 DB 경로 표기
+pub const MEMORY_PATH: &str = ":memory:";
+
+/// SQLite 커넥션 풀을 생성합니다.
+///
+/// 인메모리 DB는 커넥션마다 별도의 DB가 생기므로, 단일 커넥션을 만료 없이 유지합니다.
+/// `connection_timeout`은 풀에서 커넥션을 얻기까지 기다리는 최대 시간입니다.
+pub fn build_pool(
+    path: &str,
+    max_size: u32,
+    connection_timeout: Duration,
+) -> Result<Pool<SqliteConnectionManager>, r2d2::Error> {
+    info!("[SQLite] 커넥션 풀 생성: path={}", path);
+    let init = |conn: &mut rusqlite::Connection| {
+        conn.execute_batch("PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;")
+    };
+
+    if path == MEMORY_PATH {
+        Pool::builder()
+            .event_handler(Box::new(PoolMetrics))
+            .max_size(1)
+            .connection_timeout(connection_timeout)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .build(SqliteConnectionManager::memory().with_init(init))
+    } else {
+        Pool::builder()
+            .event_handler(Box::new(PoolMetrics))
+            .max_size(max_size)
+            .connection_timeout(connection_timeout)
+            .build(SqliteConnectionManager::file(path).with_init(init))
+    }
+}
+
+/// 적용되지 않은 마이그레이션을 모두 적용합니다. (인메모리 DB와 테스트에서 스키마를 준비할 때 사용)
+pub fn init_schema(pool: &Pool<SqliteConnectionManager>) -> Result<(), MigrationError> {
+    Migrator::new(DbPool::Sqlite(pool.clone()))
+        .up(None)
+        .map(|_| ())
+}
+
+/// 텍스트로 저장된 시각 컬럼을 Oracle과 같은 `Timestamp` 타입으로 파싱하는 헬퍼 함수
+pub fn parse_timestamp(row: &Row, column: &str) -> rusqlite::Result<Option<Timestamp>> {
+    let index = row.as_ref().column_index(column)?;
+    row.get::<_, Option<String>>(index)?
+        .map(|ts| {
+            ts.parse::<Timestamp>().map_err(|err| {
+                rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(err))
+            })
+        })
+        .transpose()
+}
+
+/// `rusqlite::Error`를 `StoreError`로 매핑하는 헬퍼 함수
+pub fn map_sqlite_err(err: rusqlite::Error) -> StoreError {
+    StoreError::from(err)
+}
+
+/// `r2d2::Error`를 `StoreError`로 매핑하는 헬퍼 함수
+pub fn map_pool_err(err: r2d2::Error) -> StoreError {
+    StoreError::from(err)
+}
+
+/// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+pub fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+    StoreError::from(err)
+}
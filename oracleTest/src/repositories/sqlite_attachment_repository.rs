@@ -0,0 +1,125 @@
+//! Repository 계층 (SQLite): 첨부파일 메타데이터 테이블 작업
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::sqlite::{
+    INSERT_ATTACHMENT, SELECT_ATTACHMENT_BY_ID, SELECT_ATTACHMENTS_BY_BOARD,
+};
+use crate::models::attachment::{Attachment, NewAttachment};
+use crate::repositories::attachment_store::AttachmentStore;
+use crate::repositories::sqlite::{map_join_err, map_pool_err, map_sqlite_err, parse_timestamp};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{OptionalExtension, Row, named_params};
+use tracing::{debug, info};
+
+/// SQLite 기반 첨부파일 메타데이터 접근 객체 (DAO).
+pub struct SqliteAttachmentRepository {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl SqliteAttachmentRepository {
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Attachment 구조체로 변환하는 헬퍼 함수.
+    fn row_to_attachment(row: &Row) -> rusqlite::Result<Attachment> {
+        Ok(Attachment {
+            id: row.get("ID")?,
+            board_id: row.get("BOARD_ID")?,
+            filename: row.get("FILENAME")?,
+            content_type: row.get("CONTENT_TYPE")?,
+            size_bytes: row.get("SIZE_BYTES")?,
+            sha256: row.get("SHA256")?,
+            storage_key: row.get("STORAGE_KEY")?,
+            uploader_id: row.get("UPLOADER_ID")?,
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl AttachmentStore for SqliteAttachmentRepository {
+    /// 새 첨부파일 메타데이터 추가 후 생성된 ID 반환
+    async fn insert(&self, attachment: NewAttachment) -> Result<i64, StoreError> {
+        info!(
+            "[Repo:SQLite] attachment insert 호출: board_id={}, filename={}",
+            attachment.board_id, attachment.filename
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", INSERT_ATTACHMENT.trim());
+            debug!("[Repo:SQLite][BIND] {:?}", attachment);
+            conn.execute(
+                INSERT_ATTACHMENT,
+                named_params! {
+                    ":board_id": attachment.board_id,
+                    ":filename": attachment.filename,
+                    ":content_type": attachment.content_type,
+                    ":size_bytes": attachment.size_bytes,
+                    ":sha256": attachment.sha256,
+                    ":storage_key": attachment.storage_key,
+                    ":uploader_id": attachment.uploader_id,
+                },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(conn.last_insert_rowid())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// ID로 단일 첨부파일 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Attachment>, StoreError> {
+        info!("[Repo:SQLite] attachment find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_ATTACHMENT_BY_ID.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            conn.query_row(
+                SELECT_ATTACHMENT_BY_ID,
+                named_params! { ":id": id },
+                Self::row_to_attachment,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 첨부파일 목록 조회
+    async fn find_by_board(&self, board_id: i64) -> Result<Vec<Attachment>, StoreError> {
+        info!(
+            "[Repo:SQLite] attachment find_by_board 호출: board_id={}",
+            board_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_ATTACHMENTS_BY_BOARD.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}", board_id);
+            let mut stmt = conn
+                .prepare(SELECT_ATTACHMENTS_BY_BOARD)
+                .map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! { ":board_id": board_id },
+                    Self::row_to_attachment,
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+}
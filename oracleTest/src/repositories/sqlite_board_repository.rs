@@ -0,0 +1,955 @@
+//! Repository 계층 (SQLite): 내장 데이터베이스 CRUD 작업
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::sqlite::{
+    DELETE_ATTACHMENTS_BY_BOARD, DELETE_BOARD, DELETE_BOARD_LIKE, DELETE_BOARD_LIKES,
+    DELETE_BOARD_TAGS, DELETE_BOARD_VIEWS, DELETE_COMMENTS_BY_BOARD, DELETE_REVISIONS_BY_BOARD,
+    INSERT_BOARD, INSERT_BOARD_LIKE, INSERT_BOARD_REVISION, INSERT_BOARD_TAG, RESTORE_BOARD,
+    SELECT_BOARD_BY_ID, SELECT_BOARD_COUNT, SELECT_BOARD_FOR_UPDATE, SELECT_BOARD_KEYSET,
+    SELECT_BOARD_LIKE_COUNT, SELECT_BOARD_PAGED, SELECT_BOARD_REVISION, SELECT_BOARD_REVISIONS,
+    SELECT_BOARD_TAGS, SELECT_EXPIRED_TRASH_IDS, SELECT_POPULAR_BOARDS, SELECT_TAG_COUNTS,
+    SELECT_TRASH_COUNT, SELECT_TRASH_PAGED, SELECT_TRASHED_BOARD_BY_ID, SOFT_DELETE_BOARD,
+    UPDATE_BOARD, UPDATE_BOARD_CATEGORY, UPDATE_BOARD_VIEW_COUNT, UPSERT_BOARD_VIEWS_DAILY,
+    UPSERT_TAG,
+};
+use crate::models::board::{
+    Board, BoardCursor, BoardFilter, BoardListItem, BoardUpdate, ContentFormat, NewBoard,
+    PopularBoard, TrashedBoard,
+};
+use crate::models::category::TagCount;
+use crate::models::revision::BoardRevision;
+use crate::repositories::board_store::{BoardStore, SearchSql};
+use crate::repositories::sqlite::{map_join_err, map_pool_err, map_sqlite_err, parse_timestamp};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::types::Type;
+use rusqlite::{
+    Connection, OptionalExtension, Row, ToSql, TransactionBehavior, named_params, params_from_iter,
+};
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+/// SQLite 기반 게시판 데이터베이스 접근 객체 (DAO).
+/// Oracle 없이 개발/CI 환경에서 동일한 `BoardStore` 계약을 제공합니다.
+pub struct SqliteBoardRepository {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl SqliteBoardRepository {
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// 검색 조건의 바인드 값을 이름 있는 파라미터 목록으로 변환하는 헬퍼 함수.
+    /// 조건이 없는 바인드 변수는 SQL에 존재하지 않으므로 건너뜁니다.
+    /// 커서 시각은 SQLite에 텍스트로 저장되어 있으므로 문자열로 변환해 전달합니다.
+    fn search_params<'a>(
+        search: &'a SearchSql,
+        cursor: Option<&'a (String, i64)>,
+    ) -> Vec<(&'static str, &'a dyn ToSql)> {
+        let mut params: Vec<(&str, &dyn ToSql)> = Vec::new();
+        if let Some(keyword) = &search.keyword {
+            debug!("[Repo:SQLite][BIND] keyword={}", keyword);
+            params.push((":keyword", keyword));
+        }
+        if let Some(title) = &search.title {
+            debug!("[Repo:SQLite][BIND] title={}", title);
+            params.push((":title", title));
+        }
+        if let Some(from_date) = &search.from_date {
+            debug!("[Repo:SQLite][BIND] from_date={}", from_date);
+            params.push((":from_date", from_date));
+        }
+        if let Some(to_date) = &search.to_date {
+            debug!("[Repo:SQLite][BIND] to_date={}", to_date);
+            params.push((":to_date", to_date));
+        }
+        if let Some(category_id) = &search.category_id {
+            debug!("[Repo:SQLite][BIND] category_id={}", category_id);
+            params.push((":category_id", category_id));
+        }
+        if let Some(tag) = &search.tag {
+            debug!("[Repo:SQLite][BIND] tag={}", tag);
+            params.push((":tag", tag));
+        }
+        if let Some((created_at, id)) = cursor {
+            debug!(
+                "[Repo:SQLite][BIND] cursor_created_at={}, cursor_id={}",
+                created_at, id
+            );
+            params.push((":cursor_created_at", created_at));
+            params.push((":cursor_id", id));
+        }
+        params
+    }
+
+    /// `CONTENT_FORMAT` 컬럼을 읽습니다. 알 수 없는 값은 일반 텍스트로 취급합니다.
+    fn content_format(row: &Row) -> rusqlite::Result<ContentFormat> {
+        Ok(row
+            .get::<_, Option<String>>("CONTENT_FORMAT")?
+            .and_then(|format| ContentFormat::parse(&format))
+            .unwrap_or_default())
+    }
+
+    /// DB Row를 Board 구조체로 변환하는 헬퍼 함수.
+    fn row_to_board(row: &Row) -> rusqlite::Result<Board> {
+        Ok(Board {
+            id: row.get("ID")?,
+            title: row.get::<_, Option<String>>("TITLE")?.unwrap_or_default(),
+            content: row.get::<_, Option<String>>("CONTENT")?.unwrap_or_default(),
+            content_format: Self::content_format(row)?,
+            author_id: row.get("AUTHOR_ID")?,
+            category_id: row.get("CATEGORY_ID")?,
+            tags: Vec::new(),
+            version: row.get("VERSION")?,
+            view_count: row.get("VIEW_COUNT")?,
+            like_count: row.get("LIKE_COUNT")?,
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+
+    /// DB Row를 `BoardListItem` 구조체로 변환하는 헬퍼 함수.
+    fn row_to_board_list_item(row: &Row) -> rusqlite::Result<BoardListItem> {
+        Ok(BoardListItem {
+            id: row.get("ID")?,
+            title: row.get::<_, Option<String>>("TITLE")?.unwrap_or_default(),
+            content: row.get::<_, Option<String>>("CONTENT")?.unwrap_or_default(),
+            content_format: Self::content_format(row)?,
+            author_id: row.get("AUTHOR_ID")?,
+            category_id: row.get("CATEGORY_ID")?,
+            tags: Vec::new(),
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+
+    /// DB Row를 `TrashedBoard` 구조체로 변환하는 헬퍼 함수.
+    fn row_to_trashed_board(row: &Row) -> rusqlite::Result<TrashedBoard> {
+        Ok(TrashedBoard {
+            id: row.get("ID")?,
+            title: row.get::<_, Option<String>>("TITLE")?.unwrap_or_default(),
+            author_id: row.get("AUTHOR_ID")?,
+            created_at: row.get("CREATED_AT")?,
+            deleted_at: row.get("DELETED_AT")?,
+        })
+    }
+
+    /// DB Row를 `BoardRevision` 구조체로 변환하는 헬퍼 함수.
+    fn row_to_revision(row: &Row) -> rusqlite::Result<BoardRevision> {
+        Ok(BoardRevision {
+            board_id: row.get("BOARD_ID")?,
+            revision: row.get("REVISION_NO")?,
+            title: row.get::<_, Option<String>>("TITLE")?.unwrap_or_default(),
+            content: row.get::<_, Option<String>>("CONTENT")?.unwrap_or_default(),
+            version: row.get("VERSION")?,
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+
+    /// 여러 게시글의 태그를 한 번에 조회하여 게시글 ID별 태그 이름 목록으로 반환합니다.
+    fn load_tags(conn: &Connection, ids: &[i64]) -> rusqlite::Result<HashMap<i64, Vec<String>>> {
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        if ids.is_empty() {
+            return Ok(tags);
+        }
+        let sql = SearchSql::render_ids(SELECT_BOARD_TAGS, ids.len(), |index| format!("?{index}"));
+        debug!("[Repo:SQLite][SQL] {}", sql.trim());
+        debug!("[Repo:SQLite][BIND] ids={:?}", ids);
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(ids), |row| {
+            Ok((
+                row.get::<_, i64>("BOARD_ID")?,
+                row.get::<_, String>("NAME")?,
+            ))
+        })?;
+        for row in rows {
+            let (board_id, name) = row?;
+            tags.entry(board_id).or_default().push(name);
+        }
+        Ok(tags)
+    }
+
+    /// 목록 항목마다 태그를 채웁니다.
+    fn attach_tags<'a>(
+        conn: &Connection,
+        items: impl IntoIterator<Item = &'a mut BoardListItem>,
+    ) -> rusqlite::Result<()> {
+        let items: Vec<&mut BoardListItem> = items.into_iter().collect();
+        let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+        let mut tags = Self::load_tags(conn, &ids)?;
+        for item in items {
+            item.tags = tags.remove(&item.id).unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// 단건 조회한 게시글에 태그를 채웁니다.
+    fn with_tags(conn: &Connection, board: Option<Board>) -> rusqlite::Result<Option<Board>> {
+        board
+            .map(|mut board| {
+                board.tags = Self::load_tags(conn, &[board.id])?
+                    .remove(&board.id)
+                    .unwrap_or_default();
+                Ok(board)
+            })
+            .transpose()
+    }
+
+    /// 게시글의 태그를 `tags`로 통째로 바꿉니다. 없는 태그는 새로 만듭니다. (트랜잭션 안에서 호출합니다)
+    fn replace_tags(conn: &Connection, board_id: i64, tags: &[String]) -> rusqlite::Result<()> {
+        debug!("[Repo:SQLite][SQL] {}", DELETE_BOARD_TAGS.trim());
+        debug!("[Repo:SQLite][BIND] board_id={}, tags={:?}", board_id, tags);
+        conn.execute(DELETE_BOARD_TAGS, named_params! { ":board_id": board_id })?;
+        for name in tags {
+            conn.execute(UPSERT_TAG, named_params! { ":name": name })?;
+            conn.execute(
+                INSERT_BOARD_TAG,
+                named_params! { ":board_id": board_id, ":name": name },
+            )?;
+        }
+        Ok(())
+    }
+
+    /// 게시글 한 건과 태그를 추가하고 생성된 ID를 반환합니다. (트랜잭션 안에서 호출합니다)
+    fn insert_one(conn: &Connection, board: &NewBoard, author_id: i64) -> rusqlite::Result<i64> {
+        debug!("[Repo:SQLite][SQL] {}", INSERT_BOARD.trim());
+        debug!(
+            "[Repo:SQLite][BIND] title={}, content_len={}, format={}, author_id={}, category_id={:?}",
+            board.title,
+            board.content.chars().count(),
+            board.format.as_str(),
+            author_id,
+            board.category_id
+        );
+        conn.execute(
+            INSERT_BOARD,
+            named_params! {
+                ":title": board.title,
+                ":content": board.content,
+                ":format": board.format.as_str(),
+                ":author_id": author_id,
+                ":category_id": board.category_id,
+            },
+        )?;
+        let id = conn.last_insert_rowid();
+        if !board.tags.is_empty() {
+            Self::replace_tags(conn, id, &board.tags)?;
+        }
+        Ok(id)
+    }
+}
+
+#[async_trait]
+impl BoardStore for SqliteBoardRepository {
+    /// 검색 조건에 맞는 게시글 수 조회
+    async fn count_all(&self, filter: &BoardFilter) -> Result<u32, StoreError> {
+        info!("[Repo:SQLite] count_all 호출: filter={:?}", filter);
+        let pool = self.pool.clone();
+        let search = SearchSql::new(filter);
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            let sql = search.render(SELECT_BOARD_COUNT);
+            debug!("[Repo:SQLite][SQL] {}", sql.trim());
+            let params = Self::search_params(&search, None);
+            conn.query_row(&sql, params.as_slice(), |row| row.get::<_, u32>(0))
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 검색 조건과 페이지네이션을 사용하여 게시글 목록 조회
+    async fn find_paged(
+        &self,
+        filter: &BoardFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BoardListItem>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_paged 호출: offset={}, limit={}, filter={:?}",
+            offset, limit, filter
+        );
+        let pool = self.pool.clone();
+        let search = SearchSql::new(filter);
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            let sql = search.render(SELECT_BOARD_PAGED);
+            debug!("[Repo:SQLite][SQL] {}", sql.trim());
+            debug!("[Repo:SQLite][BIND] offset={}, limit={}", offset, limit);
+            let mut params = Self::search_params(&search, None);
+            params.push((":offset", &offset));
+            params.push((":limit", &limit));
+            let mut stmt = conn.prepare(&sql).map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(params.as_slice(), Self::row_to_board_list_item)
+                .map_err(map_sqlite_err)?;
+            let mut items = rows
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)?;
+            Self::attach_tags(&conn, &mut items).map_err(map_sqlite_err)?;
+            Ok(items)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 키셋(커서) 방식으로 게시글 목록 조회
+    async fn find_after(
+        &self,
+        filter: &BoardFilter,
+        after: Option<BoardCursor>,
+        limit: u32,
+    ) -> Result<Vec<(BoardListItem, BoardCursor)>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_after 호출: after={:?}, limit={}, filter={:?}",
+            after, limit, filter
+        );
+        let pool = self.pool.clone();
+        let search = match after {
+            Some(cursor) => SearchSql::new(filter).after(cursor, filter.sort.direction),
+            None => SearchSql::new(filter),
+        };
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            let sql = search.render(SELECT_BOARD_KEYSET);
+            debug!("[Repo:SQLite][SQL] {}", sql.trim());
+            debug!("[Repo:SQLite][BIND] limit={}", limit);
+            let cursor = search
+                .cursor
+                .as_ref()
+                .map(|cursor| (cursor.created_at.to_string(), cursor.id));
+            let mut params = Self::search_params(&search, cursor.as_ref());
+            params.push((":limit", &limit));
+            let mut stmt = conn.prepare(&sql).map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(params.as_slice(), |row| {
+                    let created_at = parse_timestamp(row, "CREATED_AT_KEY")?;
+                    let cursor = BoardCursor {
+                        created_at: created_at.ok_or(rusqlite::Error::InvalidColumnType(
+                            6,
+                            "CREATED_AT_KEY".to_string(),
+                            Type::Null,
+                        ))?,
+                        id: row.get("ID")?,
+                    };
+                    Ok((Self::row_to_board_list_item(row)?, cursor))
+                })
+                .map_err(map_sqlite_err)?;
+            let mut items = rows
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)?;
+            Self::attach_tags(&conn, items.iter_mut().map(|(item, _)| item))
+                .map_err(map_sqlite_err)?;
+            Ok(items)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// ID로 단일 게시글 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
+        info!("[Repo:SQLite] find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_BOARD_BY_ID.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            let board = conn
+                .query_row(
+                    SELECT_BOARD_BY_ID,
+                    named_params! { ":id": id },
+                    Self::row_to_board,
+                )
+                .optional()
+                .map_err(map_sqlite_err)?;
+            Self::with_tags(&conn, board).map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 새 게시글과 태그를 한 트랜잭션으로 추가한 후 생성된 ID 반환
+    async fn insert(&self, board: NewBoard, author_id: i64) -> Result<i64, StoreError> {
+        info!(
+            "[Repo:SQLite] insert 호출: title={}, tags={:?}",
+            board.title, board.tags
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            // 트랜잭션은 커밋하지 않고 버려지면 롤백됩니다.
+            let tx = conn.transaction().map_err(map_sqlite_err)?;
+            let id = Self::insert_one(&tx, &board, author_id).map_err(map_sqlite_err)?;
+            tx.commit().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite] INSERT 완료: id={}", id);
+            Ok(id)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글 일괄 추가 (한 트랜잭션)
+    async fn insert_many(
+        &self,
+        boards: Vec<NewBoard>,
+        author_id: i64,
+    ) -> Result<Vec<i64>, StoreError> {
+        info!(
+            "[Repo:SQLite] insert_many 호출: count={}, author_id={}",
+            boards.len(),
+            author_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            // 트랜잭션은 커밋하지 않고 버려지면 롤백됩니다.
+            let tx = conn.transaction().map_err(map_sqlite_err)?;
+            let ids = boards
+                .iter()
+                .map(|board| Self::insert_one(&tx, board, author_id))
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)?;
+            tx.commit().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite] 일괄 추가된 게시글 수: {}", ids.len());
+            Ok(ids)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글 수정
+    async fn update(
+        &self,
+        id: i64,
+        changes: BoardUpdate,
+        expected_version: i64,
+    ) -> Result<bool, StoreError> {
+        info!(
+            "[Repo:SQLite] update 호출: id={}, title={}, expected_version={}",
+            id, changes.title, expected_version
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            // 쓰기 잠금을 먼저 잡고 수정 전 내용을 읽은 뒤, 게시글과 카테고리/태그를 수정하고 읽은 내용을 이력으로 보관합니다.
+            let tx = conn
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_BOARD_FOR_UPDATE.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, version={}",
+                id, expected_version
+            );
+            let previous = tx
+                .query_row(
+                    SELECT_BOARD_FOR_UPDATE,
+                    named_params! { ":id": id, ":version": expected_version },
+                    |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?)),
+                )
+                .optional()
+                .map_err(map_sqlite_err)?;
+            let Some((previous_title, previous_content)) = previous else {
+                warn!(
+                    "[Repo:SQLite] 수정할 게시글 없음 또는 버전 불일치: id={}, version={}",
+                    id, expected_version
+                );
+                return Ok(false);
+            };
+
+            debug!("[Repo:SQLite][SQL] {}", UPDATE_BOARD.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, title={}, content_len={}, format={:?}, version={}",
+                id,
+                changes.title,
+                changes.content.chars().count(),
+                changes.format,
+                expected_version
+            );
+            let rows_affected = tx
+                .execute(
+                    UPDATE_BOARD,
+                    named_params! {
+                        ":title": changes.title,
+                        ":content": changes.content,
+                        ":format": changes.format.map(ContentFormat::as_str),
+                        ":id": id,
+                        ":version": expected_version,
+                    },
+                )
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!(
+                    "[Repo:SQLite] 수정할 게시글 없음 또는 버전 불일치: id={}, version={}",
+                    id, expected_version
+                );
+                return Ok(false);
+            }
+
+            debug!("[Repo:SQLite][SQL] {}", INSERT_BOARD_REVISION.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, version={}",
+                id, expected_version
+            );
+            tx.execute(
+                INSERT_BOARD_REVISION,
+                named_params! {
+                    ":id": id,
+                    ":title": previous_title,
+                    ":content": previous_content,
+                    ":version": expected_version,
+                },
+            )
+            .map_err(map_sqlite_err)?;
+
+            if let Some(category_id) = changes.category_id {
+                debug!("[Repo:SQLite][SQL] {}", UPDATE_BOARD_CATEGORY.trim());
+                debug!(
+                    "[Repo:SQLite][BIND] id={}, category_id={:?}",
+                    id, category_id
+                );
+                tx.execute(
+                    UPDATE_BOARD_CATEGORY,
+                    named_params! { ":category_id": category_id, ":id": id },
+                )
+                .map_err(map_sqlite_err)?;
+            }
+            if let Some(tags) = &changes.tags {
+                Self::replace_tags(&tx, id, tags).map_err(map_sqlite_err)?;
+            }
+            tx.commit().map_err(map_sqlite_err)?;
+            Ok(true)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글을 휴지통으로 이동
+    async fn delete(&self, id: i64, expected_version: i64) -> Result<bool, StoreError> {
+        info!(
+            "[Repo:SQLite] delete 호출: id={}, expected_version={}",
+            id, expected_version
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SOFT_DELETE_BOARD.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, version={}",
+                id, expected_version
+            );
+            let rows_affected = conn
+                .execute(
+                    SOFT_DELETE_BOARD,
+                    named_params! { ":id": id, ":version": expected_version },
+                )
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!(
+                    "[Repo:SQLite] 삭제할 게시글 없음 또는 버전 불일치: id={}, version={}",
+                    id, expected_version
+                );
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 작성자의 휴지통에 있는 게시글 수 조회
+    async fn count_trash(&self, author_id: i64) -> Result<u32, StoreError> {
+        info!("[Repo:SQLite] count_trash 호출: author_id={}", author_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_TRASH_COUNT.trim());
+            debug!("[Repo:SQLite][BIND] author_id={}", author_id);
+            conn.query_row(
+                SELECT_TRASH_COUNT,
+                named_params! { ":author_id": author_id },
+                |row| row.get::<_, u32>(0),
+            )
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 작성자의 휴지통 목록 조회
+    async fn find_trash(
+        &self,
+        author_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TrashedBoard>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_trash 호출: author_id={}, offset={}, limit={}",
+            author_id, offset, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_TRASH_PAGED.trim());
+            debug!(
+                "[Repo:SQLite][BIND] author_id={}, offset={}, limit={}",
+                author_id, offset, limit
+            );
+            let mut stmt = conn.prepare(SELECT_TRASH_PAGED).map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! { ":author_id": author_id, ":offset": offset, ":limit": limit },
+                    Self::row_to_trashed_board,
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// ID로 휴지통에 있는 게시글 조회
+    async fn find_trashed_by_id(&self, id: i64) -> Result<Option<Board>, StoreError> {
+        info!("[Repo:SQLite] find_trashed_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_TRASHED_BOARD_BY_ID.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            let board = conn
+                .query_row(
+                    SELECT_TRASHED_BOARD_BY_ID,
+                    named_params! { ":id": id },
+                    Self::row_to_board,
+                )
+                .optional()
+                .map_err(map_sqlite_err)?;
+            Self::with_tags(&conn, board).map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 휴지통의 게시글 복원
+    async fn restore(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo:SQLite] restore 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", RESTORE_BOARD.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            let rows_affected = conn
+                .execute(RESTORE_BOARD, named_params! { ":id": id })
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!("[Repo:SQLite] 복원할 게시글이 휴지통에 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 수정 이력 목록 조회
+    async fn find_revisions(&self, board_id: i64) -> Result<Vec<BoardRevision>, StoreError> {
+        info!("[Repo:SQLite] find_revisions 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_BOARD_REVISIONS.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}", board_id);
+            let mut stmt = conn
+                .prepare(SELECT_BOARD_REVISIONS)
+                .map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! { ":board_id": board_id },
+                    Self::row_to_revision,
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 특정 이력 번호 조회
+    async fn find_revision(
+        &self,
+        board_id: i64,
+        revision: i64,
+    ) -> Result<Option<BoardRevision>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_revision 호출: board_id={}, revision={}",
+            board_id, revision
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_BOARD_REVISION.trim());
+            debug!(
+                "[Repo:SQLite][BIND] board_id={}, revision={}",
+                board_id, revision
+            );
+            conn.query_row(
+                SELECT_BOARD_REVISION,
+                named_params! { ":board_id": board_id, ":revision": revision },
+                Self::row_to_revision,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 보존 기간이 지난 휴지통 게시글 영구 삭제
+    async fn purge_expired(&self, retention_days: u32) -> Result<Vec<i64>, StoreError> {
+        info!(
+            "[Repo:SQLite] purge_expired 호출: retention_days={}",
+            retention_days
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            // 대상 조회부터 댓글/첨부파일/이력/태그/좋아요/조회 기록과 게시글 삭제까지 한 트랜잭션으로 커밋합니다.
+            let tx = conn.transaction().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_EXPIRED_TRASH_IDS.trim());
+            debug!("[Repo:SQLite][BIND] retention_days={}", retention_days);
+            let ids = {
+                let mut stmt = tx
+                    .prepare(SELECT_EXPIRED_TRASH_IDS)
+                    .map_err(map_sqlite_err)?;
+                let rows = stmt
+                    .query_map(named_params! { ":retention_days": retention_days }, |row| {
+                        row.get::<_, i64>(0)
+                    })
+                    .map_err(map_sqlite_err)?;
+                rows.collect::<rusqlite::Result<Vec<_>>>()
+                    .map_err(map_sqlite_err)?
+            };
+
+            for id in &ids {
+                debug!("[Repo:SQLite][BIND] board_id={}", id);
+                tx.execute(DELETE_COMMENTS_BY_BOARD, named_params! { ":board_id": id })
+                    .map_err(map_sqlite_err)?;
+                tx.execute(
+                    DELETE_ATTACHMENTS_BY_BOARD,
+                    named_params! { ":board_id": id },
+                )
+                .map_err(map_sqlite_err)?;
+                tx.execute(DELETE_REVISIONS_BY_BOARD, named_params! { ":board_id": id })
+                    .map_err(map_sqlite_err)?;
+                tx.execute(DELETE_BOARD_TAGS, named_params! { ":board_id": id })
+                    .map_err(map_sqlite_err)?;
+                tx.execute(DELETE_BOARD_LIKES, named_params! { ":board_id": id })
+                    .map_err(map_sqlite_err)?;
+                tx.execute(DELETE_BOARD_VIEWS, named_params! { ":board_id": id })
+                    .map_err(map_sqlite_err)?;
+                tx.execute(DELETE_BOARD, named_params! { ":id": id })
+                    .map_err(map_sqlite_err)?;
+            }
+            tx.commit().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite] 영구 삭제된 게시글: {:?}", ids);
+            Ok(ids)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 태그별 게시글 수 조회
+    async fn find_tag_counts(&self) -> Result<Vec<TagCount>, StoreError> {
+        info!("[Repo:SQLite] find_tag_counts 호출");
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_TAG_COUNTS.trim());
+            let mut stmt = conn.prepare(SELECT_TAG_COUNTS).map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok(TagCount {
+                        name: row.get("NAME")?,
+                        board_count: row.get("BOARD_COUNT")?,
+                    })
+                })
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 모아 둔 조회수를 한 트랜잭션으로 반영
+    async fn add_views(&self, views: Vec<(i64, u64)>) -> Result<(), StoreError> {
+        info!("[Repo:SQLite] add_views 호출: boards={}", views.len());
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            let tx = conn.transaction().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite][SQL] {}", UPDATE_BOARD_VIEW_COUNT.trim());
+            debug!("[Repo:SQLite][SQL] {}", UPSERT_BOARD_VIEWS_DAILY.trim());
+            for (id, count) in &views {
+                let count = i64::try_from(*count).unwrap_or(i64::MAX);
+                debug!("[Repo:SQLite][BIND] id={}, views={}", id, count);
+                tx.execute(
+                    UPDATE_BOARD_VIEW_COUNT,
+                    named_params! { ":views": count, ":id": id },
+                )
+                .map_err(map_sqlite_err)?;
+                tx.execute(
+                    UPSERT_BOARD_VIEWS_DAILY,
+                    named_params! { ":board_id": id, ":views": count },
+                )
+                .map_err(map_sqlite_err)?;
+            }
+            tx.commit().map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 좋아요 기록 (이미 있으면 무시)
+    async fn add_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        info!(
+            "[Repo:SQLite] add_like 호출: board_id={}, liker={}",
+            board_id, liker
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", INSERT_BOARD_LIKE.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}, liker={}", board_id, liker);
+            conn.execute(
+                INSERT_BOARD_LIKE,
+                named_params! { ":board_id": board_id, ":liker": liker },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 좋아요 취소 (없으면 무시)
+    async fn remove_like(&self, board_id: i64, liker: String) -> Result<(), StoreError> {
+        info!(
+            "[Repo:SQLite] remove_like 호출: board_id={}, liker={}",
+            board_id, liker
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", DELETE_BOARD_LIKE.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}, liker={}", board_id, liker);
+            conn.execute(
+                DELETE_BOARD_LIKE,
+                named_params! { ":board_id": board_id, ":liker": liker },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 좋아요 수 조회
+    async fn count_likes(&self, board_id: i64) -> Result<i64, StoreError> {
+        info!("[Repo:SQLite] count_likes 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_BOARD_LIKE_COUNT.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}", board_id);
+            conn.query_row(
+                SELECT_BOARD_LIKE_COUNT,
+                named_params! { ":board_id": board_id },
+                |row| row.get::<_, i64>(0),
+            )
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 기간 안의 조회수/좋아요 점수 순으로 인기 게시글 조회
+    async fn find_popular(
+        &self,
+        days: u32,
+        view_weight: i64,
+        like_weight: i64,
+        limit: u32,
+    ) -> Result<Vec<PopularBoard>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_popular 호출: days={}, view_weight={}, like_weight={}, limit={}",
+            days, view_weight, like_weight, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_POPULAR_BOARDS.trim());
+            debug!(
+                "[Repo:SQLite][BIND] days={}, view_weight={}, like_weight={}, limit={}",
+                days, view_weight, like_weight, limit
+            );
+            let mut stmt = conn
+                .prepare(SELECT_POPULAR_BOARDS)
+                .map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! {
+                        ":days": days,
+                        ":view_weight": view_weight,
+                        ":like_weight": like_weight,
+                        ":limit": limit,
+                    },
+                    |row| {
+                        Ok(PopularBoard {
+                            id: row.get("ID")?,
+                            title: row.get::<_, Option<String>>("TITLE")?.unwrap_or_default(),
+                            author_id: row.get("AUTHOR_ID")?,
+                            category_id: row.get("CATEGORY_ID")?,
+                            view_count: row.get("VIEW_COUNT")?,
+                            window_views: row.get("WINDOW_VIEWS")?,
+                            window_likes: row.get("WINDOW_LIKES")?,
+                            score: row.get("SCORE")?,
+                            created_at: row.get("CREATED_AT")?,
+                        })
+                    },
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+}
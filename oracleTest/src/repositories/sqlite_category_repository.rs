@@ -0,0 +1,192 @@
+//! Repository 계층 (SQLite): 카테고리 테이블 작업
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::sqlite::{
+    CLEAR_BOARD_CATEGORY, DELETE_CATEGORY, INSERT_CATEGORY, SELECT_CATEGORIES,
+    SELECT_CATEGORY_BY_ID, SELECT_CATEGORY_BY_NAME, UPDATE_CATEGORY,
+};
+use crate::models::category::Category;
+use crate::repositories::category_store::CategoryStore;
+use crate::repositories::sqlite::{map_join_err, map_pool_err, map_sqlite_err, parse_timestamp};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{OptionalExtension, Row, named_params};
+use tracing::{debug, info, warn};
+
+/// SQLite 기반 카테고리 데이터베이스 접근 객체 (DAO).
+pub struct SqliteCategoryRepository {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl SqliteCategoryRepository {
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Category 구조체로 변환하는 헬퍼 함수.
+    fn row_to_category(row: &Row) -> rusqlite::Result<Category> {
+        Ok(Category {
+            id: row.get("ID")?,
+            name: row.get("NAME")?,
+            description: row.get("DESCRIPTION")?,
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl CategoryStore for SqliteCategoryRepository {
+    /// 전체 카테고리 조회
+    async fn find_all(&self) -> Result<Vec<Category>, StoreError> {
+        info!("[Repo:SQLite] category find_all 호출");
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_CATEGORIES.trim());
+            let mut stmt = conn.prepare(SELECT_CATEGORIES).map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map([], Self::row_to_category)
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// ID로 단일 카테고리 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Category>, StoreError> {
+        info!("[Repo:SQLite] category find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_CATEGORY_BY_ID.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            conn.query_row(
+                SELECT_CATEGORY_BY_ID,
+                named_params! { ":id": id },
+                Self::row_to_category,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 이름으로 단일 카테고리 조회
+    async fn find_by_name(&self, name: String) -> Result<Option<Category>, StoreError> {
+        info!("[Repo:SQLite] category find_by_name 호출: name={}", name);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_CATEGORY_BY_NAME.trim());
+            debug!("[Repo:SQLite][BIND] name={}", name);
+            conn.query_row(
+                SELECT_CATEGORY_BY_NAME,
+                named_params! { ":name": name },
+                Self::row_to_category,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 새 카테고리 추가 후 생성된 ID 반환
+    async fn insert(&self, name: String, description: Option<String>) -> Result<i64, StoreError> {
+        info!("[Repo:SQLite] category insert 호출: name={}", name);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", INSERT_CATEGORY.trim());
+            debug!(
+                "[Repo:SQLite][BIND] name={}, description={:?}",
+                name, description
+            );
+            conn.execute(
+                INSERT_CATEGORY,
+                named_params! { ":name": name, ":description": description },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(conn.last_insert_rowid())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 카테고리 이름/설명 수정
+    async fn update(
+        &self,
+        id: i64,
+        name: String,
+        description: Option<String>,
+    ) -> Result<bool, StoreError> {
+        info!(
+            "[Repo:SQLite] category update 호출: id={}, name={}",
+            id, name
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", UPDATE_CATEGORY.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, name={}, description={:?}",
+                id, name, description
+            );
+            let rows_affected = conn
+                .execute(
+                    UPDATE_CATEGORY,
+                    named_params! { ":name": name, ":description": description, ":id": id },
+                )
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!("[Repo:SQLite] 수정할 카테고리 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 카테고리 지정을 해제한 뒤 카테고리 삭제 (한 트랜잭션)
+    async fn delete(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo:SQLite] category delete 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get().map_err(map_pool_err)?;
+            // 트랜잭션은 커밋하지 않고 버려지면 롤백됩니다.
+            let tx = conn.transaction().map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite][SQL] {}", CLEAR_BOARD_CATEGORY.trim());
+            debug!("[Repo:SQLite][BIND] category_id={}", id);
+            tx.execute(CLEAR_BOARD_CATEGORY, named_params! { ":category_id": id })
+                .map_err(map_sqlite_err)?;
+            debug!("[Repo:SQLite][SQL] {}", DELETE_CATEGORY.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            let rows_affected = tx
+                .execute(DELETE_CATEGORY, named_params! { ":id": id })
+                .map_err(map_sqlite_err)?;
+            tx.commit().map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!("[Repo:SQLite] 삭제할 카테고리 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+}
@@ -0,0 +1,253 @@
+//! Repository 계층 (SQLite): 댓글 테이블 작업
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::sqlite::{
+    DELETE_COMMENT, INSERT_COMMENT, SELECT_COMMENT_BY_ID, SELECT_COMMENT_REPLIES,
+    SELECT_COMMENT_ROOT_COUNT, SELECT_COMMENT_ROOTS_PAGED, UPDATE_COMMENT,
+};
+use crate::models::comment::Comment;
+use crate::repositories::comment_store::CommentStore;
+use crate::repositories::sqlite::{map_join_err, map_pool_err, map_sqlite_err, parse_timestamp};
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{OptionalExtension, Row, named_params};
+use tracing::{debug, info, warn};
+
+/// SQLite 기반 댓글 데이터베이스 접근 객체 (DAO).
+pub struct SqliteCommentRepository {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl SqliteCommentRepository {
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 Comment 구조체로 변환하는 헬퍼 함수.
+    fn row_to_comment(row: &Row) -> rusqlite::Result<Comment> {
+        Ok(Comment {
+            id: row.get("ID")?,
+            board_id: row.get("BOARD_ID")?,
+            parent_id: row.get("PARENT_ID")?,
+            author_id: row.get("AUTHOR_ID")?,
+            content: row.get::<_, Option<String>>("CONTENT")?.unwrap_or_default(),
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl CommentStore for SqliteCommentRepository {
+    /// 게시글의 루트 댓글 수 조회
+    async fn count_roots(&self, board_id: i64) -> Result<u32, StoreError> {
+        info!("[Repo:SQLite] count_roots 호출: board_id={}", board_id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_COMMENT_ROOT_COUNT.trim());
+            debug!("[Repo:SQLite][BIND] board_id={}", board_id);
+            conn.query_row(
+                SELECT_COMMENT_ROOT_COUNT,
+                named_params! { ":board_id": board_id },
+                |row| row.get::<_, u32>(0),
+            )
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 게시글의 루트 댓글을 작성 순으로 페이지네이션 조회
+    async fn find_roots_paged(
+        &self,
+        board_id: i64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Comment>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_roots_paged 호출: board_id={}, offset={}, limit={}",
+            board_id, offset, limit
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_COMMENT_ROOTS_PAGED.trim());
+            debug!(
+                "[Repo:SQLite][BIND] board_id={}, offset={}, limit={}",
+                board_id, offset, limit
+            );
+            let mut stmt = conn
+                .prepare(SELECT_COMMENT_ROOTS_PAGED)
+                .map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! { ":board_id": board_id, ":offset": offset, ":limit": limit },
+                    Self::row_to_comment,
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 루트 댓글 ID 범위에 달린 답글 조회
+    async fn find_replies(
+        &self,
+        board_id: i64,
+        first_parent_id: i64,
+        last_parent_id: i64,
+    ) -> Result<Vec<Comment>, StoreError> {
+        info!(
+            "[Repo:SQLite] find_replies 호출: board_id={}, parent_id={}..={}",
+            board_id, first_parent_id, last_parent_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_COMMENT_REPLIES.trim());
+            debug!(
+                "[Repo:SQLite][BIND] board_id={}, first_parent_id={}, last_parent_id={}",
+                board_id, first_parent_id, last_parent_id
+            );
+            let mut stmt = conn
+                .prepare(SELECT_COMMENT_REPLIES)
+                .map_err(map_sqlite_err)?;
+            let rows = stmt
+                .query_map(
+                    named_params! {
+                        ":board_id": board_id,
+                        ":first_parent_id": first_parent_id,
+                        ":last_parent_id": last_parent_id,
+                    },
+                    Self::row_to_comment,
+                )
+                .map_err(map_sqlite_err)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// ID로 단일 댓글 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<Comment>, StoreError> {
+        info!("[Repo:SQLite] comment find_by_id 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_COMMENT_BY_ID.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            conn.query_row(
+                SELECT_COMMENT_BY_ID,
+                named_params! { ":id": id },
+                Self::row_to_comment,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 새 댓글 추가 후 생성된 ID 반환
+    async fn insert(
+        &self,
+        board_id: i64,
+        parent_id: Option<i64>,
+        author_id: i64,
+        content: String,
+    ) -> Result<i64, StoreError> {
+        info!(
+            "[Repo:SQLite] comment insert 호출: board_id={}, parent_id={:?}",
+            board_id, parent_id
+        );
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", INSERT_COMMENT.trim());
+            debug!(
+                "[Repo:SQLite][BIND] board_id={}, parent_id={:?}, author_id={}, content_len={}",
+                board_id,
+                parent_id,
+                author_id,
+                content.chars().count()
+            );
+            conn.execute(
+                INSERT_COMMENT,
+                named_params! {
+                    ":board_id": board_id,
+                    ":parent_id": parent_id,
+                    ":author_id": author_id,
+                    ":content": content,
+                },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(conn.last_insert_rowid())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 댓글 내용 수정
+    async fn update(&self, id: i64, content: String) -> Result<bool, StoreError> {
+        info!("[Repo:SQLite] comment update 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", UPDATE_COMMENT.trim());
+            debug!(
+                "[Repo:SQLite][BIND] id={}, content_len={}",
+                id,
+                content.chars().count()
+            );
+            let rows_affected = conn
+                .execute(
+                    UPDATE_COMMENT,
+                    named_params! { ":content": content, ":id": id },
+                )
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!("[Repo:SQLite] 수정할 댓글 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 댓글과 그 답글 삭제
+    async fn delete(&self, id: i64) -> Result<bool, StoreError> {
+        info!("[Repo:SQLite] comment delete 호출: id={}", id);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", DELETE_COMMENT.trim());
+            debug!("[Repo:SQLite][BIND] id={}", id);
+            let rows_affected = conn
+                .execute(DELETE_COMMENT, named_params! { ":id": id })
+                .map_err(map_sqlite_err)?;
+
+            if rows_affected == 0 {
+                warn!("[Repo:SQLite] 삭제할 댓글 없음: id={}", id);
+            }
+
+            Ok(rows_affected > 0)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+}
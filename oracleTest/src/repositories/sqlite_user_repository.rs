@@ -0,0 +1,80 @@
+//! Repository 계층 (SQLite): 사용자 테이블 작업
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::sqlite::{INSERT_USER, SELECT_USER_BY_USERNAME};
+use crate::models::user::User;
+use crate::repositories::sqlite::{map_join_err, map_pool_err, map_sqlite_err, parse_timestamp};
+use crate::repositories::store_error::StoreError;
+use crate::repositories::user_store::UserStore;
+use async_trait::async_trait;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{OptionalExtension, Row, named_params};
+use tracing::{debug, info};
+
+/// SQLite 기반 사용자 데이터베이스 접근 객체 (DAO).
+pub struct SqliteUserRepository {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl SqliteUserRepository {
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 User 구조체로 변환하는 헬퍼 함수.
+    fn row_to_user(row: &Row) -> rusqlite::Result<User> {
+        Ok(User {
+            id: row.get("ID")?,
+            username: row.get("USERNAME")?,
+            password_hash: row.get("PASSWORD_HASH")?,
+            created_at: parse_timestamp(row, "CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl UserStore for SqliteUserRepository {
+    /// 새 사용자 추가 후 생성된 ID 반환
+    async fn insert(&self, username: String, password_hash: String) -> Result<i64, StoreError> {
+        info!("[Repo:SQLite] user insert 호출: username={}", username);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", INSERT_USER.trim());
+            // 비밀번호 해시는 로그에 남기지 않습니다.
+            debug!("[Repo:SQLite][BIND] username={}", username);
+            conn.execute(
+                INSERT_USER,
+                named_params! { ":username": username, ":password_hash": password_hash },
+            )
+            .map_err(map_sqlite_err)?;
+            Ok(conn.last_insert_rowid())
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+
+    /// 사용자명으로 단일 사용자 조회
+    async fn find_by_username(&self, username: String) -> Result<Option<User>, StoreError> {
+        info!("[Repo:SQLite] find_by_username 호출: username={}", username);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get().map_err(map_pool_err)?;
+            debug!("[Repo:SQLite][SQL] {}", SELECT_USER_BY_USERNAME.trim());
+            debug!("[Repo:SQLite][BIND] username={}", username);
+            conn.query_row(
+                SELECT_USER_BY_USERNAME,
+                named_params! { ":username": username },
+                Self::row_to_user,
+            )
+            .optional()
+            .map_err(map_sqlite_err)
+        })
+        .await
+        .map_err(map_join_err)?
+    }
+}
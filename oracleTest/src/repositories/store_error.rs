@@ -0,0 +1,75 @@
+//! 저장소 에러: 저장소 백엔드(Oracle, SQLite)에 독립적인 에러 타입
+
+use std::fmt;
+
+/// 저장소 트레이트(`BoardStore`, `UserStore` 등)가 반환하는 에러.
+///
+/// 각 백엔드는 드라이버 에러를 이 타입으로 매핑하므로, 서비스 계층은 드라이버 종류와 관계없이
+/// 고유 제약 위반(`Conflict`)과 그 밖의 장애(`Other`)를 구분할 수 있습니다.
+#[derive(Debug)]
+pub enum StoreError {
+    /// 고유 제약 위반 (Oracle ORA-00001, SQLite `UNIQUE`/`PRIMARY KEY`)
+    Conflict(String),
+    /// 반드시 있어야 하는 행이 없음 (단건 조회 결과 없음)
+    NotFound,
+    /// 연결, 풀, 작업 실행 등 그 밖의 DB 오류
+    Other(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Conflict(msg) => write!(f, "고유 제약 위반: {msg}"),
+            StoreError::NotFound => write!(f, "조회 결과가 없습니다."),
+            StoreError::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// `oracle::Error`를 `StoreError`로 변환 (ORA-00001은 `Conflict`)
+impl From<oracle::Error> for StoreError {
+    fn from(err: oracle::Error) -> Self {
+        match &err {
+            oracle::Error::OciError(db) | oracle::Error::DpiError(db) if db.code() == 1 => {
+                StoreError::Conflict(err.to_string())
+            }
+            oracle::Error::NoDataFound => StoreError::NotFound,
+            _ => StoreError::Other(err.to_string()),
+        }
+    }
+}
+
+/// `rusqlite::Error`를 `StoreError`로 변환 (`UNIQUE`/`PRIMARY KEY` 제약 위반은 `Conflict`)
+impl From<rusqlite::Error> for StoreError {
+    fn from(err: rusqlite::Error) -> Self {
+        match &err {
+            rusqlite::Error::SqliteFailure(sqlite_err, _)
+                if matches!(
+                    sqlite_err.extended_code,
+                    rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE
+                        | rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+                ) =>
+            {
+                StoreError::Conflict(err.to_string())
+            }
+            rusqlite::Error::QueryReturnedNoRows => StoreError::NotFound,
+            _ => StoreError::Other(err.to_string()),
+        }
+    }
+}
+
+/// `r2d2::Error`(커넥션 대기 시간 초과 등)를 `StoreError`로 변환
+impl From<r2d2::Error> for StoreError {
+    fn from(err: r2d2::Error) -> Self {
+        StoreError::Other(err.to_string())
+    }
+}
+
+/// `tokio::task::JoinError`(블로킹 작업 패닉/취소)를 `StoreError`로 변환
+impl From<tokio::task::JoinError> for StoreError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        StoreError::Other(err.to_string())
+    }
+}
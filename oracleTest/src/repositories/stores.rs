@@ -0,0 +1,162 @@
+//! 저장소 백엔드별 구현체 묶음: 하나의 커넥션 풀로 모든 저장소를 생성합니다.
+
+use crate::common::metrics::spawn_blocking;
+use crate::repositories::attachment_repository::AttachmentRepository;
+use crate::repositories::attachment_store::AttachmentStore;
+use crate::repositories::board_repository::BoardRepository;
+use crate::repositories::board_store::BoardStore;
+use crate::repositories::cached_board_store::{
+    BoardCache, CacheInvalidatingCategoryStore, CachedBoardStore,
+};
+use crate::repositories::category_repository::CategoryRepository;
+use crate::repositories::category_store::CategoryStore;
+use crate::repositories::comment_repository::CommentRepository;
+use crate::repositories::comment_store::CommentStore;
+use crate::repositories::sqlite_attachment_repository::SqliteAttachmentRepository;
+use crate::repositories::sqlite_board_repository::SqliteBoardRepository;
+use crate::repositories::sqlite_category_repository::SqliteCategoryRepository;
+use crate::repositories::sqlite_comment_repository::SqliteCommentRepository;
+use crate::repositories::sqlite_user_repository::SqliteUserRepository;
+use crate::repositories::user_repository::UserRepository;
+use crate::repositories::user_store::UserStore;
+use r2d2::Pool;
+use r2d2_oracle::OracleConnectionManager;
+use r2d2_sqlite::SqliteConnectionManager;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 저장소들이 공유하는 커넥션 풀 (백엔드별)
+#[derive(Clone)]
+pub enum DbPool {
+    Oracle(Pool<OracleConnectionManager>),
+    Sqlite(Pool<SqliteConnectionManager>),
+}
+
+impl DbPool {
+    /// 백엔드 이름 (`oracle`, `sqlite`)
+    pub fn backend(&self) -> &'static str {
+        match self {
+            DbPool::Oracle(_) => "oracle",
+            DbPool::Sqlite(_) => "sqlite",
+        }
+    }
+
+    /// 현재 풀 상태 (전체/유휴 커넥션 수)
+    pub fn state(&self) -> r2d2::State {
+        match self {
+            DbPool::Oracle(pool) => pool.state(),
+            DbPool::Sqlite(pool) => pool.state(),
+        }
+    }
+
+    /// 풀의 최대 커넥션 수
+    pub fn max_size(&self) -> u32 {
+        match self {
+            DbPool::Oracle(pool) => pool.max_size(),
+            DbPool::Sqlite(pool) => pool.max_size(),
+        }
+    }
+
+    /// 풀에서 커넥션을 하나 꺼내 DB 왕복(ping)을 수행하고 걸린 시간을 반환합니다.
+    ///
+    /// 커넥션 대기와 ping을 합쳐 `timeout` 안에 끝나지 않으면 실패로 봅니다.
+    pub async fn ping(&self, timeout: Duration) -> Result<Duration, String> {
+        let pool = self.clone();
+        let started_at = Instant::now();
+        let task = spawn_blocking(move || -> Result<(), String> {
+            match &pool {
+                DbPool::Oracle(pool) => {
+                    let conn = pool.get_timeout(timeout).map_err(|err| err.to_string())?;
+                    conn.ping().map_err(|err| err.to_string())
+                }
+                DbPool::Sqlite(pool) => {
+                    let conn = pool.get_timeout(timeout).map_err(|err| err.to_string())?;
+                    conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+                        .map(|_| ())
+                        .map_err(|err| err.to_string())
+                }
+            }
+        });
+
+        match tokio::time::timeout(timeout, task).await {
+            Ok(Ok(Ok(()))) => Ok(started_at.elapsed()),
+            Ok(Ok(Err(err))) => Err(err),
+            Ok(Err(join_err)) => Err(join_err.to_string()),
+            Err(_) => Err(format!("DB ping 시간 초과 ({}ms)", timeout.as_millis())),
+        }
+    }
+
+    /// 사용 중인 커넥션이 모두 반환되기를 최대 `timeout` 동안 기다린 뒤 풀을 닫습니다.
+    ///
+    /// r2d2에는 명시적인 종료 API가 없어 마지막 풀 참조가 드롭될 때 유휴 커넥션이 닫힙니다.
+    /// 따라서 풀을 들고 있는 저장소/서비스를 먼저 모두 드롭한 뒤 호출해야 합니다.
+    /// 닫기 직전의 풀 상태를 반환합니다.
+    pub async fn close(self, timeout: Duration) -> r2d2::State {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let state = self.state();
+            if state.idle_connections >= state.connections || Instant::now() >= deadline {
+                drop(self);
+                return state;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+}
+
+/// 서비스 계층에 주입할 저장소 트레이트 객체 모음
+#[derive(Clone)]
+pub struct Stores {
+    pub boards: Arc<dyn BoardStore>,
+    pub categories: Arc<dyn CategoryStore>,
+    pub users: Arc<dyn UserStore>,
+    pub comments: Arc<dyn CommentStore>,
+    pub attachments: Arc<dyn AttachmentStore>,
+    /// 저장소들이 공유하는 커넥션 풀 (메트릭/상태 확인용)
+    pub pool: DbPool,
+    /// 게시글 조회 캐시 (`with_board_cache`로 켠 경우에만, 적중률 확인용)
+    pub board_cache: Option<Arc<BoardCache>>,
+}
+
+impl Stores {
+    /// Oracle 커넥션 풀 기반 저장소들을 생성합니다.
+    pub fn oracle(pool: Pool<OracleConnectionManager>) -> Self {
+        Self {
+            boards: Arc::new(BoardRepository::new(pool.clone())),
+            categories: Arc::new(CategoryRepository::new(pool.clone())),
+            users: Arc::new(UserRepository::new(pool.clone())),
+            comments: Arc::new(CommentRepository::new(pool.clone())),
+            attachments: Arc::new(AttachmentRepository::new(pool.clone())),
+            pool: DbPool::Oracle(pool),
+            board_cache: None,
+        }
+    }
+
+    /// SQLite 커넥션 풀 기반 저장소들을 생성합니다.
+    pub fn sqlite(pool: Pool<SqliteConnectionManager>) -> Self {
+        Self {
+            boards: Arc::new(SqliteBoardRepository::new(pool.clone())),
+            categories: Arc::new(SqliteCategoryRepository::new(pool.clone())),
+            users: Arc::new(SqliteUserRepository::new(pool.clone())),
+            comments: Arc::new(SqliteCommentRepository::new(pool.clone())),
+            attachments: Arc::new(SqliteAttachmentRepository::new(pool.clone())),
+            pool: DbPool::Sqlite(pool),
+            board_cache: None,
+        }
+    }
+
+    /// 게시글 저장소 앞에 조회 캐시를 둡니다. (종류별 최대 `capacity`개, `ttl` 동안 보관)
+    /// 카테고리 삭제도 게시글을 바꾸므로 카테고리 저장소는 삭제 시 캐시를 비우도록 감쌉니다.
+    pub fn with_board_cache(self, capacity: usize, ttl: Duration) -> Self {
+        let cache = Arc::new(BoardCache::new(capacity, ttl));
+        Self {
+            boards: Arc::new(CachedBoardStore::new(self.boards, cache.clone())),
+            categories: Arc::new(CacheInvalidatingCategoryStore::new(
+                self.categories,
+                cache.clone(),
+            )),
+            board_cache: Some(cache),
+            ..self
+        }
+    }
+}
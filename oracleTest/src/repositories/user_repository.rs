@@ -0,0 +1,93 @@
+//! Repository 계층: 사용자 테이블 작업 (Oracle)
+
+use crate::common::metrics::spawn_blocking;
+use crate::common::queries::{INSERT_USER, SELECT_USER_BY_USERNAME, SELECT_USER_SEQ_CURRVAL};
+use crate::models::user::User;
+use crate::repositories::store_error::StoreError;
+use crate::repositories::user_store::UserStore;
+use async_trait::async_trait;
+use oracle::Row;
+use oracle::sql_type::ToSql;
+use r2d2::Pool;
+use r2d2_oracle::OracleConnectionManager;
+use tracing::{debug, info};
+
+/// 사용자 데이터베이스 접근 객체 (DAO).
+pub struct UserRepository {
+    pool: Pool<OracleConnectionManager>,
+}
+
+impl UserRepository {
+    /// `r2d2::Error`를 `oracle::Error`로 매핑하는 헬퍼 함수
+    fn map_pool_err(err: r2d2::Error) -> oracle::Error {
+        oracle::Error::InternalError(err.to_string())
+    }
+
+    /// `tokio::task::JoinError`를 `StoreError`로 매핑하는 헬퍼 함수
+    fn map_join_err(err: tokio::task::JoinError) -> StoreError {
+        StoreError::from(err)
+    }
+
+    /// 새로운 Repository 인스턴스 생성
+    pub fn new(pool: Pool<OracleConnectionManager>) -> Self {
+        Self { pool }
+    }
+
+    /// DB Row를 User 구조체로 변환하는 헬퍼 함수.
+    fn row_to_user(row: Row) -> Result<User, oracle::Error> {
+        Ok(User {
+            id: row.get("ID")?,
+            username: row.get("USERNAME")?,
+            password_hash: row.get("PASSWORD_HASH")?,
+            created_at: row.get("CREATED_AT")?,
+        })
+    }
+}
+
+#[async_trait]
+impl UserStore for UserRepository {
+    /// 새 사용자 추가 후 생성된 ID 반환
+    async fn insert(&self, username: String, password_hash: String) -> Result<i64, StoreError> {
+        info!("[Repo] user insert 호출: username={}", username);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+
+            let params: [(&str, &dyn ToSql); 2] =
+                [("username", &username), ("password_hash", &password_hash)];
+            debug!("[Repo][SQL] {}", INSERT_USER.trim());
+            // 비밀번호 해시는 로그에 남기지 않습니다.
+            debug!("[Repo][BIND] username={}", username);
+            conn.execute_named(INSERT_USER, &params)?;
+            // 트랜잭션 커밋
+            conn.commit()?;
+
+            debug!("[Repo][SQL] {}", SELECT_USER_SEQ_CURRVAL.trim());
+            conn.query_row_as::<i64>(SELECT_USER_SEQ_CURRVAL, &[])
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+
+    /// 사용자명으로 단일 사용자 조회
+    async fn find_by_username(&self, username: String) -> Result<Option<User>, StoreError> {
+        info!("[Repo] find_by_username 호출: username={}", username);
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || -> Result<_, oracle::Error> {
+            let conn = pool.get().map_err(Self::map_pool_err)?;
+            let params: [(&str, &dyn ToSql); 1] = [("username", &username)];
+            debug!("[Repo][SQL] {}", SELECT_USER_BY_USERNAME.trim());
+            debug!("[Repo][BIND] username={}", username);
+            let mut rows = conn.query_named(SELECT_USER_BY_USERNAME, &params)?;
+            rows.next()
+                .map(|row_result| Self::row_to_user(row_result?))
+                .transpose()
+        })
+        .await
+        .map_err(Self::map_join_err)?
+        .map_err(StoreError::from)
+    }
+}
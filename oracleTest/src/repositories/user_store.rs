@@ -0,0 +1,15 @@
+//! 사용자 저장소 추상화: 저장소 백엔드(Oracle, SQLite)에 독립적인 계약
+
+use crate::models::user::User;
+use crate::repositories::store_error::StoreError;
+use async_trait::async_trait;
+
+/// 사용자 저장소가 제공해야 하는 연산 집합.
+#[async_trait]
+pub trait UserStore: Send + Sync {
+    /// 새 사용자 추가 후 생성된 ID 반환
+    async fn insert(&self, username: String, password_hash: String) -> Result<i64, StoreError>;
+
+    /// 사용자명으로 단일 사용자 조회
+    async fn find_by_username(&self, username: String) -> Result<Option<User>, StoreError>;
+}
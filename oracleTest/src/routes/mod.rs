@@ -1,24 +1,185 @@
+pub mod openapi;
+pub mod static_files;
+
+use std::sync::Arc;
+use std::time::Duration;
+
 use axum::{
     Router,
+    extract::DefaultBodyLimit,
+    http::StatusCode,
+    middleware as axum_middleware,
     routing::{delete, get, post, put},
 };
+use tower_http::{compression::CompressionLayer, timeout::TimeoutLayer};
+
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
 use crate::{
     common::app_state::AppState,
+    config::Config,
+    controllers::attachment_controller::{
+        download_attachment, list_attachments, upload_attachment,
+    },
+    controllers::auth_controller::{login, register},
     controllers::board_controller::{
-        create_board, delete_board, get_board, list_boards, serve_index, update_board,
+        create_board, delete_board, export_boards, get_board, get_engagement, import_boards,
+        like_board, list_boards, list_trash, popular_boards, restore_board, unlike_board,
+        update_board,
+    },
+    controllers::category_controller::{
+        create_category, delete_category, get_category, list_categories, update_category,
     },
+    controllers::comment_controller::{
+        create_comment, delete_comment, list_comments, update_comment,
+    },
+    controllers::event_controller::{board_events_sse, board_events_ws},
+    controllers::health_controller::{healthz, readyz},
+    controllers::metrics_controller::get_metrics,
+    controllers::revision_controller::{
+        diff_revisions, get_revision, list_revisions, revert_revision,
+    },
+    controllers::tag_controller::list_tags,
+    middleware::cors::cors_layer,
+    middleware::logging::{HeaderRedaction, log_middleware},
+    middleware::metrics::track_metrics,
+    middleware::rate_limit::{RateLimitState, RateLimiter, rate_limit},
+    middleware::request_id::request_id,
+    middleware::security_headers::{SecurityHeaders, security_headers},
+    routes::openapi::ApiDoc,
+    routes::static_files::static_files,
 };
 
+/// 게시글 작성/수정 요청 본문 최대 크기 (본문 최대 길이의 UTF-8 바이트 수에 여유를 둔 값)
+pub const BOARD_BODY_LIMIT: usize = 256 * 1024;
+/// 게시글 가져오기 파일 최대 크기
+pub const IMPORT_BODY_LIMIT: usize = 8 * 1024 * 1024;
+/// 댓글 작성/수정 요청 본문 최대 크기
+pub const COMMENT_BODY_LIMIT: usize = 16 * 1024;
+/// 카테고리 생성/수정 요청 본문 최대 크기
+pub const CATEGORY_BODY_LIMIT: usize = 4 * 1024;
+/// 회원가입/로그인 요청 본문 최대 크기
+pub const AUTH_BODY_LIMIT: usize = 4 * 1024;
+
 pub fn api_routes() -> Router<AppState> {
     // 모든 API 라우트를 정의하고, 애플리케이션 상태(AppState)를 공유하는 라우터 인스턴스를 반환합니다.
     // 각 라우트는 특정 HTTP 메서드와 엔드포인트에 핸들러 함수를 매핑합니다.
     Router::new()
-        .route("/", get(serve_index)) // 루트 경로로 index.html 정적 파일을 서빙합니다.
-        .route("/index.html", get(serve_index)) // `/index.html` 경로로 index.html 정적 파일을 서빙합니다.
         .route("/boards", get(list_boards)) // 모든 게시글 목록을 페이지네이션으로 조회합니다.
-        .route("/boards", post(create_board)) // 새로운 게시글을 생성합니다.
+        .route(
+            "/boards",
+            post(create_board).layer(DefaultBodyLimit::max(BOARD_BODY_LIMIT)),
+        ) // 새로운 게시글을 생성합니다.
         .route("/boards/:id", get(get_board)) // 특정 ID의 게시글을 조회합니다.
-        .route("/boards/:id", put(update_board)) // 특정 ID의 게시글을 수정합니다.
-        .route("/boards/:id", delete(delete_board)) // 특정 ID의 게시글을 삭제합니다.
+        .route(
+            "/boards/:id",
+            put(update_board).layer(DefaultBodyLimit::max(BOARD_BODY_LIMIT)),
+        ) // 특정 ID의 게시글을 수정합니다.
+        .route("/boards/:id", delete(delete_board)) // 특정 ID의 게시글을 휴지통으로 옮깁니다.
+        .route("/boards/trash", get(list_trash)) // 로그인한 사용자의 휴지통 목록을 조회합니다.
+        .route("/boards/export", get(export_boards)) // 모든 게시글을 JSON Lines/CSV로 내보냅니다.
+        .route(
+            "/boards/import",
+            post(import_boards).layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT)),
+        ) // JSON Lines/CSV 파일로 게시글을 일괄 추가합니다.
+        .route("/boards/:id/restore", post(restore_board)) // 휴지통의 게시글을 복원합니다.
+        .route("/boards/events", get(board_events_sse)) // 게시글 생성/수정/삭제 이벤트를 SSE로 구독합니다.
+        .route("/boards/events/ws", get(board_events_ws)) // 같은 이벤트를 WebSocket으로 구독합니다.
+        .route("/boards/popular", get(popular_boards)) // 기간별 조회수/좋아요 점수 순 인기 게시글을 조회합니다.
+        .route("/boards/:id/engagement", get(get_engagement)) // 게시글의 조회수와 좋아요 수를 조회합니다 (조회수는 올리지 않음).
+        .route("/boards/:id/like", post(like_board)) // 게시글에 좋아요를 누릅니다 (사용자/IP당 한 번).
+        .route("/boards/:id/like", delete(unlike_board)) // 게시글의 좋아요를 취소합니다.
+        .route("/boards/:id/revisions", get(list_revisions)) // 게시글의 수정 이력 목록을 조회합니다.
+        .route("/boards/:id/revisions/diff", get(diff_revisions)) // 두 이력(또는 현재 글)을 줄 단위로 비교합니다.
+        .route("/boards/:id/revisions/:rev", get(get_revision)) // 특정 수정 이력을 조회합니다.
+        .route("/boards/:id/revisions/:rev/revert", post(revert_revision)) // 게시글을 특정 이력으로 되돌립니다.
+        .route("/boards/:id/comments", get(list_comments)) // 게시글의 댓글 목록(답글 포함)을 조회합니다.
+        .route(
+            "/boards/:id/comments",
+            post(create_comment).layer(DefaultBodyLimit::max(COMMENT_BODY_LIMIT)),
+        ) // 게시글에 댓글 또는 답글을 작성합니다.
+        .route(
+            "/comments/:id",
+            put(update_comment).layer(DefaultBodyLimit::max(COMMENT_BODY_LIMIT)),
+        ) // 특정 ID의 댓글을 수정합니다.
+        .route("/comments/:id", delete(delete_comment)) // 특정 ID의 댓글(과 답글)을 삭제합니다.
+        .route("/categories", get(list_categories)) // 전체 카테고리를 조회합니다.
+        .route(
+            "/categories",
+            post(create_category).layer(DefaultBodyLimit::max(CATEGORY_BODY_LIMIT)),
+        ) // 새 카테고리를 만듭니다.
+        .route("/categories/:id", get(get_category)) // 특정 ID의 카테고리를 조회합니다.
+        .route(
+            "/categories/:id",
+            put(update_category).layer(DefaultBodyLimit::max(CATEGORY_BODY_LIMIT)),
+        ) // 특정 ID의 카테고리를 수정합니다.
+        .route("/categories/:id", delete(delete_category)) // 카테고리를 삭제하고 게시글을 미분류로 바꿉니다.
+        .route("/tags", get(list_tags)) // 태그별 게시글 수를 조회합니다.
+        .route("/boards/:id/attachments", get(list_attachments)) // 게시글의 첨부파일 목록을 조회합니다.
+        // 라우트별 본문 크기 제한은 `main`에서 거는 전역 제한(`max_body_bytes`)보다 우선합니다.
+        // 업로드 크기 제한은 서비스 계층에서 스트리밍 중에 검사하므로 기본 본문 크기 제한(2MB)을 해제합니다.
+        .route(
+            "/boards/:id/attachments",
+            post(upload_attachment).layer(DefaultBodyLimit::disable()),
+        ) // 게시글에 파일을 업로드합니다.
+        .route("/attachments/:id", get(download_attachment)) // 첨부파일을 내려받습니다 (Range 지원).
+        .route(
+            "/auth/register",
+            post(register).layer(DefaultBodyLimit::max(AUTH_BODY_LIMIT)),
+        ) // 회원가입
+        .route(
+            "/auth/login",
+            post(login).layer(DefaultBodyLimit::max(AUTH_BODY_LIMIT)),
+        ) // 로그인 후 액세스 토큰 발급
+        .route("/metrics", get(get_metrics)) // Prometheus 메트릭을 텍스트 형식으로 노출합니다.
+        .route("/healthz", get(healthz)) // 프로세스 생존 여부 (liveness)
+        .route("/readyz", get(readyz)) // DB ping과 풀 상태로 요청 수용 가능 여부 확인 (readiness)
+        .merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", ApiDoc::openapi())) // OpenAPI 문서와 Swagger UI
+}
+
+/// API 라우트에 정적 파일 fallback과 미들웨어 스택을 얹고 상태를 주입한 애플리케이션 라우터를 만듭니다.
+///
+/// 서버(`main.rs`)와 통합 테스트가 같은 구성을 쓰도록 라우터 조립은 여기에서만 합니다.
+pub fn build_app(config: &Config, state: AppState) -> Router {
+    let mut api = api_routes();
+    if config.rate_limit_per_second > 0 {
+        // 빈도 제한은 API 라우트에만 겁니다. 페이지 하나를 열 때 받는 정적 파일(JS, CSS, 이미지)이
+        // API 요청 한도를 쓰지 않도록 아래의 정적 파일 fallback은 제한 밖에 둡니다.
+        // 로그와 메트릭에 429 응답도 남도록 로깅 미들웨어 안쪽에 둡니다.
+        let limiter = RateLimiter::new(config.rate_limit_per_second, config.rate_limit_burst);
+        api = api.layer(axum_middleware::from_fn_with_state(
+            Arc::new(RateLimitState::new(limiter, state.auth.clone())),
+            rate_limit,
+        ));
+    }
+    // 라우트에 없는 경로는 정적 파일 디렉터리에서 찾고, 브라우저의 페이지 요청이면 index.html로 돌려줍니다.
+    let app = api
+        .fallback_service(static_files(
+            &config.static_dir,
+            Duration::from_secs(config.static_max_age_secs),
+        ))
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::REQUEST_TIMEOUT,
+            Duration::from_secs(config.request_timeout_secs),
+        )) // 처리 시간 제한을 넘긴 요청은 408로 끝냅니다.
+        .layer(DefaultBodyLimit::max(config.max_body_bytes)); // 자체 제한이 없는 라우트의 본문 크기 제한 (초과 시 413)
+    let app = app
+        .layer(axum_middleware::from_fn_with_state(
+            Arc::new(HeaderRedaction::new(&config.log_redact_headers)),
+            log_middleware,
+        )) // 요청마다 요청 ID span을 열고, 자격 증명 헤더는 가려서 기록
+        .layer(axum_middleware::from_fn(track_metrics)) // 라우트별 요청 수/처리 시간을 `/metrics`로 노출
+        .layer(axum_middleware::from_fn(request_id)) // `X-Request-Id`를 이어받거나 만들어 응답에 돌려줌
+        .layer(axum_middleware::from_fn_with_state(
+            Arc::new(SecurityHeaders::new(&config.content_security_policy)),
+            security_headers,
+        )) // CSP, X-Content-Type-Options 등 보안 헤더 (에러 응답 포함)
+        .layer(CompressionLayer::new()) // `Accept-Encoding`에 따라 gzip/brotli로 압축 (Range 응답과 이미지는 제외)
+        .with_state(state); // ✅ State는 여기 단 한 번
+    match cors_layer(&config.cors_allowed_origins) {
+        // 사전 요청(OPTIONS)도 처리해야 하므로 가장 바깥에 둡니다.
+        Some(cors) => app.layer(cors),
+        None => app,
+    }
 }
@@ -0,0 +1,147 @@
+//! OpenAPI 3 문서: 핸들러의 `#[utoipa::path]` 주석과 DTO 스키마로 생성합니다.
+//!
+//! `/openapi.json`으로 문서를, `/swagger-ui`로 Swagger UI를 제공합니다.
+//! 새 라우트를 추가하면 여기 `paths`에도 등록해야 하며, `tests/openapi.rs`가 누락을 잡아냅니다.
+
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::controllers::{
+    attachment_controller, auth_controller, board_controller, category_controller,
+    comment_controller,
+    dto::{
+        AttachmentResponse, AttachmentUploadForm, BoardEventResponse, BoardListResponse,
+        BoardResponse, CategoryRequest, CategoryResponse, CommentPaginationResponse,
+        CommentResponse, CreateBoardRequest, CreateCommentRequest, CursorMeta,
+        CursorPaginationResponse, DiffLineResponse, EngagementResponse, EventNoticeResponse,
+        EventSubscribeMessage, ImportReportResponse, ImportRowErrorResponse, LikeResponse,
+        LoginRequest, PaginationMeta, PaginationResponse, PopularBoardResponse,
+        PopularBoardsResponse, RegisterRequest, RevisionDiffResponse, RevisionResponse,
+        TagCountResponse, TitleDiffResponse, TokenResponse, TrashPaginationResponse,
+        TrashedBoardResponse, UpdateBoardRequest, UpdateCommentRequest, UserResponse,
+    },
+    error::ErrorResponse,
+    event_controller,
+    health_controller::{self, DatabaseCheck, LivenessResponse, PoolStatus, ReadinessResponse},
+    metrics_controller, revision_controller, tag_controller,
+};
+
+/// 애플리케이션 API 문서
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Oracle MVC Board API", description = "게시글, 카테고리/태그, 댓글, 첨부파일, 인증, 실시간 이벤트 API"),
+    paths(
+        board_controller::list_boards,
+        board_controller::create_board,
+        board_controller::get_board,
+        board_controller::update_board,
+        board_controller::delete_board,
+        board_controller::list_trash,
+        board_controller::export_boards,
+        board_controller::import_boards,
+        board_controller::restore_board,
+        board_controller::popular_boards,
+        board_controller::get_engagement,
+        board_controller::like_board,
+        board_controller::unlike_board,
+        event_controller::board_events_sse,
+        event_controller::board_events_ws,
+        category_controller::list_categories,
+        category_controller::create_category,
+        category_controller::get_category,
+        category_controller::update_category,
+        category_controller::delete_category,
+        tag_controller::list_tags,
+        revision_controller::list_revisions,
+        revision_controller::diff_revisions,
+        revision_controller::get_revision,
+        revision_controller::revert_revision,
+        comment_controller::list_comments,
+        comment_controller::create_comment,
+        comment_controller::update_comment,
+        comment_controller::delete_comment,
+        attachment_controller::list_attachments,
+        attachment_controller::upload_attachment,
+        attachment_controller::download_attachment,
+        auth_controller::register,
+        auth_controller::login,
+        metrics_controller::get_metrics,
+        health_controller::healthz,
+        health_controller::readyz,
+    ),
+    components(schemas(
+        BoardResponse,
+        BoardListResponse,
+        PaginationResponse,
+        CursorPaginationResponse,
+        PaginationMeta,
+        CursorMeta,
+        CreateBoardRequest,
+        UpdateBoardRequest,
+        TrashedBoardResponse,
+        TrashPaginationResponse,
+        ImportReportResponse,
+        ImportRowErrorResponse,
+        EngagementResponse,
+        LikeResponse,
+        BoardEventResponse,
+        EventNoticeResponse,
+        EventSubscribeMessage,
+        PopularBoardResponse,
+        PopularBoardsResponse,
+        CategoryResponse,
+        CategoryRequest,
+        TagCountResponse,
+        RevisionResponse,
+        RevisionDiffResponse,
+        TitleDiffResponse,
+        DiffLineResponse,
+        CommentResponse,
+        CommentPaginationResponse,
+        CreateCommentRequest,
+        UpdateCommentRequest,
+        AttachmentResponse,
+        AttachmentUploadForm,
+        RegisterRequest,
+        LoginRequest,
+        UserResponse,
+        TokenResponse,
+        ErrorResponse,
+        LivenessResponse,
+        ReadinessResponse,
+        DatabaseCheck,
+        PoolStatus,
+    )),
+    modifiers(&BearerAuth),
+    tags(
+        (name = "boards", description = "게시글"),
+        (name = "trash", description = "휴지통"),
+        (name = "events", description = "게시글 변경 이벤트 구독 (SSE/WebSocket)"),
+        (name = "categories", description = "게시글 카테고리"),
+        (name = "tags", description = "게시글 태그"),
+        (name = "revisions", description = "게시글 수정 이력"),
+        (name = "comments", description = "댓글"),
+        (name = "attachments", description = "첨부파일"),
+        (name = "auth", description = "회원가입/로그인"),
+        (name = "ops", description = "헬스 체크와 메트릭"),
+    )
+)]
+pub struct ApiDoc;
+
+/// `/auth/login`으로 받은 액세스 토큰(JWT)을 `Authorization: Bearer`로 보내는 보안 스킴
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
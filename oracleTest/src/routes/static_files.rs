@@ -0,0 +1,88 @@
+//! 정적 파일 라우터: `static_dir` 디렉터리 전체를 캐시 헤더와 함께 서빙합니다.
+//!
+//! - 미리 압축해 둔 `*.br`, `*.gz` 파일이 있으면 `Accept-Encoding`에 맞춰 그 파일을 보냅니다.
+//! - HTML(`index.html` 등)은 배포 직후 새 버전을 받도록 `no-cache`로, 나머지 자산은 `max-age`로 캐시합니다.
+//! - 브라우저가 HTML을 요청했는데 파일이 없으면 SPA 라우팅을 위해 `index.html`을 돌려줍니다.
+//!   API 클라이언트(`Accept`에 `text/html`이 없음)나 확장자가 있는 자산 경로는 그대로 404입니다.
+
+use axum::{
+    Router,
+    body::Body,
+    extract::State,
+    http::{HeaderValue, Method, Request, StatusCode, header},
+    middleware::map_response_with_state,
+    response::{IntoResponse, Response},
+};
+use std::convert::Infallible;
+use std::path::Path;
+use std::time::Duration;
+use tower::{ServiceExt, service_fn};
+use tower_http::services::{ServeDir, ServeFile};
+
+/// 캐시하지 않는 응답(HTML)에 붙이는 `Cache-Control`
+const NO_CACHE: HeaderValue = HeaderValue::from_static("no-cache");
+
+/// `dir`의 파일을 서빙하는 라우터를 만듭니다. (`Router::fallback_service`에 연결합니다)
+///
+/// `max_age`는 HTML이 아닌 자산의 브라우저 캐시 시간이며, 0이면 모든 파일을 `no-cache`로 보냅니다.
+pub fn static_files(dir: impl AsRef<Path>, max_age: Duration) -> Router {
+    let index = dir.as_ref().join("index.html");
+    let spa_fallback = service_fn(move |req: Request<Body>| {
+        let index = index.clone();
+        async move {
+            if !wants_html_page(&req) {
+                return Ok::<_, Infallible>(StatusCode::NOT_FOUND.into_response());
+            }
+            let res = ServeFile::new(index).oneshot(req).await?;
+            Ok(res.map(Body::new))
+        }
+    });
+    let files = ServeDir::new(dir)
+        .precompressed_br()
+        .precompressed_gzip()
+        .call_fallback_on_method_not_allowed(true) // GET/HEAD가 아닌 요청도 405 대신 404로 끝냅니다.
+        .fallback(spa_fallback);
+
+    let cache_control = if max_age.is_zero() {
+        NO_CACHE
+    } else {
+        HeaderValue::from_str(&format!("public, max-age={}", max_age.as_secs()))
+            .expect("숫자로 만든 헤더 값은 항상 유효합니다")
+    };
+    Router::new()
+        .fallback_service(files)
+        .layer(map_response_with_state(cache_control, set_cache_control))
+}
+
+/// 브라우저의 페이지 이동 요청인지 확인합니다. (GET/HEAD, `Accept: text/html`, 확장자 없는 경로)
+fn wants_html_page(req: &Request<Body>) -> bool {
+    let accepts_html = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/html"));
+    let last_segment = req.uri().path().rsplit('/').next().unwrap_or_default();
+    matches!(*req.method(), Method::GET | Method::HEAD)
+        && accepts_html
+        && !last_segment.contains('.')
+}
+
+/// 성공 응답에 `Cache-Control`을 붙입니다. HTML은 항상 서버에 다시 확인하도록 `no-cache`입니다.
+///
+/// 304 응답에는 `Content-Type`이 없어 HTML인지 알 수 없으므로, 브라우저가 저장해 둔 값을 그대로 쓰도록 붙이지 않습니다.
+async fn set_cache_control(
+    State(cache_control): State<HeaderValue>,
+    mut res: Response,
+) -> Response {
+    if !res.status().is_success() {
+        return res;
+    }
+    let is_html = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("text/html"));
+    let value = if is_html { NO_CACHE } else { cache_control };
+    res.headers_mut().insert(header::CACHE_CONTROL, value);
+    res
+}
@@ -0,0 +1,302 @@
+//! Service 계층: 첨부파일 업로드/다운로드 및 크기·형식 제한
+
+use crate::models::attachment::{Attachment, ByteRange, NewAttachment};
+use crate::repositories::attachment_store::AttachmentStore;
+use crate::repositories::board_store::BoardStore;
+use crate::repositories::file_storage::LocalFileStorage;
+use crate::services::board_service::ServiceError;
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use sha2::{Digest, Sha256};
+use std::io::SeekFrom;
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tracing::{error, info, warn};
+
+/// 파일명 최대 길이 (문자 수)
+const MAX_FILENAME_CHARS: usize = 255;
+
+/// 업로드 제한 설정
+#[derive(Debug, Clone)]
+pub struct AttachmentLimits {
+    /// 파일 하나의 최대 크기 (바이트)
+    pub max_bytes: u64,
+    /// 허용할 MIME 타입 목록 (소문자, 파라미터 제외)
+    pub allowed_types: Vec<String>,
+}
+
+/// 다운로드할 첨부파일과 읽기 위치가 맞춰진 파일 핸들
+#[derive(Debug)]
+pub struct AttachmentDownload {
+    pub attachment: Attachment,
+    /// 응답 시작 위치로 이동(seek)된 파일
+    pub file: File,
+    /// 부분 응답이면 실제 바이트 구간 `(start, end)` (양 끝 포함)
+    pub range: Option<(u64, u64)>,
+}
+
+/// 첨부파일 비즈니스 로직을 담당하는 서비스 구조체
+pub struct AttachmentService {
+    attachments: Arc<dyn AttachmentStore>,
+    boards: Arc<dyn BoardStore>,
+    files: Arc<LocalFileStorage>,
+    limits: AttachmentLimits,
+}
+
+impl AttachmentService {
+    /// 서비스 생성자: 메타데이터 저장소, 게시글 저장소, 파일 저장소와 업로드 제한 주입
+    pub fn new(
+        attachments: Arc<dyn AttachmentStore>,
+        boards: Arc<dyn BoardStore>,
+        files: Arc<LocalFileStorage>,
+        limits: AttachmentLimits,
+    ) -> Self {
+        Self {
+            attachments,
+            boards,
+            files,
+            limits,
+        }
+    }
+
+    /// 게시글에 파일을 업로드합니다. (게시글 작성자만 가능)
+    ///
+    /// 본문은 청크 단위로 디스크에 기록하면서 크기와 SHA-256을 계산하므로
+    /// 파일 전체를 메모리에 올리지 않습니다. 실패하면 기록 중이던 파일을 지웁니다.
+    pub async fn upload<S, E>(
+        &self,
+        user_id: i64,
+        board_id: i64,
+        filename: &str,
+        content_type: &str,
+        body: S,
+    ) -> Result<Attachment, ServiceError>
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send,
+        E: std::fmt::Display,
+    {
+        info!(
+            "[Service] upload 호출됨, user_id={}, board_id={}, filename={}, content_type={}",
+            user_id, board_id, filename, content_type
+        );
+        self.validate_id(board_id)?;
+        self.ensure_board_owner(user_id, board_id).await?;
+        let filename = self.sanitize_filename(filename)?;
+        let content_type = self.validate_content_type(content_type)?;
+
+        let (storage_key, file) = self.files.create(board_id).await?;
+        let written = self.write_body(file, body).await;
+        let (size_bytes, sha256) = match written {
+            Ok(written) => written,
+            Err(err) => {
+                self.discard(&storage_key).await;
+                return Err(err);
+            }
+        };
+
+        let new_attachment = NewAttachment {
+            board_id,
+            filename,
+            content_type,
+            size_bytes,
+            sha256,
+            storage_key: storage_key.clone(),
+            uploader_id: user_id,
+        };
+        let id = match self.attachments.insert(new_attachment).await {
+            Ok(id) => id,
+            Err(err) => {
+                self.discard(&storage_key).await;
+                return Err(err.into());
+            }
+        };
+        info!(
+            "[Service] 첨부파일 업로드 완료 id={}, size={}",
+            id, size_bytes
+        );
+
+        self.get_attachment(id).await
+    }
+
+    /// 게시글의 첨부파일 목록 조회
+    pub async fn list_attachments(&self, board_id: i64) -> Result<Vec<Attachment>, ServiceError> {
+        info!("[Service] list_attachments 호출됨, board_id={}", board_id);
+        self.validate_id(board_id)?;
+        if self.boards.find_by_id(board_id).await?.is_none() {
+            return Err(ServiceError::NotFound);
+        }
+        Ok(self.attachments.find_by_board(board_id).await?)
+    }
+
+    /// 첨부파일 메타데이터 조회 (휴지통에 있는 게시글의 첨부파일은 찾을 수 없는 것으로 봅니다)
+    pub async fn get_attachment(&self, id: i64) -> Result<Attachment, ServiceError> {
+        info!("[Service] get_attachment 호출됨, id={}", id);
+        self.validate_id(id)?;
+        let attachment = self
+            .attachments
+            .find_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        if self.boards.find_by_id(attachment.board_id).await?.is_none() {
+            return Err(ServiceError::NotFound);
+        }
+        Ok(attachment)
+    }
+
+    /// 다운로드용으로 첨부파일을 엽니다. `range`가 있으면 해당 구간의 시작 위치로 이동합니다.
+    pub async fn open_download(
+        &self,
+        id: i64,
+        range: Option<ByteRange>,
+    ) -> Result<AttachmentDownload, ServiceError> {
+        info!(
+            "[Service] open_download 호출됨, id={}, range={:?}",
+            id, range
+        );
+        let attachment = self.get_attachment(id).await?;
+        let size = u64::try_from(attachment.size_bytes).unwrap_or(0);
+
+        let range = match range {
+            Some(range) => Some(range.resolve(size).ok_or_else(|| {
+                warn!(
+                    "[Service] 만족할 수 없는 범위: id={}, range={:?}, size={}",
+                    id, range, size
+                );
+                ServiceError::RangeNotSatisfiable(size)
+            })?),
+            None => None,
+        };
+
+        let mut file = self.files.open(&attachment.storage_key).await?;
+        if let Some((start, _)) = range {
+            file.seek(SeekFrom::Start(start)).await?;
+        }
+
+        Ok(AttachmentDownload {
+            attachment,
+            file,
+            range,
+        })
+    }
+
+    /// 업로드 본문을 파일에 기록하고 `(크기, SHA-256 16진수)`를 반환합니다.
+    async fn write_body<S, E>(&self, mut file: File, body: S) -> Result<(i64, String), ServiceError>
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send,
+        E: std::fmt::Display,
+    {
+        let mut body = std::pin::pin!(body);
+        let mut hasher = Sha256::new();
+        let mut size: u64 = 0;
+
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|err| {
+                warn!("[Service] 업로드 본문 읽기 실패: {}", err);
+                ServiceError::InvalidInput("업로드 본문을 읽을 수 없습니다.".to_string())
+            })?;
+            size += chunk.len() as u64;
+            if size > self.limits.max_bytes {
+                warn!(
+                    "[Service] 업로드 크기 초과: {} > {}",
+                    size, self.limits.max_bytes
+                );
+                return Err(ServiceError::PayloadTooLarge(format!(
+                    "파일이 너무 큽니다 (최대 {} 바이트)",
+                    self.limits.max_bytes
+                )));
+            }
+            hasher.update(&chunk);
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+
+        if size == 0 {
+            return Err(ServiceError::InvalidInput(
+                "빈 파일은 업로드할 수 없습니다.".to_string(),
+            ));
+        }
+        let size = i64::try_from(size)
+            .map_err(|_| ServiceError::PayloadTooLarge("파일이 너무 큽니다.".to_string()))?;
+        Ok((size, hex::encode(hasher.finalize())))
+    }
+
+    /// 실패한 업로드의 파일을 지웁니다. 삭제 실패는 로그로만 남깁니다.
+    async fn discard(&self, storage_key: &str) {
+        if let Err(err) = self.files.remove(storage_key).await {
+            error!(
+                "[Service] 업로드 실패 파일 삭제 실패 {}: {}",
+                storage_key, err
+            );
+        }
+    }
+
+    /// 게시글이 존재하고 `user_id`가 작성자인지 확인합니다.
+    async fn ensure_board_owner(&self, user_id: i64, board_id: i64) -> Result<(), ServiceError> {
+        let board = self
+            .boards
+            .find_by_id(board_id)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        if board.author_id == Some(user_id) {
+            Ok(())
+        } else {
+            warn!(
+                "[Service] 첨부 권한 없음: user_id={}, board_id={}, author_id={:?}",
+                user_id, board_id, board.author_id
+            );
+            Err(ServiceError::Forbidden)
+        }
+    }
+
+    // --- 유효성 검사 헬퍼 함수들 ---
+
+    fn validate_id(&self, id: i64) -> Result<(), ServiceError> {
+        if id > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 ID: {}", id);
+            Err(ServiceError::InvalidInput(
+                "ID는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    /// 경로 구분자 앞부분과 제어 문자를 제거한 파일명을 반환합니다.
+    fn sanitize_filename(&self, filename: &str) -> Result<String, ServiceError> {
+        let base = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+        let cleaned: String = base.chars().filter(|c| !c.is_control()).collect();
+        let cleaned = cleaned.trim();
+        if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+            return Err(ServiceError::InvalidInput(
+                "파일명은 필수입니다.".to_string(),
+            ));
+        }
+        if cleaned.chars().count() > MAX_FILENAME_CHARS {
+            return Err(ServiceError::InvalidInput(format!(
+                "파일명이 너무 깁니다 (최대 {}자)",
+                MAX_FILENAME_CHARS
+            )));
+        }
+        Ok(cleaned.to_string())
+    }
+
+    /// MIME 타입을 정규화(파라미터 제거, 소문자)하고 허용 목록에 있는지 확인합니다.
+    fn validate_content_type(&self, content_type: &str) -> Result<String, ServiceError> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if self.limits.allowed_types.contains(&mime) {
+            Ok(mime)
+        } else {
+            warn!("[Service] 허용되지 않은 파일 형식: {}", content_type);
+            Err(ServiceError::UnsupportedMediaType(format!(
+                "허용되지 않은 파일 형식입니다: {}",
+                mime
+            )))
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Service 계층: 회원가입, 로그인 및 액세스 토큰(JWT) 발급/검증
+
+use crate::common::metrics::spawn_blocking;
+use crate::repositories::store_error::StoreError;
+use crate::repositories::user_store::UserStore;
+use crate::services::board_service::ServiceError;
+use argon2::Argon2;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// 액세스 토큰에 담기는 클레임
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    /// 사용자 ID
+    pub sub: String,
+    pub username: String,
+    /// 발급 시각 (UNIX 초)
+    pub iat: u64,
+    /// 만료 시각 (UNIX 초)
+    pub exp: u64,
+}
+
+/// 토큰에서 확인된 인증 사용자 정보
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub id: i64,
+    pub username: String,
+}
+
+/// 발급된 액세스 토큰
+#[derive(Debug, Clone)]
+pub struct IssuedToken {
+    pub access_token: String,
+    pub expires_in: u64,
+}
+
+/// 인증 비즈니스 로직을 담당하는 서비스 구조체
+pub struct AuthService {
+    users: Arc<dyn UserStore>,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    token_ttl_secs: u64,
+}
+
+impl AuthService {
+    /// 해시/토큰 처리 중 발생한 내부 오류를 `ServiceError`로 매핑하는 헬퍼 함수
+    fn map_internal_err(err: impl ToString) -> ServiceError {
+        ServiceError::DatabaseError(StoreError::Other(err.to_string()))
+    }
+
+    /// 서비스 생성자: 사용자 저장소와 토큰 서명 설정 주입
+    pub fn new(users: Arc<dyn UserStore>, jwt_secret: &str, token_ttl_secs: u64) -> Self {
+        Self {
+            users,
+            encoding_key: EncodingKey::from_secret(jwt_secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(jwt_secret.as_bytes()),
+            token_ttl_secs,
+        }
+    }
+
+    /// 회원가입: 사용자명 중복을 확인하고 비밀번호를 Argon2로 해시하여 저장합니다.
+    pub async fn register(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<AuthenticatedUser, ServiceError> {
+        info!("[Service] register 호출됨, username={}", username);
+        self.validate_username(username)?;
+        self.validate_password(password)?;
+
+        let duplicate = || {
+            warn!("[Service] 이미 존재하는 사용자명: {}", username);
+            ServiceError::Conflict("이미 사용 중인 사용자명입니다.".to_string())
+        };
+
+        // 해시 계산 전에 중복을 빠르게 걸러냅니다. 동시에 들어온 가입은 아래 INSERT의 고유 제약이 막습니다.
+        if self
+            .users
+            .find_by_username(username.to_string())
+            .await?
+            .is_some()
+        {
+            return Err(duplicate());
+        }
+
+        let password = password.to_string();
+        // 해시 계산은 CPU를 많이 사용하므로 블로킹 스레드에서 수행합니다.
+        let password_hash = spawn_blocking(move || {
+            let salt = SaltString::generate(&mut OsRng);
+            Argon2::default()
+                .hash_password(password.as_bytes(), &salt)
+                .map(|hash| hash.to_string())
+        })
+        .await
+        .map_err(Self::map_internal_err)?
+        .map_err(Self::map_internal_err)?;
+
+        let id = match self.users.insert(username.to_string(), password_hash).await {
+            Ok(id) => id,
+            Err(StoreError::Conflict(_)) => return Err(duplicate()),
+            Err(err) => return Err(err.into()),
+        };
+        info!("[Service] 회원가입 완료 id={}", id);
+        Ok(AuthenticatedUser {
+            id,
+            username: username.to_string(),
+        })
+    }
+
+    /// 로그인: 비밀번호를 검증하고 액세스 토큰을 발급합니다.
+    /// 사용자 존재 여부를 노출하지 않도록 실패 사유는 하나의 메시지로 통일합니다.
+    pub async fn login(&self, username: &str, password: &str) -> Result<IssuedToken, ServiceError> {
+        info!("[Service] login 호출됨, username={}", username);
+        let invalid = || {
+            ServiceError::Unauthorized("사용자명 또는 비밀번호가 올바르지 않습니다.".to_string())
+        };
+
+        let user = self
+            .users
+            .find_by_username(username.to_string())
+            .await?
+            .ok_or_else(invalid)?;
+
+        let password = password.to_string();
+        let password_hash = user.password_hash.clone();
+        let verified = spawn_blocking(move || {
+            PasswordHash::new(&password_hash)
+                .map(|hash| {
+                    Argon2::default()
+                        .verify_password(password.as_bytes(), &hash)
+                        .is_ok()
+                })
+                .unwrap_or(false)
+        })
+        .await
+        .map_err(Self::map_internal_err)?;
+        if !verified {
+            warn!("[Service] 로그인 실패: username={}", username);
+            return Err(invalid());
+        }
+
+        let access_token = self.issue_token(&AuthenticatedUser {
+            id: user.id,
+            username: user.username,
+        })?;
+        Ok(IssuedToken {
+            access_token,
+            expires_in: self.token_ttl_secs,
+        })
+    }
+
+    /// 액세스 토큰의 서명과 만료 시각을 검증하고 사용자 정보를 반환합니다.
+    pub fn verify_token(&self, token: &str) -> Result<AuthenticatedUser, ServiceError> {
+        let data =
+            decode::<Claims>(token, &self.decoding_key, &Validation::default()).map_err(|err| {
+                warn!("[Service] 토큰 검증 실패: {}", err);
+                ServiceError::Unauthorized("유효하지 않은 토큰입니다.".to_string())
+            })?;
+        let id = data
+            .claims
+            .sub
+            .parse()
+            .map_err(|_| ServiceError::Unauthorized("유효하지 않은 토큰입니다.".to_string()))?;
+        Ok(AuthenticatedUser {
+            id,
+            username: data.claims.username,
+        })
+    }
+
+    /// 사용자 정보로 서명된 액세스 토큰을 생성합니다.
+    fn issue_token(&self, user: &AuthenticatedUser) -> Result<String, ServiceError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let claims = Claims {
+            sub: user.id.to_string(),
+            username: user.username.clone(),
+            iat: now,
+            exp: now + self.token_ttl_secs,
+        };
+        encode(&Header::default(), &claims, &self.encoding_key).map_err(Self::map_internal_err)
+    }
+
+    // --- 유효성 검사 헬퍼 함수들 ---
+
+    fn validate_username(&self, username: &str) -> Result<(), ServiceError> {
+        let length = username.chars().count();
+        let allowed = username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if (3..=30).contains(&length) && allowed {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 사용자명: {}", username);
+            Err(ServiceError::InvalidInput(
+                "사용자명은 영문/숫자/밑줄 3~30자여야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    fn validate_password(&self, password: &str) -> Result<(), ServiceError> {
+        if (8..=128).contains(&password.chars().count()) {
+            Ok(())
+        } else {
+            Err(ServiceError::InvalidInput(
+                "비밀번호는 8~128자여야 합니다.".to_string(),
+            ))
+        }
+    }
+}
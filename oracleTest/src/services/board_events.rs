@@ -0,0 +1,209 @@
+//! 게시글 변경 이벤트 허브: 서비스가 발행한 이벤트를 브로드캐스트 채널로 구독자에게 보냅니다.
+//!
+//! 최근 이벤트를 정해진 개수만큼 보관하여, 재연결한 클라이언트가 `Last-Event-ID` 이후의 이벤트를 다시 받을 수 있게 합니다.
+//! 보관 범위를 벗어나 이어 받을 수 없으면 `Delivery::Missed`로 알려 목록을 새로 불러오게 합니다.
+//! 이벤트 ID에는 허브마다 새로 정하는 `epoch`가 붙으므로, 재시작 전에 받은 ID도 `Missed`로 처리됩니다.
+
+use crate::models::board::Board;
+use crate::models::board_event::{BoardEvent, BoardEventKind, EventId};
+use crate::services::board_service::ServiceError;
+use chrono::Utc;
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// 재전송용으로 보관하는 최근 이벤트 수 기본값 (브로드캐스트 채널 용량도 같음)
+pub const DEFAULT_EVENT_BUFFER: usize = 256;
+/// 연결 유지를 위한 하트비트 주기 기본값
+pub const DEFAULT_EVENT_HEARTBEAT: Duration = Duration::from_secs(15);
+/// 한 연결에서 구독할 수 있는 게시글 수
+pub const MAX_SUBSCRIBED_BOARDS: usize = 100;
+
+/// 발행 순서와 보관 목록 (같은 잠금 안에서 ID를 매기고 보내야 재전송과 실시간 전송이 겹치거나 빠지지 않습니다)
+struct History {
+    next_id: u64,
+    recent: VecDeque<Arc<BoardEvent>>,
+}
+
+/// 게시글 변경 이벤트 허브
+pub struct BoardEvents {
+    /// 이 허브가 매기는 이벤트 ID의 시작 표식 (0이 아닌 임의 값)
+    epoch: u32,
+    sender: broadcast::Sender<Arc<BoardEvent>>,
+    history: Mutex<History>,
+    capacity: usize,
+    heartbeat: Duration,
+    /// 서버 종료 시 열린 스트림을 모두 끝내기 위한 토큰
+    closed: CancellationToken,
+}
+
+/// 구독자가 받을 다음 항목
+#[derive(Debug, Clone)]
+pub enum Delivery {
+    Event(Arc<BoardEvent>),
+    /// 놓친 이벤트가 있어 이어 받을 수 없음 (클라이언트는 목록을 새로 불러와야 합니다)
+    Missed,
+}
+
+/// 이벤트 구독: 재전송할 항목을 먼저 보낸 뒤 실시간 이벤트를 이어서 보냅니다.
+pub struct Subscription {
+    pending: VecDeque<Delivery>,
+    receiver: broadcast::Receiver<Arc<BoardEvent>>,
+    closed: CancellationToken,
+}
+
+impl Subscription {
+    /// 다음 항목을 기다립니다. 서버가 종료되면 `None`을 반환합니다.
+    pub async fn next(&mut self) -> Option<Delivery> {
+        if let Some(delivery) = self.pending.pop_front() {
+            return Some(delivery);
+        }
+        tokio::select! {
+            _ = self.closed.cancelled() => None,
+            result = self.receiver.recv() => match result {
+                Ok(event) => Some(Delivery::Event(event)),
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("[Service] 이벤트 구독자가 뒤처짐: {}개 건너뜀", skipped);
+                    Some(Delivery::Missed)
+                }
+                Err(RecvError::Closed) => None,
+            },
+        }
+    }
+}
+
+impl Default for BoardEvents {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUFFER, DEFAULT_EVENT_HEARTBEAT)
+    }
+}
+
+impl BoardEvents {
+    /// `capacity`개까지 보관하고 `heartbeat`마다 연결 유지 신호를 보내는 허브를 만듭니다.
+    pub fn new(capacity: usize, heartbeat: Duration) -> Self {
+        let capacity = capacity.max(1);
+        let (sender, _) = broadcast::channel(capacity);
+        Self {
+            epoch: Uuid::new_v4().as_u128() as u32,
+            sender,
+            history: Mutex::new(History {
+                next_id: 1,
+                recent: VecDeque::with_capacity(capacity),
+            }),
+            capacity,
+            heartbeat,
+            closed: CancellationToken::new(),
+        }
+    }
+
+    /// 이 허브가 매기는 이벤트 ID의 시작 표식
+    pub fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// 연결 유지 신호(SSE 주석, WebSocket ping) 주기
+    pub fn heartbeat(&self) -> Duration {
+        self.heartbeat
+    }
+
+    /// 게시글 변경 이벤트를 발행하고 이벤트 ID를 반환합니다. 구독자가 없어도 보관은 합니다.
+    pub fn publish(&self, kind: BoardEventKind, board: &Board) -> EventId {
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        let event = Arc::new(BoardEvent {
+            id: EventId {
+                epoch: self.epoch,
+                seq: history.next_id,
+            },
+            kind,
+            board_id: board.id,
+            author_id: board.author_id,
+            title: board.title.clone(),
+            version: (kind != BoardEventKind::Deleted).then_some(board.version),
+            occurred_at: Utc::now(),
+        });
+        history.next_id += 1;
+        if history.recent.len() == self.capacity {
+            history.recent.pop_front();
+        }
+        history.recent.push_back(event.clone());
+        // 받는 쪽이 없으면 실패하지만, 보관한 이벤트는 나중에 재전송됩니다.
+        let receivers = self.sender.send(event.clone()).unwrap_or(0);
+        debug!(
+            "[Service] 이벤트 발행: id={}, kind={}, board_id={}, receivers={}",
+            event.id,
+            kind.as_str(),
+            event.board_id,
+            receivers
+        );
+        event.id
+    }
+
+    /// 이벤트를 구독합니다. `last_event_id`가 있으면 그 뒤에 보관된 이벤트부터 다시 보냅니다.
+    ///
+    /// 보관 범위보다 오래된 ID이거나, 이 허브가 발행한 적 없는 ID(재시작 전 ID 포함)이면
+    /// 맨 앞에 `Delivery::Missed`를 두고 보관된 이벤트를 모두 보냅니다.
+    pub fn subscribe(&self, last_event_id: Option<EventId>) -> Subscription {
+        let history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        let receiver = self.sender.subscribe();
+        let mut pending = VecDeque::new();
+        if let Some(last) = last_event_id {
+            let latest = history.next_id - 1;
+            let oldest = history.recent.front().map_or(history.next_id, |e| e.id.seq);
+            let missed = last.epoch != self.epoch || last.seq > latest || last.seq + 1 < oldest;
+            if missed {
+                info!(
+                    "[Service] 이어 받을 수 없는 Last-Event-ID: {} (epoch {:08x}, 보관 범위 {}..={})",
+                    last, self.epoch, oldest, latest
+                );
+                pending.push_back(Delivery::Missed);
+            }
+            pending.extend(
+                history
+                    .recent
+                    .iter()
+                    .filter(|event| missed || event.id.seq > last.seq)
+                    .map(|event| Delivery::Event(event.clone())),
+            );
+        }
+        Subscription {
+            pending,
+            receiver,
+            closed: self.closed.clone(),
+        }
+    }
+
+    /// 열린 구독을 모두 끝냅니다. (서버 종료 시 스트리밍 연결이 종료를 막지 않도록)
+    pub fn close(&self) {
+        self.closed.cancel();
+    }
+}
+
+/// 쉼표로 구분한 게시글 ID 목록을 구독 대상으로 변환합니다. (비어 있으면 모든 게시글)
+pub fn parse_board_filter(boards: Option<&str>) -> Result<Option<HashSet<i64>>, ServiceError> {
+    let Some(boards) = boards.map(str::trim).filter(|boards| !boards.is_empty()) else {
+        return Ok(None);
+    };
+    let ids = boards
+        .split(',')
+        .map(|id| {
+            id.trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|id| *id > 0)
+                .ok_or_else(|| {
+                    ServiceError::InvalidInput(format!("잘못된 게시글 ID입니다: {}", id.trim()))
+                })
+        })
+        .collect::<Result<HashSet<_>, _>>()?;
+    if ids.len() > MAX_SUBSCRIBED_BOARDS {
+        return Err(ServiceError::InvalidInput(format!(
+            "한 번에 구독할 수 있는 게시글은 최대 {}개입니다.",
+            MAX_SUBSCRIBED_BOARDS
+        )));
+    }
+    Ok(Some(ids))
+}
@@ -1,13 +1,79 @@
 //! Service 계층: 비즈니스 로직 및 유효성 검사
 
-use crate::models::board::{Board, BoardListItem};
-use crate::repositories::board_repository::BoardRepository;
-use std::sync::Arc;
-use tracing::{debug, info, warn};
+use crate::common::etag;
+use crate::models::board::{
+    Board, BoardCursor, BoardCursorPage, BoardFilter, BoardListItem, BoardSearchQuery, BoardSort,
+    BoardUpdate, ContentFormat, NewBoard, PopularBoard, SortDirection, SortField, TrashedBoard,
+};
+use crate::models::board_event::BoardEventKind;
+use crate::models::board_transfer::{ImportReport, ImportRowError, TransferFormat};
+use crate::models::category::TagCount;
+use crate::models::revision::{BoardRevision, RevisionDiff};
+use crate::repositories::board_store::BoardStore;
+use crate::repositories::category_store::CategoryStore;
+use crate::repositories::file_storage::LocalFileStorage;
+use crate::repositories::store_error::StoreError;
+use crate::services::board_events::BoardEvents;
+use bytes::Bytes;
+use chrono::NaiveDate;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+/// 게시글 본문 최대 길이 (문자 수)
+pub const MAX_CONTENT_CHARS: usize = 20_000;
+
+/// 게시글 하나에 붙일 수 있는 태그 수
+pub const MAX_TAGS_PER_BOARD: usize = 10;
+/// 태그 이름 최대 길이 (문자 수, 정규화 후)
+pub const MAX_TAG_CHARS: usize = 30;
+
+/// 목록 한 페이지에 담을 수 있는 최대 게시글 수
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// 내보내기에서 한 번에 조회하는 게시글 수
+const EXPORT_BATCH_SIZE: u32 = 500;
+/// 가져오기에서 한 트랜잭션으로 추가하는 게시글 수
+const IMPORT_BATCH_SIZE: usize = 500;
+
+/// 인기 게시글 집계 기간 기본값과 최댓값 (일)
+pub const DEFAULT_POPULAR_WINDOW_DAYS: u32 = 7;
+pub const MAX_POPULAR_WINDOW_DAYS: u32 = 30;
+/// 인기 게시글 개수 기본값과 최댓값
+pub const DEFAULT_POPULAR_LIMIT: u32 = 10;
+pub const MAX_POPULAR_LIMIT: u32 = 50;
+/// 인기 점수 가중치: 기간 안의 조회 1회와 좋아요 1개가 더하는 점수
+pub const POPULAR_VIEW_WEIGHT: i64 = 1;
+pub const POPULAR_LIKE_WEIGHT: i64 = 5;
+/// 인기 게시글 캐시 유지 시간 기본값
+pub const DEFAULT_POPULAR_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// 인기 게시글 캐시 키: (집계 기간 일수, 개수)
+type PopularKey = (u32, u32);
+
+/// 내보내기 스트림이 다음에 할 일
+enum ExportState {
+    /// 미리 조회해 둔 첫 묶음을 보냅니다.
+    First(Vec<(BoardListItem, BoardCursor)>),
+    /// 커서 뒤의 다음 묶음을 조회합니다.
+    Next(BoardCursor),
+    Done,
+}
 
 /// 게시판 비즈니스 로직을 담당하는 서비스 구조체
 pub struct BoardService {
-    repository: Arc<BoardRepository>,
+    repository: Arc<dyn BoardStore>,
+    categories: Arc<dyn CategoryStore>,
+    files: Arc<LocalFileStorage>,
+    /// 아직 DB에 반영하지 않은 게시글별 조회수 (`flush_views`가 한꺼번에 반영)
+    pending_views: Mutex<HashMap<i64, u64>>,
+    /// 조건별 인기 게시글 목록과 조회한 시각
+    popular_cache: Mutex<HashMap<PopularKey, (Instant, Vec<PopularBoard>)>>,
+    popular_cache_ttl: Duration,
+    /// 게시글 생성/수정/삭제 이벤트를 구독자에게 보내는 허브
+    events: Arc<BoardEvents>,
 }
 
 /// 서비스 계층에서 발생할 수 있는 에러 정의
@@ -15,36 +81,103 @@ pub struct BoardService {
 pub enum ServiceError {
     NotFound,
     InvalidInput(String),
-    DatabaseError(oracle::Error),
+    /// 인증 정보가 없거나 유효하지 않음
+    Unauthorized(String),
+    /// 인증은 되었지만 해당 리소스에 대한 권한이 없음 (예: 다른 사람의 게시글)
+    Forbidden,
+    /// 이미 존재하는 리소스와 충돌 (예: 중복 사용자명)
+    Conflict(String),
+    /// `If-Match`의 버전이 현재 리소스 버전과 다름 (다른 사용자가 먼저 수정함)
+    PreconditionFailed,
+    /// 조건부 요청 헤더(`If-Match`)가 필요한데 없음
+    PreconditionRequired,
+    /// 업로드 크기 제한 초과
+    PayloadTooLarge(String),
+    /// 허용되지 않은 파일 형식
+    UnsupportedMediaType(String),
+    /// 요청한 바이트 범위를 만족할 수 없음 (값은 전체 파일 크기)
+    RangeNotSatisfiable(u64),
+    /// 요청 빈도 제한 초과 (값은 다시 시도할 수 있을 때까지의 초)
+    TooManyRequests(u64),
+    /// 첨부파일 저장소(로컬 디렉터리) 입출력 오류
+    StorageError(std::io::Error),
+    DatabaseError(StoreError),
 }
 
-/// oracle::Error를 ServiceError로 자동 변환
-impl From<oracle::Error> for ServiceError {
-    fn from(err: oracle::Error) -> Self {
-        ServiceError::DatabaseError(err)
+/// StoreError를 ServiceError로 자동 변환 (고유 제약 위반은 409, 조회 결과 없음은 404)
+impl From<StoreError> for ServiceError {
+    fn from(err: StoreError) -> Self {
+        match err {
+            StoreError::Conflict(_) => {
+                ServiceError::Conflict("이미 존재하는 데이터와 충돌합니다.".to_string())
+            }
+            StoreError::NotFound => ServiceError::NotFound,
+            other => ServiceError::DatabaseError(other),
+        }
+    }
+}
+
+/// std::io::Error를 ServiceError로 자동 변환
+impl From<std::io::Error> for ServiceError {
+    fn from(err: std::io::Error) -> Self {
+        ServiceError::StorageError(err)
     }
 }
 
 impl BoardService {
-    /// 서비스 생성자: Repository 의존성 주입
-    pub fn new(repository: Arc<BoardRepository>) -> Self {
-        Self { repository }
+    /// 서비스 생성자: Repository 의존성 주입 (`BoardStore` 구현체라면 무엇이든 가능)
+    /// `categories`는 게시글에 지정할 카테고리가 있는지 확인하는 데,
+    /// `files`는 휴지통의 게시글을 영구 삭제할 때 첨부파일 본문을 함께 지우는 데 사용합니다.
+    pub fn new(
+        repository: Arc<dyn BoardStore>,
+        categories: Arc<dyn CategoryStore>,
+        files: Arc<LocalFileStorage>,
+    ) -> Self {
+        Self {
+            repository,
+            categories,
+            files,
+            pending_views: Mutex::new(HashMap::new()),
+            popular_cache: Mutex::new(HashMap::new()),
+            popular_cache_ttl: DEFAULT_POPULAR_CACHE_TTL,
+            events: Arc::new(BoardEvents::default()),
+        }
+    }
+
+    /// 게시글 변경 이벤트를 발행할 허브를 바꿉니다. (보관 개수, 하트비트 주기 설정용)
+    pub fn with_events(mut self, events: Arc<BoardEvents>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// 게시글 변경 이벤트 허브 (SSE/WebSocket 구독에 사용)
+    pub fn events(&self) -> &Arc<BoardEvents> {
+        &self.events
     }
 
-    /// 페이지네이션을 사용하여 게시글 목록 조회
+    /// 인기 게시글 캐시 유지 시간을 바꿉니다. (`Duration::ZERO`면 캐시하지 않음)
+    pub fn with_popular_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.popular_cache_ttl = ttl;
+        self
+    }
+
+    /// 검색 조건과 페이지네이션을 사용하여 게시글 목록 조회
     pub async fn get_boards_paged(
         &self,
         page: u32,
         size: u32,
+        search: BoardSearchQuery,
     ) -> Result<(Vec<BoardListItem>, u32), ServiceError> {
         info!(
-            "[Service] get_boards_paged 호출: page={}, size={}",
-            page, size
+            "[Service] get_boards_paged 호출: page={}, size={}, search={:?}",
+            page, size, search
         );
         self.validate_page(page)?;
         self.validate_size(size)?;
+        let filter = self.validate_search(search)?;
 
-        let total_boards = self.repository.count_all().await?;
+        // 총 개수도 같은 검색 조건으로 세어야 total_pages가 검색 결과와 일치합니다.
+        let total_boards = self.repository.count_all(&filter).await?;
         // 총 페이지 수를 계산합니다 (올림 처리).
         let total_pages = total_boards.div_ceil(size);
 
@@ -61,7 +194,7 @@ impl BoardService {
         }
 
         let offset = (page - 1) * size;
-        let boards = self.repository.find_paged(offset, size).await?;
+        let boards = self.repository.find_paged(&filter, offset, size).await?;
 
         debug!(
             "[Service] get_boards_paged 반환: {}개, 총 페이지: {}",
@@ -71,6 +204,57 @@ impl BoardService {
         Ok((boards, total_pages))
     }
 
+    /// 키셋(커서) 방식으로 게시글 목록 조회.
+    ///
+    /// `(CREATED_AT, ID)` 순서로 커서 뒤의 게시글을 조회하므로 OFFSET 스캔이 없고,
+    /// 조회 중 새 글이 추가되어도 페이지 경계가 밀리지 않습니다.
+    /// 전체 개수(COUNT) 쿼리는 `with_total`이 `true`일 때만 실행합니다.
+    pub async fn get_boards_by_cursor(
+        &self,
+        cursor: Option<String>,
+        size: u32,
+        with_total: bool,
+        search: BoardSearchQuery,
+    ) -> Result<BoardCursorPage, ServiceError> {
+        info!(
+            "[Service] get_boards_by_cursor 호출: cursor={:?}, size={}, with_total={}, search={:?}",
+            cursor, size, with_total, search
+        );
+        self.validate_size(size)?;
+        let sort_given = search.sort.as_deref().is_some_and(|s| !s.trim().is_empty());
+        let mut filter = self.validate_search(search)?;
+        filter.sort = self.validate_cursor_sort(filter.sort, sort_given)?;
+        let after = self.decode_cursor(cursor)?;
+
+        // 한 개를 더 조회하여 다음 페이지 존재 여부를 판단합니다.
+        let mut rows = self
+            .repository
+            .find_after(&filter, after, size.saturating_add(1))
+            .await?;
+        let next_cursor = if rows.len() > size as usize {
+            rows.truncate(size as usize);
+            rows.last().map(|(_, cursor)| cursor.encode())
+        } else {
+            None
+        };
+        let total_count = if with_total {
+            Some(self.repository.count_all(&filter).await?)
+        } else {
+            None
+        };
+
+        debug!(
+            "[Service] get_boards_by_cursor 반환: {}개, next_cursor={:?}",
+            rows.len(),
+            next_cursor
+        );
+        Ok(BoardCursorPage {
+            items: rows.into_iter().map(|(item, _)| item).collect(),
+            next_cursor,
+            total_count,
+        })
+    }
+
     /// 특정 게시글 조회 로직 (ID 유효성 검사 포함)
     pub async fn get_board(&self, id: i64) -> Result<Board, ServiceError> {
         info!("[Service] get_board 호출됨, id={}", id);
@@ -82,63 +266,709 @@ impl BoardService {
             .ok_or(ServiceError::NotFound)
     }
 
-    /// 게시글 생성 로직 (제목/내용 유효성 검사 포함)
-    pub async fn create_board(&self, title: &str, content: &str) -> Result<Board, ServiceError> {
-        info!("[Service] create_board 호출됨, title={}", title);
-        self.validate_title(title)?;
-        self.validate_content(content)?;
+    /// 게시글 상세 조회 요청: 조회수를 하나 올리고, 아직 반영 전인 조회수까지 더해 반환합니다.
+    ///
+    /// 조회수는 요청마다 쓰지 않고 메모리에 모았다가 `flush_views`에서 한꺼번에 반영합니다.
+    pub async fn view_board(&self, id: i64) -> Result<Board, ServiceError> {
+        let mut board = self.get_board(id).await?;
+        let mut pending = self.pending_views.lock().unwrap_or_else(|e| e.into_inner());
+        let count = pending.entry(id).or_default();
+        *count += 1;
+        board.view_count = board
+            .view_count
+            .saturating_add(i64::try_from(*count).unwrap_or(i64::MAX));
+        Ok(board)
+    }
+
+    /// 조회수와 좋아요 수를 확인하기 위한 조회: 조회수는 올리지 않고, 아직 반영 전인 조회수를 더해 반환합니다.
+    pub async fn get_engagement(&self, id: i64) -> Result<Board, ServiceError> {
+        let mut board = self.get_board(id).await?;
+        let pending = self.pending_views.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(count) = pending.get(&id) {
+            board.view_count = board
+                .view_count
+                .saturating_add(i64::try_from(*count).unwrap_or(i64::MAX));
+        }
+        Ok(board)
+    }
+
+    /// 모아 둔 조회수를 DB에 반영하고 반영한 게시글 수를 반환합니다.
+    /// 실패하면 다음 반영 때 다시 시도하도록 조회수를 되돌려 둡니다.
+    pub async fn flush_views(&self) -> Result<usize, ServiceError> {
+        let views: Vec<(i64, u64)> = {
+            let mut pending = self.pending_views.lock().unwrap_or_else(|e| e.into_inner());
+            std::mem::take(&mut *pending).into_iter().collect()
+        };
+        if views.is_empty() {
+            return Ok(0);
+        }
+
+        let boards = views.len();
+        if let Err(err) = self.repository.add_views(views.clone()).await {
+            error!("[Service] 조회수 반영 실패 ({}개 게시글): {}", boards, err);
+            let mut pending = self.pending_views.lock().unwrap_or_else(|e| e.into_inner());
+            for (id, count) in views {
+                *pending.entry(id).or_default() += count;
+            }
+            return Err(err.into());
+        }
+        debug!("[Service] flush_views 반환: {}개 게시글", boards);
+        Ok(boards)
+    }
+
+    /// 게시글에 좋아요를 누르고 좋아요 수를 반환합니다. 같은 `liker`가 다시 눌러도 한 번만 셉니다.
+    pub async fn like_board(&self, id: i64, liker: &str) -> Result<i64, ServiceError> {
+        info!("[Service] like_board 호출: id={}, liker={}", id, liker);
+        self.get_board(id).await?;
+        self.repository.add_like(id, liker.to_string()).await?;
+        Ok(self.repository.count_likes(id).await?)
+    }
+
+    /// 게시글의 좋아요를 취소하고 좋아요 수를 반환합니다. 누르지 않았던 좋아요는 그대로 무시합니다.
+    pub async fn unlike_board(&self, id: i64, liker: &str) -> Result<i64, ServiceError> {
+        info!("[Service] unlike_board 호출: id={}, liker={}", id, liker);
+        self.get_board(id).await?;
+        self.repository.remove_like(id, liker.to_string()).await?;
+        Ok(self.repository.count_likes(id).await?)
+    }
+
+    /// 최근 `window`(예: `7d`) 동안의 조회수와 좋아요로 매긴 점수 순 인기 게시글과 집계 기간(일)을 반환합니다.
+    ///
+    /// 결과는 조건별로 `popular_cache_ttl` 동안 메모리에 캐시하므로 그 사이의 조회/좋아요는 바로 반영되지 않습니다.
+    pub async fn popular_boards(
+        &self,
+        window: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<(u32, Vec<PopularBoard>), ServiceError> {
+        info!(
+            "[Service] popular_boards 호출: window={:?}, limit={:?}",
+            window, limit
+        );
+        let days = self.parse_popular_window(window)?;
+        let limit = limit.unwrap_or(DEFAULT_POPULAR_LIMIT);
+        if !(1..=MAX_POPULAR_LIMIT).contains(&limit) {
+            warn!("[Service] 잘못된 인기 게시글 개수: {}", limit);
+            return Err(ServiceError::InvalidInput(format!(
+                "limit은 1 이상 {} 이하여야 합니다.",
+                MAX_POPULAR_LIMIT
+            )));
+        }
+
+        let key = (days, limit);
+        if let Some((cached_at, boards)) = self
+            .popular_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            && cached_at.elapsed() < self.popular_cache_ttl
+        {
+            debug!("[Service] popular_boards 캐시 사용: {:?}", key);
+            return Ok((days, boards.clone()));
+        }
 
-        let id = self
+        let boards = self
             .repository
-            .insert(title.to_string(), content.to_string())
+            .find_popular(days, POPULAR_VIEW_WEIGHT, POPULAR_LIKE_WEIGHT, limit)
             .await?;
+        if !self.popular_cache_ttl.is_zero() {
+            let mut cache = self.popular_cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.retain(|_, (cached_at, _)| cached_at.elapsed() < self.popular_cache_ttl);
+            cache.insert(key, (Instant::now(), boards.clone()));
+        }
+        debug!("[Service] popular_boards 반환: {}개", boards.len());
+        Ok((days, boards))
+    }
+
+    /// 게시글 생성 로직 (제목/내용 유효성 검사 포함, 작성자는 인증된 사용자)
+    ///
+    /// 태그는 정규화(앞뒤 공백 제거, 소문자, 중복 제거)한 뒤 게시글과 한 트랜잭션으로 저장합니다.
+    /// 저장에 성공하면 구독자에게 `created` 이벤트를 보냅니다.
+    pub async fn create_board(
+        &self,
+        author_id: i64,
+        board: NewBoard,
+    ) -> Result<Board, ServiceError> {
+        info!(
+            "[Service] create_board 호출됨, author_id={}, title={}, format={}, category_id={:?}, tags={:?}",
+            author_id,
+            board.title,
+            board.format.as_str(),
+            board.category_id,
+            board.tags
+        );
+        self.validate_title(&board.title)?;
+        self.validate_content(&board.content)?;
+        self.ensure_category(board.category_id).await?;
+        let board = NewBoard {
+            tags: self.normalize_tags(board.tags)?,
+            ..board
+        };
+
+        let id = self.repository.insert(board, author_id).await?;
         info!("[Service] 게시글 생성 완료 id={}, 다시 조회합니다.", id);
 
         // 생성된 게시글을 다시 조회하여 완전한 객체로 반환
-        self.get_board(id).await
+        let created = self.get_board(id).await?;
+        self.events.publish(BoardEventKind::Created, &created);
+        Ok(created)
     }
 
-    /// 게시글 수정 로직
+    /// 휴지통에 없는 모든 게시글을 작성 순서대로 내보냅니다.
+    ///
+    /// 키셋 조회로 `EXPORT_BATCH_SIZE`개씩 읽어 바로 직렬화하므로 전체 목록을 메모리에 올리지 않습니다.
+    /// 읽은 묶음이 조회 캐시에 쌓이지 않도록 캐시를 거치지 않고 조회합니다.
+    /// 첫 묶음은 미리 조회하여, 응답을 시작하기 전에 DB 오류를 에러 응답으로 돌려줄 수 있게 합니다.
+    pub async fn export_boards(
+        &self,
+        format: Option<String>,
+    ) -> Result<
+        (
+            TransferFormat,
+            BoxStream<'static, Result<Bytes, ServiceError>>,
+        ),
+        ServiceError,
+    > {
+        info!("[Service] export_boards 호출: format={:?}", format);
+        let format = self.parse_transfer_format(format)?;
+        let filter = BoardFilter {
+            sort: BoardSort {
+                field: SortField::CreatedAt,
+                direction: SortDirection::Asc,
+            },
+            ..BoardFilter::default()
+        };
+        let first = self
+            .repository
+            .find_after_uncached(&filter, None, EXPORT_BATCH_SIZE)
+            .await?;
+
+        let repository = Arc::clone(&self.repository);
+        let header = stream::iter(format.header().map(|header| Ok(Bytes::from(header))));
+        let rows = stream::unfold(ExportState::First(first), move |state| {
+            let repository = Arc::clone(&repository);
+            let filter = filter.clone();
+            async move {
+                let rows = match state {
+                    ExportState::Done => return None,
+                    ExportState::First(rows) => rows,
+                    ExportState::Next(after) => {
+                        match repository
+                            .find_after_uncached(&filter, Some(after), EXPORT_BATCH_SIZE)
+                            .await
+                        {
+                            Ok(rows) => rows,
+                            Err(err) => {
+                                error!("[Service] 내보내기 중 조회 실패: {}", err);
+                                return Some((Err(ServiceError::from(err)), ExportState::Done));
+                            }
+                        }
+                    }
+                };
+                if rows.is_empty() {
+                    return None;
+                }
+
+                let next = match rows.last() {
+                    Some((_, cursor)) if rows.len() >= EXPORT_BATCH_SIZE as usize => {
+                        ExportState::Next(cursor.clone())
+                    }
+                    _ => ExportState::Done,
+                };
+                debug!("[Service] 내보내기 묶음: {}개", rows.len());
+                let chunk = format
+                    .encode(rows.iter().map(|(item, _)| item))
+                    .map(Bytes::from)
+                    .map_err(ServiceError::from);
+                Some((chunk, next))
+            }
+        });
+
+        Ok((format, header.chain(rows).boxed()))
+    }
+
+    /// 내보내기 형식(JSON Lines, CSV)의 파일을 가져와 게시글로 추가합니다. (작성자는 가져온 사용자)
+    ///
+    /// 행마다 게시글 작성과 같은 제목/내용 검증을 거치고, 통과한 행은 `IMPORT_BATCH_SIZE`개씩
+    /// 한 트랜잭션으로 추가합니다. 실패한 행은 줄 번호와 사유를 보고서에 담고 나머지는 계속 처리합니다.
+    pub async fn import_boards(
+        &self,
+        author_id: i64,
+        format: Option<String>,
+        body: &[u8],
+    ) -> Result<ImportReport, ServiceError> {
+        info!(
+            "[Service] import_boards 호출: author_id={}, format={:?}, bytes={}",
+            author_id,
+            format,
+            body.len()
+        );
+        let format = self.parse_transfer_format(format)?;
+        let rows = format.parse_rows(body);
+
+        let mut report = ImportReport {
+            total: rows.len(),
+            ..ImportReport::default()
+        };
+        let mut batch: Vec<(u64, NewBoard)> = Vec::with_capacity(IMPORT_BATCH_SIZE);
+        // 있는 것으로 확인한 카테고리 ID (행마다 다시 조회하지 않도록)
+        let mut known_categories = HashSet::new();
+        for row in rows {
+            let mut checked = row.record.and_then(|record| {
+                self.validate_title(&record.title)
+                    .and_then(|_| self.validate_content(&record.content))
+                    .map_err(Self::input_error_message)?;
+                let tags = self
+                    .normalize_tags(record.tags)
+                    .map_err(Self::input_error_message)?;
+                let format = match record.format.as_deref().map(str::trim) {
+                    None | Some("") => ContentFormat::default(),
+                    Some(format) => ContentFormat::parse(format).ok_or_else(|| {
+                        format!("지원하지 않는 본문 형식입니다: {format} (plain 또는 markdown)")
+                    })?,
+                };
+                Ok(NewBoard {
+                    title: record.title,
+                    content: record.content,
+                    format,
+                    category_id: record.category_id,
+                    tags,
+                })
+            });
+            if let Ok(NewBoard {
+                category_id: Some(category_id),
+                ..
+            }) = &checked
+                && !known_categories.contains(category_id)
+            {
+                match self.ensure_category(Some(*category_id)).await {
+                    Ok(()) => {
+                        known_categories.insert(*category_id);
+                    }
+                    Err(err) => checked = Err(Self::input_error_message(err)),
+                }
+            }
+            match checked {
+                Ok(board) => batch.push((row.line, board)),
+                Err(error) => report.errors.push(ImportRowError {
+                    line: row.line,
+                    error,
+                }),
+            }
+            if batch.len() >= IMPORT_BATCH_SIZE {
+                self.import_batch(author_id, std::mem::take(&mut batch), &mut report)
+                    .await;
+            }
+        }
+        if !batch.is_empty() {
+            self.import_batch(author_id, batch, &mut report).await;
+        }
+
+        report.errors.sort_by_key(|error| error.line);
+        report.imported = report.ids.len();
+        report.failed = report.errors.len();
+        info!(
+            "[Service] import_boards 완료: total={}, imported={}, failed={}",
+            report.total, report.imported, report.failed
+        );
+        Ok(report)
+    }
+
+    /// 게시글 수정 로직 (작성자 본인만 가능).
+    ///
+    /// `if_match`는 클라이언트가 보낸 `If-Match` 헤더 값으로, 필수입니다.
+    /// 조회 이후 다른 수정이 있었다면 `PreconditionFailed`를 반환하며, 성공 시 수정된 게시글을 반환합니다.
+    /// 카테고리와 태그는 요청에 있을 때만 바꾸며, 태그 목록은 게시글 수정과 한 트랜잭션으로 교체합니다.
+    /// 수정에 성공하면 구독자에게 `updated` 이벤트를 보냅니다.
     pub async fn update_board(
         &self,
+        user_id: i64,
+        id: i64,
+        if_match: Option<&str>,
+        changes: BoardUpdate,
+    ) -> Result<Board, ServiceError> {
+        info!(
+            "[Service] update_board 호출됨, user_id={}, id={}, if_match={:?}, title={}, format={:?}, category_id={:?}, tags={:?}",
+            user_id, id, if_match, changes.title, changes.format, changes.category_id, changes.tags
+        );
+        self.validate_id(id)?;
+        self.validate_title(&changes.title)?;
+        self.validate_content(&changes.content)?;
+        let tags = changes
+            .tags
+            .map(|tags| self.normalize_tags(tags))
+            .transpose()?;
+        let board = self.ensure_owner(user_id, id).await?;
+        self.check_precondition(&board, if_match)?;
+        self.ensure_category(changes.category_id.flatten()).await?;
+
+        let changes = BoardUpdate { tags, ..changes };
+        let updated = self.repository.update(id, changes, board.version).await?;
+        if !updated {
+            return Err(self.lost_update_error(id).await?);
+        }
+        info!(
+            "[Service] update_board 반환: 게시글 수정 완료 id={}, version={}",
+            id,
+            board.version + 1
+        );
+        let updated = self.get_board(id).await?;
+        self.events.publish(BoardEventKind::Updated, &updated);
+        Ok(updated)
+    }
+
+    /// 게시글 삭제 로직 (작성자 본인만 가능, `If-Match` 필수).
+    ///
+    /// 게시글은 휴지통으로 옮겨질 뿐이며, 댓글과 첨부파일은 복원에 대비해 그대로 남습니다.
+    /// 이동에 성공하면 구독자에게 `deleted` 이벤트를 보냅니다.
+    pub async fn delete_board(
+        &self,
+        user_id: i64,
         id: i64,
-        title: &str,
-        content: &str,
+        if_match: Option<&str>,
     ) -> Result<(), ServiceError> {
-        info!("[Service] update_board 호출됨, id={}, title={}", id, title);
+        info!(
+            "[Service] delete_board 호출됨, user_id={}, id={}, if_match={:?}",
+            user_id, id, if_match
+        );
         self.validate_id(id)?;
-        self.validate_title(title)?;
-        self.validate_content(content)?;
+        let board = self.ensure_owner(user_id, id).await?;
+        self.check_precondition(&board, if_match)?;
+
+        if !self.repository.delete(id, board.version).await? {
+            return Err(self.lost_update_error(id).await?);
+        }
+        info!(
+            "[Service] delete_board 반환: 휴지통으로 이동 완료 id={}",
+            id
+        );
+        self.events.publish(BoardEventKind::Deleted, &board);
+        Ok(())
+    }
 
-        let updated = self
+    /// 로그인한 사용자의 휴지통 목록 조회 (삭제 시각 내림차순)
+    pub async fn get_trash(
+        &self,
+        user_id: i64,
+        page: u32,
+        size: u32,
+    ) -> Result<(Vec<TrashedBoard>, u32), ServiceError> {
+        info!(
+            "[Service] get_trash 호출: user_id={}, page={}, size={}",
+            user_id, page, size
+        );
+        self.validate_page(page)?;
+        self.validate_size(size)?;
+
+        let total_pages = self.repository.count_trash(user_id).await?.div_ceil(size);
+        let offset = (page - 1) * size;
+        let boards = self.repository.find_trash(user_id, offset, size).await?;
+
+        debug!(
+            "[Service] get_trash 반환: {}개, 총 페이지: {}",
+            boards.len(),
+            total_pages
+        );
+        Ok((boards, total_pages))
+    }
+
+    /// 휴지통의 게시글을 복원합니다. (작성자 본인만 가능)
+    pub async fn restore_board(&self, user_id: i64, id: i64) -> Result<Board, ServiceError> {
+        info!(
+            "[Service] restore_board 호출됨, user_id={}, id={}",
+            user_id, id
+        );
+        self.validate_id(id)?;
+        let board = self
             .repository
-            .update(id, title.to_string(), content.to_string())
-            .await?;
-        if updated {
-            info!("[Service] update_board 반환: 게시글 수정 완료 id={}", id);
+            .find_trashed_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        if board.author_id != Some(user_id) {
+            warn!(
+                "[Service] 권한 없음: user_id={}, id={}, author_id={:?}",
+                user_id, id, board.author_id
+            );
+            return Err(ServiceError::Forbidden);
+        }
+
+        // 확인 이후 다른 요청이 먼저 복원했거나 영구 삭제했다면 휴지통에 없는 것으로 봅니다.
+        if !self.repository.restore(id).await? {
+            return Err(ServiceError::NotFound);
+        }
+        info!("[Service] restore_board 반환: 게시글 복원 완료 id={}", id);
+        self.get_board(id).await
+    }
+
+    /// 휴지통에 들어간 지 `retention_days`일이 지난 게시글을 영구 삭제하고 삭제한 개수를 반환합니다.
+    ///
+    /// 메타데이터는 저장소 트랜잭션에서 지워지며, 남은 첨부파일 본문은 그 뒤에 정리합니다.
+    /// 파일 삭제 실패는 영구 삭제를 되돌리지 않고 로그로만 남깁니다.
+    pub async fn purge_expired(&self, retention_days: u32) -> Result<usize, ServiceError> {
+        info!(
+            "[Service] purge_expired 호출: retention_days={}",
+            retention_days
+        );
+        let ids = self.repository.purge_expired(retention_days).await?;
+        for id in &ids {
+            if let Err(err) = self.files.remove_board(*id).await {
+                error!("[Service] 첨부파일 삭제 실패 board_id={}: {}", id, err);
+            }
+        }
+        info!("[Service] purge_expired 반환: {}개 영구 삭제", ids.len());
+        Ok(ids.len())
+    }
+
+    /// 게시글의 수정 이력 목록 조회 (최신 이력부터)
+    pub async fn list_revisions(&self, id: i64) -> Result<Vec<BoardRevision>, ServiceError> {
+        info!("[Service] list_revisions 호출됨, id={}", id);
+        self.get_board(id).await?;
+        Ok(self.repository.find_revisions(id).await?)
+    }
+
+    /// 게시글의 특정 수정 이력 조회
+    pub async fn get_revision(
+        &self,
+        id: i64,
+        revision: i64,
+    ) -> Result<BoardRevision, ServiceError> {
+        info!(
+            "[Service] get_revision 호출됨, id={}, revision={}",
+            id, revision
+        );
+        self.validate_revision(revision)?;
+        self.get_board(id).await?;
+        self.repository
+            .find_revision(id, revision)
+            .await?
+            .ok_or(ServiceError::NotFound)
+    }
+
+    /// 두 시점의 게시글을 줄 단위로 비교합니다. `to`가 없으면 현재 게시글과 비교합니다.
+    pub async fn diff_revisions(
+        &self,
+        id: i64,
+        from: i64,
+        to: Option<i64>,
+    ) -> Result<RevisionDiff, ServiceError> {
+        info!(
+            "[Service] diff_revisions 호출됨, id={}, from={}, to={:?}",
+            id, from, to
+        );
+        let old = self.get_revision(id, from).await?;
+        let (new_title, new_content) = match to {
+            Some(to) => {
+                let new = self.get_revision(id, to).await?;
+                (new.title, new.content)
+            }
+            None => {
+                let board = self.get_board(id).await?;
+                (board.title, board.content)
+            }
+        };
+
+        let lines = RevisionDiff::diff_lines(&old.content, &new_content);
+        debug!("[Service] diff_revisions 반환: {}줄", lines.len());
+        Ok(RevisionDiff {
+            from,
+            to,
+            old_title: old.title,
+            new_title,
+            lines,
+        })
+    }
+
+    /// 게시글을 특정 수정 이력의 제목/내용으로 되돌립니다. (작성자 본인만 가능, `If-Match` 필수)
+    ///
+    /// 되돌리기도 일반 수정과 같으므로, 되돌리기 직전의 내용이 새 이력으로 보관됩니다.
+    pub async fn revert_revision(
+        &self,
+        user_id: i64,
+        id: i64,
+        revision: i64,
+        if_match: Option<&str>,
+    ) -> Result<Board, ServiceError> {
+        info!(
+            "[Service] revert_revision 호출됨, user_id={}, id={}, revision={}, if_match={:?}",
+            user_id, id, revision, if_match
+        );
+        self.validate_id(id)?;
+        self.validate_revision(revision)?;
+        let board = self.ensure_owner(user_id, id).await?;
+        let target = self
+            .repository
+            .find_revision(id, revision)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        self.check_precondition(&board, if_match)?;
+
+        // 이력에는 제목/내용만 남으므로 본문 형식과 카테고리, 태그는 그대로 둡니다.
+        let changes = BoardUpdate {
+            title: target.title,
+            content: target.content,
+            ..BoardUpdate::default()
+        };
+        let updated = self.repository.update(id, changes, board.version).await?;
+        if !updated {
+            return Err(self.lost_update_error(id).await?);
+        }
+        info!(
+            "[Service] revert_revision 반환: 이력 {}로 되돌림 id={}",
+            revision, id
+        );
+        let reverted = self.get_board(id).await?;
+        self.events.publish(BoardEventKind::Updated, &reverted);
+        Ok(reverted)
+    }
+
+    /// 휴지통에 없는 게시글에 붙은 태그와 태그별 게시글 수 (많이 쓰인 순)
+    pub async fn list_tags(&self) -> Result<Vec<TagCount>, ServiceError> {
+        info!("[Service] list_tags 호출");
+        let tags = self.repository.find_tag_counts().await?;
+        debug!("[Service] list_tags 반환: {}개", tags.len());
+        Ok(tags)
+    }
+
+    /// 게시글이 존재하고 `user_id`가 작성자인지 확인한 뒤 게시글을 반환합니다.
+    /// 작성자가 없는(인증 도입 이전) 게시글은 누구도 수정/삭제할 수 없습니다.
+    async fn ensure_owner(&self, user_id: i64, id: i64) -> Result<Board, ServiceError> {
+        let board = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        if board.author_id == Some(user_id) {
+            Ok(board)
+        } else {
+            warn!(
+                "[Service] 권한 없음: user_id={}, id={}, author_id={:?}",
+                user_id, id, board.author_id
+            );
+            Err(ServiceError::Forbidden)
+        }
+    }
+
+    /// `If-Match` 헤더가 있고 현재 게시글 버전과 일치하는지 확인합니다.
+    fn check_precondition(
+        &self,
+        board: &Board,
+        if_match: Option<&str>,
+    ) -> Result<(), ServiceError> {
+        let Some(if_match) = if_match else {
+            warn!("[Service] If-Match 헤더 없음: id={}", board.id);
+            return Err(ServiceError::PreconditionRequired);
+        };
+        if etag::if_match(if_match, &board.etag()) {
             Ok(())
         } else {
-            warn!("[Service] 수정할 게시글 없음 id={}", id);
-            Err(ServiceError::NotFound)
+            warn!(
+                "[Service] 버전 불일치: id={}, if_match={}, current={}",
+                board.id,
+                if_match,
+                board.etag()
+            );
+            Err(ServiceError::PreconditionFailed)
         }
     }
 
-    /// 게시글 삭제 로직
-    pub async fn delete_board(&self, id: i64) -> Result<(), ServiceError> {
-        info!("[Service] delete_board 호출됨, id={}", id);
-        self.validate_id(id)?;
+    /// 조건부 수정/삭제가 아무 행도 바꾸지 못했을 때의 원인을 판별합니다.
+    /// 확인과 실행 사이에 다른 요청이 게시글을 수정했으면 `PreconditionFailed`, 삭제했으면 `NotFound`입니다.
+    async fn lost_update_error(&self, id: i64) -> Result<ServiceError, ServiceError> {
+        if self.repository.find_by_id(id).await?.is_some() {
+            warn!("[Service] 동시 수정으로 버전 불일치 id={}", id);
+            Ok(ServiceError::PreconditionFailed)
+        } else {
+            warn!("[Service] 대상 게시글 없음 id={}", id);
+            Ok(ServiceError::NotFound)
+        }
+    }
 
-        if self.repository.delete(id).await? {
-            info!("[Service] delete_board 반환: 게시글 삭제 완료 id={}", id);
+    /// 게시글에 지정할 카테고리가 있는지 확인합니다. (`None`은 미분류)
+    async fn ensure_category(&self, category_id: Option<i64>) -> Result<(), ServiceError> {
+        let Some(category_id) = category_id else {
+            return Ok(());
+        };
+        if category_id > 0 && self.categories.find_by_id(category_id).await?.is_some() {
             Ok(())
         } else {
-            warn!("[Service] 삭제할 게시글 없음 id={}", id);
-            Err(ServiceError::NotFound)
+            warn!("[Service] 존재하지 않는 카테고리: {}", category_id);
+            Err(ServiceError::InvalidInput(format!(
+                "존재하지 않는 카테고리입니다: {}",
+                category_id
+            )))
         }
     }
 
     // --- 유효성 검사 헬퍼 함수들 ---
 
+    /// 인기 게시글 집계 기간 `<일수>d`를 일수로 변환합니다. (없으면 기본값)
+    fn parse_popular_window(&self, window: Option<&str>) -> Result<u32, ServiceError> {
+        let Some(window) = window.map(str::trim) else {
+            return Ok(DEFAULT_POPULAR_WINDOW_DAYS);
+        };
+        window
+            .strip_suffix('d')
+            .and_then(|days| days.parse::<u32>().ok())
+            .filter(|days| (1..=MAX_POPULAR_WINDOW_DAYS).contains(days))
+            .ok_or_else(|| {
+                warn!("[Service] 잘못된 집계 기간: {}", window);
+                ServiceError::InvalidInput(format!(
+                    "window는 1d부터 {}d 사이여야 합니다: {}",
+                    MAX_POPULAR_WINDOW_DAYS, window
+                ))
+            })
+    }
+
+    /// 검증된 행 묶음을 한 트랜잭션으로 추가합니다. 저장에 실패하면 묶음의 모든 행을 실패로 기록합니다.
+    async fn import_batch(
+        &self,
+        author_id: i64,
+        batch: Vec<(u64, NewBoard)>,
+        report: &mut ImportReport,
+    ) {
+        let (lines, boards): (Vec<u64>, Vec<NewBoard>) = batch.into_iter().unzip();
+        match self.repository.insert_many(boards, author_id).await {
+            Ok(ids) => report.ids.extend(ids),
+            Err(err) => {
+                error!(
+                    "[Service] 가져오기 묶음 저장 실패 ({}행): {}",
+                    lines.len(),
+                    err
+                );
+                report.errors.extend(lines.into_iter().map(|line| {
+                    ImportRowError {
+                        line,
+                        error: "저장 중 오류가 발생하여 이 행이 포함된 묶음을 추가하지 못했습니다."
+                            .to_string(),
+                    }
+                }));
+            }
+        }
+    }
+
+    /// 검증 에러를 가져오기 보고서에 담을 메시지로 변환합니다.
+    fn input_error_message(err: ServiceError) -> String {
+        match err {
+            ServiceError::InvalidInput(message) => message,
+            other => format!("{:?}", other),
+        }
+    }
+
+    /// 내보내기/가져오기 형식을 해석합니다. 지정하지 않으면 JSON Lines입니다.
+    fn parse_transfer_format(
+        &self,
+        format: Option<String>,
+    ) -> Result<TransferFormat, ServiceError> {
+        match format.as_deref().map(str::trim) {
+            None | Some("") => Ok(TransferFormat::default()),
+            Some(value) => TransferFormat::parse(value).ok_or_else(|| {
+                ServiceError::InvalidInput(format!(
+                    "지원하지 않는 형식입니다: {} (jsonl, csv 중 하나)",
+                    value
+                ))
+            }),
+        }
+    }
+
     fn validate_id(&self, id: i64) -> Result<(), ServiceError> {
         if id > 0 {
             Ok(())
@@ -150,6 +980,17 @@ impl BoardService {
         }
     }
 
+    fn validate_revision(&self, revision: i64) -> Result<(), ServiceError> {
+        if revision > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 이력 번호: {}", revision);
+            Err(ServiceError::InvalidInput(
+                "이력 번호는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
     fn validate_page(&self, page: u32) -> Result<(), ServiceError> {
         if page > 0 {
             Ok(())
@@ -162,14 +1003,20 @@ impl BoardService {
     }
 
     fn validate_size(&self, size: u32) -> Result<(), ServiceError> {
-        if size > 0 {
-            Ok(())
-        } else {
+        if size == 0 {
             warn!("[Service] 유효하지 않은 size: {}", size);
-            Err(ServiceError::InvalidInput(
+            return Err(ServiceError::InvalidInput(
                 "size는 0보다 커야 합니다.".to_string(),
-            ))
+            ));
+        }
+        if size > MAX_PAGE_SIZE {
+            warn!("[Service] size 최댓값 초과: {}", size);
+            return Err(ServiceError::InvalidInput(format!(
+                "size는 최대 {}까지 지정할 수 있습니다.",
+                MAX_PAGE_SIZE
+            )));
         }
+        Ok(())
     }
 
     fn validate_title(&self, title: &str) -> Result<(), ServiceError> {
@@ -186,9 +1033,214 @@ impl BoardService {
     }
 
     fn validate_content(&self, content: &str) -> Result<(), ServiceError> {
-        if content.trim().is_empty() {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
             return Err(ServiceError::InvalidInput("내용은 필수입니다.".to_string()));
         }
+        if trimmed.chars().count() > MAX_CONTENT_CHARS {
+            return Err(ServiceError::InvalidInput(format!(
+                "내용이 너무 깁니다 (최대 {}자)",
+                MAX_CONTENT_CHARS
+            )));
+        }
         Ok(())
     }
+
+    /// 태그 목록을 정규화합니다. 앞뒤 공백을 없애고 소문자로 바꾼 뒤, 처음 나온 순서대로 중복을 제거합니다.
+    fn normalize_tags(&self, tags: Vec<String>) -> Result<Vec<String>, ServiceError> {
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag = self.normalize_tag(&tag)?;
+            if !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+        if normalized.len() > MAX_TAGS_PER_BOARD {
+            warn!("[Service] 태그 개수 초과: {}", normalized.len());
+            return Err(ServiceError::InvalidInput(format!(
+                "태그는 최대 {}개까지 붙일 수 있습니다.",
+                MAX_TAGS_PER_BOARD
+            )));
+        }
+        Ok(normalized)
+    }
+
+    /// 태그 이름 하나를 검증하고 정규화합니다. (목록 필터에도 같은 규칙을 씁니다)
+    fn normalize_tag(&self, tag: &str) -> Result<String, ServiceError> {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "태그는 빈 문자열일 수 없습니다.".to_string(),
+            ));
+        }
+        if tag.chars().count() > MAX_TAG_CHARS {
+            warn!("[Service] 태그가 너무 김: {}", tag);
+            return Err(ServiceError::InvalidInput(format!(
+                "태그가 너무 깁니다 (최대 {}자)",
+                MAX_TAG_CHARS
+            )));
+        }
+        if tag.chars().any(char::is_control) {
+            return Err(ServiceError::InvalidInput(
+                "태그에 제어 문자를 쓸 수 없습니다.".to_string(),
+            ));
+        }
+        Ok(tag)
+    }
+
+    /// `category` 검색 조건(카테고리 ID)을 해석합니다.
+    fn parse_category_filter(&self, category: Option<String>) -> Result<Option<i64>, ServiceError> {
+        let Some(category) = category.filter(|c| !c.trim().is_empty()) else {
+            return Ok(None);
+        };
+        match category.trim().parse::<i64>() {
+            Ok(id) if id > 0 => Ok(Some(id)),
+            _ => {
+                warn!("[Service] 잘못된 카테고리 조건: {}", category);
+                Err(ServiceError::InvalidInput(
+                    "category는 0보다 큰 카테고리 ID여야 합니다.".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// 커서 문자열을 해석합니다. 비어 있으면 첫 페이지입니다.
+    fn decode_cursor(&self, cursor: Option<String>) -> Result<Option<BoardCursor>, ServiceError> {
+        match cursor.as_deref().map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(raw) => BoardCursor::decode(raw).map(Some).ok_or_else(|| {
+                warn!("[Service] 유효하지 않은 커서: {}", raw);
+                ServiceError::InvalidInput("유효하지 않은 cursor입니다.".to_string())
+            }),
+        }
+    }
+
+    /// 키셋 페이지네이션은 `(CREATED_AT, ID)` 정렬만 지원합니다.
+    /// 정렬을 지정하지 않았다면 작성일 내림차순을 사용합니다.
+    fn validate_cursor_sort(
+        &self,
+        sort: BoardSort,
+        sort_given: bool,
+    ) -> Result<BoardSort, ServiceError> {
+        if !sort_given {
+            return Ok(BoardSort {
+                field: SortField::CreatedAt,
+                direction: SortDirection::Desc,
+            });
+        }
+        match sort.field {
+            SortField::CreatedAt => Ok(sort),
+            _ => {
+                warn!("[Service] 커서 모드에서 지원하지 않는 정렬: {:?}", sort);
+                Err(ServiceError::InvalidInput(
+                    "cursor 모드에서는 sort=created_at[,asc|desc]만 지원합니다.".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// 검색 요청을 검증하여 저장소에 전달할 `BoardFilter`로 변환합니다.
+    fn validate_search(&self, search: BoardSearchQuery) -> Result<BoardFilter, ServiceError> {
+        let keyword = self.validate_search_term("q", search.q)?;
+        let title = self.validate_search_term("title", search.title)?;
+        let from = self.parse_search_date("from", search.from)?;
+        let to = self.parse_search_date("to", search.to)?;
+        if let (Some(from), Some(to)) = (from, to)
+            && from > to
+        {
+            warn!("[Service] 잘못된 기간: from={}, to={}", from, to);
+            return Err(ServiceError::InvalidInput(
+                "from은 to보다 늦을 수 없습니다.".to_string(),
+            ));
+        }
+        let sort = self.parse_sort(search.sort)?;
+        let category_id = self.parse_category_filter(search.category)?;
+        let tag = search
+            .tag
+            .filter(|tag| !tag.trim().is_empty())
+            .map(|tag| self.normalize_tag(&tag))
+            .transpose()?;
+
+        Ok(BoardFilter {
+            keyword,
+            title,
+            from,
+            to,
+            sort,
+            category_id,
+            tag,
+        })
+    }
+
+    /// 검색어 앞뒤 공백을 제거하고, 비어 있으면 조건에서 제외합니다.
+    fn validate_search_term(
+        &self,
+        name: &str,
+        term: Option<String>,
+    ) -> Result<Option<String>, ServiceError> {
+        let Some(term) = term else {
+            return Ok(None);
+        };
+        let trimmed = term.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        if trimmed.chars().count() > 100 {
+            warn!("[Service] 검색어가 너무 김: {}", name);
+            return Err(ServiceError::InvalidInput(format!(
+                "{}이(가) 너무 깁니다 (최대 100자)",
+                name
+            )));
+        }
+        Ok(Some(trimmed.to_string()))
+    }
+
+    /// `YYYY-MM-DD` 형식의 날짜를 파싱합니다.
+    fn parse_search_date(
+        &self,
+        name: &str,
+        date: Option<String>,
+    ) -> Result<Option<NaiveDate>, ServiceError> {
+        let Some(date) = date.filter(|d| !d.trim().is_empty()) else {
+            return Ok(None);
+        };
+        NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| {
+                warn!("[Service] 잘못된 날짜 형식: {}={}", name, date);
+                ServiceError::InvalidInput(format!("{}은(는) YYYY-MM-DD 형식이어야 합니다.", name))
+            })
+    }
+
+    /// `컬럼[,방향]` 형식의 정렬 기준을 파싱합니다 (예: `created_at,desc`).
+    /// 방향을 생략하면 내림차순입니다.
+    fn parse_sort(&self, sort: Option<String>) -> Result<BoardSort, ServiceError> {
+        let Some(sort) = sort.filter(|s| !s.trim().is_empty()) else {
+            return Ok(BoardSort::default());
+        };
+        let invalid = || {
+            warn!("[Service] 잘못된 정렬 기준: {}", sort);
+            ServiceError::InvalidInput(
+                "sort는 id|created_at|title[,asc|desc] 형식이어야 합니다.".to_string(),
+            )
+        };
+
+        let mut parts = sort.split(',').map(|part| part.trim().to_ascii_lowercase());
+        let field = match parts.next().as_deref() {
+            Some("id") => SortField::Id,
+            Some("created_at") => SortField::CreatedAt,
+            Some("title") => SortField::Title,
+            _ => return Err(invalid()),
+        };
+        let direction = match parts.next().as_deref() {
+            None | Some("desc") => SortDirection::Desc,
+            Some("asc") => SortDirection::Asc,
+            Some(_) => return Err(invalid()),
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(BoardSort { field, direction })
+    }
 }
@@ -0,0 +1,154 @@
+//! Service 계층: 게시글 카테고리 관리 및 유효성 검사
+
+use crate::models::category::Category;
+use crate::repositories::category_store::CategoryStore;
+use crate::services::board_service::ServiceError;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// 카테고리 이름 최대 길이 (문자 수)
+const MAX_NAME_CHARS: usize = 50;
+/// 카테고리 설명 최대 길이 (문자 수)
+const MAX_DESCRIPTION_CHARS: usize = 200;
+
+/// 카테고리 비즈니스 로직을 담당하는 서비스 구조체
+pub struct CategoryService {
+    categories: Arc<dyn CategoryStore>,
+}
+
+impl CategoryService {
+    /// 서비스 생성자: 카테고리 저장소 주입
+    pub fn new(categories: Arc<dyn CategoryStore>) -> Self {
+        Self { categories }
+    }
+
+    /// 전체 카테고리를 이름 순으로 조회
+    pub async fn list_categories(&self) -> Result<Vec<Category>, ServiceError> {
+        info!("[Service] list_categories 호출");
+        let categories = self.categories.find_all().await?;
+        debug!("[Service] list_categories 반환: {}개", categories.len());
+        Ok(categories)
+    }
+
+    /// 특정 카테고리 조회
+    pub async fn get_category(&self, id: i64) -> Result<Category, ServiceError> {
+        info!("[Service] get_category 호출: id={}", id);
+        self.validate_id(id)?;
+        self.categories
+            .find_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)
+    }
+
+    /// 카테고리 생성 (이름은 중복될 수 없음)
+    pub async fn create_category(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Category, ServiceError> {
+        info!("[Service] create_category 호출: name={}", name);
+        let name = self.validate_name(name)?;
+        let description = self.validate_description(description)?;
+        self.ensure_name_available(&name, None).await?;
+
+        let id = self.categories.insert(name, description).await?;
+        info!("[Service] 카테고리 생성 완료 id={}", id);
+        self.get_category(id).await
+    }
+
+    /// 카테고리 이름/설명 수정
+    pub async fn update_category(
+        &self,
+        id: i64,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Category, ServiceError> {
+        info!("[Service] update_category 호출: id={}, name={}", id, name);
+        self.validate_id(id)?;
+        let name = self.validate_name(name)?;
+        let description = self.validate_description(description)?;
+        self.ensure_name_available(&name, Some(id)).await?;
+
+        if !self.categories.update(id, name, description).await? {
+            return Err(ServiceError::NotFound);
+        }
+        info!("[Service] 카테고리 수정 완료 id={}", id);
+        self.get_category(id).await
+    }
+
+    /// 카테고리 삭제. 이 카테고리에 속했던 게시글은 미분류가 됩니다.
+    pub async fn delete_category(&self, id: i64) -> Result<(), ServiceError> {
+        info!("[Service] delete_category 호출: id={}", id);
+        self.validate_id(id)?;
+        if !self.categories.delete(id).await? {
+            return Err(ServiceError::NotFound);
+        }
+        info!("[Service] 카테고리 삭제 완료 id={}", id);
+        Ok(())
+    }
+
+    /// 같은 이름의 다른 카테고리가 있으면 `Conflict`를 반환합니다.
+    async fn ensure_name_available(
+        &self,
+        name: &str,
+        except_id: Option<i64>,
+    ) -> Result<(), ServiceError> {
+        match self.categories.find_by_name(name.to_string()).await? {
+            Some(existing) if Some(existing.id) != except_id => {
+                warn!("[Service] 카테고리 이름 중복: {}", name);
+                Err(ServiceError::Conflict(format!(
+                    "이미 있는 카테고리 이름입니다: {}",
+                    name
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // --- 유효성 검사 헬퍼 함수들 ---
+
+    fn validate_id(&self, id: i64) -> Result<(), ServiceError> {
+        if id > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 카테고리 ID: {}", id);
+            Err(ServiceError::InvalidInput(
+                "ID는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    /// 앞뒤 공백을 제거한 카테고리 이름을 반환합니다.
+    fn validate_name(&self, name: &str) -> Result<String, ServiceError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "카테고리 이름은 필수입니다.".to_string(),
+            ));
+        }
+        if name.chars().count() > MAX_NAME_CHARS {
+            return Err(ServiceError::InvalidInput(format!(
+                "카테고리 이름이 너무 깁니다 (최대 {}자)",
+                MAX_NAME_CHARS
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    /// 설명은 선택 사항이며, 공백뿐이면 없는 것으로 봅니다.
+    fn validate_description(
+        &self,
+        description: Option<&str>,
+    ) -> Result<Option<String>, ServiceError> {
+        let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) else {
+            return Ok(None);
+        };
+        if description.chars().count() > MAX_DESCRIPTION_CHARS {
+            return Err(ServiceError::InvalidInput(format!(
+                "카테고리 설명이 너무 깁니다 (최대 {}자)",
+                MAX_DESCRIPTION_CHARS
+            )));
+        }
+        Ok(Some(description.to_string()))
+    }
+}
@@ -0,0 +1,277 @@
+//! Service 계층: 댓글 비즈니스 로직 및 유효성 검사
+
+use crate::models::comment::{Comment, CommentThread};
+use crate::repositories::board_store::BoardStore;
+use crate::repositories::comment_store::CommentStore;
+use crate::services::board_service::ServiceError;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// 댓글 최대 길이 (문자 수)
+const MAX_CONTENT_CHARS: usize = 1000;
+
+/// 댓글 비즈니스 로직을 담당하는 서비스 구조체
+pub struct CommentService {
+    comments: Arc<dyn CommentStore>,
+    boards: Arc<dyn BoardStore>,
+}
+
+impl CommentService {
+    /// 서비스 생성자: 댓글 저장소와 (게시글 존재 확인용) 게시글 저장소 주입
+    pub fn new(comments: Arc<dyn CommentStore>, boards: Arc<dyn BoardStore>) -> Self {
+        Self { comments, boards }
+    }
+
+    /// 게시글의 댓글을 루트 댓글 기준으로 페이지네이션하여 조회합니다.
+    /// 각 루트 댓글에는 답글이 작성 순으로 함께 담깁니다.
+    pub async fn get_comments_paged(
+        &self,
+        board_id: i64,
+        page: u32,
+        size: u32,
+    ) -> Result<(Vec<CommentThread>, u32), ServiceError> {
+        info!(
+            "[Service] get_comments_paged 호출: board_id={}, page={}, size={}",
+            board_id, page, size
+        );
+        self.validate_id(board_id)?;
+        self.validate_page(page)?;
+        self.validate_size(size)?;
+        self.ensure_board_exists(board_id).await?;
+
+        let total_roots = self.comments.count_roots(board_id).await?;
+        let total_pages = total_roots.div_ceil(size);
+        if page > total_pages && total_pages > 0 {
+            warn!(
+                "[Service] 요청 페이지 초과: page={}, total_pages={}",
+                page, total_pages
+            );
+            return Err(ServiceError::InvalidInput(format!(
+                "요청 페이지가 너무 큽니다. 최대 페이지: {}",
+                total_pages
+            )));
+        }
+
+        let offset = (page - 1) * size;
+        let roots = self
+            .comments
+            .find_roots_paged(board_id, offset, size)
+            .await?;
+
+        // 루트 댓글은 ID 오름차순이므로 첫/마지막 ID 범위로 이 페이지의 답글을 한 번에 조회합니다.
+        let replies = match (roots.first(), roots.last()) {
+            (Some(first), Some(last)) => {
+                self.comments
+                    .find_replies(board_id, first.id, last.id)
+                    .await?
+            }
+            _ => Vec::new(),
+        };
+
+        let mut replies_by_parent: HashMap<i64, Vec<Comment>> = HashMap::new();
+        for reply in replies {
+            if let Some(parent_id) = reply.parent_id {
+                replies_by_parent.entry(parent_id).or_default().push(reply);
+            }
+        }
+
+        let threads: Vec<CommentThread> = roots
+            .into_iter()
+            .map(|comment| CommentThread {
+                replies: replies_by_parent.remove(&comment.id).unwrap_or_default(),
+                comment,
+            })
+            .collect();
+
+        debug!(
+            "[Service] get_comments_paged 반환: {}개 스레드, 총 페이지: {}",
+            threads.len(),
+            total_pages
+        );
+        Ok((threads, total_pages))
+    }
+
+    /// 댓글 또는 답글 작성 (작성자는 인증된 사용자).
+    /// 답글은 같은 게시글의 루트 댓글에만 달 수 있습니다.
+    pub async fn create_comment(
+        &self,
+        author_id: i64,
+        board_id: i64,
+        parent_id: Option<i64>,
+        content: &str,
+    ) -> Result<Comment, ServiceError> {
+        info!(
+            "[Service] create_comment 호출됨, author_id={}, board_id={}, parent_id={:?}",
+            author_id, board_id, parent_id
+        );
+        self.validate_id(board_id)?;
+        self.validate_content(content)?;
+        self.ensure_board_exists(board_id).await?;
+
+        if let Some(parent_id) = parent_id {
+            self.validate_parent(board_id, parent_id).await?;
+        }
+
+        let id = self
+            .comments
+            .insert(board_id, parent_id, author_id, content.to_string())
+            .await?;
+        info!("[Service] 댓글 생성 완료 id={}, 다시 조회합니다.", id);
+
+        self.comments
+            .find_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)
+    }
+
+    /// 댓글 수정 (작성자 본인만 가능)
+    pub async fn update_comment(
+        &self,
+        user_id: i64,
+        id: i64,
+        content: &str,
+    ) -> Result<Comment, ServiceError> {
+        info!(
+            "[Service] update_comment 호출됨, user_id={}, id={}",
+            user_id, id
+        );
+        self.validate_id(id)?;
+        self.validate_content(content)?;
+        let comment = self.ensure_owner(user_id, id).await?;
+
+        if !self.comments.update(id, content.to_string()).await? {
+            warn!("[Service] 수정할 댓글 없음 id={}", id);
+            return Err(ServiceError::NotFound);
+        }
+        info!("[Service] update_comment 반환: 댓글 수정 완료 id={}", id);
+        Ok(Comment {
+            content: content.to_string(),
+            ..comment
+        })
+    }
+
+    /// 댓글 삭제 (작성자 본인만 가능). 루트 댓글을 지우면 답글도 함께 삭제됩니다.
+    pub async fn delete_comment(&self, user_id: i64, id: i64) -> Result<(), ServiceError> {
+        info!(
+            "[Service] delete_comment 호출됨, user_id={}, id={}",
+            user_id, id
+        );
+        self.validate_id(id)?;
+        self.ensure_owner(user_id, id).await?;
+
+        if self.comments.delete(id).await? {
+            info!("[Service] delete_comment 반환: 댓글 삭제 완료 id={}", id);
+            Ok(())
+        } else {
+            warn!("[Service] 삭제할 댓글 없음 id={}", id);
+            Err(ServiceError::NotFound)
+        }
+    }
+
+    /// 댓글을 달 게시글이 존재하는지 확인합니다.
+    async fn ensure_board_exists(&self, board_id: i64) -> Result<(), ServiceError> {
+        match self.boards.find_by_id(board_id).await? {
+            Some(_) => Ok(()),
+            None => {
+                warn!("[Service] 댓글 대상 게시글 없음 board_id={}", board_id);
+                Err(ServiceError::NotFound)
+            }
+        }
+    }
+
+    /// 답글 대상이 같은 게시글의 루트 댓글인지 확인합니다.
+    async fn validate_parent(&self, board_id: i64, parent_id: i64) -> Result<(), ServiceError> {
+        self.validate_id(parent_id)?;
+        let parent = self.comments.find_by_id(parent_id).await?;
+        match parent {
+            Some(parent) if parent.board_id == board_id && parent.parent_id.is_none() => Ok(()),
+            Some(parent) if parent.board_id == board_id => {
+                warn!("[Service] 답글에 대한 답글 시도: parent_id={}", parent_id);
+                Err(ServiceError::InvalidInput(
+                    "답글에는 다시 답글을 달 수 없습니다.".to_string(),
+                ))
+            }
+            _ => {
+                warn!(
+                    "[Service] 유효하지 않은 부모 댓글: board_id={}, parent_id={}",
+                    board_id, parent_id
+                );
+                Err(ServiceError::InvalidInput(
+                    "부모 댓글이 이 게시글에 존재하지 않습니다.".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// 댓글이 존재하고 `user_id`가 작성자인지 확인한 뒤 댓글을 반환합니다.
+    /// 휴지통에 있는 게시글의 댓글은 찾을 수 없는 것으로 봅니다.
+    async fn ensure_owner(&self, user_id: i64, id: i64) -> Result<Comment, ServiceError> {
+        let comment = self
+            .comments
+            .find_by_id(id)
+            .await?
+            .ok_or(ServiceError::NotFound)?;
+        self.ensure_board_exists(comment.board_id).await?;
+        if comment.author_id == Some(user_id) {
+            Ok(comment)
+        } else {
+            warn!(
+                "[Service] 권한 없음: user_id={}, comment_id={}, author_id={:?}",
+                user_id, id, comment.author_id
+            );
+            Err(ServiceError::Forbidden)
+        }
+    }
+
+    // --- 유효성 검사 헬퍼 함수들 ---
+
+    fn validate_id(&self, id: i64) -> Result<(), ServiceError> {
+        if id > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 ID: {}", id);
+            Err(ServiceError::InvalidInput(
+                "ID는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    fn validate_page(&self, page: u32) -> Result<(), ServiceError> {
+        if page > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 페이지 번호: {}", page);
+            Err(ServiceError::InvalidInput(
+                "페이지 번호는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    fn validate_size(&self, size: u32) -> Result<(), ServiceError> {
+        if size > 0 {
+            Ok(())
+        } else {
+            warn!("[Service] 유효하지 않은 size: {}", size);
+            Err(ServiceError::InvalidInput(
+                "size는 0보다 커야 합니다.".to_string(),
+            ))
+        }
+    }
+
+    fn validate_content(&self, content: &str) -> Result<(), ServiceError> {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "댓글 내용은 필수입니다.".to_string(),
+            ));
+        }
+        if trimmed.chars().count() > MAX_CONTENT_CHARS {
+            return Err(ServiceError::InvalidInput(format!(
+                "댓글이 너무 깁니다 (최대 {}자)",
+                MAX_CONTENT_CHARS
+            )));
+        }
+        Ok(())
+    }
+}
@@ -1 +1,6 @@
+pub mod attachment_service;
+pub mod auth_service;
+pub mod board_events;
 pub mod board_service;
+pub mod category_service;
+pub mod comment_service;
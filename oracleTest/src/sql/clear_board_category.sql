@@ -0,0 +1 @@
+UPDATE BOARD SET CATEGORY_ID = NULL WHERE CATEGORY_ID = :category_id
@@ -0,0 +1,6 @@
+CREATE TABLE SCHEMA_MIGRATIONS (
+    VERSION    NUMBER(10) PRIMARY KEY,
+    NAME       VARCHAR2(100) NOT NULL,
+    CHECKSUM   CHAR(64) NOT NULL,
+    APPLIED_AT TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL
+)
@@ -1 +1 @@
-DELETE FROM BOARD WHERE ID = :id
\ No newline at end of file
+DELETE FROM BOARD WHERE ID = :id AND DELETED_AT IS NOT NULL
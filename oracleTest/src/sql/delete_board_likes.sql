@@ -0,0 +1 @@
+DELETE FROM BOARD_LIKES WHERE BOARD_ID = :board_id
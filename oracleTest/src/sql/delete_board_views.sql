@@ -0,0 +1 @@
+DELETE FROM BOARD_VIEWS_DAILY WHERE BOARD_ID = :board_id
@@ -0,0 +1 @@
+DELETE FROM CATEGORIES WHERE ID = :id
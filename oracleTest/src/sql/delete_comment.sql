@@ -0,0 +1 @@
+DELETE FROM COMMENTS WHERE ID = :id OR PARENT_ID = :id
@@ -0,0 +1 @@
+DELETE FROM BOARD_REVISIONS WHERE BOARD_ID = :board_id
@@ -0,0 +1 @@
+DELETE FROM SCHEMA_MIGRATIONS WHERE VERSION = :version
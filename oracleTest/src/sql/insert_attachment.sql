@@ -0,0 +1,2 @@
+INSERT INTO ATTACHMENTS (ID, BOARD_ID, FILENAME, CONTENT_TYPE, SIZE_BYTES, SHA256, STORAGE_KEY, UPLOADER_ID)
+VALUES (ATTACHMENTS_SEQ.NEXTVAL, :board_id, :filename, :content_type, :size_bytes, :sha256, :storage_key, :uploader_id)
@@ -1,2 +1,2 @@
-INSERT INTO BOARD (ID, TITLE, CONTENT)
-VALUES (BOARD_SEQ.NEXTVAL, :title, :content)
+INSERT INTO BOARD (ID, TITLE, CONTENT, CONTENT_FORMAT, AUTHOR_ID, CATEGORY_ID)
+VALUES (BOARD_SEQ.NEXTVAL, :title, :content, :format, :author_id, :category_id)
@@ -0,0 +1,4 @@
+MERGE INTO BOARD_LIKES l
+USING (SELECT :board_id AS BOARD_ID, :liker AS LIKER FROM DUAL) s
+ON (l.BOARD_ID = s.BOARD_ID AND l.LIKER = s.LIKER)
+WHEN NOT MATCHED THEN INSERT (BOARD_ID, LIKER) VALUES (s.BOARD_ID, s.LIKER)
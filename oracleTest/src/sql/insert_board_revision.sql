@@ -0,0 +1,7 @@
+INSERT INTO BOARD_REVISIONS (ID, BOARD_ID, REVISION_NO, TITLE, CONTENT, VERSION)
+VALUES (BOARD_REVISION_SEQ.NEXTVAL,
+        :id,
+        (SELECT NVL(MAX(r.REVISION_NO), 0) + 1 FROM BOARD_REVISIONS r WHERE r.BOARD_ID = :id),
+        :title,
+        :content,
+        :version)
@@ -0,0 +1,2 @@
+INSERT INTO CATEGORIES (ID, NAME, DESCRIPTION)
+VALUES (CATEGORIES_SEQ.NEXTVAL, :name, :description)
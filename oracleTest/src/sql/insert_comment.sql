@@ -0,0 +1,2 @@
+INSERT INTO COMMENTS (ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT)
+VALUES (COMMENTS_SEQ.NEXTVAL, :board_id, :parent_id, :author_id, :content)
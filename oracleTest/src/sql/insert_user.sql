@@ -0,0 +1,2 @@
+INSERT INTO USERS (ID, USERNAME, PASSWORD_HASH)
+VALUES (USERS_SEQ.NEXTVAL, :username, :password_hash)
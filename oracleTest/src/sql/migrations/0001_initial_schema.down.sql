@@ -0,0 +1,12 @@
+-- 초기 스키마 되돌리기: 외래 키를 참조하는 테이블부터 삭제합니다.
+
+DROP TABLE BOARD_REVISIONS;
+DROP SEQUENCE BOARD_REVISION_SEQ;
+DROP TABLE ATTACHMENTS;
+DROP SEQUENCE ATTACHMENTS_SEQ;
+DROP TABLE COMMENTS;
+DROP SEQUENCE COMMENTS_SEQ;
+DROP TABLE BOARD;
+DROP SEQUENCE BOARD_SEQ;
+DROP TABLE USERS;
+DROP SEQUENCE USERS_SEQ;
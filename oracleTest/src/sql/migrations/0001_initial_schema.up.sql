@@ -0,0 +1,68 @@
+-- 초기 스키마: 사용자, 게시글, 댓글, 첨부파일, 수정 이력 테이블과 ID 시퀀스
+-- 문장은 줄 끝의 `;`로 구분하며, 마이그레이션 실행기가 한 문장씩 실행합니다.
+
+CREATE SEQUENCE USERS_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE TABLE USERS (
+    ID            NUMBER(19) PRIMARY KEY,
+    USERNAME      VARCHAR2(30 CHAR) NOT NULL UNIQUE,
+    PASSWORD_HASH VARCHAR2(255) NOT NULL,
+    CREATED_AT    TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL
+);
+
+CREATE SEQUENCE BOARD_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+-- 본문은 최대 20,000자이므로 CLOB에 저장합니다.
+CREATE TABLE BOARD (
+    ID         NUMBER(19) PRIMARY KEY,
+    TITLE      VARCHAR2(200 CHAR) NOT NULL,
+    CONTENT    CLOB,
+    AUTHOR_ID  NUMBER(19) REFERENCES USERS (ID),
+    VERSION    NUMBER(10) DEFAULT 1 NOT NULL,
+    CREATED_AT TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL,
+    DELETED_AT TIMESTAMP
+);
+
+CREATE INDEX IDX_BOARD_DELETED_AT ON BOARD (DELETED_AT, ID);
+
+CREATE SEQUENCE COMMENTS_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE TABLE COMMENTS (
+    ID         NUMBER(19) PRIMARY KEY,
+    BOARD_ID   NUMBER(19) NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    PARENT_ID  NUMBER(19) REFERENCES COMMENTS (ID) ON DELETE CASCADE,
+    AUTHOR_ID  NUMBER(19) REFERENCES USERS (ID),
+    CONTENT    VARCHAR2(1000 CHAR) NOT NULL,
+    CREATED_AT TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL
+);
+
+CREATE INDEX IDX_COMMENTS_BOARD ON COMMENTS (BOARD_ID, PARENT_ID, ID);
+
+CREATE SEQUENCE ATTACHMENTS_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE TABLE ATTACHMENTS (
+    ID           NUMBER(19) PRIMARY KEY,
+    BOARD_ID     NUMBER(19) NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    FILENAME     VARCHAR2(255 CHAR) NOT NULL,
+    CONTENT_TYPE VARCHAR2(255) NOT NULL,
+    SIZE_BYTES   NUMBER(19) NOT NULL,
+    SHA256       CHAR(64) NOT NULL,
+    STORAGE_KEY  VARCHAR2(255) NOT NULL UNIQUE,
+    UPLOADER_ID  NUMBER(19) REFERENCES USERS (ID),
+    CREATED_AT   TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL
+);
+
+CREATE INDEX IDX_ATTACHMENTS_BOARD ON ATTACHMENTS (BOARD_ID, ID);
+
+CREATE SEQUENCE BOARD_REVISION_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE TABLE BOARD_REVISIONS (
+    ID          NUMBER(19) PRIMARY KEY,
+    BOARD_ID    NUMBER(19) NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    REVISION_NO NUMBER(10) NOT NULL,
+    TITLE       VARCHAR2(200 CHAR) NOT NULL,
+    CONTENT     CLOB,
+    VERSION     NUMBER(10) NOT NULL,
+    CREATED_AT  TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL,
+    CONSTRAINT UQ_BOARD_REVISIONS UNIQUE (BOARD_ID, REVISION_NO)
+);
@@ -0,0 +1,9 @@
+-- 카테고리/태그 되돌리기: 연결 테이블과 게시글 컬럼부터 삭제합니다.
+
+DROP TABLE BOARD_TAGS;
+DROP TABLE TAGS;
+DROP SEQUENCE TAGS_SEQ;
+DROP INDEX IDX_BOARD_CATEGORY;
+ALTER TABLE BOARD DROP COLUMN CATEGORY_ID;
+DROP TABLE CATEGORIES;
+DROP SEQUENCE CATEGORIES_SEQ;
@@ -0,0 +1,33 @@
+-- 게시글 분류: 관리하는 카테고리(게시글당 하나)와 자유 입력 태그(다대다)
+
+CREATE SEQUENCE CATEGORIES_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE TABLE CATEGORIES (
+    ID          NUMBER(19) PRIMARY KEY,
+    NAME        VARCHAR2(50 CHAR) NOT NULL UNIQUE,
+    DESCRIPTION VARCHAR2(200 CHAR),
+    CREATED_AT  TIMESTAMP DEFAULT SYSTIMESTAMP NOT NULL
+);
+
+-- 카테고리를 지우면 저장소가 같은 트랜잭션에서 게시글의 CATEGORY_ID를 먼저 비웁니다.
+ALTER TABLE BOARD ADD (
+    CATEGORY_ID NUMBER(19) CONSTRAINT FK_BOARD_CATEGORY REFERENCES CATEGORIES (ID)
+);
+
+CREATE INDEX IDX_BOARD_CATEGORY ON BOARD (CATEGORY_ID, DELETED_AT);
+
+CREATE SEQUENCE TAGS_SEQ START WITH 1 INCREMENT BY 1 NOCACHE;
+
+-- 태그 이름은 소문자로 정규화하여 저장합니다.
+CREATE TABLE TAGS (
+    ID   NUMBER(19) PRIMARY KEY,
+    NAME VARCHAR2(30 CHAR) NOT NULL UNIQUE
+);
+
+CREATE TABLE BOARD_TAGS (
+    BOARD_ID NUMBER(19) NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    TAG_ID   NUMBER(19) NOT NULL REFERENCES TAGS (ID) ON DELETE CASCADE,
+    CONSTRAINT PK_BOARD_TAGS PRIMARY KEY (BOARD_ID, TAG_ID)
+);
+
+CREATE INDEX IDX_BOARD_TAGS_TAG ON BOARD_TAGS (TAG_ID, BOARD_ID);
@@ -0,0 +1,5 @@
+-- 게시글 본문 형식: `plain`(일반 텍스트) 또는 `markdown`
+-- 본문은 원문 그대로 저장하고, HTML은 응답할 때 형식에 맞게 렌더링/정화합니다.
+ALTER TABLE BOARD ADD (
+    CONTENT_FORMAT VARCHAR2(10) DEFAULT 'plain' NOT NULL
+);
@@ -0,0 +1,3 @@
+SELECT ID, BOARD_ID, FILENAME, CONTENT_TYPE, SIZE_BYTES, SHA256, STORAGE_KEY, UPLOADER_ID, CREATED_AT
+FROM ATTACHMENTS
+WHERE ID = :id
@@ -0,0 +1 @@
+SELECT ATTACHMENTS_SEQ.CURRVAL FROM DUAL
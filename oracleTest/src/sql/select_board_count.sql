@@ -1 +1 @@
-SELECT COUNT(*) FROM BOARD
+SELECT COUNT(*) FROM BOARD b {where}
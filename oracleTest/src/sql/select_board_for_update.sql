@@ -0,0 +1,4 @@
+SELECT TITLE, CONTENT
+FROM BOARD
+WHERE ID = :id AND VERSION = :version AND DELETED_AT IS NULL
+FOR UPDATE
@@ -0,0 +1,15 @@
+SELECT ID, TITLE, CONTENT, CONTENT_FORMAT, AUTHOR_ID, CATEGORY_ID, CREATED_AT, CREATED_AT_KEY
+FROM (
+    SELECT b.ID,
+           b.TITLE,
+           b.CONTENT,
+           b.CONTENT_FORMAT,
+           b.AUTHOR_ID,
+           b.CATEGORY_ID,
+           TO_CHAR(b.CREATED_AT, 'YYYY-MM-DD') AS CREATED_AT,
+           b.CREATED_AT AS CREATED_AT_KEY
+    FROM BOARD b
+    {where}
+    ORDER BY {order_by}
+)
+WHERE ROWNUM <= :limit
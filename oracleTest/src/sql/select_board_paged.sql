@@ -1,13 +1,17 @@
-SELECT ID, TITLE, CONTENT, CREATED_AT
+SELECT ID, TITLE, CONTENT, CONTENT_FORMAT, AUTHOR_ID, CATEGORY_ID, CREATED_AT
 FROM (
     SELECT a.*, ROWNUM rnum
     FROM (
-        SELECT ID,
-               TITLE,
-               CONTENT,
-               TO_CHAR(CREATED_AT, 'YYYY-MM-DD') AS CREATED_AT
-        FROM BOARD
-        ORDER BY ID DESC
+        SELECT b.ID,
+               b.TITLE,
+               b.CONTENT,
+               b.CONTENT_FORMAT,
+               b.AUTHOR_ID,
+               b.CATEGORY_ID,
+               TO_CHAR(b.CREATED_AT, 'YYYY-MM-DD') AS CREATED_AT
+        FROM BOARD b
+        {where}
+        ORDER BY {order_by}
     ) a
     WHERE ROWNUM <= :end_row
 )
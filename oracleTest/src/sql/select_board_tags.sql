@@ -0,0 +1,5 @@
+SELECT bt.BOARD_ID, t.NAME
+FROM BOARD_TAGS bt
+JOIN TAGS t ON t.ID = bt.TAG_ID
+WHERE bt.BOARD_ID IN ({ids})
+ORDER BY bt.BOARD_ID, t.NAME
@@ -0,0 +1 @@
+SELECT ID, NAME, DESCRIPTION, CREATED_AT FROM CATEGORIES ORDER BY NAME ASC, ID ASC
@@ -0,0 +1 @@
+SELECT CATEGORIES_SEQ.CURRVAL FROM DUAL
@@ -0,0 +1,5 @@
+SELECT ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT, CREATED_AT
+FROM COMMENTS
+WHERE BOARD_ID = :board_id
+  AND PARENT_ID BETWEEN :first_parent_id AND :last_parent_id
+ORDER BY PARENT_ID ASC, ID ASC
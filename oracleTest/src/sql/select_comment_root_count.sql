@@ -0,0 +1 @@
+SELECT COUNT(*) FROM COMMENTS WHERE BOARD_ID = :board_id AND PARENT_ID IS NULL
@@ -0,0 +1,12 @@
+SELECT ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT, CREATED_AT
+FROM (
+    SELECT a.*, ROWNUM rnum
+    FROM (
+        SELECT ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT, CREATED_AT
+        FROM COMMENTS
+        WHERE BOARD_ID = :board_id AND PARENT_ID IS NULL
+        ORDER BY ID ASC
+    ) a
+    WHERE ROWNUM <= :end_row
+)
+WHERE rnum > :start_row
@@ -0,0 +1 @@
+SELECT COMMENTS_SEQ.CURRVAL FROM DUAL
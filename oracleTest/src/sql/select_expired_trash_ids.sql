@@ -0,0 +1,3 @@
+SELECT ID FROM BOARD
+WHERE DELETED_AT IS NOT NULL
+  AND DELETED_AT <= SYSTIMESTAMP - NUMTODSINTERVAL(:retention_days, 'DAY')
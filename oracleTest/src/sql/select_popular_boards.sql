@@ -0,0 +1,29 @@
+SELECT ID, TITLE, AUTHOR_ID, CATEGORY_ID, VIEW_COUNT, WINDOW_VIEWS, WINDOW_LIKES, SCORE, CREATED_AT
+FROM (
+    SELECT b.ID,
+           b.TITLE,
+           b.AUTHOR_ID,
+           b.CATEGORY_ID,
+           b.VIEW_COUNT,
+           NVL(v.VIEWS, 0) AS WINDOW_VIEWS,
+           NVL(l.LIKES, 0) AS WINDOW_LIKES,
+           NVL(v.VIEWS, 0) * :view_weight + NVL(l.LIKES, 0) * :like_weight AS SCORE,
+           TO_CHAR(b.CREATED_AT, 'YYYY-MM-DD') AS CREATED_AT
+    FROM BOARD b
+    LEFT JOIN (
+        SELECT BOARD_ID, SUM(VIEWS) AS VIEWS
+        FROM BOARD_VIEWS_DAILY
+        WHERE VIEW_DATE > TRUNC(SYSDATE) - :days
+        GROUP BY BOARD_ID
+    ) v ON v.BOARD_ID = b.ID
+    LEFT JOIN (
+        SELECT BOARD_ID, COUNT(*) AS LIKES
+        FROM BOARD_LIKES
+        WHERE CREATED_AT > SYSTIMESTAMP - NUMTODSINTERVAL(:days, 'DAY')
+        GROUP BY BOARD_ID
+    ) l ON l.BOARD_ID = b.ID
+    WHERE b.DELETED_AT IS NULL
+      AND (v.VIEWS > 0 OR l.LIKES > 0)
+    ORDER BY SCORE DESC, b.ID DESC
+)
+WHERE ROWNUM <= :limit
@@ -0,0 +1,3 @@
+SELECT VERSION, NAME, CHECKSUM, TO_CHAR(APPLIED_AT, 'YYYY-MM-DD HH24:MI:SS') AS APPLIED_AT
+FROM SCHEMA_MIGRATIONS
+ORDER BY VERSION
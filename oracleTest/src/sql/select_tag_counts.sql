@@ -0,0 +1,7 @@
+SELECT t.NAME, COUNT(*) AS BOARD_COUNT
+FROM TAGS t
+JOIN BOARD_TAGS bt ON bt.TAG_ID = t.ID
+JOIN BOARD b ON b.ID = bt.BOARD_ID
+WHERE b.DELETED_AT IS NULL
+GROUP BY t.NAME
+ORDER BY BOARD_COUNT DESC, t.NAME ASC
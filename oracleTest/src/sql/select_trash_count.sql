@@ -0,0 +1 @@
+SELECT COUNT(*) FROM BOARD WHERE AUTHOR_ID = :author_id AND DELETED_AT IS NOT NULL
@@ -0,0 +1,16 @@
+SELECT ID, TITLE, AUTHOR_ID, CREATED_AT, DELETED_AT
+FROM (
+    SELECT a.*, ROWNUM rnum
+    FROM (
+        SELECT b.ID,
+               b.TITLE,
+               b.AUTHOR_ID,
+               TO_CHAR(b.CREATED_AT, 'YYYY-MM-DD') AS CREATED_AT,
+               TO_CHAR(b.DELETED_AT, 'YYYY-MM-DD HH24:MI:SS') AS DELETED_AT
+        FROM BOARD b
+        WHERE b.AUTHOR_ID = :author_id AND b.DELETED_AT IS NOT NULL
+        ORDER BY b.DELETED_AT DESC, b.ID DESC
+    ) a
+    WHERE ROWNUM <= :end_row
+)
+WHERE rnum > :start_row
@@ -0,0 +1 @@
+SELECT USERS_SEQ.CURRVAL FROM DUAL
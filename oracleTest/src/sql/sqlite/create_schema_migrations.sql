@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS SCHEMA_MIGRATIONS (
+    VERSION    INTEGER PRIMARY KEY,
+    NAME       TEXT NOT NULL,
+    CHECKSUM   TEXT NOT NULL,
+    APPLIED_AT TEXT NOT NULL DEFAULT (datetime('now'))
+)
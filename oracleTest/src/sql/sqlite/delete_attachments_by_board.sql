@@ -0,0 +1 @@
+DELETE FROM ATTACHMENTS WHERE BOARD_ID = :board_id
@@ -0,0 +1 @@
+DELETE FROM BOARD WHERE ID = :id AND DELETED_AT IS NOT NULL
@@ -0,0 +1 @@
+DELETE FROM BOARD_TAGS WHERE BOARD_ID = :board_id
@@ -0,0 +1 @@
+DELETE FROM COMMENTS WHERE BOARD_ID = :board_id
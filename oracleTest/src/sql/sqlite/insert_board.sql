@@ -0,0 +1,2 @@
+INSERT INTO BOARD (TITLE, CONTENT, CONTENT_FORMAT, AUTHOR_ID, CATEGORY_ID)
+VALUES (:title, :content, :format, :author_id, :category_id)
@@ -0,0 +1 @@
+INSERT OR IGNORE INTO BOARD_LIKES (BOARD_ID, LIKER) VALUES (:board_id, :liker)
@@ -0,0 +1,6 @@
+INSERT INTO BOARD_REVISIONS (BOARD_ID, REVISION_NO, TITLE, CONTENT, VERSION)
+VALUES (:id,
+        (SELECT COALESCE(MAX(r.REVISION_NO), 0) + 1 FROM BOARD_REVISIONS r WHERE r.BOARD_ID = :id),
+        :title,
+        :content,
+        :version)
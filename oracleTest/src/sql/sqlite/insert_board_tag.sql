@@ -0,0 +1,2 @@
+INSERT INTO BOARD_TAGS (BOARD_ID, TAG_ID)
+SELECT :board_id, ID FROM TAGS WHERE NAME = :name
@@ -0,0 +1,2 @@
+INSERT INTO CATEGORIES (NAME, DESCRIPTION)
+VALUES (:name, :description)
@@ -0,0 +1,2 @@
+INSERT INTO COMMENTS (BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT)
+VALUES (:board_id, :parent_id, :author_id, :content)
@@ -0,0 +1,2 @@
+INSERT INTO SCHEMA_MIGRATIONS (VERSION, NAME, CHECKSUM)
+VALUES (:version, :name, :checksum)
@@ -0,0 +1,2 @@
+INSERT INTO USERS (USERNAME, PASSWORD_HASH)
+VALUES (:username, :password_hash)
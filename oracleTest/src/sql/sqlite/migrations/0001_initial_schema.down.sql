@@ -0,0 +1,7 @@
+-- 초기 스키마 되돌리기: 외래 키를 참조하는 테이블부터 삭제합니다.
+
+DROP TABLE IF EXISTS BOARD_REVISIONS;
+DROP TABLE IF EXISTS ATTACHMENTS;
+DROP TABLE IF EXISTS COMMENTS;
+DROP TABLE IF EXISTS BOARD;
+DROP TABLE IF EXISTS USERS;
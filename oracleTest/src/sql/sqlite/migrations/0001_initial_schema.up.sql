@@ -0,0 +1,62 @@
+-- 초기 스키마: 사용자, 게시글, 댓글, 첨부파일, 수정 이력 테이블
+-- 마이그레이션 도입 전 `init_schema`로 만든 DB에도 적용할 수 있도록 `IF NOT EXISTS`를 유지합니다.
+
+CREATE TABLE IF NOT EXISTS USERS (
+    ID            INTEGER PRIMARY KEY AUTOINCREMENT,
+    USERNAME      TEXT NOT NULL UNIQUE,
+    PASSWORD_HASH TEXT NOT NULL,
+    CREATED_AT    TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS BOARD (
+    ID         INTEGER PRIMARY KEY AUTOINCREMENT,
+    TITLE      TEXT NOT NULL,
+    CONTENT    TEXT,
+    AUTHOR_ID  INTEGER REFERENCES USERS (ID),
+    -- 낙관적 동시성 제어용 버전 (수정할 때마다 1 증가, ETag로 노출)
+    VERSION    INTEGER NOT NULL DEFAULT 1,
+    CREATED_AT TEXT NOT NULL DEFAULT (datetime('now')),
+    -- 휴지통으로 옮긴 시각 (NULL이면 게시 중). 보존 기간이 지나면 영구 삭제됩니다.
+    DELETED_AT TEXT
+);
+
+CREATE INDEX IF NOT EXISTS IDX_BOARD_DELETED_AT ON BOARD (DELETED_AT, ID);
+
+-- 답글은 PARENT_ID로 루트 댓글을 가리키며, 게시글/루트 댓글 삭제 시 함께 삭제됩니다.
+CREATE TABLE IF NOT EXISTS COMMENTS (
+    ID         INTEGER PRIMARY KEY AUTOINCREMENT,
+    BOARD_ID   INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    PARENT_ID  INTEGER REFERENCES COMMENTS (ID) ON DELETE CASCADE,
+    AUTHOR_ID  INTEGER REFERENCES USERS (ID),
+    CONTENT    TEXT NOT NULL,
+    CREATED_AT TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS IDX_COMMENTS_BOARD ON COMMENTS (BOARD_ID, PARENT_ID, ID);
+
+-- 첨부파일 메타데이터. 파일 본문은 ATTACHMENT_DIR 아래 STORAGE_KEY 경로에 저장됩니다.
+CREATE TABLE IF NOT EXISTS ATTACHMENTS (
+    ID           INTEGER PRIMARY KEY AUTOINCREMENT,
+    BOARD_ID     INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    FILENAME     TEXT NOT NULL,
+    CONTENT_TYPE TEXT NOT NULL,
+    SIZE_BYTES   INTEGER NOT NULL,
+    SHA256       TEXT NOT NULL,
+    STORAGE_KEY  TEXT NOT NULL UNIQUE,
+    UPLOADER_ID  INTEGER REFERENCES USERS (ID),
+    CREATED_AT   TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS IDX_ATTACHMENTS_BOARD ON ATTACHMENTS (BOARD_ID, ID);
+
+-- 게시글 수정 이력. 수정으로 덮어쓰기 직전의 제목/내용을 게시글별 1부터 증가하는 REVISION_NO로 보관합니다.
+CREATE TABLE IF NOT EXISTS BOARD_REVISIONS (
+    ID          INTEGER PRIMARY KEY AUTOINCREMENT,
+    BOARD_ID    INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    REVISION_NO INTEGER NOT NULL,
+    TITLE       TEXT NOT NULL,
+    CONTENT     TEXT,
+    VERSION     INTEGER NOT NULL,
+    CREATED_AT  TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (BOARD_ID, REVISION_NO)
+);
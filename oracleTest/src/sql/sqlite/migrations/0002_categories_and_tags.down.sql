@@ -0,0 +1,8 @@
+-- 카테고리/태그 되돌리기: 연결 테이블과 게시글 컬럼부터 삭제합니다.
+-- 인덱스가 걸린 컬럼은 DROP COLUMN 할 수 없으므로 인덱스를 먼저 지웁니다.
+
+DROP TABLE IF EXISTS BOARD_TAGS;
+DROP TABLE IF EXISTS TAGS;
+DROP INDEX IF EXISTS IDX_BOARD_CATEGORY;
+ALTER TABLE BOARD DROP COLUMN CATEGORY_ID;
+DROP TABLE IF EXISTS CATEGORIES;
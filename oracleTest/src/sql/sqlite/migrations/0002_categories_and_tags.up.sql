@@ -0,0 +1,28 @@
+-- 게시글 분류: 관리하는 카테고리(게시글당 하나)와 자유 입력 태그(다대다)
+
+CREATE TABLE CATEGORIES (
+    ID          INTEGER PRIMARY KEY AUTOINCREMENT,
+    NAME        TEXT NOT NULL UNIQUE,
+    DESCRIPTION TEXT,
+    CREATED_AT  TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- SQLite는 외래 키가 걸린 컬럼을 DROP COLUMN 할 수 없으므로, 되돌릴 수 있도록 제약 없이 추가합니다.
+-- 카테고리 존재 여부는 서비스 계층에서 확인하고, 카테고리를 지우면 저장소가 이 컬럼을 먼저 비웁니다.
+ALTER TABLE BOARD ADD COLUMN CATEGORY_ID INTEGER;
+
+CREATE INDEX IDX_BOARD_CATEGORY ON BOARD (CATEGORY_ID, DELETED_AT);
+
+-- 태그 이름은 소문자로 정규화하여 저장합니다.
+CREATE TABLE TAGS (
+    ID   INTEGER PRIMARY KEY AUTOINCREMENT,
+    NAME TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE BOARD_TAGS (
+    BOARD_ID INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    TAG_ID   INTEGER NOT NULL REFERENCES TAGS (ID) ON DELETE CASCADE,
+    PRIMARY KEY (BOARD_ID, TAG_ID)
+);
+
+CREATE INDEX IDX_BOARD_TAGS_TAG ON BOARD_TAGS (TAG_ID, BOARD_ID);
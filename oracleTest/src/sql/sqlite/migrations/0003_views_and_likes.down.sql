@@ -0,0 +1,5 @@
+-- 조회수/좋아요 되돌리기
+
+DROP TABLE IF EXISTS BOARD_LIKES;
+DROP TABLE IF EXISTS BOARD_VIEWS_DAILY;
+ALTER TABLE BOARD DROP COLUMN VIEW_COUNT;
@@ -0,0 +1,24 @@
+-- 게시글 참여 지표: 누적 조회수, 일별 조회수(인기 글 기간 집계용), 사용자/IP별 좋아요
+
+-- 조회수는 게시글 버전(ETag)을 바꾸지 않도록 VERSION과 따로 증가시킵니다.
+ALTER TABLE BOARD ADD COLUMN VIEW_COUNT INTEGER NOT NULL DEFAULT 0;
+
+-- VIEW_DATE는 `YYYY-MM-DD` (UTC)
+CREATE TABLE BOARD_VIEWS_DAILY (
+    BOARD_ID  INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    VIEW_DATE TEXT NOT NULL,
+    VIEWS     INTEGER NOT NULL,
+    PRIMARY KEY (BOARD_ID, VIEW_DATE)
+);
+
+CREATE INDEX IDX_BOARD_VIEWS_DAILY_DATE ON BOARD_VIEWS_DAILY (VIEW_DATE, BOARD_ID);
+
+-- LIKER는 로그인 사용자면 `user:<ID>`, 아니면 `ip:<주소>`입니다.
+CREATE TABLE BOARD_LIKES (
+    BOARD_ID   INTEGER NOT NULL REFERENCES BOARD (ID) ON DELETE CASCADE,
+    LIKER      TEXT NOT NULL,
+    CREATED_AT TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (BOARD_ID, LIKER)
+);
+
+CREATE INDEX IDX_BOARD_LIKES_CREATED ON BOARD_LIKES (CREATED_AT, BOARD_ID);
@@ -0,0 +1,3 @@
+-- 본문 형식 되돌리기
+
+ALTER TABLE BOARD DROP COLUMN CONTENT_FORMAT;
@@ -0,0 +1,3 @@
+UPDATE BOARD
+SET DELETED_AT = NULL, VERSION = VERSION + 1
+WHERE ID = :id AND DELETED_AT IS NOT NULL
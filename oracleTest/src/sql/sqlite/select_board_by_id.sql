@@ -0,0 +1,4 @@
+SELECT ID, TITLE, CONTENT, CONTENT_FORMAT, AUTHOR_ID, CATEGORY_ID, VERSION, VIEW_COUNT,
+       (SELECT COUNT(*) FROM BOARD_LIKES l WHERE l.BOARD_ID = BOARD.ID) AS LIKE_COUNT,
+       CREATED_AT
+FROM BOARD WHERE ID = :id AND DELETED_AT IS NULL
@@ -0,0 +1 @@
+SELECT COUNT(*) FROM BOARD b {where}
@@ -0,0 +1,3 @@
+SELECT TITLE, CONTENT
+FROM BOARD
+WHERE ID = :id AND VERSION = :version AND DELETED_AT IS NULL
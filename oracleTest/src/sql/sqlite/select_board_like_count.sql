@@ -0,0 +1 @@
+SELECT COUNT(*) FROM BOARD_LIKES WHERE BOARD_ID = :board_id
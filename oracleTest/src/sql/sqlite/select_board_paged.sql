@@ -0,0 +1,11 @@
+SELECT b.ID,
+       b.TITLE,
+       b.CONTENT,
+       b.CONTENT_FORMAT,
+       b.AUTHOR_ID,
+       b.CATEGORY_ID,
+       strftime('%Y-%m-%d', b.CREATED_AT) AS CREATED_AT
+FROM BOARD b
+{where}
+ORDER BY {order_by}
+LIMIT :limit OFFSET :offset
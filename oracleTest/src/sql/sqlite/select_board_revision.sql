@@ -0,0 +1,3 @@
+SELECT BOARD_ID, REVISION_NO, TITLE, CONTENT, VERSION, CREATED_AT
+FROM BOARD_REVISIONS
+WHERE BOARD_ID = :board_id AND REVISION_NO = :revision
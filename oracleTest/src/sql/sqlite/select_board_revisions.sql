@@ -0,0 +1,4 @@
+SELECT BOARD_ID, REVISION_NO, TITLE, CONTENT, VERSION, CREATED_AT
+FROM BOARD_REVISIONS
+WHERE BOARD_ID = :board_id
+ORDER BY REVISION_NO DESC
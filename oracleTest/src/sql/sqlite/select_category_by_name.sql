@@ -0,0 +1 @@
+SELECT ID, NAME, DESCRIPTION, CREATED_AT FROM CATEGORIES WHERE NAME = :name
@@ -0,0 +1 @@
+SELECT ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT, CREATED_AT FROM COMMENTS WHERE ID = :id
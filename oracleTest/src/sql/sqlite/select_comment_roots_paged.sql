@@ -0,0 +1,5 @@
+SELECT ID, BOARD_ID, PARENT_ID, AUTHOR_ID, CONTENT, CREATED_AT
+FROM COMMENTS
+WHERE BOARD_ID = :board_id AND PARENT_ID IS NULL
+ORDER BY ID ASC
+LIMIT :limit OFFSET :offset
@@ -0,0 +1,26 @@
+SELECT b.ID,
+       b.TITLE,
+       b.AUTHOR_ID,
+       b.CATEGORY_ID,
+       b.VIEW_COUNT,
+       COALESCE(v.VIEWS, 0) AS WINDOW_VIEWS,
+       COALESCE(l.LIKES, 0) AS WINDOW_LIKES,
+       COALESCE(v.VIEWS, 0) * :view_weight + COALESCE(l.LIKES, 0) * :like_weight AS SCORE,
+       strftime('%Y-%m-%d', b.CREATED_AT) AS CREATED_AT
+FROM BOARD b
+LEFT JOIN (
+    SELECT BOARD_ID, SUM(VIEWS) AS VIEWS
+    FROM BOARD_VIEWS_DAILY
+    WHERE VIEW_DATE > date('now', '-' || :days || ' days')
+    GROUP BY BOARD_ID
+) v ON v.BOARD_ID = b.ID
+LEFT JOIN (
+    SELECT BOARD_ID, COUNT(*) AS LIKES
+    FROM BOARD_LIKES
+    WHERE CREATED_AT > datetime('now', '-' || :days || ' days')
+    GROUP BY BOARD_ID
+) l ON l.BOARD_ID = b.ID
+WHERE b.DELETED_AT IS NULL
+  AND (v.VIEWS > 0 OR l.LIKES > 0)
+ORDER BY SCORE DESC, b.ID DESC
+LIMIT :limit
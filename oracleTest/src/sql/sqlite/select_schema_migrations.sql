@@ -0,0 +1,3 @@
+SELECT VERSION, NAME, CHECKSUM, APPLIED_AT
+FROM SCHEMA_MIGRATIONS
+ORDER BY VERSION
@@ -0,0 +1,9 @@
+SELECT b.ID,
+       b.TITLE,
+       b.AUTHOR_ID,
+       strftime('%Y-%m-%d', b.CREATED_AT) AS CREATED_AT,
+       b.DELETED_AT
+FROM BOARD b
+WHERE b.AUTHOR_ID = :author_id AND b.DELETED_AT IS NOT NULL
+ORDER BY b.DELETED_AT DESC, b.ID DESC
+LIMIT :limit OFFSET :offset
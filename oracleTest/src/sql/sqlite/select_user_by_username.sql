@@ -0,0 +1 @@
+SELECT ID, USERNAME, PASSWORD_HASH, CREATED_AT FROM USERS WHERE USERNAME = :username
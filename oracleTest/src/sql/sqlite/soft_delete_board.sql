@@ -0,0 +1,3 @@
+UPDATE BOARD
+SET DELETED_AT = datetime('now'), VERSION = VERSION + 1
+WHERE ID = :id AND VERSION = :version AND DELETED_AT IS NULL
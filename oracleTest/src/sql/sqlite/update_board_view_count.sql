@@ -0,0 +1 @@
+UPDATE BOARD SET VIEW_COUNT = VIEW_COUNT + :views WHERE ID = :id
@@ -0,0 +1 @@
+UPDATE CATEGORIES SET NAME = :name, DESCRIPTION = :description WHERE ID = :id
@@ -0,0 +1,3 @@
+UPDATE COMMENTS
+SET CONTENT = :content
+WHERE ID = :id
@@ -0,0 +1,3 @@
+INSERT INTO BOARD_VIEWS_DAILY (BOARD_ID, VIEW_DATE, VIEWS)
+SELECT ID, date('now'), :views FROM BOARD WHERE ID = :board_id
+ON CONFLICT (BOARD_ID, VIEW_DATE) DO UPDATE SET VIEWS = VIEWS + excluded.VIEWS
@@ -0,0 +1 @@
+INSERT OR IGNORE INTO TAGS (NAME) VALUES (:name)
@@ -1,3 +1,4 @@
 UPDATE BOARD
-SET TITLE = :title, CONTENT = :content
-WHERE ID = :id
\ No newline at end of file
+SET TITLE = :title, CONTENT = :content,
+    CONTENT_FORMAT = COALESCE(:format, CONTENT_FORMAT), VERSION = VERSION + 1
+WHERE ID = :id AND VERSION = :version AND DELETED_AT IS NULL
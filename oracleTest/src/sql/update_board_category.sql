@@ -0,0 +1 @@
+UPDATE BOARD SET CATEGORY_ID = :category_id WHERE ID = :id
@@ -0,0 +1,5 @@
+MERGE INTO BOARD_VIEWS_DAILY d
+USING (SELECT ID AS BOARD_ID, TRUNC(SYSDATE) AS VIEW_DATE FROM BOARD WHERE ID = :board_id) s
+ON (d.BOARD_ID = s.BOARD_ID AND d.VIEW_DATE = s.VIEW_DATE)
+WHEN MATCHED THEN UPDATE SET d.VIEWS = d.VIEWS + :views
+WHEN NOT MATCHED THEN INSERT (BOARD_ID, VIEW_DATE, VIEWS) VALUES (s.BOARD_ID, s.VIEW_DATE, :views)
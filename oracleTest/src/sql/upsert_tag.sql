@@ -0,0 +1,4 @@
+MERGE INTO TAGS t
+USING (SELECT :name AS NAME FROM DUAL) s
+ON (t.NAME = s.NAME)
+WHEN NOT MATCHED THEN INSERT (ID, NAME) VALUES (TAGS_SEQ.NEXTVAL, s.NAME)
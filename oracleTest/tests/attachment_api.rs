@@ -0,0 +1,254 @@
+//! 첨부파일 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터와 임시 저장 디렉터리를 구성하고
+//! multipart 업로드, Range 다운로드, 게시글 삭제 시 파일 정리를 검증합니다.
+
+mod common;
+
+use axum::response::Response;
+use common::*;
+use sha2::{Digest, Sha256};
+
+const BOUNDARY: &str = "test-boundary-7MA4YWxkTrZu0gW";
+
+/// multipart 요청을 보내고 응답을 반환합니다.
+async fn upload_raw(
+    app: &TestApp,
+    token: Option<&str>,
+    board_id: i64,
+    field: &str,
+    filename: &str,
+    content_type: &str,
+    data: &[u8],
+) -> Response {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"{filename}\"\r\nContent-Type: {content_type}\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/boards/{board_id}/attachments"))
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        );
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let request = builder.body(Body::from(body)).unwrap();
+    app.router.clone().oneshot(request).await.unwrap()
+}
+
+/// 기본 사용자로 파일을 업로드하고 상태 코드와 JSON 본문을 반환합니다.
+async fn upload(
+    app: &TestApp,
+    board_id: i64,
+    filename: &str,
+    content_type: &str,
+    data: &[u8],
+) -> (StatusCode, Value) {
+    let response = upload_raw(
+        app,
+        Some(&app.token),
+        board_id,
+        "file",
+        filename,
+        content_type,
+        data,
+    )
+    .await;
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// 다운로드 요청을 보내고 응답을 반환합니다.
+async fn download(app: &TestApp, id: i64, range: Option<&str>) -> Response {
+    let mut builder = Request::builder().uri(format!("/attachments/{id}"));
+    if let Some(range) = range {
+        builder = builder.header(header::RANGE, range);
+    }
+    let request = builder.body(Body::empty()).unwrap();
+    app.router.clone().oneshot(request).await.unwrap()
+}
+
+async fn body_bytes(response: Response) -> Vec<u8> {
+    to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn upload_stores_file_with_metadata() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let data = b"hello attachment";
+
+    let (status, body) = upload(&app, board_id, "../secret/notes.txt", "text/plain", data).await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["board_id"], board_id);
+    assert_eq!(body["filename"], "notes.txt");
+    assert_eq!(body["content_type"], "text/plain");
+    assert_eq!(body["size_bytes"], data.len());
+    assert_eq!(body["sha256"], hex::encode(Sha256::digest(data)));
+    assert!(body.get("storage_key").is_none());
+
+    let id = body["id"].as_i64().unwrap();
+    assert_eq!(body["url"], format!("/attachments/{id}"));
+
+    let (status, list) = send(
+        &app,
+        Method::GET,
+        &format!("/boards/{board_id}/attachments"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(list.as_array().unwrap().len(), 1);
+    assert_eq!(list[0]["id"], id);
+}
+
+#[tokio::test]
+async fn upload_enforces_limits_and_ownership() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+
+    let too_big = vec![b'a'; ATTACHMENT_MAX_BYTES as usize + 1];
+    let (status, _) = upload(&app, board_id, "big.txt", "text/plain", &too_big).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    let (status, _) = upload(&app, board_id, "run.sh", "application/x-sh", b"echo").await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let (status, _) = upload(&app, board_id, "empty.txt", "text/plain", b"").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = upload(&app, 9999, "a.txt", "text/plain", b"data").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let response = upload_raw(&app, None, board_id, "file", "a.txt", "text/plain", b"x").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let other = login(&app, "intruder").await;
+    let response = upload_raw(
+        &app,
+        Some(&other),
+        board_id,
+        "file",
+        "a.txt",
+        "text/plain",
+        b"x",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = upload_raw(
+        &app,
+        Some(&app.token),
+        board_id,
+        "other",
+        "a.txt",
+        "text/plain",
+        b"x",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // 실패한 업로드는 파일도 메타데이터도 남기지 않습니다.
+    let (_, list) = send(
+        &app,
+        Method::GET,
+        &format!("/boards/{board_id}/attachments"),
+        None,
+    )
+    .await;
+    assert_eq!(list.as_array().unwrap().len(), 0);
+    let leftovers = std::fs::read_dir(app.attachment_dir.join(board_id.to_string()))
+        .map(|dir| dir.count())
+        .unwrap_or(0);
+    assert_eq!(leftovers, 0);
+}
+
+#[tokio::test]
+async fn download_streams_whole_file() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let (_, body) = upload(&app, board_id, "보고서.txt", "text/plain", b"0123456789").await;
+    let id = body["id"].as_i64().unwrap();
+
+    let response = download(&app, id, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert_eq!(headers[header::CONTENT_TYPE], "text/plain");
+    assert_eq!(headers[header::CONTENT_LENGTH], "10");
+    assert_eq!(headers[header::ACCEPT_RANGES], "bytes");
+    let disposition = headers[header::CONTENT_DISPOSITION].to_str().unwrap();
+    assert!(disposition.contains("filename*=UTF-8''%EB%B3%B4%EA%B3%A0%EC%84%9C.txt"));
+    assert_eq!(body_bytes(response).await, b"0123456789");
+
+    let response = download(&app, 9999, None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn download_supports_byte_ranges() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let (_, body) = upload(&app, board_id, "digits.txt", "text/plain", b"0123456789").await;
+    let id = body["id"].as_i64().unwrap();
+
+    let cases = [
+        ("bytes=2-5", "2345", "bytes 2-5/10"),
+        ("bytes=7-", "789", "bytes 7-9/10"),
+        ("bytes=-3", "789", "bytes 7-9/10"),
+        ("bytes=8-100", "89", "bytes 8-9/10"),
+    ];
+    for (range, expected, content_range) in cases {
+        let response = download(&app, id, Some(range)).await;
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT, "{range}");
+        assert_eq!(response.headers()[header::CONTENT_RANGE], content_range);
+        assert_eq!(
+            response.headers()[header::CONTENT_LENGTH],
+            expected.len().to_string()
+        );
+        assert_eq!(body_bytes(response).await, expected.as_bytes());
+    }
+
+    let response = download(&app, id, Some("bytes=10-")).await;
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */10");
+
+    // 해석할 수 없는 Range 헤더는 무시하고 전체 파일을 보냅니다.
+    let response = download(&app, id, Some("bytes=0-1,4-5")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_bytes(response).await, b"0123456789");
+}
+
+#[tokio::test]
+async fn purging_board_removes_attachments() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let (_, body) = upload(&app, board_id, "a.txt", "text/plain", b"data").await;
+    let id = body["id"].as_i64().unwrap();
+    let board_dir = app.attachment_dir.join(board_id.to_string());
+    assert_eq!(std::fs::read_dir(&board_dir).unwrap().count(), 1);
+
+    // 휴지통에 있는 동안에는 파일이 남아 있지만 내려받을 수 없습니다.
+    assert_eq!(delete_board(&app, board_id).await, StatusCode::NO_CONTENT);
+    assert!(board_dir.exists());
+    let response = download(&app, id, None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    assert_eq!(app.boards.purge_expired(0).await.unwrap(), 1);
+    assert!(!board_dir.exists());
+    let response = download(&app, id, None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
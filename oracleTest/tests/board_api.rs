@@ -0,0 +1,753 @@
+//! 게시판 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 각 라우트를 검증합니다.
+
+mod common;
+
+use common::*;
+use oracle_test::services::board_service::MAX_CONTENT_CHARS;
+
+#[tokio::test]
+async fn serves_index_page() {
+    let app = app().await;
+    for uri in ["/", "/index.html"] {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+        assert!(content_type.starts_with("text/html"));
+    }
+}
+
+#[tokio::test]
+async fn create_returns_201_with_board() {
+    let app = app().await;
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "첫 글", "content": "안녕하세요" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(body["id"].as_i64().unwrap() > 0);
+    assert_eq!(body["title"], "첫 글");
+    assert_eq!(body["content"], "안녕하세요");
+    assert!(body["created_at"].is_string());
+}
+
+#[tokio::test]
+async fn create_rejects_invalid_input() {
+    let app = app().await;
+    let long_title = "가".repeat(201);
+    let long_content = "a".repeat(MAX_CONTENT_CHARS + 1);
+    let cases = [
+        json!({ "title": "   ", "content": "내용" }),
+        json!({ "title": long_title, "content": "내용" }),
+        json!({ "title": "제목", "content": "" }),
+        json!({ "title": "제목", "content": long_content }),
+    ];
+
+    for case in cases {
+        let (status, body) = send(&app, Method::POST, "/boards", Some(case)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].is_string());
+    }
+
+    // 필드가 누락된 JSON은 Axum의 Json 추출기에서 거부됩니다.
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "제목" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn get_board_by_id() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+
+    let (status, body) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], id);
+    assert_eq!(body["title"], "제목");
+}
+
+#[tokio::test]
+async fn get_board_maps_errors() {
+    let app = app().await;
+
+    let (status, body) = send(&app, Method::GET, "/boards/999", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "요청한 리소스를 찾을 수 없습니다.");
+
+    let (status, _) = send(&app, Method::GET, "/boards/0", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&app, Method::GET, "/boards/abc", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn update_board_returns_200() {
+    let app = app().await;
+    let id = create(&app, "원래 제목", "원래 내용").await;
+    let etag = etag(&app, id).await;
+
+    let (status, headers, _) = request(
+        &app,
+        Some(&app.token),
+        Method::PUT,
+        &format!("/boards/{id}"),
+        &[(header::IF_MATCH, etag.as_str())],
+        Some(json!({ "title": "새 제목", "content": "새 내용" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::ETAG], "\"2\"");
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(body["title"], "새 제목");
+    assert_eq!(body["content"], "새 내용");
+}
+
+#[tokio::test]
+async fn update_board_maps_errors() {
+    let app = app().await;
+    let update = json!({ "title": "제목", "content": "내용" });
+
+    let (status, _) = send(&app, Method::PUT, "/boards/999", Some(update.clone())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, Method::PUT, "/boards/-1", Some(update)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let id = create(&app, "제목", "내용").await;
+    let (status, _) = send(
+        &app,
+        Method::PUT,
+        &format!("/boards/{id}"),
+        Some(json!({ "title": "", "content": "내용" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn delete_board_returns_204_then_404() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn list_boards_on_empty_table() {
+    let app = app().await;
+
+    let (status, body) = send(&app, Method::GET, "/boards", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"], json!([]));
+    assert_eq!(
+        body["pagination"],
+        json!({ "current_page": 1, "total_pages": 0, "size": 10 })
+    );
+
+    // 게시글이 하나도 없으면 페이지 초과 검사를 하지 않습니다.
+    let (status, body) = send(&app, Method::GET, "/boards?page=5", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"], json!([]));
+}
+
+#[tokio::test]
+async fn list_boards_paginates_newest_first() {
+    let app = app().await;
+    for i in 1..=25 {
+        create(&app, &format!("글 {i}"), "내용").await;
+    }
+
+    let (status, body) = send(&app, Method::GET, "/boards?page=1&size=10", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let data = body["data"].as_array().unwrap();
+    assert_eq!(data.len(), 10);
+    assert_eq!(data[0]["title"], "글 25");
+    assert_eq!(body["pagination"]["total_pages"], 3);
+
+    let (status, body) = send(&app, Method::GET, "/boards?page=3&size=10", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let data = body["data"].as_array().unwrap();
+    assert_eq!(data.len(), 5);
+    assert_eq!(data[4]["title"], "글 1");
+    assert_eq!(body["pagination"]["current_page"], 3);
+
+    // 게시글 수가 size의 배수가 아니어도 올림 처리됩니다.
+    let (_, body) = send(&app, Method::GET, "/boards?size=7", None).await;
+    assert_eq!(body["pagination"]["total_pages"], 4);
+    assert_eq!(body["pagination"]["size"], 7);
+}
+
+#[tokio::test]
+async fn list_boards_rejects_invalid_paging() {
+    let app = app().await;
+    for i in 1..=3 {
+        create(&app, &format!("글 {i}"), "내용").await;
+    }
+
+    for uri in [
+        "/boards?page=0",
+        "/boards?size=0",
+        "/boards?size=101",
+        "/boards?page=2&size=10",
+    ] {
+        let (status, body) = send(&app, Method::GET, uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}");
+        assert!(body["error"].is_string());
+    }
+
+    // 숫자가 아닌 쿼리 파라미터는 Query 추출기에서 거부됩니다.
+    let (status, _) = send(&app, Method::GET, "/boards?page=abc", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+/// 목록 응답에서 제목만 뽑아냅니다.
+fn titles(body: &Value) -> Vec<String> {
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|board| board["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn list_boards_searches_title_and_content() {
+    let app = app().await;
+    create(&app, "Rust 입문", "소유권 이야기").await;
+    create(&app, "Oracle 튜닝", "rust로 작성한 드라이버").await;
+    create(&app, "점심 메뉴", "김치찌개").await;
+
+    let (status, body) = send(&app, Method::GET, "/boards?q=RUST", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&body), ["Oracle 튜닝", "Rust 입문"]);
+    assert_eq!(body["pagination"]["total_pages"], 1);
+
+    let (_, body) = send(&app, Method::GET, "/boards?title=rust", None).await;
+    assert_eq!(titles(&body), ["Rust 입문"]);
+
+    let (_, body) = send(&app, Method::GET, "/boards?q=rust&title=oracle", None).await;
+    assert_eq!(titles(&body), ["Oracle 튜닝"]);
+
+    // 빈 검색어는 조건에서 제외됩니다.
+    let (_, body) = send(&app, Method::GET, "/boards?q=%20%20", None).await;
+    assert_eq!(titles(&body).len(), 3);
+}
+
+#[tokio::test]
+async fn list_boards_escapes_like_wildcards() {
+    let app = app().await;
+    create(&app, "100% 할인", "내용").await;
+    create(&app, "1000원 할인", "내용").await;
+
+    let (_, body) = send(&app, Method::GET, "/boards?title=100%25", None).await;
+    assert_eq!(titles(&body), ["100% 할인"]);
+}
+
+#[tokio::test]
+async fn list_boards_counts_filtered_pages() {
+    let app = app().await;
+    for i in 1..=12 {
+        let title = if i % 2 == 0 { "짝수" } else { "홀수" };
+        create(&app, &format!("{title} {i}"), "내용").await;
+    }
+
+    let (_, body) = send(&app, Method::GET, "/boards?title=짝수&size=5", None).await;
+    assert_eq!(body["pagination"]["total_pages"], 2);
+
+    let (_, body) = send(&app, Method::GET, "/boards?title=짝수&size=5&page=2", None).await;
+    assert_eq!(titles(&body), ["짝수 2"]);
+
+    let (status, _) = send(&app, Method::GET, "/boards?title=짝수&size=5&page=3", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn list_boards_filters_by_created_date() {
+    let app = app().await;
+    create(&app, "오늘 글", "내용").await;
+
+    let today = chrono::Utc::now().date_naive();
+    let tomorrow = today.succ_opt().unwrap();
+    let yesterday = today.pred_opt().unwrap();
+
+    let (_, body) = send(
+        &app,
+        Method::GET,
+        &format!("/boards?from={today}&to={today}"),
+        None,
+    )
+    .await;
+    assert_eq!(titles(&body), ["오늘 글"]);
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards?from={tomorrow}"), None).await;
+    assert_eq!(titles(&body).len(), 0);
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards?to={yesterday}"), None).await;
+    assert_eq!(titles(&body).len(), 0);
+}
+
+#[tokio::test]
+async fn list_boards_sorts_by_requested_column() {
+    let app = app().await;
+    for title in ["바나나", "사과", "딸기"] {
+        create(&app, title, "내용").await;
+    }
+
+    let (_, body) = send(&app, Method::GET, "/boards?sort=title,asc", None).await;
+    assert_eq!(titles(&body), ["딸기", "바나나", "사과"]);
+
+    let (_, body) = send(&app, Method::GET, "/boards?sort=id,asc", None).await;
+    assert_eq!(titles(&body), ["바나나", "사과", "딸기"]);
+
+    let (_, body) = send(&app, Method::GET, "/boards?sort=created_at,desc", None).await;
+    assert_eq!(titles(&body), ["딸기", "사과", "바나나"]);
+}
+
+#[tokio::test]
+async fn list_boards_rejects_invalid_search() {
+    let app = app().await;
+    let long_query = "a".repeat(101);
+
+    for uri in [
+        "/boards?sort=content".to_string(),
+        "/boards?sort=title,sideways".to_string(),
+        "/boards?from=2024-13-01".to_string(),
+        "/boards?to=yesterday".to_string(),
+        "/boards?from=2024-05-02&to=2024-05-01".to_string(),
+        format!("/boards?q={long_query}"),
+    ] {
+        let (status, body) = send(&app, Method::GET, &uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}");
+        assert!(body["error"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn cursor_mode_walks_all_pages_without_count() {
+    let app = app().await;
+    for i in 1..=25 {
+        create(&app, &format!("글 {i}"), "내용").await;
+    }
+
+    let mut seen = Vec::new();
+    let mut uri = "/boards?cursor=&size=10".to_string();
+    loop {
+        let (status, body) = send(&app, Method::GET, &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["pagination"].get("total_count").is_none());
+        seen.extend(titles(&body));
+
+        match body["pagination"]["next_cursor"].as_str() {
+            Some(next) => uri = format!("/boards?cursor={next}&size=10"),
+            None => break,
+        }
+    }
+
+    let expected: Vec<String> = (1..=25).rev().map(|i| format!("글 {i}")).collect();
+    assert_eq!(seen, expected);
+}
+
+#[tokio::test]
+async fn cursor_mode_is_stable_under_inserts() {
+    let app = app().await;
+    for i in 1..=4 {
+        create(&app, &format!("글 {i}"), "내용").await;
+    }
+
+    let (_, body) = send(&app, Method::GET, "/boards?cursor=&size=2", None).await;
+    assert_eq!(titles(&body), ["글 4", "글 3"]);
+    let next = body["pagination"]["next_cursor"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // 페이지 사이에 새 글이 추가되어도 다음 페이지가 밀리지 않습니다.
+    create(&app, "새 글", "내용").await;
+
+    let (_, body) = send(
+        &app,
+        Method::GET,
+        &format!("/boards?cursor={next}&size=2"),
+        None,
+    )
+    .await;
+    assert_eq!(titles(&body), ["글 2", "글 1"]);
+    assert!(body["pagination"]["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn cursor_mode_supports_total_filters_and_ascending_order() {
+    let app = app().await;
+    for i in 1..=5 {
+        create(&app, &format!("공지 {i}"), "내용").await;
+        create(&app, &format!("잡담 {i}"), "내용").await;
+    }
+
+    let (_, body) = send(
+        &app,
+        Method::GET,
+        "/boards?cursor=&size=3&title=공지&with_total=true&sort=created_at,asc",
+        None,
+    )
+    .await;
+    assert_eq!(titles(&body), ["공지 1", "공지 2", "공지 3"]);
+    assert_eq!(body["pagination"]["total_count"], 5);
+
+    let next = body["pagination"]["next_cursor"].as_str().unwrap();
+    let (_, body) = send(
+        &app,
+        Method::GET,
+        &format!("/boards?cursor={next}&size=3&title=공지&sort=created_at,asc"),
+        None,
+    )
+    .await;
+    assert_eq!(titles(&body), ["공지 4", "공지 5"]);
+    assert!(body["pagination"]["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn cursor_mode_rejects_invalid_requests() {
+    let app = app().await;
+    create(&app, "제목", "내용").await;
+
+    for uri in [
+        "/boards?cursor=not-a-cursor",
+        "/boards?cursor=&sort=title,asc",
+        "/boards?cursor=&sort=id,asc",
+        "/boards?cursor=&sort=id,desc",
+        "/boards?cursor=&sort=id",
+        "/boards?cursor=&size=0",
+        "/boards?cursor=&size=4000000000",
+    ] {
+        let (status, body) = send(&app, Method::GET, uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}");
+        assert!(body["error"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn register_and_login_issue_token() {
+    let app = app().await;
+    let credentials = json!({ "username": "newbie", "password": "password123" });
+
+    let (status, body) = send_as(
+        &app,
+        None,
+        Method::POST,
+        "/auth/register",
+        Some(credentials.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["username"], "newbie");
+    assert!(body.get("password_hash").is_none());
+
+    let (status, body) = send_as(&app, None, Method::POST, "/auth/login", Some(credentials)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["token_type"], "Bearer");
+    assert!(body["access_token"].is_string());
+    assert_eq!(body["expires_in"], 3600);
+}
+
+#[tokio::test]
+async fn register_rejects_duplicates_and_invalid_input() {
+    let app = app().await;
+    let cases = [
+        (
+            json!({ "username": "tester", "password": "password123" }),
+            StatusCode::CONFLICT,
+        ),
+        (
+            json!({ "username": "a!", "password": "password123" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "username": "shortpw", "password": "short" }),
+            StatusCode::BAD_REQUEST,
+        ),
+    ];
+
+    for (case, expected) in cases {
+        let (status, _) = send_as(&app, None, Method::POST, "/auth/register", Some(case)).await;
+        assert_eq!(status, expected);
+    }
+}
+
+#[tokio::test]
+async fn concurrent_registrations_with_same_username_conflict() {
+    let app = app().await;
+    let credentials = json!({ "username": "racer", "password": "password123" });
+
+    // 중복 확인을 동시에 통과한 가입도 하나만 성공하고 나머지는 500이 아닌 409를 받습니다.
+    let statuses = futures_util::future::join_all((0..8).map(|_| {
+        send_as(
+            &app,
+            None,
+            Method::POST,
+            "/auth/register",
+            Some(credentials.clone()),
+        )
+    }))
+    .await;
+    let created = statuses
+        .iter()
+        .filter(|(status, _)| *status == StatusCode::CREATED)
+        .count();
+    assert_eq!(created, 1);
+    assert!(
+        statuses
+            .iter()
+            .all(|(status, _)| matches!(*status, StatusCode::CREATED | StatusCode::CONFLICT)),
+        "{statuses:?}"
+    );
+}
+
+#[tokio::test]
+async fn login_rejects_bad_credentials() {
+    let app = app().await;
+    let cases = [
+        json!({ "username": "tester", "password": "wrong-password" }),
+        json!({ "username": "nobody", "password": "password123" }),
+    ];
+
+    for case in cases {
+        let (status, body) = send_as(&app, None, Method::POST, "/auth/login", Some(case)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(body["error"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn writes_require_valid_token() {
+    let app = app().await;
+    let id = create(&app, "원본", "내용").await;
+    let body = json!({ "title": "제목", "content": "내용" });
+
+    for token in [None, Some("not-a-jwt")] {
+        let (status, _) = send_as(&app, token, Method::POST, "/boards", Some(body.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let uri = format!("/boards/{id}");
+        let (status, _) = send_as(&app, token, Method::PUT, &uri, Some(body.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = send_as(&app, token, Method::DELETE, &uri, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // 조회는 익명으로도 가능합니다.
+    let (status, _) = send_as(&app, None, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn only_author_can_modify_board() {
+    let app = app().await;
+    let id = create(&app, "내 글", "내용").await;
+    let other = login(&app, "intruder").await;
+    let uri = format!("/boards/{id}");
+
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert!(body["author_id"].as_i64().is_some());
+
+    let update = json!({ "title": "남의 글", "content": "변경" });
+    let (status, _) = send_as(&app, Some(&other), Method::PUT, &uri, Some(update)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send_as(&app, Some(&other), Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["title"], "내 글");
+
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn get_board_returns_etag_and_honors_if_none_match() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+
+    let etag = etag(&app, id).await;
+    assert_eq!(etag, "\"1\"");
+
+    let headers = [(header::IF_NONE_MATCH, etag.as_str())];
+    let (status, headers, _) = request(&app, None, Method::GET, &uri, &headers, None).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(headers[header::ETAG], etag);
+
+    let headers = [(header::IF_NONE_MATCH, "\"99\"")];
+    let (status, _, body) = request(&app, None, Method::GET, &uri, &headers, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["version"], 1);
+}
+
+#[tokio::test]
+async fn writes_without_if_match_return_428() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    let update = json!({ "title": "새 제목", "content": "새 내용" });
+
+    let (status, _) = send(&app, Method::PUT, &uri, Some(update)).await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+    assert_eq!(etag(&app, id).await, "\"1\"");
+}
+
+#[tokio::test]
+async fn stale_if_match_returns_412() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    let stale = etag(&app, id).await;
+    let token = Some(app.token.as_str());
+
+    // 첫 번째 수정은 성공하여 버전이 올라갑니다.
+    let if_match = [(header::IF_MATCH, stale.as_str())];
+    let update = json!({ "title": "먼저 수정", "content": "내용" });
+    let (status, _, _) = request(&app, token, Method::PUT, &uri, &if_match, Some(update)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 같은 ETag로 다시 수정/삭제하면 412가 됩니다.
+    let update = json!({ "title": "나중 수정", "content": "내용" });
+    let (status, _, _) = request(&app, token, Method::PUT, &uri, &if_match, Some(update)).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (status, _, _) = request(&app, token, Method::DELETE, &uri, &if_match, None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    // 약한 ETag는 If-Match에서 일치하지 않습니다.
+    let weak = [(header::IF_MATCH, "W/\"2\"")];
+    let (status, _, _) = request(&app, token, Method::DELETE, &uri, &weak, None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["title"], "먼저 수정");
+}
+
+#[tokio::test]
+async fn if_match_wildcard_matches_any_version() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    let token = Some(app.token.as_str());
+    let any = [(header::IF_MATCH, "*")];
+
+    let update = json!({ "title": "새 제목", "content": "새 내용" });
+    let (status, _, _) = request(&app, token, Method::PUT, &uri, &any, Some(update)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, _) = request(&app, token, Method::DELETE, &uri, &any, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn deleted_board_moves_to_trash_and_can_be_restored() {
+    let app = app().await;
+    let id = create(&app, "지울 글", "내용").await;
+    create(&app, "남는 글", "내용").await;
+    let uri = format!("/boards/{id}");
+
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, body) = send(&app, Method::GET, "/boards", None).await;
+    assert_eq!(titles(&body), ["남는 글"]);
+
+    let (status, body) = send(&app, Method::GET, "/boards/trash", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&body), ["지울 글"]);
+    assert!(body["data"][0]["deleted_at"].is_string());
+    assert_eq!(body["pagination"]["total_pages"], 1);
+
+    let restore = format!("/boards/{id}/restore");
+    let token = Some(app.token.as_str());
+    let (status, headers, body) = request(&app, token, Method::POST, &restore, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "지울 글");
+    // 삭제와 복원 모두 버전을 올리므로 이전 ETag로는 수정할 수 없습니다.
+    assert_eq!(headers[header::ETAG], "\"3\"");
+
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = send(&app, Method::GET, "/boards/trash", None).await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    let (status, _) = send(&app, Method::POST, &restore, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn trash_is_private_to_author() {
+    let app = app().await;
+    let id = create(&app, "내 글", "내용").await;
+    let other = login(&app, "intruder").await;
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+
+    let (status, _) = send_as(&app, None, Method::GET, "/boards/trash", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = send_as(&app, Some(&other), Method::GET, "/boards/trash", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    let restore = format!("/boards/{id}/restore");
+    let (status, _) = send_as(&app, Some(&other), Method::POST, &restore, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send_as(&app, None, Method::POST, &restore, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn purge_removes_only_expired_trash() {
+    let app = app().await;
+    let trashed = create(&app, "지울 글", "내용").await;
+    let live = create(&app, "남는 글", "내용").await;
+    assert_eq!(delete_board(&app, trashed).await, StatusCode::NO_CONTENT);
+
+    // 보존 기간이 지나지 않았으면 휴지통에 남습니다.
+    assert_eq!(app.boards.purge_expired(30).await.unwrap(), 0);
+    let (_, body) = send(&app, Method::GET, "/boards/trash", None).await;
+    assert_eq!(titles(&body), ["지울 글"]);
+
+    assert_eq!(app.boards.purge_expired(0).await.unwrap(), 1);
+    let (_, body) = send(&app, Method::GET, "/boards/trash", None).await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        &format!("/boards/{trashed}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, Method::GET, &format!("/boards/{live}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
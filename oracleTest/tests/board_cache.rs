@@ -0,0 +1,362 @@
+//! 게시글 조회 캐시 통합 테스트
+//!
+//! API 테스트는 공용 헬퍼(`common`)의 라우터로 쓰기 후 무효화와 동시 조회 병합을 확인하고,
+//! 만료와 LRU 교체는 인메모리 SQLite 저장소를 캐시로 직접 감싸 확인합니다.
+
+mod common;
+
+use common::*;
+use futures_util::future::join_all;
+use oracle_test::common::cache::ReadThroughCache;
+use oracle_test::models::board::{BoardFilter, NewBoard};
+use oracle_test::repositories::{sqlite, stores::Stores};
+use std::time::Duration;
+
+/// 게시글 목록 응답의 제목 순서
+async fn list_titles(app: &TestApp, uri: &str) -> Vec<String> {
+    let (status, body) = send_as(app, None, Method::GET, uri, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|board| board["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// 현재 `ETag`로 게시글 제목과 내용을 바꿉니다.
+async fn rename(app: &TestApp, id: i64, title: &str) {
+    let etag = etag(app, id).await;
+    let uri = format!("/boards/{id}");
+    let (status, _, _) = request(
+        app,
+        Some(&app.token),
+        Method::PUT,
+        &uri,
+        &[(header::IF_MATCH, etag.as_str())],
+        Some(json!({ "title": title, "content": "내용" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn detail_reads_are_cached_and_invalidated_by_writes() {
+    let app = app().await;
+    let id = create(&app, "처음 제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    app.board_cache.clear();
+
+    let before = app.board_cache.detail_stats();
+    for _ in 0..3 {
+        let (_, body) = send_as(&app, None, Method::GET, &uri, None).await;
+        assert_eq!(body["title"], "처음 제목");
+    }
+    let after = app.board_cache.detail_stats();
+    assert_eq!(after.misses - before.misses, 1);
+    assert_eq!(after.hits - before.hits, 2);
+
+    // 수정하면 바로 새 내용과 새 ETag가 보입니다.
+    rename(&app, id, "바뀐 제목").await;
+    let (_, headers, body) = request(&app, None, Method::GET, &uri, &[], None).await;
+    assert_eq!(body["title"], "바뀐 제목");
+    assert_eq!(headers[header::ETAG], "\"2\"");
+
+    // 좋아요, 조회수 반영, 삭제도 상세 항목을 버립니다.
+    let engagement = format!("/boards/{id}/engagement");
+    send(&app, Method::POST, &format!("/boards/{id}/like"), None).await;
+    let (_, body) = send(&app, Method::GET, &engagement, None).await;
+    assert_eq!(body["like_count"], 1);
+    // 반영한 조회수가 캐시된 옛 값에 가려지지 않습니다. (응답은 반영 전 조회수를 더해 보여 줍니다)
+    let views = body["view_count"].as_i64().unwrap();
+    app.boards.flush_views().await.unwrap();
+    let (_, body) = send(&app, Method::GET, &engagement, None).await;
+    assert_eq!(body["view_count"], views);
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn list_and_count_reads_are_cached_and_invalidated_by_writes() {
+    let app = app().await;
+    let first = create(&app, "첫 글", "내용").await;
+    create(&app, "둘째 글", "내용").await;
+
+    let before = app.board_cache.list_stats();
+    assert_eq!(list_titles(&app, "/boards").await, ["둘째 글", "첫 글"]);
+    assert_eq!(list_titles(&app, "/boards").await, ["둘째 글", "첫 글"]);
+    let after = app.board_cache.list_stats();
+    // 첫 요청은 개수와 페이지를 읽고, 두 번째 요청은 둘 다 캐시에서 가져옵니다.
+    assert_eq!(after.misses - before.misses, 2);
+    assert_eq!(after.hits - before.hits, 2);
+
+    // 검색 조건과 커서가 다르면 다른 항목입니다.
+    assert_eq!(list_titles(&app, "/boards?q=첫").await, ["첫 글"]);
+    assert_eq!(
+        list_titles(&app, "/boards?cursor=&size=1").await,
+        ["둘째 글"]
+    );
+
+    create(&app, "셋째 글", "내용").await;
+    assert_eq!(
+        list_titles(&app, "/boards").await,
+        ["셋째 글", "둘째 글", "첫 글"]
+    );
+    rename(&app, first, "고친 글").await;
+    assert_eq!(
+        list_titles(&app, "/boards?q=첫").await,
+        Vec::<String>::new()
+    );
+    assert_eq!(delete_board(&app, first).await, StatusCode::NO_CONTENT);
+    assert_eq!(list_titles(&app, "/boards").await, ["셋째 글", "둘째 글"]);
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        &format!("/boards/{first}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(list_titles(&app, "/boards?cursor=").await.len(), 3);
+}
+
+#[tokio::test]
+async fn deleting_a_category_invalidates_cached_boards() {
+    let app = app().await;
+    let (_, category) = send(
+        &app,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": "공지" })),
+    )
+    .await;
+    let category_id = category["id"].as_i64().unwrap();
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "분류된 글", "content": "내용", "category_id": category_id })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let uri = format!("/boards/{}", body["id"]);
+    assert_eq!(
+        send(&app, Method::GET, &uri, None).await.1["category_id"],
+        category_id
+    );
+
+    let (status, _) = send(
+        &app,
+        Method::DELETE,
+        &format!("/categories/{category_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert!(send(&app, Method::GET, &uri, None).await.1["category_id"].is_null());
+}
+
+#[tokio::test]
+async fn export_reads_around_the_cache() {
+    let app = app().await;
+    for title in ["하나", "둘", "셋"] {
+        create(&app, title, "내용").await;
+    }
+    app.board_cache.clear();
+
+    let before = app.board_cache.list_stats();
+    let request = Request::builder()
+        .uri("/boards/export?format=jsonl")
+        .header(header::AUTHORIZATION, format!("Bearer {}", app.token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(
+        String::from_utf8(bytes.to_vec()).unwrap().lines().count(),
+        3
+    );
+
+    // 내보낸 묶음은 캐시에 보관하지도, 캐시에서 찾지도 않습니다.
+    assert_eq!(app.board_cache.list_stats(), before);
+}
+
+#[tokio::test]
+async fn concurrent_misses_for_the_same_key_share_one_load() {
+    let app = app().await;
+    let id = create(&app, "동시 조회", "내용").await;
+    app.board_cache.clear();
+
+    let before = app.board_cache.detail_stats();
+    let uri = format!("/boards/{id}");
+    let responses = join_all((0..16).map(|_| {
+        let router = app.router.clone();
+        let uri = uri.clone();
+        tokio::spawn(async move {
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            router.oneshot(request).await.unwrap().status()
+        })
+    }))
+    .await;
+    assert!(
+        responses
+            .into_iter()
+            .all(|status| status.unwrap() == StatusCode::OK)
+    );
+    let after = app.board_cache.detail_stats();
+    assert_eq!(after.misses - before.misses, 1);
+    assert_eq!(after.hits - before.hits, 15);
+
+    // 적중/실패 수는 메트릭으로도 노출됩니다.
+    let response = app
+        .router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/metrics")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let metrics = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(metrics.contains(r#"cache_requests_total{cache="board_detail",result="hit"}"#));
+    assert!(metrics.contains(r#"cache_requests_total{cache="board_detail",result="miss"}"#));
+}
+
+#[tokio::test]
+async fn entries_expire_after_ttl_and_least_recently_used_are_evicted() {
+    let pool = sqlite::build_pool(sqlite::MEMORY_PATH, 1, Duration::from_secs(5)).unwrap();
+    sqlite::init_schema(&pool).unwrap();
+    let stores = Stores::sqlite(pool).with_board_cache(2, Duration::from_millis(200));
+    let cache = stores.board_cache.clone().unwrap();
+    let boards = stores.boards;
+    let author_id = stores
+        .users
+        .insert("writer".to_string(), "hash".to_string())
+        .await
+        .unwrap();
+    let mut ids = Vec::new();
+    for title in ["하나", "둘", "셋"] {
+        let board = NewBoard {
+            title: title.to_string(),
+            content: "내용".to_string(),
+            ..NewBoard::default()
+        };
+        ids.push(boards.insert(board, author_id).await.unwrap());
+    }
+
+    // 최대 2개: 세 번째를 읽으면 가장 오래 쓰지 않은 첫 번째가 빠집니다.
+    for id in &ids {
+        boards.find_by_id(*id).await.unwrap();
+    }
+    boards.find_by_id(ids[2]).await.unwrap();
+    assert_eq!(cache.detail_stats().misses, 3);
+    assert_eq!(cache.detail_stats().hits, 1);
+    boards.find_by_id(ids[0]).await.unwrap();
+    assert_eq!(cache.detail_stats().misses, 4);
+    assert_eq!(cache.detail_stats().entries, 2);
+
+    // 유지 시간이 지나면 다시 읽어 옵니다.
+    let filter = BoardFilter::default();
+    assert_eq!(boards.count_all(&filter).await.unwrap(), 3);
+    assert_eq!(boards.count_all(&filter).await.unwrap(), 3);
+    assert_eq!(cache.list_stats().misses, 1);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(boards.count_all(&filter).await.unwrap(), 3);
+    assert_eq!(cache.list_stats().misses, 2);
+    assert_eq!(cache.list_stats().hits, 1);
+}
+
+#[tokio::test]
+async fn cancelled_loads_do_not_leave_loading_entries_behind() {
+    let cache: ReadThroughCache<u32, u32> =
+        ReadThroughCache::new("test_cancel", 10, Duration::from_secs(60));
+
+    // 읽는 도중 요청이 취소(future가 버려짐)되어도 잠금 항목이 남지 않습니다.
+    for key in 0..5 {
+        let load = cache.get_or_load(key, std::future::pending::<Result<u32, ()>>);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), load)
+                .await
+                .is_err()
+        );
+    }
+    assert_eq!(cache.stats().loading, 0);
+    assert_eq!(cache.stats().entries, 0);
+
+    // 취소된 키도 다음 요청은 정상적으로 읽어 옵니다.
+    assert_eq!(
+        cache.get_or_load(0, || async { Ok::<_, ()>(7) }).await,
+        Ok(7)
+    );
+    assert_eq!(
+        cache.get_or_load(0, || async { Ok::<_, ()>(8) }).await,
+        Ok(7)
+    );
+    assert_eq!(cache.stats().loading, 0);
+}
+
+#[tokio::test]
+async fn invalidation_drops_only_in_flight_loads_of_the_same_key() {
+    let cache: ReadThroughCache<u32, u32> =
+        ReadThroughCache::new("test_generation", 10, Duration::from_secs(60));
+
+    // 키 2를 읽어 오는 사이 키 1을 버려도 키 2의 값은 저장합니다.
+    let (release, released) = tokio::sync::oneshot::channel::<()>();
+    let load = cache.get_or_load(2, || async move {
+        released.await.unwrap();
+        Ok::<_, ()>(20)
+    });
+    let invalidate = async {
+        tokio::task::yield_now().await;
+        cache.invalidate(&1);
+        release.send(()).unwrap();
+    };
+    let (loaded, _) = tokio::join!(load, invalidate);
+    assert_eq!(loaded, Ok(20));
+    assert_eq!(
+        cache.get_or_load(2, || async { Ok::<_, ()>(21) }).await,
+        Ok(20)
+    );
+
+    // 같은 키를 버리면 읽던 값은 응답에만 쓰고 저장하지 않습니다.
+    let (release, released) = tokio::sync::oneshot::channel::<()>();
+    let load = cache.get_or_load(3, || async move {
+        released.await.unwrap();
+        Ok::<_, ()>(30)
+    });
+    let invalidate = async {
+        tokio::task::yield_now().await;
+        cache.invalidate(&3);
+        release.send(()).unwrap();
+    };
+    let (loaded, _) = tokio::join!(load, invalidate);
+    assert_eq!(loaded, Ok(30));
+    assert_eq!(
+        cache.get_or_load(3, || async { Ok::<_, ()>(31) }).await,
+        Ok(31)
+    );
+
+    // 전체를 비우면 모든 키의 읽던 값을 저장하지 않습니다.
+    let (release, released) = tokio::sync::oneshot::channel::<()>();
+    let load = cache.get_or_load(4, || async move {
+        released.await.unwrap();
+        Ok::<_, ()>(40)
+    });
+    let clear = async {
+        tokio::task::yield_now().await;
+        cache.clear();
+        release.send(()).unwrap();
+    };
+    let (loaded, _) = tokio::join!(load, clear);
+    assert_eq!(loaded, Ok(40));
+    assert_eq!(
+        cache.get_or_load(4, || async { Ok::<_, ()>(41) }).await,
+        Ok(41)
+    );
+    assert_eq!(cache.stats().loading, 0);
+}
@@ -0,0 +1,403 @@
+//! 게시글 변경 이벤트 SSE/WebSocket 통합 테스트
+//!
+//! SSE는 공용 헬퍼(`common`)의 라우터로 응답 본문을 스트림으로 읽고,
+//! WebSocket은 같은 라우터를 임시 포트에 띄운 뒤 `tokio-tungstenite` 클라이언트로 연결합니다.
+
+mod common;
+
+use axum::body::BodyDataStream;
+use common::*;
+use futures_util::{SinkExt, StreamExt};
+use oracle_test::models::board_event::EventId;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+
+/// 스트림에서 다음 항목을 기다리는 최대 시간
+const WAIT: Duration = Duration::from_secs(5);
+
+/// 테스트 앱의 이벤트 허브가 `seq`번째로 매기는 이벤트 ID
+fn event_id(app: &TestApp, seq: u64) -> String {
+    let epoch = app.boards.events().epoch();
+    EventId { epoch, seq }.to_string()
+}
+
+/// SSE 응답 본문을 프레임(빈 줄로 구분) 단위로 읽는 클라이언트
+struct SseClient {
+    body: BodyDataStream,
+    buffer: String,
+}
+
+/// SSE 프레임 하나 (주석만 있는 하트비트는 `event`가 `None`)
+#[derive(Debug)]
+struct SseFrame {
+    id: Option<String>,
+    event: Option<String>,
+    data: Value,
+}
+
+impl SseClient {
+    /// `/boards/events`에 연결합니다. (`last_event_id`는 `Last-Event-ID` 헤더)
+    async fn open(app: &TestApp, query: &str, last_event_id: Option<&str>) -> Self {
+        let (status, headers, client) = Self::try_open(app, query, last_event_id).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(
+            headers[header::CONTENT_TYPE]
+                .to_str()
+                .unwrap()
+                .starts_with("text/event-stream")
+        );
+        client
+    }
+
+    async fn try_open(
+        app: &TestApp,
+        query: &str,
+        last_event_id: Option<&str>,
+    ) -> (StatusCode, HeaderMap, Self) {
+        let mut builder = Request::builder().uri(format!("/boards/events{query}"));
+        if let Some(id) = last_event_id {
+            builder = builder.header("Last-Event-ID", id);
+        }
+        let response = app
+            .router
+            .clone()
+            .oneshot(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let client = Self {
+            body: response.into_body().into_data_stream(),
+            buffer: String::new(),
+        };
+        (status, headers, client)
+    }
+
+    /// 다음 프레임 (스트림이 끝나면 `None`)
+    async fn next_frame(&mut self) -> Option<SseFrame> {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let raw: String = self.buffer.drain(..end + 2).collect();
+                let mut frame = SseFrame {
+                    id: None,
+                    event: None,
+                    data: Value::Null,
+                };
+                for line in raw.lines() {
+                    if let Some(id) = line.strip_prefix("id: ") {
+                        frame.id = Some(id.to_string());
+                    } else if let Some(event) = line.strip_prefix("event: ") {
+                        frame.event = Some(event.to_string());
+                    } else if let Some(data) = line.strip_prefix("data: ") {
+                        frame.data = serde_json::from_str(data).unwrap();
+                    }
+                }
+                return Some(frame);
+            }
+            let chunk = timeout(WAIT, self.body.next())
+                .await
+                .expect("SSE 프레임 대기 시간 초과")?
+                .unwrap();
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    /// 하트비트를 건너뛴 다음 이벤트
+    async fn next_event(&mut self) -> SseFrame {
+        loop {
+            let frame = self.next_frame().await.expect("SSE 스트림이 끝났습니다");
+            if frame.event.is_some() {
+                return frame;
+            }
+        }
+    }
+
+    /// 다음 이벤트들의 (이벤트 이름, 게시글 ID)
+    async fn next_events(&mut self, count: usize) -> Vec<(String, i64)> {
+        let mut events = Vec::new();
+        for _ in 0..count {
+            let frame = self.next_event().await;
+            events.push((
+                frame.event.unwrap(),
+                frame.data["board_id"].as_i64().unwrap_or_default(),
+            ));
+        }
+        events
+    }
+}
+
+/// 현재 `ETag`로 제목을 바꿉니다.
+async fn update_title(app: &TestApp, id: i64, title: &str) {
+    let etag = etag(app, id).await;
+    let (status, _, _) = request(
+        app,
+        Some(&app.token),
+        Method::PUT,
+        &format!("/boards/{id}"),
+        &[(header::IF_MATCH, etag.as_str())],
+        Some(json!({ "title": title, "content": "수정한 내용" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn sse_streams_created_updated_and_deleted_events() {
+    let app = app().await;
+    let mut events = SseClient::open(&app, "", None).await;
+
+    let id = create(&app, "새 글", "내용").await;
+    update_title(&app, id, "고친 글").await;
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+
+    let created = events.next_event().await;
+    assert_eq!(created.event.as_deref(), Some("created"));
+    assert_eq!(created.id, Some(event_id(&app, 1)));
+    assert_eq!(created.data["id"], event_id(&app, 1));
+    assert_eq!(created.data["kind"], "created");
+    assert_eq!(created.data["board_id"], id);
+    assert_eq!(created.data["title"], "새 글");
+    assert_eq!(created.data["version"], 1);
+    assert!(created.data["author_id"].is_i64());
+    assert!(created.data["occurred_at"].is_string());
+
+    let updated = events.next_event().await;
+    assert_eq!(updated.event.as_deref(), Some("updated"));
+    assert_eq!(updated.id, Some(event_id(&app, 2)));
+    assert_eq!(updated.data["title"], "고친 글");
+    assert_eq!(updated.data["version"], 2);
+
+    let deleted = events.next_event().await;
+    assert_eq!(deleted.event.as_deref(), Some("deleted"));
+    assert_eq!(deleted.id, Some(event_id(&app, 3)));
+    assert_eq!(deleted.data["board_id"], id);
+    assert!(deleted.data.get("version").is_none());
+
+    // 서버가 종료를 시작하면 스트림이 끝납니다.
+    app.boards.events().close();
+    while events.next_frame().await.is_some() {}
+}
+
+#[tokio::test]
+async fn sse_sends_heartbeats_and_filters_by_board() {
+    let app = app().await;
+    let first = create(&app, "첫 글", "내용").await;
+    let second = create(&app, "둘째 글", "내용").await;
+
+    let mut events = SseClient::open(&app, &format!("?boards={second}"), None).await;
+    // 이벤트가 없어도 하트비트 주기마다 주석 프레임을 보냅니다.
+    let heartbeat = events.next_frame().await.unwrap();
+    assert!(heartbeat.event.is_none());
+
+    update_title(&app, first, "첫 글 수정").await;
+    update_title(&app, second, "둘째 글 수정").await;
+    assert_eq!(delete_board(&app, first).await, StatusCode::NO_CONTENT);
+    assert_eq!(delete_board(&app, second).await, StatusCode::NO_CONTENT);
+    assert_eq!(
+        events.next_events(2).await,
+        [
+            ("updated".to_string(), second),
+            ("deleted".to_string(), second)
+        ]
+    );
+
+    for query in ["?boards=abc", "?boards=0", "?boards=1,,2"] {
+        let (status, _, _) = SseClient::try_open(&app, query, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+    }
+    let too_many: Vec<String> = (1..=101).map(|id| id.to_string()).collect();
+    let query = format!("?boards={}", too_many.join(","));
+    let (status, _, _) = SseClient::try_open(&app, &query, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn sse_replays_after_last_event_id_and_resets_when_too_old() {
+    let app = app().await;
+    let mut ids = Vec::new();
+    for n in 1..=3 {
+        ids.push(create(&app, &format!("글 {n}"), "내용").await);
+    }
+
+    // 마지막으로 받은 이벤트 뒤부터 다시 받고, 이어서 실시간 이벤트를 받습니다.
+    let mut events = SseClient::open(&app, "", Some(&event_id(&app, 1))).await;
+    let fourth = create(&app, "글 4", "내용").await;
+    assert_eq!(
+        events.next_events(3).await,
+        [
+            ("created".to_string(), ids[1]),
+            ("created".to_string(), ids[2]),
+            ("created".to_string(), fourth)
+        ]
+    );
+
+    // 브라우저가 아닌 클라이언트는 쿼리 파라미터로도 요청할 수 있습니다.
+    let query = format!("?last_event_id={}", event_id(&app, 3));
+    let mut events = SseClient::open(&app, &query, None).await;
+    assert_eq!(events.next_event().await.id, Some(event_id(&app, 4)));
+
+    // 이 프로세스가 발행한 적 없는 ID는 reset 뒤에 보관된 이벤트를 모두 보냅니다.
+    let mut events = SseClient::open(&app, "", Some(&event_id(&app, 99))).await;
+    let reset = events.next_event().await;
+    assert_eq!(reset.event.as_deref(), Some("reset"));
+    assert_eq!(reset.data["kind"], "reset");
+    assert!(reset.id.is_none());
+    assert_eq!(events.next_event().await.id, Some(event_id(&app, 1)));
+
+    // 재시작 전 프로세스(다른 epoch)의 ID는 순번이 보관 범위 안이어도 reset입니다.
+    let epoch = app.boards.events().epoch().wrapping_add(1);
+    let restarted = EventId { epoch, seq: 2 }.to_string();
+    let mut events = SseClient::open(&app, "", Some(&restarted)).await;
+    assert_eq!(events.next_event().await.event.as_deref(), Some("reset"));
+    assert_eq!(events.next_event().await.id, Some(event_id(&app, 1)));
+
+    // 보관 범위(테스트 앱은 16개)를 벗어난 ID도 reset으로 알립니다.
+    for n in 5..=24 {
+        create(&app, &format!("글 {n}"), "내용").await;
+    }
+    let mut events = SseClient::open(&app, "", Some(&event_id(&app, 3))).await;
+    assert_eq!(events.next_event().await.event.as_deref(), Some("reset"));
+    assert_eq!(events.next_event().await.id, Some(event_id(&app, 9)));
+    let mut events = SseClient::open(&app, "", Some(&event_id(&app, 8))).await;
+    assert_eq!(events.next_event().await.id, Some(event_id(&app, 9)));
+
+    for last in [
+        "abc",
+        "2",
+        "0001-2",
+        "zzzzzzzz-1",
+        "00000001-x",
+        "00000001-",
+        "+0000001-2",
+    ] {
+        let (status, _, _) = SseClient::try_open(&app, "", Some(last)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{last}");
+    }
+}
+
+/// 라우터를 임시 포트에 띄우고 주소를 반환합니다.
+async fn serve(app: &TestApp) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = app.router.clone();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+#[tokio::test]
+async fn websocket_streams_events_with_replay_pings_and_subscription_changes() {
+    let app = app().await;
+    let first = create(&app, "첫 글", "내용").await;
+    let second = create(&app, "둘째 글", "내용").await;
+    let addr = serve(&app).await;
+
+    let url = format!(
+        "ws://{addr}/boards/events/ws?last_event_id={}",
+        event_id(&app, 1)
+    );
+    let (mut socket, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+    // 다음 텍스트 메시지 (ping은 건너뜀)
+    macro_rules! next_text {
+        () => {
+            loop {
+                match timeout(WAIT, socket.next())
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .unwrap()
+                {
+                    Message::Text(text) => break serde_json::from_str::<Value>(&text).unwrap(),
+                    Message::Ping(_) | Message::Pong(_) => continue,
+                    other => panic!("예상하지 못한 메시지: {other:?}"),
+                }
+            }
+        };
+    }
+
+    let replayed = next_text!();
+    assert_eq!(replayed["id"], event_id(&app, 2));
+    assert_eq!(replayed["kind"], "created");
+    assert_eq!(replayed["board_id"], second);
+
+    // 하트비트 주기마다 ping을 보냅니다.
+    let ping = timeout(WAIT, async {
+        loop {
+            if let Message::Ping(_) = socket.next().await.unwrap().unwrap() {
+                break;
+            }
+        }
+    })
+    .await;
+    assert!(ping.is_ok());
+
+    // 구독할 게시글을 바꾸고, 잘못된 메시지에는 오류 알림으로 답합니다.
+    socket
+        .send(Message::Text(json!({ "subscribe": [first] }).to_string()))
+        .await
+        .unwrap();
+    socket
+        .send(Message::Text("구독 아님".to_string()))
+        .await
+        .unwrap();
+    let error = next_text!();
+    assert_eq!(error["kind"], "error");
+    assert!(error["error"].as_str().unwrap().contains("구독 메시지"));
+
+    assert_eq!(delete_board(&app, second).await, StatusCode::NO_CONTENT);
+    assert_eq!(delete_board(&app, first).await, StatusCode::NO_CONTENT);
+    let deleted = next_text!();
+    assert_eq!(deleted["kind"], "deleted");
+    assert_eq!(deleted["board_id"], first);
+
+    // 빈 목록은 다시 모든 게시글을 구독합니다.
+    socket
+        .send(Message::Text(json!({ "subscribe": [] }).to_string()))
+        .await
+        .unwrap();
+    socket
+        .send(Message::Text(json!({ "subscribe": [0] }).to_string()))
+        .await
+        .unwrap();
+    assert_eq!(next_text!()["kind"], "error");
+    let third = create(&app, "셋째 글", "내용").await;
+    assert_eq!(next_text!()["board_id"], third);
+
+    // 서버가 종료를 시작하면 Close 프레임을 보내고 연결을 끝냅니다.
+    app.boards.events().close();
+    let closed = timeout(WAIT, async {
+        loop {
+            match socket.next().await {
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(_) => continue,
+            }
+        }
+    })
+    .await;
+    assert!(closed.is_ok());
+}
+
+#[tokio::test]
+async fn websocket_rejects_invalid_subscription_before_upgrade() {
+    let app = app().await;
+    let addr = serve(&app).await;
+    let err = tokio_tungstenite::connect_async(format!("ws://{addr}/boards/events/ws?boards=x"))
+        .await
+        .unwrap_err();
+    match err {
+        tokio_tungstenite::tungstenite::Error::Http(response) => {
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+        other => panic!("예상하지 못한 오류: {other:?}"),
+    }
+}
@@ -0,0 +1,333 @@
+//! 게시글 일괄 내보내기/가져오기 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 `/boards/export`, `/boards/import`를 검증합니다.
+
+mod common;
+
+use common::*;
+
+/// 본문을 그대로 보내고 상태 코드, 응답 헤더, 응답 본문(바이트)을 반환합니다.
+async fn raw(
+    app: &TestApp,
+    token: Option<&str>,
+    method: Method,
+    uri: &str,
+    body: Vec<u8>,
+) -> (StatusCode, HeaderMap, Vec<u8>) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let response = app
+        .router
+        .clone()
+        .oneshot(builder.body(Body::from(body)).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, bytes.to_vec())
+}
+
+/// 기본 사용자로 파일을 가져오고 결과 보고서를 반환합니다.
+async fn import(app: &TestApp, format: &str, body: impl Into<Vec<u8>>) -> Value {
+    let uri = format!("/boards/import?format={format}");
+    let (status, _, bytes) = raw(app, Some(&app.token), Method::POST, &uri, body.into()).await;
+    assert_eq!(status, StatusCode::OK);
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+/// 기본 사용자로 내보낸 파일 본문
+async fn export(app: &TestApp, format: &str) -> String {
+    let uri = format!("/boards/export?format={format}");
+    let (status, _, bytes) = raw(app, Some(&app.token), Method::GET, &uri, Vec::new()).await;
+    assert_eq!(status, StatusCode::OK);
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn export_jsonl_streams_live_boards_in_creation_order() {
+    let app = app().await;
+    let first = create(&app, "첫 글", "첫 내용").await;
+    let trashed = create(&app, "지울 글", "지울 내용").await;
+    let last = create(&app, "마지막 글", "마지막 내용").await;
+    assert_eq!(delete_board(&app, trashed).await, StatusCode::NO_CONTENT);
+
+    let (status, headers, bytes) = raw(
+        &app,
+        Some(&app.token),
+        Method::GET,
+        "/boards/export",
+        Vec::new(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/x-ndjson");
+    assert_eq!(
+        headers[header::CONTENT_DISPOSITION],
+        "attachment; filename=\"boards.jsonl\""
+    );
+
+    let rows: Vec<Value> = String::from_utf8(bytes)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["id"], first);
+    assert_eq!(rows[0]["title"], "첫 글");
+    assert_eq!(rows[0]["content"], "첫 내용");
+    assert_eq!(rows[0]["format"], "plain");
+    assert!(rows[0]["author_id"].is_i64());
+    assert!(rows[0]["created_at"].is_string());
+    assert_eq!(rows[1]["id"], last);
+}
+
+#[tokio::test]
+async fn csv_export_round_trips_through_import() {
+    let source = app().await;
+    create(
+        &source,
+        "쉼표, \"따옴표\"",
+        "여러 줄\n본문, 그리고 \"인용\"",
+    )
+    .await;
+    create(&source, "두 번째", "평범한 내용").await;
+
+    let csv = export(&source, "csv").await;
+    assert!(csv.starts_with("id,title,content,format,category_id,tags,author_id,created_at\n"));
+
+    let target = app().await;
+    let report = import(&target, "csv", csv).await;
+    assert_eq!(report["total"], 2);
+    assert_eq!(report["imported"], 2);
+    assert_eq!(report["failed"], 0);
+    assert_eq!(report["errors"], json!([]));
+
+    let id = report["ids"][0].as_i64().unwrap();
+    let (status, body) = send(&target, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "쉼표, \"따옴표\"");
+    assert_eq!(body["content"], "여러 줄\n본문, 그리고 \"인용\"");
+}
+
+#[tokio::test]
+async fn content_format_round_trips_through_export_and_import() {
+    let source = app().await;
+    let (status, _) = send(
+        &source,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "마크다운", "content": "**굵게**", "format": "markdown" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    create(&source, "일반", "**그대로**").await;
+
+    for format in ["jsonl", "csv"] {
+        let target = app().await;
+        let report = import(&target, format, export(&source, format).await).await;
+        assert_eq!(report["imported"], 2, "{format}: {report}");
+
+        let (_, list) = send(&target, Method::GET, "/boards?sort=created_at,asc", None).await;
+        assert_eq!(list["data"][0]["format"], "markdown", "{format}");
+        assert_eq!(
+            list["data"][0]["content_html"],
+            "<p><strong>굵게</strong></p>\n"
+        );
+        assert_eq!(list["data"][1]["format"], "plain", "{format}");
+        assert_eq!(list["data"][1]["content_html"], "**그대로**");
+    }
+
+    // 형식을 빼면 plain, 알 수 없는 형식은 그 행만 실패합니다.
+    let target = app().await;
+    let jsonl = concat!(
+        "{\"title\":\"없음\",\"content\":\"내용\"}\n",
+        "{\"title\":\"잘못\",\"content\":\"내용\",\"format\":\"html\"}\n",
+    );
+    let report = import(&target, "jsonl", jsonl).await;
+    assert_eq!(report["imported"], 1);
+    assert_eq!(report["errors"][0]["line"], 2);
+    assert!(
+        report["errors"][0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("html")
+    );
+    let csv = "title,content,format\n빈 형식,내용,\n";
+    assert_eq!(import(&target, "csv", csv).await["imported"], 1);
+}
+
+#[tokio::test]
+async fn category_and_tags_round_trip_through_export_and_import() {
+    /// 카테고리 하나를 만들고 그 ID를 반환합니다.
+    async fn category(app: &TestApp) -> i64 {
+        let (status, body) = send(
+            app,
+            Method::POST,
+            "/categories",
+            Some(json!({ "name": "공지" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        body["id"].as_i64().unwrap()
+    }
+
+    let source = app().await;
+    let category_id = category(&source).await;
+    let (status, _) = send(
+        &source,
+        Method::POST,
+        "/boards",
+        Some(json!({
+            "title": "분류된 글",
+            "content": "내용",
+            "category_id": category_id,
+            "tags": ["rust", "쉼표, 있음"],
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    create(&source, "분류 없는 글", "내용").await;
+
+    for format in ["jsonl", "csv"] {
+        let target = app().await;
+        assert_eq!(category(&target).await, category_id);
+        let report = import(&target, format, export(&source, format).await).await;
+        assert_eq!(report["imported"], 2, "{format}: {report}");
+
+        let (_, list) = send(&target, Method::GET, "/boards?sort=created_at,asc", None).await;
+        assert_eq!(list["data"][0]["category_id"], category_id, "{format}");
+        assert_eq!(list["data"][0]["tags"], json!(["rust", "쉼표, 있음"]));
+        assert!(list["data"][1]["category_id"].is_null());
+        assert_eq!(list["data"][1]["tags"], json!([]));
+    }
+
+    // 직접 쓴 CSV는 쉼표로 구분한 태그도 받고, 없는 카테고리는 그 행만 실패합니다.
+    let target = app().await;
+    let csv = "title,content,category_id,tags\n손으로 쓴 글,내용,,\"a, B\"\n없는 분류,내용,99,\n";
+    let report = import(&target, "csv", csv).await;
+    assert_eq!(report["imported"], 1, "{report}");
+    assert_eq!(report["errors"][0]["line"], 3);
+    assert!(
+        report["errors"][0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("카테고리")
+    );
+    let id = report["ids"][0].as_i64().unwrap();
+    let (_, body) = send(&target, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(body["tags"], json!(["a", "b"]));
+}
+
+#[tokio::test]
+async fn import_reports_invalid_rows_with_line_numbers() {
+    let app = app().await;
+    let too_long = "가".repeat(oracle_test::services::board_service::MAX_CONTENT_CHARS + 1);
+    let body = [
+        json!({ "title": "정상 글", "content": "정상 내용", "id": 77 }).to_string(),
+        json!({ "title": "  ", "content": "제목 없음" }).to_string(),
+        String::new(),
+        "{ 깨진 JSON".to_string(),
+        json!({ "title": "본문 초과", "content": too_long }).to_string(),
+        json!({ "content": "제목 필드 없음" }).to_string(),
+        json!({ "title": "또 정상", "content": "또 내용" }).to_string(),
+    ]
+    .join("\n");
+
+    let report = import(&app, "jsonl", body).await;
+    assert_eq!(report["total"], 6);
+    assert_eq!(report["imported"], 2);
+    assert_eq!(report["failed"], 4);
+    let lines: Vec<i64> = report["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["line"].as_i64().unwrap())
+        .collect();
+    assert_eq!(lines, [2, 4, 5, 6]);
+    assert_eq!(report["errors"][0]["error"], "제목은 필수입니다.");
+    assert!(
+        report["errors"][1]["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("JSON 형식 오류")
+    );
+
+    // 파일에 있던 `id`는 무시하고 새 ID로 추가합니다.
+    let id = report["ids"][0].as_i64().unwrap();
+    assert_ne!(id, 77);
+    let (status, body) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "정상 글");
+
+    let csv = "title,content\n정상,내용\n열 부족\n,빈 제목\n";
+    let report = import(&app, "csv", csv).await;
+    assert_eq!(report["imported"], 1);
+    assert_eq!(report["errors"][0]["line"], 3);
+    assert_eq!(report["errors"][1]["line"], 4);
+    assert_eq!(report["errors"][1]["error"], "제목은 필수입니다.");
+}
+
+#[tokio::test]
+async fn large_import_and_export_span_multiple_batches() {
+    let app = app().await;
+    let count = 1_234;
+    let body: String = (1..=count)
+        .map(|n| {
+            json!({ "title": format!("글 {n}"), "content": format!("내용 {n}") }).to_string() + "\n"
+        })
+        .collect();
+
+    let report = import(&app, "jsonl", body).await;
+    assert_eq!(report["imported"], count);
+    let ids: Vec<i64> = report["ids"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_i64().unwrap())
+        .collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let exported = export(&app, "jsonl").await;
+    let exported_ids: Vec<i64> = exported
+        .lines()
+        .map(|line| {
+            serde_json::from_str::<Value>(line).unwrap()["id"]
+                .as_i64()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(exported_ids, ids);
+
+    let csv = export(&app, "csv").await;
+    assert_eq!(csv.lines().count(), count + 1);
+}
+
+#[tokio::test]
+async fn transfer_requires_login_and_known_format() {
+    let app = app().await;
+    let (status, _, _) = raw(&app, None, Method::GET, "/boards/export", Vec::new()).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _, _) = raw(&app, None, Method::POST, "/boards/import", Vec::new()).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = send(&app, Method::GET, "/boards/export?format=xml", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("xml"));
+    let (status, _, _) = raw(
+        &app,
+        Some(&app.token),
+        Method::POST,
+        "/boards/import?format=xml",
+        b"{}".to_vec(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 빈 파일은 아무것도 추가하지 않습니다.
+    let report = import(&app, "jsonl", "").await;
+    assert_eq!(report["total"], 0);
+    assert_eq!(report["imported"], 0);
+}
@@ -0,0 +1,305 @@
+//! 카테고리/태그 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 `/categories`, `/tags`와
+//! 게시글의 카테고리/태그 저장 및 목록 필터를 검증합니다.
+
+mod common;
+
+use common::*;
+
+/// 카테고리를 만들고 생성된 ID를 반환합니다.
+async fn create_category(app: &TestApp, name: &str) -> i64 {
+    let (status, body) = send(
+        app,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": name })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["id"].as_i64().unwrap()
+}
+
+/// 카테고리와 태그를 지정하여 게시글을 만들고 생성된 ID를 반환합니다.
+async fn create_tagged(app: &TestApp, title: &str, category_id: Option<i64>, tags: &[&str]) -> i64 {
+    let body =
+        json!({ "title": title, "content": "내용", "category_id": category_id, "tags": tags });
+    let (status, body) = send(app, Method::POST, "/boards", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    body["id"].as_i64().unwrap()
+}
+
+/// 현재 `ETag`를 `If-Match`로 실어 게시글을 수정합니다.
+async fn update(app: &TestApp, id: i64, body: Value) -> StatusCode {
+    let etag = etag(app, id).await;
+    let uri = format!("/boards/{id}");
+    let headers = [(header::IF_MATCH, etag.as_str())];
+    let (status, _, _) = request(
+        app,
+        Some(&app.token),
+        Method::PUT,
+        &uri,
+        &headers,
+        Some(body),
+    )
+    .await;
+    status
+}
+
+/// 목록 조회 결과의 게시글 ID
+async fn list_ids(app: &TestApp, query: &str) -> Vec<i64> {
+    let (status, body) = send(app, Method::GET, &format!("/boards?{query}"), None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|board| board["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn category_crud_requires_login_and_unique_names() {
+    let app = app().await;
+    let (status, _) = send_as(
+        &app,
+        None,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": "공지" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let notice = create_category(&app, "  공지 ").await;
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": "자유", "description": "아무 이야기" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let free = body["id"].as_i64().unwrap();
+    assert_eq!(body["description"], "아무 이야기");
+
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": "공지" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/categories",
+        Some(json!({ "name": " " })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 목록과 단건 조회는 로그인 없이 가능하며, 이름 순으로 정렬됩니다.
+    let (status, body) = send_as(&app, None, Method::GET, "/categories", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let names: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|category| category["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["공지", "자유"]);
+
+    let uri = format!("/categories/{free}");
+    let (status, body) = send(&app, Method::PUT, &uri, Some(json!({ "name": "잡담" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "잡담");
+    assert_eq!(body["description"], Value::Null);
+    let (status, _) = send(&app, Method::PUT, &uri, Some(json!({ "name": "공지" }))).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    // 자기 자신의 이름으로 바꾸는 것은 충돌이 아닙니다.
+    let (status, _) = send(&app, Method::PUT, &uri, Some(json!({ "name": "잡담" }))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&app, Method::GET, &format!("/categories/{notice}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "공지");
+    let (status, _) = send(&app, Method::GET, "/categories/999", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        &app,
+        Method::PUT,
+        "/categories/999",
+        Some(json!({ "name": "x" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn deleting_category_leaves_boards_uncategorized() {
+    let app = app().await;
+    let category = create_category(&app, "공지").await;
+    let id = create_tagged(&app, "공지 글", Some(category), &[]).await;
+
+    let uri = format!("/categories/{category}");
+    let (status, _) = send_as(&app, None, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(body["category_id"], Value::Null);
+}
+
+#[tokio::test]
+async fn boards_store_normalized_tags_and_known_categories() {
+    let app = app().await;
+    let category = create_category(&app, "개발").await;
+    let id = create_tagged(&app, "태그 글", Some(category), &[" Rust", "WEB ", "rust"]).await;
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(body["category_id"], category);
+    assert_eq!(body["tags"], json!(["rust", "web"]));
+
+    // 태그와 카테고리 없이 만든 게시글은 미분류에 태그가 없습니다.
+    let plain = create(&app, "평범한 글", "내용").await;
+    let (_, body) = send(&app, Method::GET, &format!("/boards/{plain}"), None).await;
+    assert_eq!(body["category_id"], Value::Null);
+    assert_eq!(body["tags"], json!([]));
+
+    let invalid = [
+        json!({ "title": "t", "content": "c", "category_id": 999 }),
+        json!({ "title": "t", "content": "c", "tags": ["  "] }),
+        json!({ "title": "t", "content": "c", "tags": ["a".repeat(31)] }),
+        json!({ "title": "t", "content": "c", "tags": (0..11).map(|n| format!("t{n}")).collect::<Vec<_>>() }),
+    ];
+    for body in invalid {
+        let (status, _) = send(&app, Method::POST, "/boards", Some(body.clone())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    }
+}
+
+#[tokio::test]
+async fn update_keeps_or_replaces_category_and_tags() {
+    let app = app().await;
+    let category = create_category(&app, "개발").await;
+    let id = create_tagged(&app, "글", Some(category), &["rust", "web"]).await;
+    let uri = format!("/boards/{id}");
+
+    // 필드를 보내지 않으면 카테고리와 태그는 그대로입니다.
+    let status = update(&app, id, json!({ "title": "수정 1", "content": "내용" })).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["category_id"], category);
+    assert_eq!(body["tags"], json!(["rust", "web"]));
+
+    let body =
+        json!({ "title": "수정 2", "content": "내용", "category_id": null, "tags": ["Axum"] });
+    assert_eq!(update(&app, id, body).await, StatusCode::OK);
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["category_id"], Value::Null);
+    assert_eq!(body["tags"], json!(["axum"]));
+
+    // 잘못된 카테고리로 수정하면 제목도 바뀌지 않습니다.
+    let body = json!({ "title": "수정 3", "content": "내용", "category_id": 999 });
+    assert_eq!(update(&app, id, body).await, StatusCode::BAD_REQUEST);
+    let body = json!({ "title": "수정 3", "content": "내용", "tags": [] });
+    assert_eq!(update(&app, id, body).await, StatusCode::OK);
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["title"], "수정 3");
+    assert_eq!(body["tags"], json!([]));
+
+    // 이력으로 되돌려도 카테고리와 태그는 건드리지 않습니다.
+    assert_eq!(
+        update(
+            &app,
+            id,
+            json!({ "title": "t", "content": "c", "tags": ["keep"] })
+        )
+        .await,
+        StatusCode::OK
+    );
+    let etag = etag(&app, id).await;
+    let headers = [(header::IF_MATCH, etag.as_str())];
+    let revert = format!("/boards/{id}/revisions/1/revert");
+    let (status, _, _) = request(
+        &app,
+        Some(&app.token),
+        Method::POST,
+        &revert,
+        &headers,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(body["title"], "글");
+    assert_eq!(body["tags"], json!(["keep"]));
+}
+
+#[tokio::test]
+async fn list_filters_by_category_and_tag() {
+    let app = app().await;
+    let dev = create_category(&app, "개발").await;
+    let talk = create_category(&app, "잡담").await;
+    let a = create_tagged(&app, "a", Some(dev), &["rust"]).await;
+    let b = create_tagged(&app, "b", Some(dev), &["web"]).await;
+    let c = create_tagged(&app, "c", Some(talk), &["rust", "web"]).await;
+    create_tagged(&app, "d", None, &[]).await;
+
+    assert_eq!(
+        list_ids(&app, &format!("category={dev}&sort=id,asc")).await,
+        [a, b]
+    );
+    assert_eq!(list_ids(&app, "tag=RUST&sort=id,asc").await, [a, c]);
+    assert_eq!(
+        list_ids(&app, &format!("category={dev}&tag=web")).await,
+        [b]
+    );
+    assert!(list_ids(&app, "tag=none").await.is_empty());
+
+    // 목록 응답에도 태그가 담기고, 커서 모드에서도 같은 필터를 씁니다.
+    let (_, body) = send(
+        &app,
+        Method::GET,
+        "/boards?tag=web&cursor=&with_total=true",
+        None,
+    )
+    .await;
+    assert_eq!(body["pagination"]["total_count"], 2);
+    assert_eq!(body["data"][0]["id"], c);
+    assert_eq!(body["data"][0]["tags"], json!(["rust", "web"]));
+    assert_eq!(body["data"][0]["category_id"], talk);
+
+    for query in [
+        "category=abc",
+        "category=0",
+        &format!("tag={}", "a".repeat(31)),
+    ] {
+        let (status, _) = send(&app, Method::GET, &format!("/boards?{query}"), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+    }
+}
+
+#[tokio::test]
+async fn tag_counts_skip_trashed_boards() {
+    let app = app().await;
+    create_tagged(&app, "a", None, &["rust", "web"]).await;
+    create_tagged(&app, "b", None, &["rust"]).await;
+    let trashed = create_tagged(&app, "c", None, &["web", "old"]).await;
+    assert_eq!(delete_board(&app, trashed).await, StatusCode::NO_CONTENT);
+
+    let (status, body) = send_as(&app, None, Method::GET, "/tags", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        json!([
+            { "name": "rust", "board_count": 2 },
+            { "name": "web", "board_count": 1 },
+        ])
+    );
+}
@@ -0,0 +1,258 @@
+//! 댓글 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 댓글 라우트를 검증합니다.
+
+mod common;
+
+use common::*;
+
+/// 댓글(또는 답글)을 작성하고 생성된 ID를 반환합니다.
+async fn comment(app: &TestApp, board_id: i64, parent_id: Option<i64>, content: &str) -> i64 {
+    let (status, body) = send(
+        app,
+        Method::POST,
+        &format!("/boards/{board_id}/comments"),
+        Some(json!({ "content": content, "parent_id": parent_id })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["id"].as_i64().unwrap()
+}
+
+/// 응답 본문의 루트 댓글 내용 목록
+fn contents(body: &Value) -> Vec<String> {
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|comment| comment["content"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn create_comment_returns_201() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        &format!("/boards/{board_id}/comments"),
+        Some(json!({ "content": "첫 댓글" })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(body["id"].as_i64().unwrap() > 0);
+    assert_eq!(body["board_id"], board_id);
+    assert_eq!(body["content"], "첫 댓글");
+    assert!(body["parent_id"].is_null());
+    assert!(body["author_id"].as_i64().is_some());
+    assert!(body.get("replies").is_none());
+}
+
+#[tokio::test]
+async fn create_comment_validates_input() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let uri = format!("/boards/{board_id}/comments");
+
+    let long_content = "가".repeat(1001);
+    for case in [
+        json!({ "content": "  " }),
+        json!({ "content": long_content }),
+    ] {
+        let (status, _) = send(&app, Method::POST, &uri, Some(case)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/boards/9999/comments",
+        Some(json!({ "content": "없는 글" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send_as(
+        &app,
+        None,
+        Method::POST,
+        &uri,
+        Some(json!({ "content": "익명" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn replies_are_nested_under_their_parent() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let first = comment(&app, board_id, None, "루트 1").await;
+    let second = comment(&app, board_id, None, "루트 2").await;
+    comment(&app, board_id, Some(second), "답글 2-1").await;
+    comment(&app, board_id, Some(first), "답글 1-1").await;
+    comment(&app, board_id, Some(first), "답글 1-2").await;
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        &format!("/boards/{board_id}/comments"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(contents(&body), ["루트 1", "루트 2"]);
+
+    let replies = |index: usize| -> Vec<String> {
+        body["data"][index]["replies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|reply| reply["content"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(replies(0), ["답글 1-1", "답글 1-2"]);
+    assert_eq!(replies(1), ["답글 2-1"]);
+    assert_eq!(body["data"][0]["replies"][0]["parent_id"], first);
+}
+
+#[tokio::test]
+async fn replies_must_target_root_comment_of_same_board() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let other_board_id = create(&app, "다른 글", "내용").await;
+    let root = comment(&app, board_id, None, "루트").await;
+    let reply = comment(&app, board_id, Some(root), "답글").await;
+    let other_root = comment(&app, other_board_id, None, "다른 글 루트").await;
+
+    let uri = format!("/boards/{board_id}/comments");
+    for parent_id in [reply, other_root, 9999] {
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            &uri,
+            Some(json!({ "content": "답글", "parent_id": parent_id })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn list_comments_paginates_root_comments() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    for i in 1..=5 {
+        let root = comment(&app, board_id, None, &format!("루트 {i}")).await;
+        comment(&app, board_id, Some(root), &format!("답글 {i}")).await;
+    }
+
+    let uri = format!("/boards/{board_id}/comments?size=2&page=3");
+    let (status, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(contents(&body), ["루트 5"]);
+    assert_eq!(body["data"][0]["replies"][0]["content"], "답글 5");
+    assert_eq!(body["pagination"]["total_pages"], 3);
+    assert_eq!(body["pagination"]["current_page"], 3);
+
+    let uri = format!("/boards/{board_id}/comments?size=2&page=4");
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&app, Method::GET, "/boards/9999/comments", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn only_author_can_modify_comment() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let id = comment(&app, board_id, None, "원래 댓글").await;
+    let other = login(&app, "intruder").await;
+    let uri = format!("/comments/{id}");
+
+    let update = json!({ "content": "남의 댓글" });
+    let (status, _) = send_as(&app, Some(&other), Method::PUT, &uri, Some(update)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send_as(&app, Some(&other), Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(
+        &app,
+        Method::PUT,
+        &uri,
+        Some(json!({ "content": "수정된 댓글" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["content"], "수정된 댓글");
+
+    let (status, _) = send(
+        &app,
+        Method::PUT,
+        "/comments/9999",
+        Some(json!({ "content": "없음" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn deleting_root_comment_removes_replies() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let root = comment(&app, board_id, None, "루트").await;
+    let reply = comment(&app, board_id, Some(root), "답글").await;
+    comment(&app, board_id, None, "남는 댓글").await;
+
+    let (status, _) = send(&app, Method::DELETE, &format!("/comments/{root}"), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&app, Method::DELETE, &format!("/comments/{reply}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let uri = format!("/boards/{board_id}/comments");
+    let (_, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(contents(&body), ["남는 댓글"]);
+}
+
+#[tokio::test]
+async fn deleting_board_removes_its_comments() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let root = comment(&app, board_id, None, "루트").await;
+    let reply = comment(&app, board_id, Some(root), "답글").await;
+
+    assert_eq!(delete_board(&app, board_id).await, StatusCode::NO_CONTENT);
+
+    for id in [root, reply] {
+        let (status, _) = send(&app, Method::DELETE, &format!("/comments/{id}"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+    let uri = format!("/boards/{board_id}/comments");
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn restored_board_keeps_its_comments() {
+    let app = app().await;
+    let board_id = create(&app, "글", "내용").await;
+    let root = comment(&app, board_id, None, "루트").await;
+    comment(&app, board_id, Some(root), "답글").await;
+
+    assert_eq!(delete_board(&app, board_id).await, StatusCode::NO_CONTENT);
+    let uri = format!("/boards/{board_id}/restore");
+    let (status, _) = send(&app, Method::POST, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let uri = format!("/boards/{board_id}/comments");
+    let (status, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(contents(&body), ["루트"]);
+    assert_eq!(body["data"][0]["replies"][0]["content"], "답글");
+}
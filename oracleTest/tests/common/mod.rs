@@ -0,0 +1,252 @@
+//! 통합 테스트 공용 헬퍼
+//!
+//! Oracle 대신 인메모리 SQLite 저장소로 서버와 같은 `build_app()` 라우터(미들웨어 스택 포함)를 구성하고,
+//! `tower::ServiceExt::oneshot`으로 요청을 보내는 함수들을 제공합니다.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{HeaderMap, Method, Request, StatusCode, header},
+};
+use oracle_test::{
+    common::{app_state::AppState, health::Health},
+    config::{Config, Secret},
+    repositories::{
+        cached_board_store::BoardCache, file_storage::LocalFileStorage, sqlite, stores::Stores,
+    },
+    routes::build_app,
+    services::{
+        attachment_service::{AttachmentLimits, AttachmentService},
+        auth_service::AuthService,
+        board_events::BoardEvents,
+        board_service::BoardService,
+        category_service::CategoryService,
+        comment_service::CommentService,
+    },
+};
+pub use serde_json::{Value, json};
+pub use tower::ServiceExt;
+use uuid::Uuid;
+
+/// 테스트용 첨부파일 최대 크기 (바이트)
+pub const ATTACHMENT_MAX_BYTES: u64 = 1024;
+
+/// 테스트용 이벤트 구독 하트비트 주기 (하트비트를 기다리는 테스트가 오래 걸리지 않도록 짧게)
+pub const EVENT_HEARTBEAT: Duration = Duration::from_millis(200);
+
+/// 테스트용 정적 파일 캐시 시간 (초)
+pub const STATIC_MAX_AGE_SECS: u64 = 600;
+
+/// 테스트용 게시글 조회 캐시 크기와 유지 시간 (테스트 도중 만료되지 않도록 길게)
+pub const BOARD_CACHE_CAPACITY: usize = 100;
+pub const BOARD_CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// 테스트용 라우터와 기본 사용자의 액세스 토큰
+pub struct TestApp {
+    pub router: Router,
+    /// 라우터와 같은 저장소를 공유하는 게시글 서비스 (백그라운드 작업을 직접 실행할 때 사용)
+    pub boards: Arc<BoardService>,
+    /// 라우터와 공유하는 헬스 체크 상태 (종료 진행을 흉내 낼 때 사용)
+    pub health: Arc<Health>,
+    /// 라우터와 같은 사용자 저장소를 쓰는 인증 서비스 (라우터에 미들웨어를 더 얹을 때 사용)
+    pub auth: Arc<AuthService>,
+    /// 라우터의 게시글 저장소 앞에 둔 조회 캐시 (적중/실패 수 확인용)
+    pub board_cache: Arc<BoardCache>,
+    pub token: String,
+    /// 테스트마다 새로 만드는 첨부파일 저장 디렉터리 (drop 시 삭제)
+    pub attachment_dir: PathBuf,
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.attachment_dir);
+    }
+}
+
+/// 테스트용 설정: 기본값에 테스트 값을 덮어씁니다.
+///
+/// 테스트는 한 클라이언트에서 요청을 몰아 보내므로 빈도 제한은 끕니다. (빈도 제한 테스트는 `app_with`로 켭니다)
+pub fn test_config() -> Config {
+    Config {
+        static_max_age_secs: STATIC_MAX_AGE_SECS,
+        rate_limit_per_second: 0,
+        jwt_secret: Secret::new("test-secret"),
+        attachment_max_bytes: ATTACHMENT_MAX_BYTES,
+        attachment_allowed_types: vec!["text/plain".to_string(), "image/png".to_string()],
+        ..Config::default()
+    }
+}
+
+/// 빈 인메모리 DB 위에 애플리케이션 라우터를 구성하고 기본 사용자로 로그인합니다.
+pub async fn app() -> TestApp {
+    app_with(test_config()).await
+}
+
+/// `config`로 `app()`과 같은 라우터를 구성하고 기본 사용자로 로그인합니다.
+pub async fn app_with(config: Config) -> TestApp {
+    let pool = sqlite::build_pool(sqlite::MEMORY_PATH, 1, Duration::from_secs(5))
+        .expect("SQLite 풀 생성 실패");
+    sqlite::init_schema(&pool).expect("스키마 초기화 실패");
+    let attachment_dir = std::env::temp_dir().join(format!("board-test-{}", Uuid::new_v4()));
+    let files = Arc::new(LocalFileStorage::new(&attachment_dir));
+    // 모든 API 테스트가 캐시를 거치도록 하여 쓰기 후 무효화 누락을 잡아냅니다.
+    let stores = Stores::sqlite(pool).with_board_cache(BOARD_CACHE_CAPACITY, BOARD_CACHE_TTL);
+    let board_cache = stores.board_cache.clone().expect("게시글 캐시 없음");
+    let service = Arc::new(
+        BoardService::new(
+            stores.boards.clone(),
+            stores.categories.clone(),
+            files.clone(),
+        )
+        .with_events(Arc::new(BoardEvents::new(16, EVENT_HEARTBEAT))),
+    );
+    let categories = Arc::new(CategoryService::new(stores.categories));
+    let comments = Arc::new(CommentService::new(stores.comments, stores.boards.clone()));
+    let attachments = Arc::new(AttachmentService::new(
+        stores.attachments,
+        stores.boards,
+        files,
+        AttachmentLimits {
+            max_bytes: config.attachment_max_bytes,
+            allowed_types: config.attachment_allowed_types.clone(),
+        },
+    ));
+    let auth = Arc::new(AuthService::new(
+        stores.users,
+        config.jwt_secret.expose(),
+        config.jwt_ttl_secs,
+    ));
+    let health = Arc::new(Health::new(Duration::from_secs(1)));
+    let router = build_app(
+        &config,
+        AppState {
+            service: service.clone(),
+            categories,
+            comments,
+            attachments,
+            auth: auth.clone(),
+            pool: stores.pool,
+            health: health.clone(),
+        },
+    );
+
+    let mut app = TestApp {
+        router,
+        boards: service,
+        health,
+        auth,
+        board_cache,
+        token: String::new(),
+        attachment_dir,
+    };
+    app.token = login(&app, "tester").await;
+    app
+}
+
+/// 사용자를 등록하고 로그인하여 액세스 토큰을 반환합니다.
+pub async fn login(app: &TestApp, username: &str) -> String {
+    let credentials = json!({ "username": username, "password": "password123" });
+    let (status, _) = send_as(
+        app,
+        None,
+        Method::POST,
+        "/auth/register",
+        Some(credentials.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send_as(app, None, Method::POST, "/auth/login", Some(credentials)).await;
+    assert_eq!(status, StatusCode::OK);
+    body["access_token"].as_str().unwrap().to_string()
+}
+
+/// 기본 사용자의 토큰으로 요청을 보내고 상태 코드와 (JSON이라면) 본문을 반환합니다.
+pub async fn send(
+    app: &TestApp,
+    method: Method,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    send_as(app, Some(&app.token), method, uri, body).await
+}
+
+/// 지정한 토큰(없으면 익명)으로 요청을 보냅니다.
+pub async fn send_as(
+    app: &TestApp,
+    token: Option<&str>,
+    method: Method,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let (status, _, value) = request(app, token, method, uri, &[], body).await;
+    (status, value)
+}
+
+/// 추가 헤더와 함께 요청을 보내고 상태 코드, 응답 헤더, (JSON이라면) 본문을 반환합니다.
+pub async fn request(
+    app: &TestApp,
+    token: Option<&str>,
+    method: Method,
+    uri: &str,
+    headers: &[(header::HeaderName, &str)],
+    body: Option<Value>,
+) -> (StatusCode, HeaderMap, Value) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    for (name, value) in headers {
+        builder = builder.header(name, *value);
+    }
+    let request = match body {
+        Some(json) => builder
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, headers, value)
+}
+
+/// 게시글을 조회하여 현재 `ETag` 값을 반환합니다.
+pub async fn etag(app: &TestApp, id: i64) -> String {
+    let uri = format!("/boards/{id}");
+    let (status, headers, _) = request(app, None, Method::GET, &uri, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    headers[header::ETAG].to_str().unwrap().to_string()
+}
+
+/// 현재 `ETag`를 `If-Match`로 실어 기본 사용자로 게시글을 삭제합니다.
+pub async fn delete_board(app: &TestApp, id: i64) -> StatusCode {
+    let etag = etag(app, id).await;
+    let uri = format!("/boards/{id}");
+    let headers = [(header::IF_MATCH, etag.as_str())];
+    let (status, _, _) = request(app, Some(&app.token), Method::DELETE, &uri, &headers, None).await;
+    status
+}
+
+/// 게시글을 생성하고 생성된 ID를 반환합니다.
+pub async fn create(app: &TestApp, title: &str, content: &str) -> i64 {
+    let (status, body) = send(
+        app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": title, "content": content })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["id"].as_i64().unwrap()
+}
@@ -0,0 +1,166 @@
+//! 설정 로드 테스트
+//!
+//! 기본값 < 설정 파일 < 환경 변수 < 명령줄 인자 순서의 덮어쓰기, 검증 에러, 비밀 값 가림을 검증합니다.
+
+use clap::Parser;
+use oracle_test::config::{CliArgs, Config, ConfigError, DbBackend, LogFormat};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const SECRET: &str = "0123456789abcdef-test-secret";
+
+/// 임시 디렉터리에 설정 파일을 쓰고 경로를 반환합니다.
+fn write_file(extension: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("board-config-{}.{extension}", Uuid::new_v4()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn cli(args: &[&str]) -> CliArgs {
+    CliArgs::try_parse_from(std::iter::once("oracleTest").chain(args.iter().copied())).unwrap()
+}
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn layers_file_env_and_cli_in_order() {
+    let path = write_file(
+        "toml",
+        &format!(
+            r#"
+server_port = 9000
+db_backend = "sqlite"
+sqlite_path = "from-file.db"
+db_pool_max_size = 4
+jwt_secret = "{SECRET}"
+cors_allowed_origins = ["https://board.example.com/"]
+"#
+        ),
+    );
+    let config = Config::load_from(
+        &cli(&[
+            "--config",
+            path.to_str().unwrap(),
+            "--port",
+            "9200",
+            "--set",
+            "db_pool_max_size=8",
+        ]),
+        vars(&[
+            ("BOARD_SERVER_PORT", "9100"),
+            ("BOARD_SQLITE_PATH", "from-env.db"),
+            ("BOARD_DB_POOL_MAX_SIZE", "6"),
+            ("BOARD_ATTACHMENT_ALLOWED_TYPES", "Image/PNG, text/plain"),
+            ("RUST_LOG", "debug"),
+            ("UNRELATED", "ignored"),
+        ]),
+    )
+    .unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(config.db_backend, DbBackend::Sqlite);
+    assert_eq!(config.server_port, 9200);
+    assert_eq!(config.sqlite_path, "from-env.db");
+    assert_eq!(config.db_pool_max_size, 8);
+    assert_eq!(config.rust_log, "debug");
+    assert_eq!(config.attachment_allowed_types, ["image/png", "text/plain"]);
+    assert_eq!(config.cors_allowed_origins, ["https://board.example.com"]);
+    // 파일에도 환경 변수에도 없는 값은 기본값입니다.
+    assert_eq!(config.request_timeout_secs, 30);
+    assert_eq!(config.log_format, LogFormat::Text);
+}
+
+#[test]
+fn reads_yaml_file_named_by_env() {
+    let path = write_file(
+        "yaml",
+        &format!(
+            "db_backend: oracle\ndb_user: board\ndb_password: s3cret\nlog_format: json\njwt_secret: {SECRET}\n"
+        ),
+    );
+    let config = Config::load_from(
+        &CliArgs::default(),
+        vars(&[("BOARD_CONFIG", path.to_str().unwrap())]),
+    )
+    .unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(config.db_backend, DbBackend::Oracle);
+    assert_eq!(config.db_user, "board");
+    assert_eq!(config.db_password.expose(), "s3cret");
+    assert_eq!(config.log_format, LogFormat::Json);
+}
+
+#[test]
+fn reports_all_validation_problems_at_once() {
+    let err = Config::load_from(
+        &CliArgs::default(),
+        vars(&[
+            ("BOARD_DB_POOL_MAX_SIZE", "0"),
+            ("BOARD_CORS_ALLOWED_ORIGINS", "ftp://files.example.com"),
+            ("BOARD_BOARD_CACHE_CAPACITY", "0"),
+        ]),
+    )
+    .unwrap_err();
+
+    let ConfigError::Invalid(problems) = &err else {
+        panic!("검증 에러가 아닙니다: {err}");
+    };
+    let text = err.to_string();
+    // 기본 백엔드(Oracle)는 자격 증명이 필요하며, 하드코딩된 기본 계정은 없습니다.
+    assert!(text.contains("db_user"), "{text}");
+    assert!(text.contains("db_password"), "{text}");
+    assert!(text.contains("jwt_secret"), "{text}");
+    assert!(text.contains("db_pool_max_size"), "{text}");
+    assert!(text.contains("ftp://files.example.com"), "{text}");
+    assert!(text.contains("board_cache_capacity"), "{text}");
+    assert_eq!(problems.len(), 6);
+}
+
+#[test]
+fn rejects_bad_overrides_and_files_with_clear_errors() {
+    let err = Config::load_from(
+        &CliArgs::default(),
+        vars(&[("BOARD_SERVER_PORT", "eighty")]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("BOARD_SERVER_PORT"), "{err}");
+
+    let err =
+        Config::load_from(&CliArgs::default(), vars(&[("BOARD_SERVR_PORT", "80")])).unwrap_err();
+    assert!(err.to_string().contains("알 수 없는 설정 키"), "{err}");
+
+    let err = Config::load_from(&cli(&["--set", "db_pool_max_size"]), vars(&[])).unwrap_err();
+    assert!(err.to_string().contains("KEY=VALUE"), "{err}");
+
+    let path = write_file("toml", "server_prot = 80\n");
+    let err =
+        Config::load_from(&cli(&["--config", path.to_str().unwrap()]), vars(&[])).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(err, ConfigError::File { .. }), "{err}");
+    assert!(err.to_string().contains("server_prot"), "{err}");
+}
+
+#[test]
+fn debug_output_redacts_secrets() {
+    let config = Config::load_from(
+        &CliArgs::default(),
+        vars(&[
+            ("BOARD_DB_USER", "board"),
+            ("BOARD_DB_PASSWORD", "hunter2-password"),
+            ("BOARD_JWT_SECRET", SECRET),
+        ]),
+    )
+    .unwrap();
+
+    let debug = format!("{config:?}");
+    assert!(!debug.contains("hunter2-password"), "{debug}");
+    assert!(!debug.contains(SECRET), "{debug}");
+    assert!(debug.contains("[REDACTED]"), "{debug}");
+    assert!(debug.contains("board"), "{debug}");
+}
@@ -0,0 +1,270 @@
+//! 조회수, 좋아요, 인기 게시글 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고,
+//! 조회수 일괄 반영은 `TestApp::boards`로 직접 실행합니다.
+
+mod common;
+
+use axum::extract::ConnectInfo;
+use common::*;
+use std::net::SocketAddr;
+
+/// 로그인하지 않은 클라이언트로 좋아요/좋아요 취소를 보냅니다. (`addr`은 접속한 클라이언트 주소)
+async fn like_from(app: &TestApp, method: Method, id: i64, addr: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(format!("/boards/{id}/like"))
+        .extension(ConnectInfo(addr.parse::<SocketAddr>().unwrap()))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// 게시글의 조회수와 좋아요 수 (조회수를 올리지 않음)
+async fn engagement(app: &TestApp, id: i64) -> Value {
+    let uri = format!("/boards/{id}/engagement");
+    let (status, body) = send_as(app, None, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    body
+}
+
+/// 게시글 상세를 조회하고 조회 후의 조회수를 반환합니다.
+async fn view(app: &TestApp, id: i64) -> i64 {
+    let (status, _) = send_as(app, None, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    engagement(app, id).await["view_count"].as_i64().unwrap()
+}
+
+/// 인기 게시글 목록의 ID 순서와 응답 본문
+async fn popular(app: &TestApp, query: &str) -> (Vec<i64>, Value) {
+    let uri = format!("/boards/popular{query}");
+    let (status, body) = send_as(app, None, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let ids = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|board| board["id"].as_i64().unwrap())
+        .collect();
+    (ids, body)
+}
+
+#[tokio::test]
+async fn views_are_buffered_in_memory_and_flushed_in_batches() {
+    let app = app().await;
+    let id = create(&app, "조회수", "내용").await;
+    let other = create(&app, "다른 글", "내용").await;
+
+    // 반영 전에도 응답에는 모아 둔 조회수가 더해집니다.
+    assert_eq!(view(&app, id).await, 1);
+    assert_eq!(view(&app, id).await, 2);
+    assert_eq!(view(&app, other).await, 1);
+
+    // 304 응답도 조회로 셉니다.
+    let uri = format!("/boards/{id}");
+    let (_, headers, _) = request(&app, None, Method::GET, &uri, &[], None).await;
+    let etag = headers[header::ETAG].to_str().unwrap().to_string();
+    let (status, _, _) = request(
+        &app,
+        None,
+        Method::GET,
+        &uri,
+        &[(header::IF_NONE_MATCH, etag.as_str())],
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+
+    assert_eq!(app.boards.flush_views().await.unwrap(), 2);
+    assert_eq!(app.boards.flush_views().await.unwrap(), 0);
+    assert_eq!(view(&app, id).await, 5);
+    assert_eq!(app.boards.get_board(id).await.unwrap().view_count, 4);
+
+    // 조회수는 버전을 바꾸지 않으므로 ETag도 그대로입니다.
+    assert_eq!(
+        headers[header::ETAG],
+        request(&app, None, Method::GET, &uri, &[], None).await.1[header::ETAG]
+    );
+
+    // 상세와 목록에는 조회수/좋아요 수를 싣지 않습니다. (버전과 무관하게 바뀌므로 ETag가 붙는 본문에서 뺍니다)
+    let (_, body) = send_as(&app, None, Method::GET, &uri, None).await;
+    assert!(body.get("view_count").is_none());
+    assert!(body.get("like_count").is_none());
+    let (_, body) = send_as(&app, None, Method::GET, "/boards", None).await;
+    assert!(body["data"][0].get("view_count").is_none());
+    assert!(body["data"][0].get("like_count").is_none());
+
+    // 없는 게시글은 조회수를 모으지 않습니다.
+    let (status, _) = send_as(&app, None, Method::GET, "/boards/9999", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    app.boards.flush_views().await.unwrap();
+    assert_eq!(app.boards.flush_views().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn likes_are_idempotent_per_user_and_ip() {
+    let app = app().await;
+    let id = create(&app, "좋아요", "내용").await;
+    let uri = format!("/boards/{id}/like");
+
+    let (status, body) = send(&app, Method::POST, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        json!({ "board_id": id, "liked": true, "like_count": 1 })
+    );
+    let (_, body) = send(&app, Method::POST, &uri, None).await;
+    assert_eq!(body["like_count"], 1);
+
+    let other = login(&app, "other").await;
+    let (_, body) = send_as(&app, Some(&other), Method::POST, &uri, None).await;
+    assert_eq!(body["like_count"], 2);
+
+    // 로그인하지 않으면 IP(포트 제외)로 구분합니다.
+    let (status, body) = like_from(&app, Method::POST, id, "10.0.0.1:40001").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["like_count"], 3);
+    let (_, body) = like_from(&app, Method::POST, id, "10.0.0.1:40002").await;
+    assert_eq!(body["like_count"], 3);
+    let (_, body) = like_from(&app, Method::POST, id, "10.0.0.2:40001").await;
+    assert_eq!(body["like_count"], 4);
+
+    let (status, body) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        json!({ "board_id": id, "liked": false, "like_count": 3 })
+    );
+    let (status, body) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["like_count"], 3);
+    let (_, body) = like_from(&app, Method::DELETE, id, "10.0.0.2:50000").await;
+    assert_eq!(body["like_count"], 2);
+
+    assert_eq!(engagement(&app, id).await["like_count"], 2);
+
+    // 없는 게시글과 휴지통의 게시글에는 좋아요를 누를 수 없습니다.
+    let (status, _) = send(&app, Method::POST, "/boards/9999/like", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::POST, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn like_then_conditional_get_serves_fresh_counts() {
+    let app = app().await;
+    let id = create(&app, "좋아요 후 재검증", "내용").await;
+    let uri = format!("/boards/{id}");
+    let (_, headers, _) = request(&app, None, Method::GET, &uri, &[], None).await;
+    let etag = headers[header::ETAG].to_str().unwrap().to_string();
+    assert_eq!(engagement(&app, id).await["like_count"], 0);
+
+    let (status, _) = send(&app, Method::POST, &format!("{uri}/like"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 좋아요는 버전을 바꾸지 않으므로 게시글은 304이지만, 304로 재사용하는 본문에는 좋아요 수가 없고
+    // 최신 좋아요 수는 별도 엔드포인트에서 받습니다.
+    let (status, headers, _) = request(
+        &app,
+        None,
+        Method::GET,
+        &uri,
+        &[(header::IF_NONE_MATCH, etag.as_str())],
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(headers[header::ETAG], etag.as_str());
+    let body = engagement(&app, id).await;
+    assert_eq!(
+        body,
+        json!({ "board_id": id, "view_count": 2, "like_count": 1 })
+    );
+
+    // 조회수/좋아요 조회는 조회수를 올리지 않습니다.
+    assert_eq!(engagement(&app, id).await["view_count"], 2);
+    let (status, _) = send(&app, Method::GET, "/boards/9999/engagement", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn simultaneous_likes_from_one_client_count_once() {
+    let app = app().await;
+    let id = create(&app, "좋아요", "내용").await;
+
+    // 같은 클라이언트가 연달아 누른 좋아요가 동시에 도착해도 모두 성공하고 한 번만 셉니다.
+    let statuses = futures_util::future::join_all(
+        (0..8).map(|_| like_from(&app, Method::POST, id, "10.0.0.9:40000")),
+    )
+    .await;
+    assert!(statuses.iter().all(|(status, _)| *status == StatusCode::OK));
+    assert_eq!(engagement(&app, id).await["like_count"], 1);
+}
+
+#[tokio::test]
+async fn popular_boards_are_ranked_by_weighted_score_and_cached() {
+    let app = app().await;
+    let viewed = create(&app, "많이 본 글", "내용").await;
+    let liked = create(&app, "좋아요 받은 글", "내용").await;
+    let quiet = create(&app, "조용한 글", "내용").await;
+
+    for _ in 0..3 {
+        view(&app, viewed).await;
+    }
+    send(&app, Method::POST, &format!("/boards/{liked}/like"), None).await;
+    app.boards.flush_views().await.unwrap();
+
+    // 좋아요 1개(5점)가 조회 3회(3점)보다 앞서고, 활동이 없는 글은 빠집니다.
+    let (ids, body) = popular(&app, "").await;
+    assert_eq!(ids, [liked, viewed]);
+    assert_eq!(body["window"], "7d");
+    assert_eq!(body["data"][0]["score"], 5);
+    assert_eq!(body["data"][0]["window_likes"], 1);
+    assert_eq!(body["data"][1]["score"], 3);
+    assert_eq!(body["data"][1]["window_views"], 3);
+    assert_eq!(body["data"][1]["view_count"], 3);
+    assert!(!ids.contains(&quiet));
+
+    for _ in 0..3 {
+        view(&app, viewed).await;
+    }
+    app.boards.flush_views().await.unwrap();
+
+    // 같은 조건은 캐시된 결과를 그대로 반환합니다.
+    let (ids, _) = popular(&app, "?window=7d&limit=10").await;
+    assert_eq!(ids, [liked, viewed]);
+    // 조건이 다르면 새로 집계합니다.
+    let (ids, body) = popular(&app, "?window=1d&limit=1").await;
+    assert_eq!(ids, [viewed]);
+    assert_eq!(body["window"], "1d");
+    assert_eq!(body["data"][0]["score"], 6);
+}
+
+#[tokio::test]
+async fn popular_rejects_invalid_window_and_limit() {
+    let app = app().await;
+    for query in [
+        "window=0d",
+        "window=31d",
+        "window=7h",
+        "window=d",
+        "limit=0",
+        "limit=51",
+    ] {
+        let uri = format!("/boards/popular?{query}");
+        let (status, body) = send_as(&app, None, Method::GET, &uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+        assert!(body["error"].is_string(), "{query}");
+    }
+
+    let (ids, body) = popular(&app, "?window=30d&limit=50").await;
+    assert!(ids.is_empty());
+    assert_eq!(body["window"], "30d");
+}
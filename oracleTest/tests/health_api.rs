@@ -0,0 +1,49 @@
+//! 헬스 체크 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 liveness/readiness 응답을 검증합니다.
+
+mod common;
+
+use common::*;
+
+#[tokio::test]
+async fn healthz_reports_process_up() {
+    let app = app().await;
+
+    let (status, body) = send_as(&app, None, Method::GET, "/healthz", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+}
+
+#[tokio::test]
+async fn readyz_pings_database_and_reports_pool_state() {
+    let app = app().await;
+
+    let (status, body) = send_as(&app, None, Method::GET, "/readyz", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ready");
+    assert_eq!(body["shutting_down"], false);
+    assert_eq!(body["database"]["backend"], "sqlite");
+    assert_eq!(body["database"]["ok"], true);
+    assert!(body["database"]["latency_ms"].is_u64());
+    assert_eq!(body["database"]["error"], Value::Null);
+    assert_eq!(body["pool"]["max_size"], 1);
+    assert_eq!(body["pool"]["active_connections"], 0);
+}
+
+#[tokio::test]
+async fn readyz_fails_once_shutdown_starts() {
+    let app = app().await;
+    app.health.mark_shutting_down();
+
+    let (status, body) = send_as(&app, None, Method::GET, "/readyz", None).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "not_ready");
+    assert_eq!(body["shutting_down"], true);
+    // DB 자체는 정상이지만 종료 중이므로 준비 상태가 아닙니다.
+    assert_eq!(body["database"]["ok"], true);
+
+    // 생존 여부는 종료 중에도 성공합니다.
+    let (status, _) = send_as(&app, None, Method::GET, "/healthz", None).await;
+    assert_eq!(status, StatusCode::OK);
+}
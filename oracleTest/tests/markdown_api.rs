@@ -0,0 +1,194 @@
+//! 게시글 본문 형식(`plain`/`markdown`)과 정화된 HTML(`content_html`) 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성합니다.
+
+mod common;
+
+use common::*;
+
+/// 지정한 형식으로 게시글을 생성하고 생성 응답 본문을 반환합니다.
+async fn create_with_format(app: &TestApp, content: &str, format: Value) -> Value {
+    let (status, body) = send(
+        app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "형식", "content": content, "format": format })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    body
+}
+
+/// 게시글 상세 응답 본문
+async fn detail(app: &TestApp, id: i64) -> Value {
+    let (status, body) = send_as(app, None, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+/// 현재 `ETag`로 게시글을 수정합니다.
+async fn update(app: &TestApp, id: i64, body: Value) -> StatusCode {
+    let etag = etag(app, id).await;
+    let uri = format!("/boards/{id}");
+    let headers = [(header::IF_MATCH, etag.as_str())];
+    let (status, _, _) = request(
+        app,
+        Some(&app.token),
+        Method::PUT,
+        &uri,
+        &headers,
+        Some(body),
+    )
+    .await;
+    status
+}
+
+#[tokio::test]
+async fn markdown_is_rendered_to_html_alongside_raw_content() {
+    let app = app().await;
+    let content = "# 제목\n\n**굵게** 와 ~~취소~~, [링크](https://example.com)\n\n| a | b |\n|---|---|\n| 1 | 2 |";
+    let body = create_with_format(&app, content, json!("markdown")).await;
+    assert_eq!(body["format"], "markdown");
+    assert_eq!(body["content"], content);
+
+    let html = body["content_html"].as_str().unwrap();
+    assert!(html.contains("<h1>제목</h1>"), "{html}");
+    assert!(html.contains("<strong>굵게</strong>"), "{html}");
+    assert!(html.contains("<del>취소</del>"), "{html}");
+    assert!(html.contains("<td>1</td>"), "{html}");
+    assert!(
+        html.contains(
+            r#"<a href="https://example.com" rel="noopener noreferrer nofollow">링크</a>"#
+        ),
+        "{html}"
+    );
+
+    // 목록에도 같은 HTML이 실립니다.
+    let id = body["id"].as_i64().unwrap();
+    let (_, list) = send_as(&app, None, Method::GET, "/boards", None).await;
+    assert_eq!(list["data"][0]["id"], id);
+    assert_eq!(list["data"][0]["format"], "markdown");
+    assert_eq!(list["data"][0]["content_html"], html);
+    let (_, list) = send_as(&app, None, Method::GET, "/boards?cursor=", None).await;
+    assert_eq!(list["data"][0]["content_html"], html);
+}
+
+#[tokio::test]
+async fn markdown_html_is_sanitised_against_stored_xss() {
+    let app = app().await;
+    let content = concat!(
+        "<script>alert(1)</script>\n\n",
+        "<img src=\"x\" onerror=\"alert(2)\">\n\n",
+        "[클릭](javascript:alert(3)) <a href=\"data:text/html,hi\" onclick=\"alert(4)\">데이터</a>\n\n",
+        "<iframe src=\"https://evil.example\"></iframe><style>body{}</style>\n\n",
+        "<p style=\"color:red\" class=\"x\">스타일</p>\n\n",
+        "![그림](https://example.com/a.png \"제목\")",
+    );
+    let body = create_with_format(&app, content, json!("markdown")).await;
+    let html = body["content_html"].as_str().unwrap();
+    let lower = html.to_ascii_lowercase();
+    for forbidden in [
+        "<script",
+        "alert(1)",
+        "onerror",
+        "javascript:",
+        "data:",
+        "onclick",
+        "<iframe",
+        "<style",
+        "style=",
+        "class=",
+    ] {
+        assert!(!lower.contains(forbidden), "{forbidden}: {html}");
+    }
+    assert!(html.contains("<p>스타일</p>"), "{html}");
+    assert!(
+        html.contains(r#"<img src="https://example.com/a.png" alt="그림" title="제목">"#),
+        "{html}"
+    );
+    // 원문은 손대지 않고 그대로 돌려줍니다.
+    assert_eq!(body["content"], content);
+}
+
+#[tokio::test]
+async fn plain_content_is_escaped_and_is_the_default() {
+    let app = app().await;
+    let id = create(&app, "일반", "<b>굵게</b> & \"따옴표\"\n# 제목 아님").await;
+    let body = detail(&app, id).await;
+    assert_eq!(body["format"], "plain");
+    assert_eq!(
+        body["content_html"],
+        "&lt;b&gt;굵게&lt;/b&gt; &amp; &quot;따옴표&quot;<br>\n# 제목 아님"
+    );
+
+    // null도 기본 형식으로 취급합니다.
+    let body = create_with_format(&app, "*기울임 아님*", Value::Null).await;
+    assert_eq!(body["format"], "plain");
+    assert_eq!(body["content_html"], "*기울임 아님*");
+}
+
+#[tokio::test]
+async fn update_changes_or_keeps_the_format() {
+    let app = app().await;
+    let id = create(&app, "수정", "*내용*").await;
+
+    let status = update(
+        &app,
+        id,
+        json!({ "title": "수정", "content": "*내용*", "format": "Markdown" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let body = detail(&app, id).await;
+    assert_eq!(body["format"], "markdown");
+    assert_eq!(body["content_html"], "<p><em>내용</em></p>\n");
+
+    // 형식을 빼면 그대로 둡니다.
+    let status = update(&app, id, json!({ "title": "수정", "content": "`코드`" })).await;
+    assert_eq!(status, StatusCode::OK);
+    let body = detail(&app, id).await;
+    assert_eq!(body["format"], "markdown");
+    assert_eq!(body["content_html"], "<p><code>코드</code></p>\n");
+
+    // 이력으로 되돌려도 형식은 현재 값을 유지합니다.
+    let etag = etag(&app, id).await;
+    let uri = format!("/boards/{id}/revisions/1/revert");
+    let headers = [(header::IF_MATCH, etag.as_str())];
+    let (status, _, _) = request(&app, Some(&app.token), Method::POST, &uri, &headers, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let body = detail(&app, id).await;
+    assert_eq!(body["content"], "*내용*");
+    assert_eq!(body["format"], "markdown");
+
+    let status = update(
+        &app,
+        id,
+        json!({ "title": "수정", "content": "*내용*", "format": "plain" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail(&app, id).await["content_html"], "*내용*");
+}
+
+#[tokio::test]
+async fn unknown_format_is_rejected() {
+    let app = app().await;
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/boards",
+        Some(json!({ "title": "형식", "content": "내용", "format": "html" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let id = create(&app, "형식", "내용").await;
+    let status = update(
+        &app,
+        id,
+        json!({ "title": "형식", "content": "내용", "format": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(detail(&app, id).await["version"], 1);
+}
@@ -0,0 +1,62 @@
+//! 메트릭 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 `/metrics` 노출 형식을 검증합니다.
+
+mod common;
+
+use common::*;
+
+/// `/metrics`를 조회하여 Content-Type과 본문 텍스트를 반환합니다.
+async fn scrape(app: &TestApp) -> (String, String) {
+    let request = Request::builder()
+        .uri("/metrics")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (content_type, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn metrics_expose_requests_by_route_and_status() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let (status, _) = send(&app, Method::GET, &format!("/boards/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&app, Method::GET, "/no-such-route/123", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (content_type, body) = scrape(&app).await;
+    assert!(content_type.starts_with("text/plain; version=0.0.4"));
+    // 경로 파라미터가 아닌 라우트 패턴으로 묶이고, 매칭되지 않은 경로는 하나의 레이블로 모입니다.
+    assert!(body.contains(r#"http_requests_total{method="GET",route="/boards/:id",status="200"}"#));
+    assert!(body.contains(r#"http_requests_total{method="POST",route="/boards",status="201"}"#));
+    assert!(body.contains(r#"route="unmatched",status="404""#));
+    assert!(!body.contains("/no-such-route"));
+    assert!(body.contains(
+        r#"http_request_duration_seconds_bucket{method="GET",route="/boards/:id",status="200""#
+    ));
+}
+
+#[tokio::test]
+async fn metrics_expose_pool_blocking_and_process_stats() {
+    let app = app().await;
+    create(&app, "제목", "내용").await;
+
+    let (_, body) = scrape(&app).await;
+    for name in [
+        r#"db_pool_connections{state="idle"}"#,
+        r#"db_pool_connections{state="active"}"#,
+        "db_pool_checkout_wait_seconds_count",
+        "db_pool_checkout_timeouts_total",
+        "blocking_task_queue_seconds_count",
+        "process_resident_memory_bytes",
+    ] {
+        assert!(body.contains(name), "{name} 메트릭이 없습니다");
+    }
+}
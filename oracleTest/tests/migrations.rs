@@ -0,0 +1,185 @@
+//! 스키마 마이그레이션 테스트
+//!
+//! 인메모리 SQLite에서 up/down/baseline과 적용 기록 검증을, Oracle 스크립트는 문장 분리 결과를 확인합니다.
+
+use clap::Parser;
+use oracle_test::config::{CliArgs, Command, MigrateAction};
+use oracle_test::repositories::migrations::{
+    MigrationError, Migrator, ORACLE_MIGRATIONS, SQLITE_MIGRATIONS, split_statements,
+};
+use oracle_test::repositories::sqlite;
+use oracle_test::repositories::stores::DbPool;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use std::time::Duration;
+
+fn memory_pool() -> Pool<SqliteConnectionManager> {
+    sqlite::build_pool(sqlite::MEMORY_PATH, 1, Duration::from_secs(5)).unwrap()
+}
+
+fn table_exists(pool: &Pool<SqliteConnectionManager>, table: &str) -> bool {
+    pool.get()
+        .unwrap()
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |row| row.get::<_, i64>(0),
+        )
+        .unwrap()
+        == 1
+}
+
+fn applied_versions(migrator: &Migrator) -> Vec<u32> {
+    migrator
+        .status()
+        .unwrap()
+        .into_iter()
+        .filter(|status| status.applied_at.is_some())
+        .map(|status| status.version)
+        .collect()
+}
+
+#[test]
+fn up_and_down_are_tracked_and_repeatable() {
+    let pool = memory_pool();
+    let migrator = Migrator::new(DbPool::Sqlite(pool.clone()));
+    let latest = SQLITE_MIGRATIONS.last().unwrap().version;
+
+    assert!(applied_versions(&migrator).is_empty());
+    assert!(!table_exists(&pool, "BOARD"));
+
+    let applied = migrator.up(None).unwrap();
+    assert_eq!(applied.last(), Some(&latest));
+    assert!(table_exists(&pool, "BOARD"));
+    assert!(table_exists(&pool, "BOARD_REVISIONS"));
+    assert_eq!(applied_versions(&migrator), applied);
+
+    // 이미 적용된 버전은 다시 실행하지 않습니다.
+    assert!(migrator.up(None).unwrap().is_empty());
+
+    // 전부 되돌리면 테이블이 사라지고, 다시 적용할 수 있습니다.
+    let reverted = migrator.down(u32::MAX).unwrap();
+    assert_eq!(reverted.first(), Some(&latest));
+    assert_eq!(reverted.len(), applied.len());
+    assert!(!table_exists(&pool, "BOARD"));
+    assert!(applied_versions(&migrator).is_empty());
+    assert_eq!(migrator.up(None).unwrap(), applied);
+}
+
+#[test]
+fn rejects_modified_unknown_and_invalid_versions() {
+    let pool = memory_pool();
+    let migrator = Migrator::new(DbPool::Sqlite(pool.clone()));
+
+    assert!(matches!(
+        migrator.up(Some(999)),
+        Err(MigrationError::InvalidTarget(999))
+    ));
+
+    migrator.up(None).unwrap();
+    let execute = |sql: &str| pool.get().unwrap().execute(sql, []).unwrap();
+    execute("UPDATE SCHEMA_MIGRATIONS SET CHECKSUM = 'edited' WHERE VERSION = 1");
+    let err = migrator.up(None).unwrap_err();
+    assert!(
+        matches!(err, MigrationError::ChecksumMismatch { version: 1, .. }),
+        "{err}"
+    );
+    assert!(migrator.status().is_err());
+
+    execute("DELETE FROM SCHEMA_MIGRATIONS");
+    execute("INSERT INTO SCHEMA_MIGRATIONS (VERSION, NAME, CHECKSUM) VALUES (999, 'future', 'x')");
+    assert!(matches!(
+        migrator.down(1),
+        Err(MigrationError::UnknownVersion(999))
+    ));
+}
+
+#[test]
+fn baseline_records_without_running_scripts() {
+    let pool = memory_pool();
+    let migrator = Migrator::new(DbPool::Sqlite(pool.clone()));
+
+    assert_eq!(migrator.baseline(1).unwrap(), [1]);
+    assert!(!table_exists(&pool, "BOARD"));
+    assert!(applied_versions(&migrator).contains(&1));
+
+    // 이미 만들어 둔 v1 스키마 위에서는 그 뒤의 마이그레이션만 적용합니다.
+    pool.get()
+        .unwrap()
+        .execute_batch(SQLITE_MIGRATIONS[0].up)
+        .unwrap();
+    let applied = migrator.up(None).unwrap();
+    assert!(!applied.contains(&1));
+    assert_eq!(applied.len(), SQLITE_MIGRATIONS.len() - 1);
+    assert!(table_exists(&pool, "TAGS"));
+}
+
+#[test]
+fn init_schema_adopts_databases_created_before_migrations() {
+    // 마이그레이션 도입 전 스키마(추적 테이블 없음) 위에서도 초기 마이그레이션이 적용됩니다.
+    let pool = memory_pool();
+    pool.get()
+        .unwrap()
+        .execute_batch(SQLITE_MIGRATIONS[0].up)
+        .unwrap();
+    sqlite::init_schema(&pool).unwrap();
+    let migrator = Migrator::new(DbPool::Sqlite(pool));
+    assert_eq!(applied_versions(&migrator).len(), SQLITE_MIGRATIONS.len());
+}
+
+#[test]
+fn oracle_scripts_split_into_single_statements() {
+    let versions = |list: &[oracle_test::repositories::migrations::Migration]| {
+        list.iter().map(|m| (m.version, m.name)).collect::<Vec<_>>()
+    };
+    assert_eq!(versions(ORACLE_MIGRATIONS), versions(SQLITE_MIGRATIONS));
+
+    for migration in ORACLE_MIGRATIONS {
+        let up = split_statements(migration.up);
+        let down = split_statements(migration.down);
+        for statement in up.iter().chain(&down) {
+            assert!(!statement.contains(';'), "{statement}");
+            assert!(!statement.starts_with("--"), "{statement}");
+        }
+        // up에서 만든 테이블/시퀀스는 down에서 모두 지웁니다.
+        for kind in ["TABLE", "SEQUENCE"] {
+            let created = up
+                .iter()
+                .filter(|s| s.starts_with(&format!("CREATE {kind}")))
+                .count();
+            let dropped = down
+                .iter()
+                .filter(|s| s.starts_with(&format!("DROP {kind}")))
+                .count();
+            assert_eq!(created, dropped, "{} {kind}", migration.name);
+        }
+    }
+}
+
+#[test]
+fn parses_migrate_subcommands() {
+    let parse = |args: &[&str]| {
+        CliArgs::try_parse_from(std::iter::once("oracleTest").chain(args.iter().copied()))
+            .unwrap()
+            .command
+    };
+    assert_eq!(parse(&[]), None);
+    assert_eq!(
+        parse(&["--config", "board.toml", "migrate", "up", "--to", "1"]),
+        Some(Command::Migrate {
+            action: MigrateAction::Up { to: Some(1) }
+        })
+    );
+    assert_eq!(
+        parse(&["migrate", "down"]),
+        Some(Command::Migrate {
+            action: MigrateAction::Down { steps: 1 }
+        })
+    );
+    assert_eq!(
+        parse(&["migrate", "baseline", "1"]),
+        Some(Command::Migrate {
+            action: MigrateAction::Baseline { version: 1 }
+        })
+    );
+}
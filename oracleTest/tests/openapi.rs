@@ -0,0 +1,155 @@
+//! OpenAPI 문서 테스트
+//!
+//! `routes/mod.rs`에 등록된 라우트와 `/openapi.json`의 경로가 서로 어긋나면 실패합니다.
+
+mod common;
+
+use common::*;
+use std::collections::BTreeSet;
+
+/// 라우트 정의 파일 (문서와 비교할 기준)
+const ROUTES_SOURCE: &str = include_str!("../src/routes/mod.rs");
+
+/// `.route("경로", get(..).post(..))` 형태의 등록을 `(메서드, OpenAPI 경로)` 목록으로 읽습니다.
+fn registered_routes() -> BTreeSet<(String, String)> {
+    let source: String = ROUTES_SOURCE
+        .lines()
+        .map(|line| line.split(" //").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut routes = BTreeSet::new();
+    for segment in source.split(".route(").skip(1) {
+        let path = segment.split('"').nth(1).expect("라우트 경로 문자열");
+        // axum의 `:id`를 OpenAPI의 `{id}`로 바꿉니다.
+        let path = path
+            .split('/')
+            .map(|part| match part.strip_prefix(':') {
+                Some(name) => format!("{{{name}}}"),
+                None => part.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let handlers = segment.split(".merge(").next().unwrap_or_default();
+        for method in ["get", "post", "put", "delete", "patch"] {
+            let found = handlers
+                .match_indices(&format!("{method}("))
+                .any(|(index, _)| {
+                    index == 0
+                        || !handlers.as_bytes()[index - 1].is_ascii_alphanumeric()
+                            && handlers.as_bytes()[index - 1] != b'_'
+                });
+            if found {
+                routes.insert((method.to_string(), path.clone()));
+            }
+        }
+    }
+    routes
+}
+
+/// 문서에 있는 `(메서드, 경로)` 목록
+fn documented_routes(spec: &Value) -> BTreeSet<(String, String)> {
+    spec["paths"]
+        .as_object()
+        .expect("paths 객체")
+        .iter()
+        .flat_map(|(path, item)| {
+            item.as_object()
+                .unwrap()
+                .keys()
+                .filter(|key| *key != "parameters")
+                .map(move |method| (method.clone(), path.clone()))
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn serves_spec_and_swagger_ui() {
+    let app = app().await;
+
+    let (status, spec) = send(&app, Method::GET, "/openapi.json", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+    let schemas = &spec["components"]["schemas"];
+    for name in [
+        "BoardResponse",
+        "PaginationResponse",
+        "CreateBoardRequest",
+        "ErrorResponse",
+    ] {
+        assert!(schemas[name].is_object(), "{name} 스키마가 없습니다");
+    }
+    assert_eq!(
+        spec["components"]["securitySchemes"]["bearer_auth"]["scheme"],
+        "bearer"
+    );
+    assert_eq!(
+        spec["paths"]["/boards/{id}"]["put"]["responses"]["412"]["content"]["application/json"]["schema"]
+            ["$ref"],
+        "#/components/schemas/ErrorResponse"
+    );
+
+    let request = Request::builder()
+        .uri("/swagger-ui/")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let html = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(String::from_utf8_lossy(&html).contains("swagger-ui"));
+}
+
+#[tokio::test]
+async fn spec_matches_registered_routes() {
+    let app = app().await;
+    let (_, spec) = send(&app, Method::GET, "/openapi.json", None).await;
+
+    let registered = registered_routes();
+    let documented = documented_routes(&spec);
+    assert!(
+        registered.len() > 10,
+        "라우트를 읽지 못했습니다: {registered:?}"
+    );
+
+    let undocumented: Vec<_> = registered.difference(&documented).collect();
+    let stale: Vec<_> = documented.difference(&registered).collect();
+    assert!(
+        undocumented.is_empty(),
+        "문서에 없는 라우트: {undocumented:?} (routes/openapi.rs의 paths에 추가하세요)"
+    );
+    assert!(stale.is_empty(), "라우터에 없는 문서 경로: {stale:?}");
+}
+
+#[tokio::test]
+async fn every_documented_operation_is_routed() {
+    let app = app().await;
+    let (_, spec) = send(&app, Method::GET, "/openapi.json", None).await;
+
+    for (method, path) in documented_routes(&spec) {
+        let uri = path.replace("{id}", "1").replace("{rev}", "1");
+        let request = Request::builder()
+            .method(method.to_uppercase().as_str())
+            .uri(&uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = app.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        // 이벤트 스트림은 끝나지 않으므로 본문을 읽지 않습니다.
+        let streaming = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .is_some_and(|value| value.as_bytes().starts_with(b"text/event-stream"));
+        if streaming {
+            assert_eq!(status, StatusCode::OK, "{method} {uri}");
+            continue;
+        }
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        // 라우터에 없는 경로는 본문 없는 404, 메서드가 다르면 405입니다.
+        assert_ne!(status, StatusCode::METHOD_NOT_ALLOWED, "{method} {uri}");
+        assert!(
+            !(status == StatusCode::NOT_FOUND && body.is_empty()),
+            "{method} {uri}: 라우트가 없습니다"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! 요청 제한 테스트
+//!
+//! 클라이언트별 토큰 버킷 빈도 제한(429 + `Retry-After`)과 라우트별 요청 본문 크기 제한(413)을 검증합니다.
+
+mod common;
+
+use axum::extract::ConnectInfo;
+use axum::http::HeaderValue;
+use common::*;
+use oracle_test::config::Config;
+use oracle_test::middleware::rate_limit::RateLimiter;
+use oracle_test::routes::{AUTH_BODY_LIMIT, BOARD_BODY_LIMIT};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+#[test]
+fn token_bucket_allows_burst_then_refills() {
+    let limiter = RateLimiter::new(2, 3);
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        assert!(limiter.check_at("ip:1", start).is_ok());
+    }
+    let wait = limiter.check_at("ip:1", start).unwrap_err();
+    assert_eq!(wait, Duration::from_millis(500));
+    // 다른 클라이언트는 자기 버킷을 씁니다.
+    assert!(limiter.check_at("ip:2", start).is_ok());
+
+    // 초당 2개씩 채워지므로 0.5초 뒤에 하나를 더 쓸 수 있습니다.
+    let later = start + Duration::from_millis(500);
+    assert!(limiter.check_at("ip:1", later).is_ok());
+    assert!(limiter.check_at("ip:1", later).is_err());
+
+    // 오래 쉬어도 burst 이상은 쌓이지 않습니다.
+    let idle = start + Duration::from_secs(60);
+    for _ in 0..3 {
+        assert!(limiter.check_at("ip:1", idle).is_ok());
+    }
+    assert!(limiter.check_at("ip:1", idle).is_err());
+}
+
+/// 초당 1개, 최대 2개를 허용하는 빈도 제한을 켠 애플리케이션
+async fn limited() -> TestApp {
+    app_with(Config {
+        rate_limit_per_second: 1,
+        rate_limit_burst: 2,
+        ..test_config()
+    })
+    .await
+}
+
+/// 지정한 IP(와 토큰)에서 요청을 보내고 상태 코드와 응답 헤더를 반환합니다.
+async fn hit(router: &Router, ip: &str, token: Option<&str>, uri: &str) -> (StatusCode, HeaderMap) {
+    let addr: SocketAddr = format!("{ip}:40000").parse().unwrap();
+    let mut builder = Request::builder().uri(uri).extension(ConnectInfo(addr));
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let response = router
+        .clone()
+        .oneshot(builder.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    (response.status(), response.headers().clone())
+}
+
+#[tokio::test]
+async fn rate_limit_returns_429_with_retry_after() {
+    let app = limited().await;
+    let router = app.router.clone();
+
+    assert_eq!(
+        hit(&router, "10.0.0.1", None, "/boards").await.0,
+        StatusCode::OK
+    );
+    assert_eq!(
+        hit(&router, "10.0.0.1", None, "/boards").await.0,
+        StatusCode::OK
+    );
+
+    let request = Request::builder()
+        .uri("/boards")
+        .extension(ConnectInfo("10.0.0.1:40001".parse::<SocketAddr>().unwrap()))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(
+        response.headers().get(header::RETRY_AFTER),
+        Some(&HeaderValue::from_static("1"))
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["error"].is_string());
+
+    // 다른 IP와 운영용 엔드포인트는 영향을 받지 않습니다.
+    assert_eq!(
+        hit(&router, "10.0.0.2", None, "/boards").await.0,
+        StatusCode::OK
+    );
+    for uri in ["/healthz", "/readyz", "/metrics"] {
+        assert_eq!(
+            hit(&router, "10.0.0.1", None, uri).await.0,
+            StatusCode::OK,
+            "{uri}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn static_assets_do_not_consume_api_tokens() {
+    let dir = std::env::temp_dir().join(format!("board-static-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for file in ["index.html", "app.js", "app.css", "logo.png"] {
+        std::fs::write(dir.join(file), file).unwrap();
+    }
+    let app = app_with(Config {
+        rate_limit_per_second: 1,
+        rate_limit_burst: 2,
+        static_dir: dir.to_string_lossy().into_owned(),
+        ..test_config()
+    })
+    .await;
+    let router = app.router.clone();
+
+    // 페이지를 여러 번 열어 정적 파일을 버스트보다 많이 받아도 제한되지 않습니다.
+    for _ in 0..3 {
+        for uri in ["/", "/app.js", "/app.css", "/logo.png"] {
+            assert_eq!(
+                hit(&router, "10.0.0.20", None, uri).await.0,
+                StatusCode::OK,
+                "{uri}"
+            );
+        }
+    }
+
+    // 같은 클라이언트의 API 한도는 그대로 남아 있습니다.
+    for _ in 0..2 {
+        assert_eq!(
+            hit(&router, "10.0.0.20", None, "/boards").await.0,
+            StatusCode::OK
+        );
+    }
+    assert_eq!(
+        hit(&router, "10.0.0.20", None, "/boards").await.0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn authenticated_clients_are_limited_per_user() {
+    let app = limited().await;
+    // 접속 정보가 없는 요청은 같은 IP 버킷을 쓰므로, 두 번째 사용자는 라우터를 거치지 않고 발급받습니다.
+    app.auth.register("other", "password123").await.unwrap();
+    let other = app
+        .auth
+        .login("other", "password123")
+        .await
+        .unwrap()
+        .access_token;
+    let router = app.router.clone();
+
+    // 같은 IP라도 로그인 사용자는 사용자별 버킷을 씁니다.
+    for _ in 0..2 {
+        assert_eq!(
+            hit(&router, "10.0.0.9", None, "/boards").await.0,
+            StatusCode::OK
+        );
+    }
+    assert_eq!(
+        hit(&router, "10.0.0.9", None, "/boards").await.0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    let token = app.token.clone();
+    assert_eq!(
+        hit(&router, "10.0.0.9", Some(&token), "/boards").await.0,
+        StatusCode::OK
+    );
+
+    // 사용자 버킷은 IP가 바뀌어도 이어집니다.
+    assert_eq!(
+        hit(&router, "10.0.0.10", Some(&token), "/boards").await.0,
+        StatusCode::OK
+    );
+    assert_eq!(
+        hit(&router, "10.0.0.11", Some(&token), "/boards").await.0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    assert_eq!(
+        hit(&router, "10.0.0.11", Some(&other), "/boards").await.0,
+        StatusCode::OK
+    );
+
+    // 유효하지 않은 토큰은 IP로 제한됩니다.
+    assert_eq!(
+        hit(&router, "10.0.0.9", Some("not-a-token"), "/boards")
+            .await
+            .0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+/// 기본 사용자의 토큰으로 JSON 본문을 그대로 보내고 상태 코드를 반환합니다.
+async fn post_raw(app: &TestApp, uri: &str, body: String) -> StatusCode {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {}", app.token))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    app.router.clone().oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn oversized_bodies_return_413() {
+    let app = app().await;
+
+    let content = "a".repeat(BOARD_BODY_LIMIT);
+    let body = json!({ "title": "큰 글", "content": content }).to_string();
+    assert_eq!(
+        post_raw(&app, "/boards", body).await,
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+
+    let password = "a".repeat(AUTH_BODY_LIMIT);
+    let body = json!({ "username": "big", "password": password }).to_string();
+    assert_eq!(
+        post_raw(&app, "/auth/login", body).await,
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+
+    // 라우트 제한은 axum 기본 제한(2MB)보다 작지만, 제한 안쪽의 본문은 그대로 처리합니다.
+    let body = json!({ "title": "보통 글", "content": "a".repeat(1000) }).to_string();
+    assert_eq!(post_raw(&app, "/boards", body).await, StatusCode::CREATED);
+}
@@ -0,0 +1,157 @@
+//! 요청 추적 테스트
+//!
+//! `X-Request-Id` 생성/전파, 로그 헤더 가림, 블로킹 저장소 작업까지 이어지는 요청 span을 검증합니다.
+
+mod common;
+
+use axum::http::{HeaderName, HeaderValue};
+use common::*;
+use oracle_test::middleware::logging::HeaderRedaction;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing::Level;
+use uuid::Uuid;
+
+const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// 요청 ID 헤더를 붙여(또는 없이) 요청하고 응답의 요청 ID를 반환합니다.
+async fn echoed_request_id(app: &TestApp, request_id: Option<&str>) -> String {
+    let mut builder = Request::builder().uri("/healthz");
+    if let Some(id) = request_id {
+        builder = builder.header(&REQUEST_ID, id);
+    }
+    let response = app
+        .router
+        .clone()
+        .oneshot(builder.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    response.headers()[&REQUEST_ID]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn request_id_is_generated_or_propagated() {
+    let app = app().await;
+
+    let generated = echoed_request_id(&app, None).await;
+    assert!(Uuid::parse_str(&generated).is_ok(), "{generated}");
+    assert_ne!(echoed_request_id(&app, None).await, generated);
+
+    assert_eq!(
+        echoed_request_id(&app, Some("edge-7f3a.1")).await,
+        "edge-7f3a.1"
+    );
+
+    // 로그를 어지럽힐 수 있는 값은 이어받지 않고 새로 만듭니다.
+    let replaced = echoed_request_id(&app, Some("bad id\twith spaces")).await;
+    assert!(Uuid::parse_str(&replaced).is_ok(), "{replaced}");
+    let too_long = "a".repeat(200);
+    assert_ne!(echoed_request_id(&app, Some(&too_long)).await, too_long);
+
+    // 에러 응답에도 요청 ID가 붙습니다.
+    let (status, headers, _) = request(&app, None, Method::GET, "/boards/9999", &[], None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(headers.contains_key(&REQUEST_ID));
+}
+
+#[test]
+fn redaction_hides_credentials() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::AUTHORIZATION,
+        HeaderValue::from_static("Bearer secret-token"),
+    );
+    headers.insert(header::COOKIE, HeaderValue::from_static("session=abc"));
+    headers.insert("x-tenant-key", HeaderValue::from_static("tenant-secret"));
+    headers.insert(header::USER_AGENT, HeaderValue::from_static("curl/8"));
+
+    let redacted = HeaderRedaction::new(&["X-Tenant-Key".to_string()]).redact(&headers);
+    let value = |name: &str| {
+        redacted
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap()
+    };
+    assert_eq!(value("authorization"), "[REDACTED]");
+    assert_eq!(value("cookie"), "[REDACTED]");
+    assert_eq!(value("x-tenant-key"), "[REDACTED]");
+    assert_eq!(value("user-agent"), "curl/8");
+
+    let default = HeaderRedaction::default().redact(&headers);
+    assert!(default.contains(&("x-tenant-key".to_string(), "tenant-secret".to_string())));
+}
+
+/// 로그 출력을 모아 두는 버퍼
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn sql_logs_carry_the_request_span() {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_max_level(Level::DEBUG)
+        .with_writer(move || writer.clone())
+        .finish();
+    // 저장소 작업은 블로킹 스레드에서 실행되므로 스레드 로컬이 아닌 전역 구독자가 필요합니다.
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let app = app().await;
+    let id = create(&app, "추적", "내용").await;
+    // 생성 직후의 상세는 캐시되어 있으므로, 비워서 이 요청이 DB를 조회하게 합니다.
+    app.board_cache.clear();
+    let request = Request::builder()
+        .uri(format!("/boards/{id}"))
+        .header(&REQUEST_ID, "trace-me-42")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<Value> = output
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .filter(|line: &Value| line["span"]["request_id"] == "trace-me-42")
+        .collect();
+    let message = |line: &Value| line["fields"]["message"].as_str().unwrap_or("").to_string();
+
+    assert!(
+        lines
+            .iter()
+            .any(|line| message(line).starts_with("[Repo:SQLite][SQL]")),
+        "요청 ID가 붙은 SQL 로그가 없습니다:\n{output}"
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| message(line).starts_with("[Service]"))
+    );
+    let done = lines
+        .iter()
+        .find(|line| message(line) == "응답 완료")
+        .expect("응답 로그");
+    assert_eq!(done["span"]["route"], "/boards/:id");
+    assert_eq!(done["span"]["method"], "GET");
+    assert_eq!(done["fields"]["status"], "200 OK");
+
+    // 헤더는 디버그 로그에만 남고, 자격 증명 값은 가려집니다.
+    assert!(!output.contains(&app.token), "토큰이 로그에 남았습니다");
+}
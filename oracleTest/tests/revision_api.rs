@@ -0,0 +1,262 @@
+//! 게시글 수정 이력 API 통합 테스트
+//!
+//! 공용 헬퍼(`common`)로 인메모리 SQLite 기반 라우터를 구성하고 수정 이력 라우트를 검증합니다.
+
+mod common;
+
+use common::*;
+use futures_util::future::join_all;
+use oracle_test::models::board::{BoardUpdate, NewBoard};
+use oracle_test::repositories::{sqlite, stores::Stores};
+use std::time::Duration;
+
+/// 현재 `ETag`를 `If-Match`로 실어 게시글을 수정합니다.
+async fn update(app: &TestApp, id: i64, title: &str, content: &str) {
+    let etag = etag(app, id).await;
+    let (status, _, _) = request(
+        app,
+        Some(&app.token),
+        Method::PUT,
+        &format!("/boards/{id}"),
+        &[(header::IF_MATCH, etag.as_str())],
+        Some(json!({ "title": title, "content": content })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// 응답 본문의 이력 번호 목록
+fn revisions(body: &Value) -> Vec<i64> {
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|revision| revision["revision"].as_i64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn update_records_previous_content_as_revision() {
+    let app = app().await;
+    let id = create(&app, "첫 제목", "첫 내용").await;
+    update(&app, id, "둘째 제목", "둘째 내용").await;
+    update(&app, id, "셋째 제목", "셋째 내용").await;
+
+    let uri = format!("/boards/{id}/revisions");
+    let (status, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(revisions(&body), [2, 1]);
+    assert_eq!(body[0]["title"], "둘째 제목");
+    assert_eq!(body[1]["version"], 1);
+    assert!(body[0].get("content").is_none());
+
+    let (status, body) = send(&app, Method::GET, &format!("{uri}/1"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "첫 제목");
+    assert_eq!(body["content"], "첫 내용");
+
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/3"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/0"), None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = send(&app, Method::GET, "/boards/999/revisions", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn failed_update_does_not_record_revision() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    let token = Some(app.token.as_str());
+
+    let stale = [(header::IF_MATCH, "\"99\"")];
+    let body = json!({ "title": "새 제목", "content": "새 내용" });
+    let (status, _, _) = request(&app, token, Method::PUT, &uri, &stale, Some(body)).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (_, body) = send(&app, Method::GET, &format!("{uri}/revisions"), None).await;
+    assert!(revisions(&body).is_empty());
+}
+
+#[tokio::test]
+async fn concurrent_updates_with_same_etag_record_one_revision() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    let uri = format!("/boards/{id}");
+    let etag = etag(&app, id).await;
+
+    let statuses = join_all((0..8).map(|n| {
+        let router = app.router.clone();
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri(&uri)
+            .header(header::AUTHORIZATION, format!("Bearer {}", app.token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::IF_MATCH, &etag)
+            .body(Body::from(
+                json!({ "title": format!("제목 {n}"), "content": "새 내용" }).to_string(),
+            ))
+            .unwrap();
+        tokio::spawn(async move { router.oneshot(request).await.unwrap().status() })
+    }))
+    .await;
+    let statuses: Vec<StatusCode> = statuses.into_iter().map(Result::unwrap).collect();
+    assert_eq!(statuses.iter().filter(|s| **s == StatusCode::OK).count(), 1);
+    assert!(
+        statuses
+            .iter()
+            .all(|s| matches!(*s, StatusCode::OK | StatusCode::PRECONDITION_FAILED)),
+        "{statuses:?}"
+    );
+
+    let (_, body) = send(&app, Method::GET, &format!("{uri}/revisions"), None).await;
+    assert_eq!(revisions(&body), [1]);
+}
+
+#[tokio::test]
+async fn concurrent_updates_on_separate_connections_do_not_collide() {
+    // 인메모리 DB는 커넥션이 하나뿐이므로, 파일 DB에 커넥션 여러 개를 열어 실제로 동시에 수정합니다.
+    let path = std::env::temp_dir().join(format!("board-revision-{}.db", uuid::Uuid::new_v4()));
+    let pool = sqlite::build_pool(path.to_str().unwrap(), 4, Duration::from_secs(5)).unwrap();
+    sqlite::init_schema(&pool).unwrap();
+    let stores = Stores::sqlite(pool);
+    let author_id = stores
+        .users
+        .insert("writer".to_string(), "hash".to_string())
+        .await
+        .unwrap();
+    let board = NewBoard {
+        title: "제목".to_string(),
+        content: "내용".to_string(),
+        ..NewBoard::default()
+    };
+    let id = stores.boards.insert(board, author_id).await.unwrap();
+
+    for version in 1..=3 {
+        let results = join_all((0..4).map(|n| {
+            let boards = stores.boards.clone();
+            let changes = BoardUpdate {
+                title: format!("제목 {version}-{n}"),
+                content: "새 내용".to_string(),
+                ..BoardUpdate::default()
+            };
+            tokio::spawn(async move { boards.update(id, changes, version).await })
+        }))
+        .await;
+        let updated = results
+            .into_iter()
+            .map(|result| result.unwrap().unwrap())
+            .filter(|updated| *updated)
+            .count();
+        assert_eq!(updated, 1, "version={version}");
+    }
+
+    let numbers: Vec<i64> = stores
+        .boards
+        .find_revisions(id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|revision| revision.revision)
+        .collect();
+    assert_eq!(numbers, [3, 2, 1]);
+    drop(stores);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn diff_compares_lines_between_revisions() {
+    let app = app().await;
+    let id = create(&app, "제목", "하나\n둘\n셋").await;
+    update(&app, id, "제목", "하나\n둘!\n셋\n넷").await;
+    update(&app, id, "새 제목", "하나\n셋").await;
+
+    let uri = format!("/boards/{id}/revisions/diff?from=1&to=2");
+    let (status, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["from"], 1);
+    assert_eq!(body["to"], 2);
+    assert_eq!(body["title"]["changed"], false);
+    let lines: Vec<(String, String)> = body["lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|line| {
+            (
+                line["op"].as_str().unwrap().to_string(),
+                line["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    let expected = [
+        ("equal", "하나"),
+        ("delete", "둘"),
+        ("insert", "둘!"),
+        ("equal", "셋"),
+        ("insert", "넷"),
+    ];
+    assert_eq!(
+        lines,
+        expected.map(|(op, text)| (op.to_string(), text.to_string()))
+    );
+    assert_eq!(body["lines"][4]["old_line"], Value::Null);
+    assert_eq!(body["lines"][4]["new_line"], 4);
+
+    // `to`를 생략하면 현재 게시글과 비교합니다.
+    let uri = format!("/boards/{id}/revisions/diff?from=2");
+    let (status, body) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["to"], Value::Null);
+    assert_eq!(body["title"]["changed"], true);
+    assert_eq!(body["title"]["new"], "새 제목");
+
+    let uri = format!("/boards/{id}/revisions/diff?from=1&to=9");
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn revert_restores_revision_and_records_current_content() {
+    let app = app().await;
+    let id = create(&app, "원래 제목", "원래 내용").await;
+    update(&app, id, "바뀐 제목", "바뀐 내용").await;
+    let uri = format!("/boards/{id}/revisions/1/revert");
+
+    let (status, _) = send(&app, Method::POST, &uri, None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+    let other = login(&app, "intruder").await;
+    let (status, _) = send_as(&app, Some(&other), Method::POST, &uri, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let etag = etag(&app, id).await;
+    let if_match = [(header::IF_MATCH, etag.as_str())];
+    let token = Some(app.token.as_str());
+    let (status, headers, body) = request(&app, token, Method::POST, &uri, &if_match, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "원래 제목");
+    assert_eq!(body["content"], "원래 내용");
+    assert_eq!(headers[header::ETAG], "\"3\"");
+
+    let (_, body) = send(&app, Method::GET, &format!("/boards/{id}/revisions"), None).await;
+    assert_eq!(revisions(&body), [2, 1]);
+    assert_eq!(body[0]["title"], "바뀐 제목");
+
+    let uri = format!("/boards/{id}/revisions/5/revert");
+    let (status, _, _) = request(&app, token, Method::POST, &uri, &if_match, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn revisions_of_trashed_board_are_hidden() {
+    let app = app().await;
+    let id = create(&app, "제목", "내용").await;
+    update(&app, id, "새 제목", "새 내용").await;
+    assert_eq!(delete_board(&app, id).await, StatusCode::NO_CONTENT);
+
+    let uri = format!("/boards/{id}/revisions");
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/1"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,21 @@
+//! 우아한 종료 테스트
+//!
+//! 종료 시 처리 중인 블로킹 DB 작업을 기다리는 동작을 검증합니다.
+
+use oracle_test::common::metrics::spawn_blocking;
+use oracle_test::common::shutdown::{in_flight_blocking, wait_blocking_idle};
+use std::time::Duration;
+
+#[tokio::test]
+async fn waits_for_in_flight_blocking_work() {
+    assert!(wait_blocking_idle(Duration::from_millis(10)).await);
+
+    let task = spawn_blocking(|| std::thread::sleep(Duration::from_millis(300)));
+    assert_eq!(in_flight_blocking(), 1);
+
+    // 시간 안에 끝나지 않으면 `false`를 반환하고 작업은 계속 실행됩니다.
+    assert!(!wait_blocking_idle(Duration::from_millis(20)).await);
+    assert!(wait_blocking_idle(Duration::from_secs(5)).await);
+    assert_eq!(in_flight_blocking(), 0);
+    task.await.unwrap();
+}
@@ -0,0 +1,196 @@
+//! 정적 파일/보안 헤더 테스트
+//!
+//! 정적 디렉터리 서빙(캐시 헤더, 미리 압축된 파일, SPA 폴백), 응답 압축, 보안 헤더, CORS를 검증합니다.
+
+mod common;
+
+use common::*;
+use oracle_test::middleware::cors::cors_layer;
+use oracle_test::middleware::security_headers::DEFAULT_CONTENT_SECURITY_POLICY;
+use oracle_test::routes::static_files::static_files;
+use std::path::PathBuf;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// 헤더를 붙여 GET 요청을 보내고 응답을 반환합니다.
+async fn get(
+    router: &Router,
+    uri: &str,
+    headers: &[(header::HeaderName, &str)],
+) -> axum::response::Response {
+    let mut builder = Request::builder().uri(uri);
+    for (name, value) in headers {
+        builder = builder.header(name, *value);
+    }
+    router
+        .clone()
+        .oneshot(builder.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+fn header_value(response: &axum::response::Response, name: header::HeaderName) -> &str {
+    response
+        .headers()
+        .get(name)
+        .map(|value| value.to_str().unwrap())
+        .unwrap_or_default()
+}
+
+/// `index.html`, `app.js`와 미리 압축한 `app.js.gz`가 있는 임시 정적 디렉터리
+struct AssetDir(PathBuf);
+
+impl AssetDir {
+    fn new() -> Self {
+        let dir = std::env::temp_dir().join(format!("board-static-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("index.html"),
+            "<!doctype html><title>board</title>",
+        )
+        .unwrap();
+        std::fs::write(dir.join("app.js"), "console.log('board');").unwrap();
+        std::fs::write(dir.join("app.js.gz"), b"pretend-gzip").unwrap();
+        Self(dir)
+    }
+}
+
+impl Drop for AssetDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[tokio::test]
+async fn serves_assets_with_cache_headers_and_precompressed_files() {
+    let assets = AssetDir::new();
+    let router = static_files(&assets.0, Duration::from_secs(120));
+
+    let response = get(&router, "/app.js", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL),
+        "public, max-age=120"
+    );
+    assert!(header_value(&response, header::CONTENT_TYPE).contains("javascript"));
+    let last_modified = header_value(&response, header::LAST_MODIFIED).to_string();
+
+    // gzip을 받는 클라이언트에는 미리 압축해 둔 파일을 그대로 보냅니다.
+    let response = get(&router, "/app.js", &[(header::ACCEPT_ENCODING, "gzip")]).await;
+    assert_eq!(header_value(&response, header::CONTENT_ENCODING), "gzip");
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(&body[..], b"pretend-gzip");
+
+    // HTML은 배포 직후 바로 바뀌도록 매번 다시 확인합니다.
+    let response = get(&router, "/", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(header_value(&response, header::CACHE_CONTROL), "no-cache");
+
+    let response = get(
+        &router,
+        "/app.js",
+        &[(header::IF_MODIFIED_SINCE, &last_modified)],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn html_navigation_falls_back_to_index() {
+    let app = app().await;
+    let html = [(header::ACCEPT, "text/html,application/xhtml+xml")];
+
+    let response = get(&app.router, "/boards/view/3", &html).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(header_value(&response, header::CONTENT_TYPE).starts_with("text/html"));
+    assert_eq!(header_value(&response, header::CACHE_CONTROL), "no-cache");
+
+    // API 클라이언트와 없는 자산 파일은 404입니다.
+    for (uri, headers) in [
+        ("/boards/view/3", &[][..]),
+        ("/assets/missing.js", &html[..]),
+    ] {
+        let response = get(&app.router, uri, headers).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{uri}");
+    }
+    let (status, _) = send(&app, Method::POST, "/no/such/route", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn responses_carry_security_headers_and_are_compressed() {
+    let app = app().await;
+    for _ in 0..5 {
+        create(&app, "압축", &"본문 ".repeat(50)).await;
+    }
+
+    let response = get(
+        &app.router,
+        "/boards",
+        &[(header::ACCEPT_ENCODING, "br, gzip")],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(header_value(&response, header::CONTENT_ENCODING), "br");
+
+    for uri in ["/", "/boards/9999", "/healthz"] {
+        let response = get(&app.router, uri, &[]).await;
+        assert_eq!(
+            header_value(&response, header::X_CONTENT_TYPE_OPTIONS),
+            "nosniff",
+            "{uri}"
+        );
+        assert_eq!(header_value(&response, header::X_FRAME_OPTIONS), "DENY");
+        assert_eq!(
+            header_value(&response, header::CONTENT_SECURITY_POLICY),
+            DEFAULT_CONTENT_SECURITY_POLICY
+        );
+    }
+}
+
+#[tokio::test]
+async fn cors_allows_configured_frontend_origin() {
+    let app = app().await;
+    let router = app
+        .router
+        .clone()
+        .layer(cors_layer(&["https://front.example.com".to_string()]).unwrap());
+
+    let preflight = Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/boards/1")
+        .header(header::ORIGIN, "https://front.example.com")
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "PUT")
+        .header(
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            "authorization,if-match,x-request-id",
+        )
+        .body(Body::empty())
+        .unwrap();
+    let response = router.clone().oneshot(preflight).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_value(&response, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+        "https://front.example.com"
+    );
+    assert!(header_value(&response, header::ACCESS_CONTROL_ALLOW_METHODS).contains("PUT"));
+
+    let origin = [(header::ORIGIN, "https://front.example.com")];
+    let response = get(&router, "/boards", &origin).await;
+    assert!(
+        header_value(&response, header::ACCESS_CONTROL_EXPOSE_HEADERS).contains("x-request-id")
+    );
+
+    // 허용하지 않은 출처에는 CORS 헤더를 붙이지 않습니다.
+    let response = get(
+        &router,
+        "/boards",
+        &[(header::ORIGIN, "https://evil.example.com")],
+    )
+    .await;
+    assert!(
+        !response
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+    );
+}